@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One entry of the route manifest (GET /api/routes)
+ */
+export type ApiRoute = { 
+/**
+ * HTTP method, e.g. "GET"
+ */
+method: string, 
+/**
+ * Path pattern; `{name}` marks a path parameter
+ */
+path: string, 
+/**
+ * Whether the route upgrades to a WebSocket
+ */
+websocket: boolean, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Add or remove one user in a channel's DM allowlist
+ */
+export type ChannelAllowlistRequest = { 
+/**
+ * "add" or "remove"
+ */
+action: string, 
+/**
+ * Telegram/Discord numeric ID, Slack member ID, or WhatsApp phone number
+ */
+user_id: string, };
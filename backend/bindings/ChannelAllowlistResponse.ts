@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A channel's allowlist after an update
+ */
+export type ChannelAllowlistResponse = { platform: string, allowed_users: Array<string>, 
+/**
+ * False when the user was already (or was never) on the list
+ */
+changed: boolean, };
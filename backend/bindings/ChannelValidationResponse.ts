@@ -3,4 +3,12 @@
 /**
  * Channel token validation response
  */
-export type ChannelValidationResponse = { valid: boolean, error: string | null, bot_name: string | null, bot_username: string | null, };
+export type ChannelValidationResponse = { valid: boolean, error: string | null, bot_name: string | null, bot_username: string | null, 
+/**
+ * Scopes the bot still needs (Slack)
+ */
+missing_scopes?: Array<string>, 
+/**
+ * Follow-up advice, e.g. how to grant missing permissions
+ */
+hint?: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChannelValidationResponse } from "./ChannelValidationResponse";
+
+/**
+ * Validation result for one configured channel
+ */
+export type ChannelValidationResult = { platform: string, result: ChannelValidationResponse, };
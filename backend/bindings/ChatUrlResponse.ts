@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Gateway chat/dashboard URL for the "Open Chat" button
+ */
+export type ChatUrlResponse = { 
+/**
+ * URL to open; carries `#token=...` when token auth is configured
+ */
+url: string, 
+/**
+ * Gateway auth mode ("token", "password", "none", ...)
+ */
+auth_mode: string, 
+/**
+ * Gateway token when `auth_mode` is "token"
+ */
+token: string | null, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A place an OpenClaw config can live
+ */
+export type ConfigLocation = { path: string, 
+/**
+ * "env" (OPENCLAW_CONFIG_PATH), "state-dir" (OPENCLAW_STATE_DIR),
+ * "default" (~/.openclaw), "wizard" (the wizard's saved settings) or
+ * "working-dir" (./openclaw.json)
+ */
+source: string, exists: boolean, 
+/**
+ * Whether this is the file the gateway loads
+ */
+active: boolean, };
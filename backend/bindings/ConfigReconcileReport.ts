@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Comparison of the wizard's saved settings with the gateway config
+ */
+export type ConfigReconcileReport = { 
+/**
+ * The wizard's saved settings (`config_dir/openclaw.json`)
+ */
+wizard_path: string, wizard_exists: boolean, 
+/**
+ * The config the gateway loads
+ */
+gateway_path: string, gateway_exists: boolean, 
+/**
+ * Differences found; secret values are never included
+ */
+divergence: Array<string>, 
+/**
+ * Whether the gateway config already carries the wizard's settings
+ */
+in_sync: boolean, 
+/**
+ * Set after a sync: whether the gateway config was rewritten
+ */
+synced?: boolean, };
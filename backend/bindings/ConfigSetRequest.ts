@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request to set one gateway config key through `openclaw config set`
+ */
+export type ConfigSetRequest = { 
+/**
+ * Dotted key path, e.g. "gateway.port" or "channels.telegram.enabled"
+ */
+path: string, value: any, };
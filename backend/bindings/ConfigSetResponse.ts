@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Value of a config key after it was set
+ */
+export type ConfigSetResponse = { path: string, 
+/**
+ * Value read back from the gateway config; secrets are masked
+ */
+value: any, };
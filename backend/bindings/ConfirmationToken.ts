@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DestructiveAction } from "./DestructiveAction";
+
+/**
+ * Single-use token authorizing one destructive action.
+ * Send it back in the `X-Confirm-Token` header.
+ */
+export type ConfirmationToken = { token: string, action: DestructiveAction, target: string | null, expires_in_secs: bigint, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DestructiveAction } from "./DestructiveAction";
+
+/**
+ * Query for GET /api/confirm
+ */
+export type ConfirmationTokenRequest = { action: DestructiveAction, 
+/**
+ * What the action applies to (container id, skill name, server id, PID);
+ * omitted for actions without a target
+ */
+target?: string, };
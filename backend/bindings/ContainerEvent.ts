@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ContainerStatus } from "./ContainerStatus";
+
+/**
+ * State change of a managed container, reported by the Docker events stream
+ */
+export type ContainerEvent = { container_id: string, name: string | null, 
+/**
+ * Docker event action ("start", "die", "oom", "destroy", ...)
+ */
+action: string, 
+/**
+ * State after the event; None once the container has been removed
+ */
+status: ContainerStatus | null, 
+/**
+ * Exit code reported with a "die" event
+ */
+exit_code: number | null, 
+/**
+ * When the daemon emitted the event (RFC 3339)
+ */
+time: string | null, };
@@ -4,4 +4,12 @@ import type { ContainerStatus } from "./ContainerStatus";
 /**
  * Information about a managed Docker container
  */
-export type ContainerInfo = { id: string, name: string, image: string, status: ContainerStatus, created_at: string, port: number | null, };
+export type ContainerInfo = { id: string, name: string, image: string, status: ContainerStatus, created_at: string, 
+/**
+ * Host port mapped to the container's app port
+ */
+port: number | null, 
+/**
+ * App port inside the container (default 3000)
+ */
+container_port: number, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ContainerStatus } from "./ContainerStatus";
+
+/**
+ * Query options for listing managed containers
+ */
+export type ContainerListQuery = { 
+/**
+ * Only return containers in this state
+ */
+status?: ContainerStatus, 
+/**
+ * Include exited containers (default true)
+ */
+all?: boolean, 
+/**
+ * "running" (running first, then newest; default), "newest" or "oldest"
+ */
+sort?: string, };
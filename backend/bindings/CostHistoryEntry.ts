@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One persisted cost analysis, kept for charting costs over time
+ */
+export type CostHistoryEntry = { analysis_date: string, 
+/**
+ * Unix timestamp (seconds) when the analysis was recorded
+ */
+recorded_at: bigint, total_current_monthly: number, total_recommended_monthly: number, total_savings_monthly: number, recommendation_count: number, };
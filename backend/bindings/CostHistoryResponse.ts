@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CostHistoryEntry } from "./CostHistoryEntry";
+
+/**
+ * Cost analysis history, oldest first
+ */
+export type CostHistoryResponse = { entries: Array<CostHistoryEntry>, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Response from POST /api/dashboard/auth/rotate.
+ * The credential is only ever returned here; it is not logged.
+ */
+export type CredentialRotationResponse = { credential: string, auth_mode: string, 
+/**
+ * False when the config was updated but the gateway restart failed
+ */
+gateway_restarted: boolean, message: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DeploymentRecord } from "./DeploymentRecord";
+
+/**
+ * Past multi-server deployments, oldest first
+ */
+export type DeploymentHistoryResponse = { deployments: Array<DeploymentRecord>, };
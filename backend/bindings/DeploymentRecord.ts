@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ServerDeployResult } from "./ServerDeployResult";
+
+/**
+ * One recorded multi-server deployment run
+ */
+export type DeploymentRecord = { 
+/**
+ * Unix timestamp (seconds) when the deployment started
+ */
+started_at: bigint, 
+/**
+ * Unix timestamp (seconds) when the last server finished
+ */
+finished_at: bigint, 
+/**
+ * Servers requested for this run
+ */
+server_ids: Array<string>, results: Array<ServerDeployResult>, };
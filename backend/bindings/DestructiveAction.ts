@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Irreversible actions that require a confirmation token
+ */
+export type DestructiveAction = "RemoveContainer" | "UninstallSkill" | "RollbackInstallation" | "RollbackServer" | "RollbackServers" | "WeakenGatewayAccess" | "KillProcess" | "PruneContainers" | "UninstallOpenClaw";
@@ -7,4 +7,16 @@ export type DockerCreateRequest = { name: string,
 /**
  * Docker image to use; defaults to "node:20-alpine" if None
  */
-image: string | null, };
+image: string | null, 
+/**
+ * Remove exited/created sandbox containers before enforcing the container limit
+ */
+prune_exited: boolean, 
+/**
+ * Port the app listens on inside the container (default 3000)
+ */
+container_port?: number, 
+/**
+ * Mount the root filesystem read-only, with tmpfs for the writable paths
+ */
+readonly: boolean, };
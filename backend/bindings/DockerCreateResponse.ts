@@ -3,4 +3,24 @@
 /**
  * Response after creating a Docker container
  */
-export type DockerCreateResponse = { success: boolean, container_id: string | null, port: number | null, error: string | null, };
+export type DockerCreateResponse = { success: boolean, container_id: string | null, 
+/**
+ * Host port mapped to `container_port`
+ */
+port: number | null, 
+/**
+ * App port inside the container the host port maps to
+ */
+container_port: number, 
+/**
+ * Number of stale containers pruned before creation
+ */
+pruned: number, error: string | null, 
+/**
+ * Set when the image has no variant for the Docker host's architecture
+ */
+arch_warning?: string, 
+/**
+ * What a read-only sandbox can't do, set when `readonly` was requested
+ */
+readonly_note?: string, };
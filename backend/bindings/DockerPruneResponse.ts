@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of pruning stale sandbox containers
+ */
+export type DockerPruneResponse = { pruned: number, container_ids: Array<string>, };
@@ -4,4 +4,24 @@ import type { ContainerInfo } from "./ContainerInfo";
 /**
  * Docker daemon status and running containers
  */
-export type DockerStatusResponse = { available: boolean, version: string | null, containers: Array<ContainerInfo>, error: string | null, };
+export type DockerStatusResponse = { available: boolean, version: string | null, containers: Array<ContainerInfo>, 
+/**
+ * Docker endpoint in use (DOCKER_HOST, rootful or rootless socket)
+ */
+socket: string | null, 
+/**
+ * Why Docker is unavailable, or why listing failed while `available` is true
+ */
+error: string | null, 
+/**
+ * Managed containers that count against the limit
+ */
+current_count: number, 
+/**
+ * Container limit (`OPENCLAW_MAX_CONTAINERS`, default 5)
+ */
+max_containers: number, 
+/**
+ * Docker API version negotiated with the daemon, e.g. "1.43"
+ */
+api_version?: string, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One VirusTotal engine's malicious or suspicious verdict
+ */
+export type EngineDetection = { engine: string, 
+/**
+ * "malicious" or "suspicious"
+ */
+category: string, 
+/**
+ * Engine's signature name, e.g. "Trojan.JS.Agent"
+ */
+result: string | null, };
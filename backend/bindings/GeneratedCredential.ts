@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Response from GET /api/wizard/generate-credential
+ */
+export type GeneratedCredential = { credential: string, length: number, };
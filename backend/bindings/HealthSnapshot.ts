@@ -4,4 +4,31 @@ import type { ChannelHealth } from "./ChannelHealth";
 /**
  * HealthSnapshot - returned by health endpoint
  */
-export type HealthSnapshot = { gateway_reachable: boolean, gateway_mode: string, channels: Array<ChannelHealth>, session_count: number, probe_duration_ms: number, };
+export type HealthSnapshot = { gateway_reachable: boolean, gateway_mode: string, 
+/**
+ * "healthy", "unauthorized" (gateway is up but rejected the probe's
+ * credentials) or "unreachable"
+ */
+gateway_status: string, 
+/**
+ * Gateway auth mode from openclaw.json ("none", "token", "password")
+ */
+auth_mode: string, 
+/**
+ * Why the gateway isn't healthy, when known
+ */
+gateway_detail?: string, 
+/**
+ * Port the running gateway process is listening on, when it can be found
+ */
+listening_port?: number, 
+/**
+ * Set when the running gateway isn't listening on the configured port,
+ * with a hint to restart it
+ */
+port_mismatch?: string, 
+/**
+ * How the snapshot was produced: "gateway" (the gateway's own health
+ * endpoint), "cli" (`openclaw health`) or "inferred" (config and port)
+ */
+source?: string, channels: Array<ChannelHealth>, session_count: number, probe_duration_ms: number, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Liveness status - returned by /api/health and /api/health/live
+ */
+export type HealthStatus = { 
+/**
+ * Outbound network calls are disabled (`--offline`)
+ */
+offline: boolean, };
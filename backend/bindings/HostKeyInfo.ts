@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A single host key offered by a server
+ */
+export type HostKeyInfo = { 
+/**
+ * Key algorithm, e.g. "ssh-ed25519"
+ */
+key_type: string, 
+/**
+ * OpenSSH SHA256 fingerprint, e.g. "SHA256:abc..."
+ */
+fingerprint: string, };
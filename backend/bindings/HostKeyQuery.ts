@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Query for fetching a server's SSH host key
+ */
+export type HostKeyQuery = { host: string, 
+/**
+ * SSH port (default 22)
+ */
+port?: number, };
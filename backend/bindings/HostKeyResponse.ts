@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HostKeyInfo } from "./HostKeyInfo";
+
+/**
+ * Host keys offered by a server, for user confirmation before trusting it
+ */
+export type HostKeyResponse = { host: string, port: number, keys: Array<HostKeyInfo>, 
+/**
+ * Whether the host already has an entry in ~/.ssh/known_hosts
+ */
+known: boolean, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request to cancel a running install
+ */
+export type InstallCancelRequest = { 
+/**
+ * Install to cancel, as reported by `install-started`
+ */
+install_id: string, };
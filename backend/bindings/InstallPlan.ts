@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InstallPlanStage } from "./InstallPlanStage";
+
+/**
+ * Ordered stages an install request will run
+ */
+export type InstallPlan = { stages: Array<InstallPlanStage>, };
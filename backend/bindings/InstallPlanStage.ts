@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One stage an install will run
+ */
+export type InstallPlanStage = { 
+/**
+ * Stage name used in `InstallProgress.stage`
+ */
+id: string, label: string, };
@@ -3,4 +3,16 @@
 /**
  * Installation progress update
  */
-export type InstallProgress = { stage: string, status: string, message: string, output_line: string | null, error: string | null, progress_pct: number | null, };
+export type InstallProgress = { stage: string, status: string, message: string, output_line: string | null, error: string | null, progress_pct: number | null, 
+/**
+ * Position of `stage` in the install plan, when it is one of its stages
+ */
+stage_index?: number, 
+/**
+ * Milliseconds since `stage` started; set on completed/failed updates
+ */
+elapsed_ms?: number, 
+/**
+ * Severity of `output_line`: "info", "warning" or "error"
+ */
+line_level?: string, };
@@ -3,4 +3,21 @@
 /**
  * Installation request
  */
-export type InstallRequest = { install_node: boolean, install_openclaw: boolean, };
+export type InstallRequest = { install_node: boolean, install_openclaw: boolean, 
+/**
+ * Client-chosen id used to cancel the install; generated when omitted
+ */
+install_id?: string, 
+/**
+ * Run `openclaw gateway install` (default true); turn off when the
+ * gateway runs under a service you manage yourself
+ */
+install_gateway_service?: boolean, 
+/**
+ * Run `openclaw gateway start` and wait for it to be healthy (default true)
+ */
+start_gateway?: boolean, 
+/**
+ * OpenClaw version or npm dist-tag to install; latest when omitted
+ */
+openclaw_version?: string, };
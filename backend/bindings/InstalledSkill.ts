@@ -1,6 +1,11 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VulnerabilitySummary } from "./VulnerabilitySummary";
 
 /**
  * Information about a locally installed skill
  */
-export type InstalledSkill = { name: string, version: string, path: string, size_bytes: bigint | null, };
+export type InstalledSkill = { name: string, version: string, path: string, size_bytes: bigint | null, 
+/**
+ * Known dependency vulnerabilities; only filled in when audit is requested
+ */
+vulnerabilities: VulnerabilitySummary | null, };
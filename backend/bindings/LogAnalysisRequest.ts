@@ -3,4 +3,13 @@
 /**
  * Request to analyze log context with AI
  */
-export type LogAnalysisRequest = { log_context: string, service: string, };
+export type LogAnalysisRequest = { 
+/**
+ * Log excerpt to analyze; when omitted or empty the server picks the
+ * most recent error cluster from the service's logs
+ */
+log_context?: string, service: string, 
+/**
+ * Model override for this analysis; falls back to the configured analysis model
+ */
+model?: string, };
@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DoctorReport } from "./DoctorReport";
+import type { LogAnalysis } from "./LogAnalysis";
+import type { LogLine } from "./LogLine";
+
+/**
+ * Combined incident diagnosis: recent errors, doctor checks and an analysis
+ */
+export type LogDiagnosis = { service: string, 
+/**
+ * Latest error cluster from the service's logs; empty when none was found
+ */
+error_lines: Array<LogLine>, doctor: DoctorReport, 
+/**
+ * Failing doctor checks that line up with the logged errors
+ */
+correlations: Array<string>, analysis: LogAnalysis, 
+/**
+ * Whether `analysis` came from the AI provider rather than the heuristics
+ */
+ai_analysis: boolean, };
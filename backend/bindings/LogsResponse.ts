@@ -4,4 +4,20 @@ import type { LogLine } from "./LogLine";
 /**
  * Response containing recent log lines
  */
-export type LogsResponse = { service: string, lines: Array<LogLine>, total: number, };
+export type LogsResponse = { service: string, lines: Array<LogLine>, total: number, 
+/**
+ * Log file the lines were read from; None if they came from `openclaw logs` or journald
+ */
+log_path: string | null, 
+/**
+ * Rotated files (oldest first) that contributed lines before `log_path`
+ */
+rotated_paths?: Array<string>, 
+/**
+ * Systemd unit whose journal the lines came from, when there was no log file
+ */
+journald_unit?: string, 
+/**
+ * Lines per level ("error", "warn", ...) across the requested window, before filtering
+ */
+level_counts: { [key in string]?: number }, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MetricsSample } from "./MetricsSample";
+
+/**
+ * Recent metrics samples, oldest first
+ */
+export type MetricsHistoryResponse = { 
+/**
+ * Seconds between consecutive samples
+ */
+interval_seconds: bigint, samples: Array<MetricsSample>, };
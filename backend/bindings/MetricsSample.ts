@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One point-in-time sample of system and gateway resource usage
+ */
+export type MetricsSample = { 
+/**
+ * Unix timestamp (seconds) when the sample was taken
+ */
+timestamp: bigint, system_cpu_percent: number, system_memory_used_mb: bigint, system_memory_total_mb: bigint, gateway_running: boolean, gateway_cpu_percent: number | null, gateway_memory_mb: bigint | null, };
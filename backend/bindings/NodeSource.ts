@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How the active Node.js was installed, judged from its path
+ */
+export type NodeSource = "Homebrew" | "Nvm" | "Volta" | "Fnm" | "Asdf" | "SystemPackage" | "Official" | "Unknown";
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Preview of the `openclaw onboard` invocation built from the saved config.
+ * Secret values (API keys, tokens) are masked.
+ */
+export type OnboardPreview = { program: string, args: Array<string>, 
+/**
+ * Shell-style command line, suitable for copy/paste after filling in secrets
+ */
+command: string, };
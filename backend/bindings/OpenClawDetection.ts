@@ -1,6 +1,28 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConfigLocation } from "./ConfigLocation";
 
 /**
  * OpenClaw detection result
  */
-export type OpenClawDetection = { installed: boolean, version: string | null, install_path: string | null, config_found: boolean, config_path: string | null, existing_config: Record<string, any> | null, };
+export type OpenClawDetection = { installed: boolean, version: string | null, install_path: string | null, config_found: boolean, config_path: string | null, existing_config: Record<string, any> | null, 
+/**
+ * Latest version published on npm, if the registry could be reached
+ */
+latest_version: string | null, 
+/**
+ * True when the installed version is older than `latest_version`
+ */
+update_available: boolean, 
+/**
+ * Every known config location, in the gateway's load precedence
+ */
+config_locations: Array<ConfigLocation>, 
+/**
+ * Config file the gateway loads (it may not exist yet)
+ */
+active_config_path?: string, 
+/**
+ * How the found configs disagree: ignored gateway configs, or wizard
+ * settings that differ from the loaded config; empty when consistent
+ */
+config_divergence: Array<string>, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A running openclaw-related process, listed so strays can be cleaned up
+ */
+export type OpenClawProcess = { pid: number, name: string, 
+/**
+ * Full command line, empty when it isn't readable
+ */
+cmdline: string, memory_mb: number, 
+/**
+ * Whether this looks like the gateway (killing it takes the bot offline)
+ */
+gateway: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A single readiness check (config, gateway)
+ */
+export type ReadinessCheck = { name: string, ok: boolean, message: string, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReadinessCheck } from "./ReadinessCheck";
+
+/**
+ * Readiness report - returned by /api/health/ready
+ */
+export type ReadinessReport = { 
+/**
+ * True when every check passed
+ */
+ready: boolean, checks: Array<ReadinessCheck>, };
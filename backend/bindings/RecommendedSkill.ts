@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SkillMetadata } from "./SkillMetadata";
+
+/**
+ * A curated skill suggested to new users
+ */
+export type RecommendedSkill = { name: string, 
+/**
+ * Why the skill is worth installing
+ */
+rationale: string, 
+/**
+ * Whether the entry comes from the bundled list vetted by the wizard
+ * maintainers; entries from a remote list are unverified
+ */
+verified: boolean, 
+/**
+ * Current registry metadata; None when the registry lookup failed or
+ * the wizard is offline
+ */
+details: SkillMetadata | null, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecommendedSkill } from "./RecommendedSkill";
+
+/**
+ * Response from the recommended skills endpoint
+ */
+export type RecommendedSkillsResponse = { skills: Array<RecommendedSkill>, 
+/**
+ * "bundled" or the URL the list was fetched from
+ */
+source: string, };
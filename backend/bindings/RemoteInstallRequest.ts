@@ -7,4 +7,13 @@ export type RemoteInstallRequest = { host: string, username: string,
 /**
  * Optional: override WizardConfig from saved config
  */
-config: Record<string, any> | null, };
+config: Record<string, any> | null, 
+/**
+ * Copy the local ~/.openclaw/openclaw.json verbatim instead of
+ * rebuilding the config from WizardConfig
+ */
+use_local_config?: boolean, 
+/**
+ * OpenClaw version or npm dist-tag to install; latest when omitted
+ */
+openclaw_version?: string, };
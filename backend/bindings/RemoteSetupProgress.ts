@@ -23,4 +23,8 @@ error: string | null,
 /**
  * Unix timestamp (seconds)
  */
-timestamp: bigint, };
+timestamp: bigint, 
+/**
+ * Milliseconds since `stage` started; set on completed/failed updates
+ */
+elapsed_ms?: number, };
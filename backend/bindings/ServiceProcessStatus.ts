@@ -3,4 +3,8 @@
 /**
  * Status of an individual service process (gateway or daemon)
  */
-export type ServiceProcessStatus = { running: boolean, pid: number | null, uptime_seconds: bigint | null, memory_mb: bigint | null, cpu_percent: number | null, };
+export type ServiceProcessStatus = { running: boolean, pid: number | null, uptime_seconds: bigint | null, memory_mb: bigint | null, cpu_percent: number | null, 
+/**
+ * Why metrics are missing, or why a busy gateway port isn't counted as the gateway
+ */
+note?: string, };
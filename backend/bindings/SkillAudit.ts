@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VulnerabilitySummary } from "./VulnerabilitySummary";
+
+/**
+ * Dependency audit result for an installed skill
+ */
+export type SkillAudit = { name: string, version: string, vulnerabilities: VulnerabilitySummary, 
+/**
+ * "npm-audit" when the skill's dependency tree was audited, or
+ * "advisory-api" when only the package itself could be checked
+ */
+source: string, };
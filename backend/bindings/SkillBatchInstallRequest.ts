@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SkillInstallRequest } from "./SkillInstallRequest";
+
+/**
+ * Request to install several skills in one go, in order
+ */
+export type SkillBatchInstallRequest = { skills: Array<SkillInstallRequest>, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SkillInstallResponse } from "./SkillInstallResponse";
+
+/**
+ * Outcome of one skill in a batch install
+ */
+export type SkillBatchItem = { name: string, version: string | null, 
+/**
+ * "installed", "blocked" (VirusTotal flagged it malicious) or "failed"
+ */
+status: string, error: string | null, 
+/**
+ * The install result, when the install ran
+ */
+response: SkillInstallResponse | null, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InstallProgress } from "./InstallProgress";
+
+/**
+ * Streamed progress of one skill in a batch install
+ */
+export type SkillBatchProgress = { 
+/**
+ * Zero-based position of the skill in the batch
+ */
+index: number, total: number, name: string, progress: InstallProgress, };
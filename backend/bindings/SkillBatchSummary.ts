@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SkillBatchItem } from "./SkillBatchItem";
+
+/**
+ * Result of a batch install
+ */
+export type SkillBatchSummary = { items: Array<SkillBatchItem>, installed: number, blocked: number, failed: number, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SkillUninstallItem } from "./SkillUninstallItem";
+
+/**
+ * Request to uninstall several skills
+ */
+export type SkillBatchUninstallRequest = { skills: Array<SkillUninstallItem>, };
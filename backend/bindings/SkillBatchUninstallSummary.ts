@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SkillUninstallResult } from "./SkillUninstallResult";
+
+/**
+ * Result of a batch uninstall
+ */
+export type SkillBatchUninstallSummary = { items: Array<SkillUninstallResult>, uninstalled: number, failed: number, };
@@ -7,4 +7,8 @@ export type SkillInstallRequest = { name: string,
 /**
  * Version to install; None = latest
  */
-version: string | null, };
+version: string | null, 
+/**
+ * Install even if VirusTotal reports the package as malicious
+ */
+force?: boolean, };
@@ -4,4 +4,9 @@ import type { ScanResult } from "./ScanResult";
 /**
  * Response after installing a skill
  */
-export type SkillInstallResponse = { success: boolean, name: string, version: string, error: string | null, scan_result: ScanResult | null, };
+export type SkillInstallResponse = { success: boolean, name: string, version: string, error: string | null, scan_result: ScanResult | null, 
+/**
+ * Security warning for an install that went ahead anyway, e.g. a
+ * forced install of a VT-flagged package
+ */
+warning?: string, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One skill of a batch uninstall with its confirmation token
+ */
+export type SkillUninstallItem = { name: string, 
+/**
+ * Token for `UninstallSkill` on this name from GET /api/confirm
+ */
+confirm_token: string, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of one skill in a batch uninstall
+ */
+export type SkillUninstallResult = { name: string, 
+/**
+ * "uninstalled" or "failed"
+ */
+status: string, error: string | null, };
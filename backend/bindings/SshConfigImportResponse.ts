@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ServerTarget } from "./ServerTarget";
+
+/**
+ * Result of POST /api/multi-server/import-ssh-config
+ */
+export type SshConfigImportResponse = { 
+/**
+ * Servers added to servers.json
+ */
+imported: Array<ServerTarget>, 
+/**
+ * Hosts not imported, each with the reason
+ */
+skipped: Array<string>, };
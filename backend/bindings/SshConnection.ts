@@ -7,5 +7,7 @@
  * - host: Must match `^[a-zA-Z0-9.-]+$` (hostname or IP)
  * - username: Must match `^[a-z_][a-z0-9_-]*$` (valid Unix username)
  * - key_path: Absolute path or `~/.ssh/id_*` pattern
+ *
+ * Enforced by `SshService::validate_ssh_request`.
  */
 export type SshConnection = { host: string, username: string, key_path: string, connected: boolean, error: string | null, };
@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Who the wizard is running as and which directories it resolves
+ */
+export type SystemIdentity = { 
+/**
+ * Effective user id (Unix only)
+ */
+uid: number | null, 
+/**
+ * Effective user name
+ */
+username: string | null, 
+/**
+ * Original user when launched via sudo (SUDO_USER)
+ */
+sudo_user: string | null, 
+/**
+ * HOME (or USERPROFILE) as seen by the wizard
+ */
+home: string | null, 
+/**
+ * Wizard config directory from `Platform::config_dir`
+ */
+config_dir: string | null, config_dir_writable: boolean, 
+/**
+ * OpenClaw's own directory (~/.openclaw)
+ */
+openclaw_dir: string | null, openclaw_dir_writable: boolean, };
@@ -1,6 +1,15 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NodeSource } from "./NodeSource";
 
 /**
  * System information structure
  */
-export type SystemInfo = { os: string, arch: string, node_version: string | null, openclaw_installed: boolean, };
+export type SystemInfo = { os: string, arch: string, node_version: string | null, 
+/**
+ * Resolved path of the `node` on PATH
+ */
+node_path?: string, 
+/**
+ * Which installer provided that `node`
+ */
+node_source?: NodeSource, openclaw_installed: boolean, };
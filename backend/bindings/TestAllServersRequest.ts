@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request to test connections to several servers at once
+ */
+export type TestAllServersRequest = { 
+/**
+ * Servers to test; all configured servers when omitted
+ */
+server_ids?: Array<string>, };
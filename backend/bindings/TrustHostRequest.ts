@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request to add a server's host key to ~/.ssh/known_hosts
+ */
+export type TrustHostRequest = { host: string, port?: number, 
+/**
+ * Fingerprint the user approved; the key is only added if it still matches
+ */
+fingerprint: string, };
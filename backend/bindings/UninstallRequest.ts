@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Payload of the "start-uninstall" WebSocket message
+ */
+export type UninstallRequest = { 
+/**
+ * Token for `UninstallOpenClaw` from GET /api/confirm. WebSockets
+ * can't carry the X-Confirm-Token header, so it goes in the payload.
+ */
+confirm_token?: string, };
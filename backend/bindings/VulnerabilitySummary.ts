@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Count of known vulnerabilities by npm advisory severity
+ */
+export type VulnerabilitySummary = { info: number, low: number, moderate: number, high: number, critical: number, total: number, };
@@ -4,7 +4,12 @@ import type { ChannelConfig } from "./ChannelConfig";
 /**
  * Wizard config to save
  */
-export type WizardConfig = { provider: string, api_key: string, 
+export type WizardConfig = { 
+/**
+ * Saved config layout version; older files are migrated on read
+ * (see `services::wizard_config`)
+ */
+schema_version?: number, provider: string, api_key: string, 
 /**
  * "api-key", "setup-token", "oauth", or "skip"
  */
@@ -20,4 +25,12 @@ account_id: string | null,
 /**
  * Cloudflare gateway ID
  */
-gateway_id: string | null, };
+gateway_id: string | null, 
+/**
+ * Directory containing OpenClaw service logs, if not in a default location
+ */
+log_dir?: string, 
+/**
+ * Model used for AI log and cost analysis; defaults per provider when unset
+ */
+analysis_model?: string, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Version and build metadata of the running wizard binary
+ */
+export type WizardVersion = { 
+/**
+ * Crate version (`CARGO_PKG_VERSION`)
+ */
+version: string, 
+/**
+ * Short git commit the binary was built from ("-dirty" with local
+ * changes, "unknown" without git)
+ */
+git_commit: string, 
+/**
+ * RFC 3339 build time
+ */
+build_timestamp: string, };
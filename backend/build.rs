@@ -0,0 +1,64 @@
+//! Embeds build metadata for `/api/system/wizard-version`.
+//!
+//! `WIZARD_GIT_COMMIT` and `WIZARD_BUILD_TIMESTAMP` (unix seconds) may be set
+//! by the packaging environment; otherwise they're taken from `git` and the
+//! clock. Builds from a source tarball without git report "unknown".
+
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-env-changed=WIZARD_GIT_COMMIT");
+    println!("cargo:rerun-if-env-changed=WIZARD_BUILD_TIMESTAMP");
+
+    // Rebuild when HEAD moves so the commit stays accurate
+    if let Some(git_dir) = git(&["rev-parse", "--git-dir"]) {
+        for path in ["HEAD", "refs/heads", "packed-refs"] {
+            let path = std::path::Path::new(&git_dir).join(path);
+            if path.exists() {
+                println!("cargo:rerun-if-changed={}", path.display());
+            }
+        }
+    }
+
+    let commit = std::env::var("WIZARD_GIT_COMMIT")
+        .ok()
+        .filter(|c| !c.is_empty())
+        .or_else(|| {
+            git(&["rev-parse", "--short=12", "HEAD"]).map(|commit| {
+                let dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+                    .is_some_and(|status| !status.is_empty());
+                if dirty {
+                    format!("{}-dirty", commit)
+                } else {
+                    commit
+                }
+            })
+        })
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let timestamp = std::env::var("WIZARD_BUILD_TIMESTAMP")
+        .ok()
+        .filter(|t| t.parse::<i64>().is_ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default()
+                .to_string()
+        });
+
+    println!("cargo:rustc-env=WIZARD_GIT_COMMIT={}", commit);
+    println!("cargo:rustc-env=WIZARD_BUILD_TIMESTAMP={}", timestamp);
+}
+
+/// Trimmed stdout of a successful git command
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
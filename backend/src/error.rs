@@ -5,6 +5,7 @@ use axum::{
 };
 use thiserror::Error;
 
+use crate::middleware::current_correlation_id;
 use crate::models::ApiResponse;
 
 #[derive(Error, Debug)]
@@ -43,8 +44,11 @@ pub enum AppError {
     #[error("Container not found: {0}")]
     ContainerNotFound(String),
 
-    #[error("Container limit exceeded: {0}")]
-    ContainerLimitExceeded(String),
+    #[error("Container limit exceeded: {current} of {max} containers in use")]
+    ContainerLimitExceeded { current: usize, max: usize },
+
+    #[error("Container name conflict: {0}")]
+    ContainerNameConflict(String),
 
     #[error("Skill not found: {0}")]
     SkillNotFound(String),
@@ -70,6 +74,9 @@ pub enum AppError {
     #[error("Analysis rate limited: {0}")]
     AnalysisRateLimited(String),
 
+    #[error("AI provider error: {0}")]
+    AiProviderError(String),
+
     #[error("Config analysis failed: {0}")]
     ConfigAnalysisFailed(String),
 
@@ -78,10 +85,21 @@ pub enum AppError {
 
     #[error("Deployment failed: {0}")]
     DeploymentFailed(String),
+
+    #[error("Confirmation required: {0}")]
+    ConfirmationRequired(String),
+
+    #[error("Offline: {0}")]
+    Offline(String),
 }
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
+        let container_limit = match &self {
+            AppError::ContainerLimitExceeded { current, max } => Some((*current, *max)),
+            _ => None,
+        };
+
         let (status, error_message) = match self {
             AppError::InternalError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
             AppError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
@@ -94,7 +112,15 @@ impl IntoResponse for AppError {
             AppError::DockerNotAvailable(msg) => (StatusCode::SERVICE_UNAVAILABLE, msg),
             AppError::DockerOperationFailed(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
             AppError::ContainerNotFound(msg) => (StatusCode::NOT_FOUND, msg),
-            AppError::ContainerLimitExceeded(msg) => (StatusCode::TOO_MANY_REQUESTS, msg),
+            AppError::ContainerLimitExceeded { current, max } => (
+                StatusCode::TOO_MANY_REQUESTS,
+                format!(
+                    "{} of {} containers in use. Remove existing containers first, \
+                     or raise OPENCLAW_MAX_CONTAINERS.",
+                    current, max
+                ),
+            ),
+            AppError::ContainerNameConflict(msg) => (StatusCode::CONFLICT, msg),
             AppError::SkillNotFound(msg) => (StatusCode::NOT_FOUND, msg),
             AppError::SkillInstallFailed(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
             AppError::VirusTotalError(msg) => (StatusCode::BAD_GATEWAY, msg),
@@ -103,17 +129,96 @@ impl IntoResponse for AppError {
             AppError::LogsNotFound(msg) => (StatusCode::NOT_FOUND, msg),
             AppError::AiProviderNotConfigured(msg) => (StatusCode::SERVICE_UNAVAILABLE, msg),
             AppError::AnalysisRateLimited(msg) => (StatusCode::TOO_MANY_REQUESTS, msg),
+            AppError::AiProviderError(msg) => (StatusCode::BAD_GATEWAY, msg),
             AppError::ConfigAnalysisFailed(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
             AppError::ServerNotFound(msg) => (StatusCode::NOT_FOUND, msg),
             AppError::DeploymentFailed(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
+            AppError::ConfirmationRequired(msg) => (StatusCode::PRECONDITION_REQUIRED, msg),
+            AppError::Offline(msg) => (StatusCode::SERVICE_UNAVAILABLE, msg),
         };
 
-        let body = Json(ApiResponse::<()> {
+        let body = ApiResponse::<()> {
             success: false,
             data: None,
             error: Some(error_message),
-        });
+        };
+
+        // Include the correlation id so a failed request can be found in the logs
+        let mut body = serde_json::to_value(body).unwrap_or_default();
+        if let Some(id) = current_correlation_id() {
+            body["request_id"] = serde_json::Value::String(id);
+        }
+        if let Some((current, max)) = container_limit {
+            body["current_count"] = current.into();
+            body["max"] = max.into();
+        }
+
+        (status, Json(body)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body_util::BodyExt;
+
+    async fn status_and_body(err: AppError) -> (StatusCode, serde_json::Value) {
+        let response = err.into_response();
+        let status = response.status();
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_status_codes_by_error_kind() {
+        let cases = [
+            (AppError::NotFound("x".into()), StatusCode::NOT_FOUND),
+            (AppError::ServerNotFound("x".into()), StatusCode::NOT_FOUND),
+            (AppError::LogsNotFound("x".into()), StatusCode::NOT_FOUND),
+            (AppError::BadRequest("x".into()), StatusCode::BAD_REQUEST),
+            (
+                AppError::ConfirmationRequired("x".into()),
+                StatusCode::PRECONDITION_REQUIRED,
+            ),
+            (
+                AppError::AnalysisRateLimited("x".into()),
+                StatusCode::TOO_MANY_REQUESTS,
+            ),
+            (
+                AppError::DockerNotAvailable("x".into()),
+                StatusCode::SERVICE_UNAVAILABLE,
+            ),
+            (
+                AppError::Offline("x".into()),
+                StatusCode::SERVICE_UNAVAILABLE,
+            ),
+            (
+                AppError::InternalError("x".into()),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+        ];
+        for (err, expected) in cases {
+            let (status, _) = status_and_body(err).await;
+            assert_eq!(status, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_error_body_shape() {
+        let (_, body) =
+            status_and_body(AppError::BadRequest("Config must be a JSON object".into())).await;
+        assert_eq!(body["success"], false);
+        assert!(body["data"].is_null());
+        assert_eq!(body["error"], "Config must be a JSON object");
+    }
 
-        (status, body).into_response()
+    #[tokio::test]
+    async fn test_container_limit_reports_counts() {
+        let (status, body) =
+            status_and_body(AppError::ContainerLimitExceeded { current: 5, max: 5 }).await;
+        assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(body["current_count"], 5);
+        assert_eq!(body["max"], 5);
+        assert!(body["error"].as_str().unwrap().contains("5 of 5"));
     }
 }
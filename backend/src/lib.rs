@@ -1,4 +1,6 @@
 pub mod error;
+pub mod middleware;
 pub mod models;
 pub mod routes;
 pub mod services;
+pub mod static_assets;
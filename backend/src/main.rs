@@ -1,15 +1,18 @@
 mod error;
+mod middleware;
 mod models;
 mod routes;
 mod services;
+mod static_assets;
 
 use axum::{
     Router,
     routing::{get, post},
 };
 use clap::Parser;
+use std::path::{Path, PathBuf};
 use tower_http::services::ServeDir;
-use tracing::info;
+use tracing::{info, warn};
 
 #[derive(Parser)]
 #[command(name = "openclaw-wizard", about = "OpenClaw Setup Wizard")]
@@ -18,22 +21,105 @@ struct Args {
     #[arg(short, long, default_value_t = 3030)]
     port: u16,
 
-    /// Don't open browser automatically
+    /// Don't open browser automatically (also skipped when
+    /// OPENCLAW_WIZARD_NO_OPEN=1, over SSH, or on Linux without a display)
     #[arg(long)]
     no_open: bool,
+
+    /// Disable all outbound network calls: registry searches, version checks,
+    /// VirusTotal, AI analysis (also set with OPENCLAW_WIZARD_OFFLINE=1)
+    #[arg(long)]
+    offline: bool,
+
+    /// Directory containing the built frontend (default: `static` next to the executable)
+    #[arg(long)]
+    static_dir: Option<PathBuf>,
+}
+
+/// Resolve where the frontend is served from.
+///
+/// Order: `--static-dir`, then `static/` next to the executable (release
+/// package layout), then `static/` in the working directory (`setup.sh` runs
+/// from `backend/`).
+fn resolve_static_dir(override_dir: Option<PathBuf>) -> PathBuf {
+    override_dir.unwrap_or_else(|| {
+        let beside_exe = std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(|dir| dir.join("static")));
+        match beside_exe {
+            Some(dir) if dir.is_dir() => dir,
+            _ => PathBuf::from("static"),
+        }
+    })
+}
+
+/// Absolute form of `path` for log messages, falling back to it as given
+fn display_path(path: &Path) -> String {
+    std::path::absolute(path)
+        .unwrap_or_else(|_| path.to_path_buf())
+        .display()
+        .to_string()
 }
 
 #[tokio::main]
 async fn main() {
     let args = Args::parse();
 
-    // Initialize tracing
+    // Initialize tracing (request spans carry the correlation id)
     tracing_subscriber::fmt::init();
 
+    let build = services::platform::Platform::wizard_version();
+    info!(
+        "OpenClaw Wizard v{} (commit {}, built {})",
+        build.version, build.git_commit, build.build_timestamp
+    );
+
+    if args.offline || services::offline::OfflineMode::env_requested() {
+        services::offline::OfflineMode::enable();
+        info!("Offline mode: outbound network calls are disabled");
+    }
+
+    // Sample CPU/memory for the dashboard history charts
+    services::metrics::MetricsService::spawn_sampler();
+
+    // Keep sandbox container state current when it changes outside the wizard
+    services::docker_events::DockerEvents::spawn_watcher();
+
+    // Serve the frontend from disk, falling back to the assets embedded in
+    // the binary (feature `embed-static`) when no directory is found
+    let explicit_static_dir = args.static_dir.is_some();
+    let static_dir = resolve_static_dir(args.static_dir);
+    let on_disk = static_dir.join("index.html").is_file();
+    let use_embedded = !on_disk && !explicit_static_dir && static_assets::has_embedded_index();
+    if use_embedded {
+        info!("Serving embedded frontend assets");
+    } else if on_disk {
+        info!("Serving frontend from {}", display_path(&static_dir));
+    } else {
+        warn!(
+            "Frontend assets not found at {} (missing index.html); the UI will not load. \
+             Pass --static-dir to point at the built frontend.",
+            display_path(&static_dir)
+        );
+    }
+
     // Build application router
     let app = Router::new()
         .route("/api/health", get(routes::api::health))
+        .route("/api/health/live", get(routes::api::health_live))
+        .route("/api/health/ready", get(routes::api::health_ready))
+        .route("/api/routes", get(routes::manifest::list_routes))
+        .route("/api/confirm", get(routes::confirm::issue_confirmation))
         .route("/api/system/info", get(routes::api::system_info))
+        .route("/api/system/identity", get(routes::api::system_identity))
+        .route(
+            "/api/system/wizard-version",
+            get(routes::api::wizard_version),
+        )
+        .route(
+            "/api/system/support-bundle",
+            get(routes::api::support_bundle),
+        )
         .route(
             "/api/system/requirements",
             get(routes::api::system_requirements),
@@ -42,20 +128,54 @@ async fn main() {
             "/api/system/detect-openclaw",
             get(routes::api::detect_openclaw),
         )
+        .route("/api/system/processes", get(routes::api::list_processes))
+        .route(
+            "/api/system/processes/{pid}/kill",
+            post(routes::api::kill_process),
+        )
         .route(
             "/api/wizard/validate-key",
             post(routes::wizard::validate_api_key),
         )
+        .route(
+            "/api/wizard/generate-credential",
+            get(routes::wizard::generate_credential),
+        )
         .route("/api/wizard/save-config", post(routes::wizard::save_config))
+        .route(
+            "/api/wizard/config-schema",
+            get(routes::wizard::config_schema),
+        )
         .route("/api/wizard/install", post(routes::wizard::start_install))
+        .route(
+            "/api/wizard/install-plan",
+            get(routes::wizard::install_plan),
+        )
+        .route(
+            "/api/wizard/install/cancel",
+            post(routes::wizard::cancel_install),
+        )
+        .route(
+            "/api/wizard/onboard-preview",
+            get(routes::wizard::onboard_preview),
+        )
         .route(
             "/api/wizard/rollback",
             post(routes::wizard::rollback_installation),
         )
+        .route("/ws/wizard/full-setup", get(routes::wizard::ws_full_setup))
         .route(
             "/api/channels/validate",
             post(routes::channels::validate_channel_token),
         )
+        .route(
+            "/api/channels/validate-all",
+            post(routes::channels::validate_all_channels),
+        )
+        .route(
+            "/api/channels/{platform}/allowlist",
+            post(routes::channels::update_channel_allowlist),
+        )
         // Dashboard routes
         .route(
             "/api/dashboard/daemon/status",
@@ -74,10 +194,18 @@ async fn main() {
             post(routes::dashboard::daemon_restart),
         )
         .route("/api/dashboard/health", get(routes::dashboard::get_health))
+        .route(
+            "/api/dashboard/metrics/history",
+            get(routes::dashboard::metrics_history),
+        )
         .route(
             "/api/dashboard/config",
             get(routes::dashboard::get_config).put(routes::dashboard::save_config_handler),
         )
+        .route(
+            "/api/dashboard/config/template",
+            get(routes::dashboard::config_template),
+        )
         .route(
             "/api/dashboard/config/import",
             post(routes::dashboard::import_config),
@@ -86,10 +214,23 @@ async fn main() {
             "/api/dashboard/config/export",
             get(routes::dashboard::export_config),
         )
+        .route(
+            "/api/dashboard/config/reconcile",
+            get(routes::dashboard::config_reconcile_status)
+                .post(routes::dashboard::config_reconcile_sync),
+        )
+        .route(
+            "/api/dashboard/config/set",
+            post(routes::dashboard::config_set),
+        )
         .route(
             "/api/dashboard/chat-url",
             get(routes::dashboard::get_chat_url),
         )
+        .route(
+            "/api/dashboard/auth/rotate",
+            post(routes::dashboard::rotate_auth_credential),
+        )
         .route(
             "/api/dashboard/version",
             get(routes::dashboard::get_version_info),
@@ -100,6 +241,8 @@ async fn main() {
             "/api/remote/test-connection",
             post(routes::remote::test_ssh_connection),
         )
+        .route("/api/remote/host-key", get(routes::remote::get_host_key))
+        .route("/api/remote/trust-host", post(routes::remote::trust_host))
         .route("/ws/remote/install", get(routes::remote::ws_remote_install))
         // Docker routes
         .route("/api/docker/status", get(routes::docker::docker_status))
@@ -108,6 +251,7 @@ async fn main() {
             get(routes::docker::list_containers),
         )
         .route("/api/docker/create", post(routes::docker::create_container))
+        .route("/api/docker/prune", post(routes::docker::prune_containers))
         .route(
             "/api/docker/{id}/stop",
             post(routes::docker::stop_container),
@@ -117,16 +261,42 @@ async fn main() {
             axum::routing::delete(routes::docker::remove_container),
         )
         .route("/api/docker/{id}/logs", get(routes::docker::container_logs))
+        .route(
+            "/ws/docker/{id}/logs",
+            get(routes::docker::ws_container_logs),
+        )
+        .route(
+            "/ws/docker/events",
+            get(routes::docker::ws_container_events),
+        )
         // Skills routes (literal paths BEFORE {name} parameter route)
         .route("/api/skills/search", get(routes::skills::search_skills))
         .route("/api/skills/installed", get(routes::skills::list_installed))
+        .route(
+            "/api/skills/recommended",
+            get(routes::skills::recommended_skills),
+        )
         .route("/api/skills/install", post(routes::skills::install_skill))
+        .route("/ws/skills/install", get(routes::skills::ws_skill_install))
+        .route(
+            "/api/skills/install-batch",
+            post(routes::skills::install_skill_batch),
+        )
+        .route(
+            "/ws/skills/install-batch",
+            get(routes::skills::ws_skill_batch_install),
+        )
+        .route(
+            "/api/skills/uninstall-batch",
+            post(routes::skills::uninstall_skill_batch),
+        )
         .route("/api/skills/scan", post(routes::skills::scan_skill))
         .route("/api/skills/{name}", get(routes::skills::skill_details))
         .route(
             "/api/skills/{name}",
             axum::routing::delete(routes::skills::uninstall_skill),
         )
+        .route("/api/skills/{name}/audit", get(routes::skills::audit_skill))
         // Service management routes
         .route(
             "/api/services/status",
@@ -157,10 +327,13 @@ async fn main() {
             post(routes::services::restart_daemon),
         )
         .route("/api/services/doctor", get(routes::services::run_doctor))
+        .route("/ws/services/upgrade", get(routes::services::ws_upgrade))
         // Log routes
         .route("/api/logs/recent", get(routes::logs::get_recent_logs))
         .route("/api/logs/analyze", post(routes::logs::analyze_logs))
+        .route("/api/logs/diagnose", post(routes::logs::diagnose_logs))
         .route("/ws/logs", get(routes::logs::ws_log_stream))
+        .route("/ws/logs/analyze", get(routes::logs::ws_analyze_logs))
         // WhatsApp connection
         .route(
             "/ws/whatsapp/connect",
@@ -171,6 +344,10 @@ async fn main() {
             "/api/intelligence/cost-analysis",
             post(routes::intelligence::analyze_cost),
         )
+        .route(
+            "/api/intelligence/cost-history",
+            get(routes::intelligence::cost_history),
+        )
         .route(
             "/api/intelligence/security-audit",
             get(routes::intelligence::security_audit),
@@ -184,6 +361,10 @@ async fn main() {
             "/api/multi-server/servers",
             get(routes::multi_server::get_servers).post(routes::multi_server::add_server),
         )
+        .route(
+            "/api/multi-server/import-ssh-config",
+            post(routes::multi_server::import_ssh_config),
+        )
         .route(
             "/api/multi-server/servers/{id}",
             axum::routing::delete(routes::multi_server::remove_server),
@@ -192,15 +373,33 @@ async fn main() {
             "/api/multi-server/servers/{id}/test",
             post(routes::multi_server::test_server),
         )
+        .route(
+            "/api/multi-server/test-all",
+            post(routes::multi_server::test_all_servers),
+        )
         .route(
             "/api/multi-server/rollback/{id}",
             post(routes::multi_server::rollback_server),
         )
+        .route(
+            "/api/multi-server/rollback-batch",
+            post(routes::multi_server::rollback_batch),
+        )
+        .route(
+            "/api/multi-server/history",
+            get(routes::multi_server::get_deploy_history),
+        )
         .route(
             "/ws/multi-server/deploy",
             get(routes::multi_server::ws_multi_server_deploy),
-        )
-        .fallback_service(ServeDir::new("static"));
+        );
+
+    let app = if use_embedded {
+        app.fallback(static_assets::serve_embedded)
+    } else {
+        app.fallback_service(ServeDir::new(static_dir))
+    }
+    .layer(axum::middleware::from_fn(middleware::correlation_id));
 
     let addr = format!("127.0.0.1:{}", args.port);
     let url = format!("http://{}", addr);
@@ -211,7 +410,14 @@ async fn main() {
 
     info!("🐾 OpenClaw Wizard running at {}", url);
 
-    if !args.no_open {
+    let skip_reason = if args.no_open {
+        Some("--no-open")
+    } else {
+        services::platform::Platform::browser_skip_reason()
+    };
+    if let Some(reason) = skip_reason {
+        info!("Not opening a browser ({}); open {} manually", reason, url);
+    } else {
         let url_clone = url.clone();
         tokio::task::spawn_blocking(move || {
             println!("  Opening browser → {}", url_clone);
@@ -0,0 +1,113 @@
+//! # Request Correlation Middleware
+//!
+//! Assigns every HTTP request and WebSocket upgrade a correlation id and runs
+//! the handler inside a `request` tracing span carrying it, so all log lines
+//! for one install or deployment can be followed together.
+//!
+//! - A valid incoming `x-request-id` header is reused; otherwise one is generated
+//! - The id is echoed back in the `x-request-id` response header
+//! - Error responses include it as `request_id` (see `AppError::into_response`)
+//! - Spawned tasks keep the span by using `.in_current_span()`
+
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::Instrument;
+
+/// Header used to pass the correlation id in and out
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Maximum accepted length of a client-supplied id
+const MAX_REQUEST_ID_LEN: usize = 64;
+
+/// Per-process counter making generated ids unique within the same millisecond
+static REQUEST_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+tokio::task_local! {
+    /// Correlation id of the request being handled on this task
+    static CORRELATION_ID: String;
+}
+
+/// Correlation id of the request currently being handled, if any
+pub fn current_correlation_id() -> Option<String> {
+    CORRELATION_ID.try_with(|id| id.clone()).ok()
+}
+
+/// Middleware: assign a correlation id and wrap the handler in a tracing span
+pub async fn correlation_id(request: Request, next: Next) -> Response {
+    let id = request
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .filter(|v| is_valid_request_id(v))
+        .map(|v| v.to_string())
+        .unwrap_or_else(generate_request_id);
+
+    let span = tracing::info_span!(
+        "request",
+        id = %id,
+        method = %request.method(),
+        path = %request.uri().path(),
+    );
+
+    let mut response = CORRELATION_ID
+        .scope(id.clone(), next.run(request).instrument(span))
+        .await;
+
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
+
+/// Generate a short id such as `req-18d3f2a9c41-002a`
+fn generate_request_id() -> String {
+    let millis = chrono::Utc::now().timestamp_millis();
+    let seq = REQUEST_COUNTER.fetch_add(1, Ordering::Relaxed) & 0xffff;
+    format!("req-{:x}-{:04x}", millis, seq)
+}
+
+/// Client-supplied ids must be short and limited to `[A-Za-z0-9_-]`
+fn is_valid_request_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_REQUEST_ID_LEN
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_ids_are_valid_and_unique() {
+        let a = generate_request_id();
+        let b = generate_request_id();
+        assert!(a.starts_with("req-"));
+        assert!(is_valid_request_id(&a));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_rejects_unsafe_client_ids() {
+        assert!(is_valid_request_id("install-42_a"));
+        assert!(!is_valid_request_id(""));
+        assert!(!is_valid_request_id("abc\ndef"));
+        assert!(!is_valid_request_id("id with spaces"));
+        assert!(!is_valid_request_id(&"x".repeat(65)));
+    }
+
+    #[tokio::test]
+    async fn test_current_correlation_id_scoped() {
+        assert_eq!(current_correlation_id(), None);
+        let inside = CORRELATION_ID
+            .scope("req-test".to_string(), async { current_correlation_id() })
+            .await;
+        assert_eq!(inside.as_deref(), Some("req-test"));
+    }
+}
@@ -2,7 +2,7 @@ pub mod types;
 
 pub use types::{
     ApiKeyValidationRequest, ApiKeyValidationResponse, ApiResponse, EmptyResponse, InstallProgress,
-    InstallRequest, OpenClawDetection, RemoteInstallRequest, RemoteSetupProgress, RequirementCheck,
-    RollbackResult, SshConnection, SshConnectionRequest, SshConnectionResponse, SystemInfo,
-    SystemRequirements, WizardConfig, WsMessage,
+    InstallRequest, OnboardPreview, OpenClawDetection, RemoteInstallRequest, RemoteSetupProgress,
+    RequirementCheck, RollbackResult, SshConnection, SshConnectionRequest, SshConnectionResponse,
+    SystemInfo, SystemRequirements, WizardConfig, WsMessage,
 };
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use ts_rs::TS;
 
 /// System information structure
@@ -8,9 +9,66 @@ pub struct SystemInfo {
     pub os: String,
     pub arch: String,
     pub node_version: Option<String>,
+    /// Resolved path of the `node` on PATH
+    #[serde(default)]
+    #[ts(optional)]
+    pub node_path: Option<String>,
+    /// Which installer provided that `node`
+    #[serde(default)]
+    #[ts(optional)]
+    pub node_source: Option<NodeSource>,
     pub openclaw_installed: bool,
 }
 
+/// How the active Node.js was installed, judged from its path
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub enum NodeSource {
+    Homebrew,
+    Nvm,
+    Volta,
+    Fnm,
+    Asdf,
+    /// Distro package (apt, dnf, ...) in /usr/bin
+    SystemPackage,
+    /// nodejs.org package or tarball, e.g. /usr/local/bin
+    Official,
+    Unknown,
+}
+
+/// Version and build metadata of the running wizard binary
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct WizardVersion {
+    /// Crate version (`CARGO_PKG_VERSION`)
+    pub version: String,
+    /// Short git commit the binary was built from ("-dirty" with local
+    /// changes, "unknown" without git)
+    pub git_commit: String,
+    /// RFC 3339 build time
+    pub build_timestamp: String,
+}
+
+/// Who the wizard is running as and which directories it resolves
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct SystemIdentity {
+    /// Effective user id (Unix only)
+    pub uid: Option<u32>,
+    /// Effective user name
+    pub username: Option<String>,
+    /// Original user when launched via sudo (SUDO_USER)
+    pub sudo_user: Option<String>,
+    /// HOME (or USERPROFILE) as seen by the wizard
+    pub home: Option<String>,
+    /// Wizard config directory from `Platform::config_dir`
+    pub config_dir: Option<String>,
+    pub config_dir_writable: bool,
+    /// OpenClaw's own directory (~/.openclaw)
+    pub openclaw_dir: Option<String>,
+    pub openclaw_dir_writable: bool,
+}
+
 /// Generic API response wrapper
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiResponse<T>
@@ -40,6 +98,18 @@ pub struct EmptyResponse {
     pub error: Option<String>,
 }
 
+/// One entry of the route manifest (GET /api/routes)
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct ApiRoute {
+    /// HTTP method, e.g. "GET"
+    pub method: String,
+    /// Path pattern; `{name}` marks a path parameter
+    pub path: String,
+    /// Whether the route upgrades to a WebSocket
+    pub websocket: bool,
+}
+
 /// WebSocket message envelope
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../bindings/")]
@@ -49,6 +119,17 @@ pub struct WsMessage {
     pub payload: serde_json::Value,
 }
 
+/// Payload of the "start-uninstall" WebSocket message
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct UninstallRequest {
+    /// Token for `UninstallOpenClaw` from GET /api/confirm. WebSockets
+    /// can't carry the X-Confirm-Token header, so it goes in the payload.
+    #[serde(default)]
+    #[ts(optional)]
+    pub confirm_token: Option<String>,
+}
+
 /// Individual requirement check result
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../bindings/")]
@@ -81,6 +162,95 @@ pub struct OpenClawDetection {
     pub config_path: Option<String>,
     #[ts(type = "Record<string, any> | null")]
     pub existing_config: Option<serde_json::Value>,
+    /// Latest version published on npm, if the registry could be reached
+    pub latest_version: Option<String>,
+    /// True when the installed version is older than `latest_version`
+    pub update_available: bool,
+    /// Every known config location, in the gateway's load precedence
+    #[serde(default)]
+    pub config_locations: Vec<ConfigLocation>,
+    /// Config file the gateway loads (it may not exist yet)
+    #[serde(default)]
+    #[ts(optional)]
+    pub active_config_path: Option<String>,
+    /// How the found configs disagree: ignored gateway configs, or wizard
+    /// settings that differ from the loaded config; empty when consistent
+    #[serde(default)]
+    pub config_divergence: Vec<String>,
+}
+
+/// A place an OpenClaw config can live
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct ConfigLocation {
+    pub path: String,
+    /// "env" (OPENCLAW_CONFIG_PATH), "state-dir" (OPENCLAW_STATE_DIR),
+    /// "default" (~/.openclaw), "wizard" (the wizard's saved settings) or
+    /// "working-dir" (./openclaw.json)
+    pub source: String,
+    pub exists: bool,
+    /// Whether this is the file the gateway loads
+    pub active: bool,
+}
+
+/// Comparison of the wizard's saved settings with the gateway config
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct ConfigReconcileReport {
+    /// The wizard's saved settings (`config_dir/openclaw.json`)
+    pub wizard_path: String,
+    pub wizard_exists: bool,
+    /// The config the gateway loads
+    pub gateway_path: String,
+    pub gateway_exists: bool,
+    /// Differences found; secret values are never included
+    pub divergence: Vec<String>,
+    /// Whether the gateway config already carries the wizard's settings
+    pub in_sync: bool,
+    /// Set after a sync: whether the gateway config was rewritten
+    #[serde(default)]
+    #[ts(optional)]
+    pub synced: Option<bool>,
+}
+
+/// Request to set one gateway config key through `openclaw config set`
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct ConfigSetRequest {
+    /// Dotted key path, e.g. "gateway.port" or "channels.telegram.enabled"
+    pub path: String,
+    #[ts(type = "any")]
+    pub value: serde_json::Value,
+}
+
+/// Value of a config key after it was set
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct ConfigSetResponse {
+    pub path: String,
+    /// Value read back from the gateway config; secrets are masked
+    #[ts(type = "any")]
+    pub value: serde_json::Value,
+}
+
+/// Add or remove one user in a channel's DM allowlist
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct ChannelAllowlistRequest {
+    /// "add" or "remove"
+    pub action: String,
+    /// Telegram/Discord numeric ID, Slack member ID, or WhatsApp phone number
+    pub user_id: String,
+}
+
+/// A channel's allowlist after an update
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct ChannelAllowlistResponse {
+    pub platform: String,
+    pub allowed_users: Vec<String>,
+    /// False when the user was already (or was never) on the list
+    pub changed: bool,
 }
 
 /// API key validation request/response
@@ -117,6 +287,22 @@ pub struct ChannelValidationResponse {
     pub error: Option<String>,
     pub bot_name: Option<String>,     // Display name of validated bot
     pub bot_username: Option<String>, // Username (@handle) of bot
+    /// Scopes the bot still needs (Slack)
+    #[serde(default)]
+    #[ts(optional)]
+    pub missing_scopes: Option<Vec<String>>,
+    /// Follow-up advice, e.g. how to grant missing permissions
+    #[serde(default)]
+    #[ts(optional)]
+    pub hint: Option<String>,
+}
+
+/// Validation result for one configured channel
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct ChannelValidationResult {
+    pub platform: String,
+    pub result: ChannelValidationResponse,
 }
 
 /// Channel configuration for saving
@@ -135,6 +321,11 @@ pub struct ChannelConfig {
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../bindings/")]
 pub struct WizardConfig {
+    /// Saved config layout version; older files are migrated on read
+    /// (see `services::wizard_config`)
+    #[serde(default)]
+    #[ts(optional)]
+    pub schema_version: Option<u32>,
     pub provider: String,
     #[serde(default)]
     pub api_key: String,
@@ -154,6 +345,14 @@ pub struct WizardConfig {
     pub account_id: Option<String>,
     /// Cloudflare gateway ID
     pub gateway_id: Option<String>,
+    /// Directory containing OpenClaw service logs, if not in a default location
+    #[serde(default)]
+    #[ts(optional)]
+    pub log_dir: Option<String>,
+    /// Model used for AI log and cost analysis; defaults per provider when unset
+    #[serde(default)]
+    #[ts(optional)]
+    pub analysis_model: Option<String>,
 }
 
 /// Installation request
@@ -162,6 +361,38 @@ pub struct WizardConfig {
 pub struct InstallRequest {
     pub install_node: bool,
     pub install_openclaw: bool,
+    /// Client-chosen id used to cancel the install; generated when omitted
+    #[serde(default)]
+    #[ts(optional)]
+    pub install_id: Option<String>,
+    /// Run `openclaw gateway install` (default true); turn off when the
+    /// gateway runs under a service you manage yourself
+    #[serde(default)]
+    #[ts(optional)]
+    pub install_gateway_service: Option<bool>,
+    /// Run `openclaw gateway start` and wait for it to be healthy (default true)
+    #[serde(default)]
+    #[ts(optional)]
+    pub start_gateway: Option<bool>,
+    /// OpenClaw version or npm dist-tag to install; latest when omitted
+    #[serde(default)]
+    #[ts(optional)]
+    pub openclaw_version: Option<String>,
+}
+
+/// Request to cancel a running install
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct InstallCancelRequest {
+    /// Install to cancel, as reported by `install-started`
+    pub install_id: String,
+}
+
+/// The install that was cancelled
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct InstallCancelResponse {
+    pub install_id: String,
 }
 
 /// Installation progress update
@@ -174,6 +405,45 @@ pub struct InstallProgress {
     pub output_line: Option<String>, // Raw command output line
     pub error: Option<String>,       // Error details if failed
     pub progress_pct: Option<u8>,    // Optional percentage (0-100)
+    /// Position of `stage` in the install plan, when it is one of its stages
+    #[serde(default)]
+    #[ts(optional)]
+    pub stage_index: Option<u8>,
+    /// Milliseconds since `stage` started; set on completed/failed updates
+    #[serde(default)]
+    #[ts(optional)]
+    pub elapsed_ms: Option<u32>,
+    /// Severity of `output_line`: "info", "warning" or "error"
+    #[serde(default)]
+    #[ts(optional)]
+    pub line_level: Option<String>,
+}
+
+/// One stage an install will run
+#[derive(Debug, Clone, Serialize, Deserialize, TS, PartialEq)]
+#[ts(export, export_to = "../bindings/")]
+pub struct InstallPlanStage {
+    /// Stage name used in `InstallProgress.stage`
+    pub id: String,
+    pub label: String,
+}
+
+/// Ordered stages an install request will run
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct InstallPlan {
+    pub stages: Vec<InstallPlanStage>,
+}
+
+/// Preview of the `openclaw onboard` invocation built from the saved config.
+/// Secret values (API keys, tokens) are masked.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct OnboardPreview {
+    pub program: String,
+    pub args: Vec<String>,
+    /// Shell-style command line, suitable for copy/paste after filling in secrets
+    pub command: String,
 }
 
 // ===== Dashboard Types =====
@@ -199,17 +469,78 @@ pub struct ChannelHealth {
     pub error_message: Option<String>,
 }
 
+/// Gateway chat/dashboard URL for the "Open Chat" button
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct ChatUrlResponse {
+    /// URL to open; carries `#token=...` when token auth is configured
+    pub url: String,
+    /// Gateway auth mode ("token", "password", "none", ...)
+    pub auth_mode: String,
+    /// Gateway token when `auth_mode` is "token"
+    pub token: Option<String>,
+}
+
 /// HealthSnapshot - returned by health endpoint
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../bindings/")]
 pub struct HealthSnapshot {
     pub gateway_reachable: bool,
     pub gateway_mode: String,
+    /// "healthy", "unauthorized" (gateway is up but rejected the probe's
+    /// credentials) or "unreachable"
+    pub gateway_status: String,
+    /// Gateway auth mode from openclaw.json ("none", "token", "password")
+    pub auth_mode: String,
+    /// Why the gateway isn't healthy, when known
+    #[serde(default)]
+    #[ts(optional)]
+    pub gateway_detail: Option<String>,
+    /// Port the running gateway process is listening on, when it can be found
+    #[serde(default)]
+    #[ts(optional)]
+    pub listening_port: Option<u16>,
+    /// Set when the running gateway isn't listening on the configured port,
+    /// with a hint to restart it
+    #[serde(default)]
+    #[ts(optional)]
+    pub port_mismatch: Option<String>,
+    /// How the snapshot was produced: "gateway" (the gateway's own health
+    /// endpoint), "cli" (`openclaw health`) or "inferred" (config and port)
+    #[serde(default)]
+    #[ts(optional)]
+    pub source: Option<String>,
     pub channels: Vec<ChannelHealth>,
     pub session_count: u32,
     pub probe_duration_ms: u32,
 }
 
+/// A single readiness check (config, gateway)
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct ReadinessCheck {
+    pub name: String,
+    pub ok: bool,
+    pub message: String,
+}
+
+/// Liveness status - returned by /api/health and /api/health/live
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct HealthStatus {
+    /// Outbound network calls are disabled (`--offline`)
+    pub offline: bool,
+}
+
+/// Readiness report - returned by /api/health/ready
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct ReadinessReport {
+    /// True when every check passed
+    pub ready: bool,
+    pub checks: Vec<ReadinessCheck>,
+}
+
 /// DaemonActionResponse - returned by start/stop/restart
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../bindings/")]
@@ -221,7 +552,7 @@ pub struct DaemonActionResponse {
 // ===== Docker Types =====
 
 /// Status of a Docker container
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../bindings/")]
 pub enum ContainerStatus {
     Running,
@@ -240,7 +571,44 @@ pub struct ContainerInfo {
     pub image: String,
     pub status: ContainerStatus,
     pub created_at: String,
+    /// Host port mapped to the container's app port
     pub port: Option<u16>,
+    /// App port inside the container (default 3000)
+    pub container_port: u16,
+}
+
+/// State change of a managed container, reported by the Docker events stream
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct ContainerEvent {
+    pub container_id: String,
+    pub name: Option<String>,
+    /// Docker event action ("start", "die", "oom", "destroy", ...)
+    pub action: String,
+    /// State after the event; None once the container has been removed
+    pub status: Option<ContainerStatus>,
+    /// Exit code reported with a "die" event
+    pub exit_code: Option<i32>,
+    /// When the daemon emitted the event (RFC 3339)
+    pub time: Option<String>,
+}
+
+/// Query options for listing managed containers
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct ContainerListQuery {
+    /// Only return containers in this state
+    #[serde(default)]
+    #[ts(optional)]
+    pub status: Option<ContainerStatus>,
+    /// Include exited containers (default true)
+    #[serde(default)]
+    #[ts(optional)]
+    pub all: Option<bool>,
+    /// "running" (running first, then newest; default), "newest" or "oldest"
+    #[serde(default)]
+    #[ts(optional)]
+    pub sort: Option<String>,
 }
 
 /// Request to create a new Docker sandbox container
@@ -250,6 +618,16 @@ pub struct DockerCreateRequest {
     pub name: String,
     /// Docker image to use; defaults to "node:20-alpine" if None
     pub image: Option<String>,
+    /// Remove exited/created sandbox containers before enforcing the container limit
+    #[serde(default)]
+    pub prune_exited: bool,
+    /// Port the app listens on inside the container (default 3000)
+    #[serde(default)]
+    #[ts(optional)]
+    pub container_port: Option<u16>,
+    /// Mount the root filesystem read-only, with tmpfs for the writable paths
+    #[serde(default)]
+    pub readonly: bool,
 }
 
 /// Response after creating a Docker container
@@ -258,8 +636,29 @@ pub struct DockerCreateRequest {
 pub struct DockerCreateResponse {
     pub success: bool,
     pub container_id: Option<String>,
+    /// Host port mapped to `container_port`
     pub port: Option<u16>,
+    /// App port inside the container the host port maps to
+    pub container_port: u16,
+    /// Number of stale containers pruned before creation
+    pub pruned: u32,
     pub error: Option<String>,
+    /// Set when the image has no variant for the Docker host's architecture
+    #[serde(default)]
+    #[ts(optional)]
+    pub arch_warning: Option<String>,
+    /// What a read-only sandbox can't do, set when `readonly` was requested
+    #[serde(default)]
+    #[ts(optional)]
+    pub readonly_note: Option<String>,
+}
+
+/// Result of pruning stale sandbox containers
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct DockerPruneResponse {
+    pub pruned: u32,
+    pub container_ids: Vec<String>,
 }
 
 /// Docker daemon status and running containers
@@ -269,7 +668,18 @@ pub struct DockerStatusResponse {
     pub available: bool,
     pub version: Option<String>,
     pub containers: Vec<ContainerInfo>,
+    /// Docker endpoint in use (DOCKER_HOST, rootful or rootless socket)
+    pub socket: Option<String>,
+    /// Why Docker is unavailable, or why listing failed while `available` is true
     pub error: Option<String>,
+    /// Managed containers that count against the limit
+    pub current_count: u32,
+    /// Container limit (`OPENCLAW_MAX_CONTAINERS`, default 5)
+    pub max_containers: u32,
+    /// Docker API version negotiated with the daemon, e.g. "1.43"
+    #[serde(default)]
+    #[ts(optional)]
+    pub api_version: Option<String>,
 }
 
 /// Container log output
@@ -312,6 +722,30 @@ pub struct SkillMetadata {
     pub verified: bool,
 }
 
+/// A curated skill suggested to new users
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct RecommendedSkill {
+    pub name: String,
+    /// Why the skill is worth installing
+    pub rationale: String,
+    /// Whether the entry comes from the bundled list vetted by the wizard
+    /// maintainers; entries from a remote list are unverified
+    pub verified: bool,
+    /// Current registry metadata; None when the registry lookup failed or
+    /// the wizard is offline
+    pub details: Option<SkillMetadata>,
+}
+
+/// Response from the recommended skills endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct RecommendedSkillsResponse {
+    pub skills: Vec<RecommendedSkill>,
+    /// "bundled" or the URL the list was fetched from
+    pub source: String,
+}
+
 /// Request to search for skills
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../bindings/")]
@@ -336,6 +770,10 @@ pub struct SkillInstallRequest {
     pub name: String,
     /// Version to install; None = latest
     pub version: Option<String>,
+    /// Install even if VirusTotal reports the package as malicious
+    #[serde(default)]
+    #[ts(optional)]
+    pub force: Option<bool>,
 }
 
 /// Response after installing a skill
@@ -347,6 +785,87 @@ pub struct SkillInstallResponse {
     pub version: String,
     pub error: Option<String>,
     pub scan_result: Option<ScanResult>,
+    /// Security warning for an install that went ahead anyway, e.g. a
+    /// forced install of a VT-flagged package
+    #[serde(default)]
+    #[ts(optional)]
+    pub warning: Option<String>,
+}
+
+/// Request to install several skills in one go, in order
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct SkillBatchInstallRequest {
+    pub skills: Vec<SkillInstallRequest>,
+}
+
+/// Outcome of one skill in a batch install
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct SkillBatchItem {
+    pub name: String,
+    pub version: Option<String>,
+    /// "installed", "blocked" (VirusTotal flagged it malicious) or "failed"
+    pub status: String,
+    pub error: Option<String>,
+    /// The install result, when the install ran
+    pub response: Option<SkillInstallResponse>,
+}
+
+/// Result of a batch install
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct SkillBatchSummary {
+    pub items: Vec<SkillBatchItem>,
+    pub installed: u32,
+    pub blocked: u32,
+    pub failed: u32,
+}
+
+/// Streamed progress of one skill in a batch install
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct SkillBatchProgress {
+    /// Zero-based position of the skill in the batch
+    pub index: u32,
+    pub total: u32,
+    pub name: String,
+    pub progress: InstallProgress,
+}
+
+/// One skill of a batch uninstall with its confirmation token
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct SkillUninstallItem {
+    pub name: String,
+    /// Token for `UninstallSkill` on this name from GET /api/confirm
+    pub confirm_token: String,
+}
+
+/// Request to uninstall several skills
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct SkillBatchUninstallRequest {
+    pub skills: Vec<SkillUninstallItem>,
+}
+
+/// Outcome of one skill in a batch uninstall
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct SkillUninstallResult {
+    pub name: String,
+    /// "uninstalled" or "failed"
+    pub status: String,
+    pub error: Option<String>,
+}
+
+/// Result of a batch uninstall
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct SkillBatchUninstallSummary {
+    pub items: Vec<SkillUninstallResult>,
+    pub uninstalled: u32,
+    pub failed: u32,
 }
 
 /// Information about a locally installed skill
@@ -357,6 +876,32 @@ pub struct InstalledSkill {
     pub version: String,
     pub path: String,
     pub size_bytes: Option<u64>,
+    /// Known dependency vulnerabilities; only filled in when audit is requested
+    pub vulnerabilities: Option<VulnerabilitySummary>,
+}
+
+/// Count of known vulnerabilities by npm advisory severity
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct VulnerabilitySummary {
+    pub info: u32,
+    pub low: u32,
+    pub moderate: u32,
+    pub high: u32,
+    pub critical: u32,
+    pub total: u32,
+}
+
+/// Dependency audit result for an installed skill
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct SkillAudit {
+    pub name: String,
+    pub version: String,
+    pub vulnerabilities: VulnerabilitySummary,
+    /// "npm-audit" when the skill's dependency tree was audited, or
+    /// "advisory-api" when only the package itself could be checked
+    pub source: String,
 }
 
 /// Threat level determined by VirusTotal scan
@@ -378,6 +923,21 @@ pub struct ScanResult {
     pub total_scanners: u32,
     pub scan_date: String,
     pub permalink: Option<String>,
+    /// Engines that reported the package as malicious or suspicious,
+    /// malicious first
+    #[serde(default)]
+    pub detections: Vec<EngineDetection>,
+}
+
+/// One VirusTotal engine's malicious or suspicious verdict
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct EngineDetection {
+    pub engine: String,
+    /// "malicious" or "suspicious"
+    pub category: String,
+    /// Engine's signature name, e.g. "Trojan.JS.Agent"
+    pub result: Option<String>,
 }
 
 /// Request to scan a skill package
@@ -399,6 +959,23 @@ pub struct ServiceProcessStatus {
     pub uptime_seconds: Option<u64>,
     pub memory_mb: Option<u64>,
     pub cpu_percent: Option<f32>,
+    /// Why metrics are missing, or why a busy gateway port isn't counted as the gateway
+    #[serde(default)]
+    #[ts(optional)]
+    pub note: Option<String>,
+}
+
+/// A running openclaw-related process, listed so strays can be cleaned up
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct OpenClawProcess {
+    pub pid: u32,
+    pub name: String,
+    /// Full command line, empty when it isn't readable
+    pub cmdline: String,
+    pub memory_mb: u32,
+    /// Whether this looks like the gateway (killing it takes the bot offline)
+    pub gateway: bool,
 }
 
 /// Combined status of all OpenClaw services with system metrics
@@ -413,6 +990,29 @@ pub struct ServicesStatus {
     pub system_memory_used_mb: Option<u64>,
 }
 
+/// One point-in-time sample of system and gateway resource usage
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct MetricsSample {
+    /// Unix timestamp (seconds) when the sample was taken
+    pub timestamp: u64,
+    pub system_cpu_percent: f32,
+    pub system_memory_used_mb: u64,
+    pub system_memory_total_mb: u64,
+    pub gateway_running: bool,
+    pub gateway_cpu_percent: Option<f32>,
+    pub gateway_memory_mb: Option<u64>,
+}
+
+/// Recent metrics samples, oldest first
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct MetricsHistoryResponse {
+    /// Seconds between consecutive samples
+    pub interval_seconds: u64,
+    pub samples: Vec<MetricsSample>,
+}
+
 /// Response after performing a service action (start/stop/restart)
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../bindings/")]
@@ -422,6 +1022,26 @@ pub struct ServiceActionResponse {
     pub message: String,
 }
 
+/// Response from GET /api/wizard/generate-credential
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct GeneratedCredential {
+    pub credential: String,
+    pub length: u32,
+}
+
+/// Response from POST /api/dashboard/auth/rotate.
+/// The credential is only ever returned here; it is not logged.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct CredentialRotationResponse {
+    pub credential: String,
+    pub auth_mode: String,
+    /// False when the config was updated but the gateway restart failed
+    pub gateway_restarted: bool,
+    pub message: String,
+}
+
 /// Individual diagnostic check from openclaw doctor
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../bindings/")]
@@ -459,14 +1079,34 @@ pub struct LogsResponse {
     pub service: String,
     pub lines: Vec<LogLine>,
     pub total: u32,
+    /// Log file the lines were read from; None if they came from `openclaw logs` or journald
+    pub log_path: Option<String>,
+    /// Rotated files (oldest first) that contributed lines before `log_path`
+    #[serde(default)]
+    #[ts(optional)]
+    pub rotated_paths: Option<Vec<String>>,
+    /// Systemd unit whose journal the lines came from, when there was no log file
+    #[serde(default)]
+    #[ts(optional)]
+    pub journald_unit: Option<String>,
+    /// Lines per level ("error", "warn", ...) across the requested window, before filtering
+    pub level_counts: HashMap<String, u32>,
 }
 
 /// Request to analyze log context with AI
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../bindings/")]
 pub struct LogAnalysisRequest {
-    pub log_context: String,
+    /// Log excerpt to analyze; when omitted or empty the server picks the
+    /// most recent error cluster from the service's logs
+    #[serde(default)]
+    #[ts(optional)]
+    pub log_context: Option<String>,
     pub service: String,
+    /// Model override for this analysis; falls back to the configured analysis model
+    #[serde(default)]
+    #[ts(optional)]
+    pub model: Option<String>,
 }
 
 /// AI-generated analysis of log errors
@@ -479,6 +1119,21 @@ pub struct LogAnalysis {
     pub confidence: String,
 }
 
+/// Combined incident diagnosis: recent errors, doctor checks and an analysis
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct LogDiagnosis {
+    pub service: String,
+    /// Latest error cluster from the service's logs; empty when none was found
+    pub error_lines: Vec<LogLine>,
+    pub doctor: DoctorReport,
+    /// Failing doctor checks that line up with the logged errors
+    pub correlations: Vec<String>,
+    pub analysis: LogAnalysis,
+    /// Whether `analysis` came from the AI provider rather than the heuristics
+    pub ai_analysis: bool,
+}
+
 // ===== Intelligence Types =====
 
 /// AI-powered cost optimization recommendation
@@ -507,6 +1162,26 @@ pub struct CostAnalysis {
     pub summary: Option<String>,
 }
 
+/// One persisted cost analysis, kept for charting costs over time
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct CostHistoryEntry {
+    pub analysis_date: String,
+    /// Unix timestamp (seconds) when the analysis was recorded
+    pub recorded_at: u64,
+    pub total_current_monthly: f64,
+    pub total_recommended_monthly: f64,
+    pub total_savings_monthly: f64,
+    pub recommendation_count: u32,
+}
+
+/// Cost analysis history, oldest first
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct CostHistoryResponse {
+    pub entries: Vec<CostHistoryEntry>,
+}
+
 /// Individual security finding from audit
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../bindings/")]
@@ -567,6 +1242,16 @@ pub struct ServerTarget {
     pub status: String,
 }
 
+/// Result of POST /api/multi-server/import-ssh-config
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct SshConfigImportResponse {
+    /// Servers added to servers.json
+    pub imported: Vec<ServerTarget>,
+    /// Hosts not imported, each with the reason
+    pub skipped: Vec<String>,
+}
+
 /// Response containing list of server targets
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../bindings/")]
@@ -583,6 +1268,16 @@ pub struct ServerTestResult {
     pub message: String,
 }
 
+/// Request to test connections to several servers at once
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct TestAllServersRequest {
+    /// Servers to test; all configured servers when omitted
+    #[serde(default)]
+    #[ts(optional)]
+    pub server_ids: Option<Vec<String>>,
+}
+
 /// Request to deploy to multiple servers
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../bindings/")]
@@ -590,6 +1285,13 @@ pub struct MultiServerDeployRequest {
     pub server_ids: Vec<String>,
 }
 
+/// Request to roll back several servers at once
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct RollbackBatchRequest {
+    pub server_ids: Vec<String>,
+}
+
 /// Progress update for a single server during multi-server deployment
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../bindings/")]
@@ -614,6 +1316,26 @@ pub struct ServerDeployResult {
     pub completed_stages: Vec<String>,
 }
 
+/// One recorded multi-server deployment run
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct DeploymentRecord {
+    /// Unix timestamp (seconds) when the deployment started
+    pub started_at: u64,
+    /// Unix timestamp (seconds) when the last server finished
+    pub finished_at: u64,
+    /// Servers requested for this run
+    pub server_ids: Vec<String>,
+    pub results: Vec<ServerDeployResult>,
+}
+
+/// Past multi-server deployments, oldest first
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct DeploymentHistoryResponse {
+    pub deployments: Vec<DeploymentRecord>,
+}
+
 // ===== SSH & Remote Setup Types =====
 
 /// SSH connection status and details
@@ -622,6 +1344,8 @@ pub struct ServerDeployResult {
 /// - host: Must match `^[a-zA-Z0-9.-]+$` (hostname or IP)
 /// - username: Must match `^[a-z_][a-z0-9_-]*$` (valid Unix username)
 /// - key_path: Absolute path or `~/.ssh/id_*` pattern
+///
+/// Enforced by `SshService::validate_ssh_request`.
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../bindings/")]
 pub struct SshConnection {
@@ -650,6 +1374,50 @@ pub struct SshConnectionResponse {
     pub connection: Option<SshConnection>,
 }
 
+/// Query for fetching a server's SSH host key
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct HostKeyQuery {
+    pub host: String,
+    /// SSH port (default 22)
+    #[serde(default)]
+    #[ts(optional)]
+    pub port: Option<u16>,
+}
+
+/// A single host key offered by a server
+#[derive(Debug, Clone, Serialize, Deserialize, TS, PartialEq)]
+#[ts(export, export_to = "../bindings/")]
+pub struct HostKeyInfo {
+    /// Key algorithm, e.g. "ssh-ed25519"
+    pub key_type: String,
+    /// OpenSSH SHA256 fingerprint, e.g. "SHA256:abc..."
+    pub fingerprint: String,
+}
+
+/// Host keys offered by a server, for user confirmation before trusting it
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct HostKeyResponse {
+    pub host: String,
+    pub port: u16,
+    pub keys: Vec<HostKeyInfo>,
+    /// Whether the host already has an entry in ~/.ssh/known_hosts
+    pub known: bool,
+}
+
+/// Request to add a server's host key to ~/.ssh/known_hosts
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct TrustHostRequest {
+    pub host: String,
+    #[serde(default)]
+    #[ts(optional)]
+    pub port: Option<u16>,
+    /// Fingerprint the user approved; the key is only added if it still matches
+    pub fingerprint: String,
+}
+
 /// Remote setup progress update (streamed via WebSocket)
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../bindings/")]
@@ -664,6 +1432,10 @@ pub struct RemoteSetupProgress {
     pub error: Option<String>,
     /// Unix timestamp (seconds)
     pub timestamp: u64,
+    /// Milliseconds since `stage` started; set on completed/failed updates
+    #[serde(default)]
+    #[ts(optional)]
+    pub elapsed_ms: Option<u32>,
 }
 
 /// Remote installation request (sent via WebSocket)
@@ -675,6 +1447,15 @@ pub struct RemoteInstallRequest {
     /// Optional: override WizardConfig from saved config
     #[ts(type = "Record<string, any> | null")]
     pub config: Option<serde_json::Value>,
+    /// Copy the local ~/.openclaw/openclaw.json verbatim instead of
+    /// rebuilding the config from WizardConfig
+    #[serde(default)]
+    #[ts(optional)]
+    pub use_local_config: Option<bool>,
+    /// OpenClaw version or npm dist-tag to install; latest when omitted
+    #[serde(default)]
+    #[ts(optional)]
+    pub openclaw_version: Option<String>,
 }
 
 // ===== Rollback Types =====
@@ -697,3 +1478,53 @@ pub struct RollbackResult {
     pub stages: Vec<RollbackStage>,
     pub error: Option<String>,
 }
+
+// ===== Confirmation Types =====
+
+/// Irreversible actions that require a confirmation token
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub enum DestructiveAction {
+    /// DELETE /api/docker/{id}
+    RemoveContainer,
+    /// DELETE /api/skills/{name}, or one entry of POST /api/skills/uninstall-batch
+    UninstallSkill,
+    /// POST /api/wizard/rollback
+    RollbackInstallation,
+    /// POST /api/multi-server/rollback/{id}
+    RollbackServer,
+    /// POST /api/multi-server/rollback-batch
+    RollbackServers,
+    /// POST /api/dashboard/config/set or /api/dashboard/config/reconcile
+    /// turning gateway auth off or binding the gateway beyond loopback
+    WeakenGatewayAccess,
+    /// POST /api/system/processes/{pid}/kill
+    KillProcess,
+    /// POST /api/docker/prune
+    PruneContainers,
+    /// "start-uninstall" on the /ws socket
+    UninstallOpenClaw,
+}
+
+/// Query for GET /api/confirm
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct ConfirmationTokenRequest {
+    pub action: DestructiveAction,
+    /// What the action applies to (container id, skill name, server id, PID);
+    /// omitted for actions without a target
+    #[serde(default)]
+    #[ts(optional)]
+    pub target: Option<String>,
+}
+
+/// Single-use token authorizing one destructive action.
+/// Send it back in the `X-Confirm-Token` header.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../bindings/")]
+pub struct ConfirmationToken {
+    pub token: String,
+    pub action: DestructiveAction,
+    pub target: Option<String>,
+    pub expires_in_secs: u64,
+}
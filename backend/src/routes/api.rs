@@ -1,17 +1,74 @@
-use axum::Json;
+use axum::{
+    Json,
+    extract::{Path, Query},
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
 
+use crate::error::AppError;
+
+use crate::models::types::{
+    DestructiveAction, HealthStatus, OpenClawProcess, ReadinessReport, SystemIdentity,
+    WizardVersion,
+};
 use crate::models::{ApiResponse, OpenClawDetection, SystemInfo, SystemRequirements};
-use crate::services::{detection::DetectionService, platform::Platform};
+use crate::routes::confirm::confirm_token;
+use crate::services::{
+    DockerService,
+    confirmation::ConfirmationService,
+    detection::DetectionService,
+    health::HealthService,
+    offline::OfflineMode,
+    platform::{FeatureTool, Platform},
+    processes::ProcessService,
+    support_bundle::SupportBundle,
+};
 
-/// Health check endpoint
-pub async fn health() -> Json<ApiResponse<()>> {
+/// Health check endpoint (alias for liveness), reporting offline mode
+pub async fn health() -> Json<ApiResponse<HealthStatus>> {
     Json(ApiResponse {
         success: true,
-        data: None,
+        data: Some(HealthStatus {
+            offline: OfflineMode::is_enabled(),
+        }),
         error: None,
     })
 }
 
+/// Liveness: always 200 while the process is serving requests
+pub async fn health_live() -> Json<ApiResponse<HealthStatus>> {
+    health().await
+}
+
+/// Readiness: 200 when the config is valid and the gateway is reachable, 503 otherwise
+pub async fn health_ready() -> (StatusCode, Json<ApiResponse<ReadinessReport>>) {
+    let report = HealthService::readiness().await;
+    let status = if report.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    let error = (!report.ready).then(|| {
+        report
+            .checks
+            .iter()
+            .filter(|c| !c.ok)
+            .map(|c| c.message.clone())
+            .collect::<Vec<_>>()
+            .join("; ")
+    });
+
+    (
+        status,
+        Json(ApiResponse {
+            success: report.ready,
+            data: Some(report),
+            error,
+        }),
+    )
+}
+
 /// System information endpoint
 pub async fn system_info() -> Json<ApiResponse<SystemInfo>> {
     let info = Platform::system_info();
@@ -23,20 +80,91 @@ pub async fn system_info() -> Json<ApiResponse<SystemInfo>> {
     })
 }
 
-/// System requirements endpoint
-pub async fn system_requirements() -> Json<ApiResponse<SystemRequirements>> {
-    let requirements = Platform::system_requirements();
+/// Effective user, HOME and resolved config directories
+pub async fn system_identity() -> Json<ApiResponse<SystemIdentity>> {
+    Json(ApiResponse {
+        success: true,
+        data: Some(Platform::identity()),
+        error: None,
+    })
+}
 
+/// Version, git commit and build time of the running wizard
+pub async fn wizard_version() -> Json<ApiResponse<WizardVersion>> {
     Json(ApiResponse {
         success: true,
-        data: Some(requirements),
+        data: Some(Platform::wizard_version()),
         error: None,
     })
 }
 
+/// Support bundle download: redacted configs, recent logs, doctor report,
+/// system info and wizard version as a `.tar.gz`
+pub async fn support_bundle() -> Result<Response, AppError> {
+    let (file_name, archive) = tokio::task::spawn_blocking(SupportBundle::build)
+        .await
+        .map_err(|e| AppError::InternalError(format!("Support bundle task failed: {}", e)))?
+        .map_err(|e| AppError::InternalError(format!("Failed to build support bundle: {:#}", e)))?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/gzip".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", file_name),
+            ),
+            (header::CACHE_CONTROL, "no-store".to_string()),
+        ],
+        archive,
+    )
+        .into_response())
+}
+
+/// Query parameters for the requirements endpoint
+#[derive(Debug, Deserialize)]
+pub struct RequirementsQuery {
+    /// Comma-separated optional features to check tools for
+    /// (`docker`/`sandbox`, `git`/`skills`)
+    pub features: Option<String>,
+}
+
+/// System requirements endpoint
+///
+/// Always checks the OS, Node.js, npm and disk space; Docker and Git are
+/// checked only when their features are requested.
+pub async fn system_requirements(
+    Query(params): Query<RequirementsQuery>,
+) -> Result<Json<ApiResponse<SystemRequirements>>, AppError> {
+    let tools = FeatureTool::parse_list(params.features.as_deref().unwrap_or_default())
+        .map_err(AppError::BadRequest)?;
+
+    let mut extra = Vec::new();
+    for tool in tools {
+        extra.push(match tool {
+            FeatureTool::Docker => {
+                let status = DockerService::new().await.check_available().await?;
+                Platform::docker_requirement(&status)
+            }
+            FeatureTool::Git => Platform::git_requirement(),
+        });
+    }
+    let requirements = Platform::with_feature_checks(Platform::system_requirements(), extra);
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(requirements),
+        error: None,
+    }))
+}
+
 /// OpenClaw detection endpoint
+///
+/// Also reports whether a newer version is published on npm.
 pub async fn detect_openclaw() -> Json<ApiResponse<OpenClawDetection>> {
-    let detection = DetectionService::detect_openclaw();
+    let mut detection = DetectionService::detect_openclaw();
+    if detection.installed && !OfflineMode::is_enabled() {
+        DetectionService::check_for_update(&mut detection).await;
+    }
 
     Json(ApiResponse {
         success: true,
@@ -44,3 +172,40 @@ pub async fn detect_openclaw() -> Json<ApiResponse<OpenClawDetection>> {
         error: None,
     })
 }
+
+/// Running openclaw processes, so strays left by failed installs or logins
+/// can be cleaned up from the dashboard
+pub async fn list_processes() -> Result<Json<ApiResponse<Vec<OpenClawProcess>>>, AppError> {
+    let processes = tokio::task::spawn_blocking(ProcessService::list)
+        .await
+        .map_err(|e| AppError::InternalError(format!("Process listing task failed: {}", e)))?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(processes),
+        error: None,
+    }))
+}
+
+/// Kill one openclaw process; any other PID is rejected
+///
+/// Requires an `X-Confirm-Token` for `KillProcess` on this PID.
+pub async fn kill_process(
+    Path(pid): Path<u32>,
+    headers: HeaderMap,
+) -> Result<Json<ApiResponse<OpenClawProcess>>, AppError> {
+    ConfirmationService::consume(
+        DestructiveAction::KillProcess,
+        Some(&pid.to_string()),
+        confirm_token(&headers),
+    )?;
+    let killed = tokio::task::spawn_blocking(move || ProcessService::kill(pid))
+        .await
+        .map_err(|e| AppError::InternalError(format!("Process kill task failed: {}", e)))??;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(killed),
+        error: None,
+    }))
+}
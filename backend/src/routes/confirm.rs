@@ -0,0 +1,36 @@
+//! # Confirmation Routes
+//!
+//! Issues the tokens that destructive endpoints require:
+//! - GET /api/confirm?action=...&target=... - Issue a single-use confirmation token
+//!
+//! The token goes back in the `X-Confirm-Token` header of the DELETE/POST
+//! that performs the action.
+
+use axum::{Json, extract::Query, http::HeaderMap};
+
+use crate::error::AppError;
+use crate::models::types::{ApiResponse, ConfirmationToken, ConfirmationTokenRequest};
+use crate::services::confirmation::{CONFIRM_TOKEN_HEADER, ConfirmationService};
+
+/// GET /api/confirm
+///
+/// Issue a token for one destructive action. Returns 400 when the action
+/// needs a target and none is given.
+pub async fn issue_confirmation(
+    Query(request): Query<ConfirmationTokenRequest>,
+) -> Result<Json<ApiResponse<ConfirmationToken>>, AppError> {
+    let token = ConfirmationService::issue(request.action, request.target)?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(token),
+        error: None,
+    }))
+}
+
+/// Confirmation token sent with a destructive request, if any
+pub fn confirm_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(CONFIRM_TOKEN_HEADER)
+        .and_then(|value| value.to_str().ok())
+}
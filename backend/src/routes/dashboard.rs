@@ -2,15 +2,39 @@
 //!
 //! Provides HTTP endpoints for dashboard functionality:
 //! - Daemon process management (start/stop/restart/status)
-//! - Health monitoring (gateway health snapshot)
+//! - Health monitoring (gateway health snapshot, CPU/memory history)
 //! - Configuration CRUD (read/write/import/export openclaw.json)
-
-use crate::models::types::{ApiResponse, DaemonActionResponse, DaemonStatus, HealthSnapshot};
+//! - Config templates with `${ENV_VAR}` placeholders for secrets
+//! - Setting single allowlisted gateway config keys
+//! - Gateway auth credential rotation
+
+use crate::error::AppError;
+use crate::models::WizardConfig;
+use crate::models::types::{
+    ApiResponse, ChatUrlResponse, ConfigReconcileReport, ConfigSetRequest, ConfigSetResponse,
+    CredentialRotationResponse, DaemonActionResponse, DaemonStatus, DestructiveAction,
+    HealthSnapshot, MetricsHistoryResponse,
+};
+use crate::routes::confirm::confirm_token;
 use crate::services::{
-    config::ConfigWriter, daemon::DaemonService, health::HealthService, platform::Platform,
+    config::ConfigWriter,
+    config_set::ConfigSetter,
+    config_template::ConfigTemplate,
+    confirmation::ConfirmationService,
+    credentials::{CredentialService, DEFAULT_CREDENTIAL_LENGTH},
+    daemon::DaemonService,
+    health::HealthService,
+    metrics::MetricsService,
+    offline::OfflineMode,
+    platform::{DEFAULT_GATEWAY_PORT, Platform},
+    reconcile::ConfigReconciler,
+    service_manager::ServiceManager,
+    wizard_config::WizardConfigStore,
 };
 use axum::Json;
-use std::path::PathBuf;
+use axum::http::{HeaderMap, HeaderName, header};
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
 
 // ===== Daemon Management Endpoints =====
 
@@ -30,73 +54,52 @@ pub async fn daemon_status() -> Json<ApiResponse<DaemonStatus>> {
 /// POST /api/dashboard/daemon/start
 ///
 /// Starts the OpenClaw gateway daemon process.
-pub async fn daemon_start() -> Json<ApiResponse<DaemonActionResponse>> {
-    match DaemonService::start() {
-        Ok(_) => Json(ApiResponse {
+pub async fn daemon_start() -> Result<Json<ApiResponse<DaemonActionResponse>>, AppError> {
+    DaemonService::start()
+        .map_err(|e| AppError::InternalError(format!("Failed to start daemon: {}", e)))?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(DaemonActionResponse {
             success: true,
-            data: Some(DaemonActionResponse {
-                success: true,
-                message: "Daemon started successfully".to_string(),
-            }),
-            error: None,
-        }),
-        Err(e) => Json(ApiResponse {
-            success: false,
-            data: Some(DaemonActionResponse {
-                success: false,
-                message: format!("Failed to start daemon: {}", e),
-            }),
-            error: Some(format!("Failed to start daemon: {}", e)),
+            message: "Daemon started successfully".to_string(),
         }),
-    }
+        error: None,
+    }))
 }
 
 /// POST /api/dashboard/daemon/stop
 ///
 /// Stops the OpenClaw gateway daemon process.
-pub async fn daemon_stop() -> Json<ApiResponse<DaemonActionResponse>> {
-    match DaemonService::stop() {
-        Ok(_) => Json(ApiResponse {
+pub async fn daemon_stop() -> Result<Json<ApiResponse<DaemonActionResponse>>, AppError> {
+    DaemonService::stop()
+        .map_err(|e| AppError::InternalError(format!("Failed to stop daemon: {}", e)))?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(DaemonActionResponse {
             success: true,
-            data: Some(DaemonActionResponse {
-                success: true,
-                message: "Daemon stopped successfully".to_string(),
-            }),
-            error: None,
-        }),
-        Err(e) => Json(ApiResponse {
-            success: false,
-            data: Some(DaemonActionResponse {
-                success: false,
-                message: format!("Failed to stop daemon: {}", e),
-            }),
-            error: Some(format!("Failed to stop daemon: {}", e)),
+            message: "Daemon stopped successfully".to_string(),
         }),
-    }
+        error: None,
+    }))
 }
 
 /// POST /api/dashboard/daemon/restart
 ///
 /// Restarts the OpenClaw gateway daemon process (stop + wait + start).
-pub async fn daemon_restart() -> Json<ApiResponse<DaemonActionResponse>> {
-    match DaemonService::restart() {
-        Ok(_) => Json(ApiResponse {
+pub async fn daemon_restart() -> Result<Json<ApiResponse<DaemonActionResponse>>, AppError> {
+    DaemonService::restart()
+        .map_err(|e| AppError::InternalError(format!("Failed to restart daemon: {}", e)))?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(DaemonActionResponse {
             success: true,
-            data: Some(DaemonActionResponse {
-                success: true,
-                message: "Daemon restarted successfully".to_string(),
-            }),
-            error: None,
+            message: "Daemon restarted successfully".to_string(),
         }),
-        Err(e) => Json(ApiResponse {
-            success: false,
-            data: Some(DaemonActionResponse {
-                success: false,
-                message: format!("Failed to restart daemon: {}", e),
-            }),
-            error: Some(format!("Failed to restart daemon: {}", e)),
-        }),
-    }
+        error: None,
+    }))
 }
 
 // ===== Health Monitoring Endpoint =====
@@ -106,7 +109,7 @@ pub async fn daemon_restart() -> Json<ApiResponse<DaemonActionResponse>> {
 /// Returns current health snapshot of the OpenClaw gateway.
 /// Includes gateway reachability, mode, channel status, and session count.
 pub async fn get_health() -> Json<ApiResponse<HealthSnapshot>> {
-    let health = HealthService::get_health();
+    let health = HealthService::get_health().await;
 
     Json(ApiResponse {
         success: true,
@@ -115,126 +118,269 @@ pub async fn get_health() -> Json<ApiResponse<HealthSnapshot>> {
     })
 }
 
+/// GET /api/dashboard/metrics/history
+///
+/// Returns the last few minutes of system and gateway CPU/memory samples.
+pub async fn metrics_history() -> Json<ApiResponse<MetricsHistoryResponse>> {
+    Json(ApiResponse {
+        success: true,
+        data: Some(MetricsService::history()),
+        error: None,
+    })
+}
+
 // ===== Configuration CRUD Endpoints =====
 
 /// Get the path to the wizard configuration file
 fn config_path() -> PathBuf {
-    Platform::config_dir()
-        .unwrap_or_else(|_| PathBuf::from("."))
-        .join("openclaw.json")
+    Platform::wizard_config_path().unwrap_or_else(|_| PathBuf::from("./openclaw.json"))
+}
+
+/// Read the wizard config, mapping a missing file to 404
+fn read_config(path: &Path) -> Result<serde_json::Value, AppError> {
+    if !path.exists() {
+        return Err(AppError::NotFound(format!(
+            "Config not found at {}",
+            path.display()
+        )));
+    }
+    ConfigWriter::read_json::<serde_json::Value>(path).map_err(|e| {
+        AppError::InternalError(format!(
+            "Failed to read config from {}: {}",
+            path.display(),
+            e
+        ))
+    })
 }
 
 /// GET /api/dashboard/config
 ///
 /// Returns the current OpenClaw configuration as JSON.
-pub async fn get_config() -> Json<ApiResponse<serde_json::Value>> {
-    let path = config_path();
+pub async fn get_config() -> Result<Json<ApiResponse<serde_json::Value>>, AppError> {
+    let config = read_config(&config_path())?;
 
-    match ConfigWriter::read_json::<serde_json::Value>(&path) {
-        Ok(config) => Json(ApiResponse {
-            success: true,
-            data: Some(config),
-            error: None,
-        }),
-        Err(e) => Json(ApiResponse {
-            success: false,
-            data: None,
-            error: Some(format!(
-                "Failed to read config from {}: {}",
-                path.display(),
-                e
-            )),
-        }),
-    }
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(config),
+        error: None,
+    }))
 }
 
 /// PUT /api/dashboard/config
 ///
 /// Saves new configuration atomically to openclaw.json in the wizard config directory.
 /// Validates that input is valid JSON before saving.
-pub async fn save_config_handler(Json(config): Json<serde_json::Value>) -> Json<ApiResponse<()>> {
+pub async fn save_config_handler(
+    Json(config): Json<serde_json::Value>,
+) -> Result<Json<ApiResponse<()>>, AppError> {
     let path = config_path();
 
     // Validate it's a JSON object (not null, array, or primitive)
     if !config.is_object() {
-        return Json(ApiResponse {
-            success: false,
-            data: None,
-            error: Some("Config must be a JSON object".to_string()),
-        });
+        return Err(AppError::BadRequest(
+            "Config must be a JSON object".to_string(),
+        ));
     }
 
-    match ConfigWriter::write_json(&path, &config) {
-        Ok(_) => Json(ApiResponse {
-            success: true,
-            data: Some(()),
-            error: None,
-        }),
-        Err(e) => Json(ApiResponse {
-            success: false,
-            data: None,
-            error: Some(format!("Failed to save config: {}", e)),
-        }),
-    }
+    ConfigWriter::write_json(&path, &config)
+        .map_err(|e| AppError::InternalError(format!("Failed to save config: {}", e)))?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(()),
+        error: None,
+    }))
 }
 
 /// POST /api/dashboard/config/import
 ///
 /// Imports configuration from uploaded JSON.
+/// `${OPENCLAW_*}` placeholders (see `/config/template`) are resolved from the
+/// environment first; an unset variable or any other name rejects the import.
 /// Validates and saves atomically to openclaw.json in the wizard config directory.
-pub async fn import_config(Json(config): Json<serde_json::Value>) -> Json<ApiResponse<()>> {
-    // Import is the same as save - just semantically different endpoint
+pub async fn import_config(
+    Json(config): Json<serde_json::Value>,
+) -> Result<Json<ApiResponse<()>>, AppError> {
+    let config =
+        ConfigTemplate::resolve_env(&config).map_err(|e| AppError::BadRequest(e.to_string()))?;
+
     save_config_handler(Json(config)).await
 }
 
 /// GET /api/dashboard/config/export
 ///
 /// Exports current configuration as JSON (same as get_config).
-pub async fn export_config() -> Json<ApiResponse<serde_json::Value>> {
+pub async fn export_config() -> Result<Json<ApiResponse<serde_json::Value>>, AppError> {
     // Export is the same as get - just semantically different endpoint
     get_config().await
 }
 
+/// GET /api/dashboard/config/reconcile
+///
+/// Compares the wizard's saved settings with the config the gateway loads.
+pub async fn config_reconcile_status() -> Result<Json<ApiResponse<ConfigReconcileReport>>, AppError>
+{
+    let report = ConfigReconciler::status().map_err(|e| AppError::InternalError(e.to_string()))?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(report),
+        error: None,
+    }))
+}
+
+/// POST /api/dashboard/config/reconcile
+///
+/// Deploys the wizard's saved settings to the gateway config when they
+/// differ. Sections the wizard doesn't manage are kept. Settings with gateway
+/// auth off or a bind beyond loopback require an `X-Confirm-Token` for
+/// `WeakenGatewayAccess`.
+pub async fn config_reconcile_sync(
+    headers: HeaderMap,
+) -> Result<Json<ApiResponse<ConfigReconcileReport>>, AppError> {
+    let report = ConfigReconciler::sync(|| {
+        ConfirmationService::consume(
+            DestructiveAction::WeakenGatewayAccess,
+            None,
+            confirm_token(&headers),
+        )
+    })
+    .map_err(|e| {
+        e.downcast::<AppError>()
+            .unwrap_or_else(|e| AppError::InternalError(format!("Failed to sync config: {:#}", e)))
+    })?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(report),
+        error: None,
+    }))
+}
+
+/// POST /api/dashboard/config/set
+///
+/// Sets one allowlisted gateway config key via `openclaw config set` and
+/// returns the value read back (secrets masked). Unknown keys and values of
+/// the wrong type are rejected with 400. Turning gateway auth off or binding
+/// beyond loopback requires an `X-Confirm-Token` for `WeakenGatewayAccess`.
+pub async fn config_set(
+    headers: HeaderMap,
+    Json(request): Json<ConfigSetRequest>,
+) -> Result<Json<ApiResponse<ConfigSetResponse>>, AppError> {
+    if ConfigSetter::weakens_access(&request.path, &request.value) {
+        ConfirmationService::consume(
+            DestructiveAction::WeakenGatewayAccess,
+            None,
+            confirm_token(&headers),
+        )?;
+    }
+    let updated =
+        tokio::task::spawn_blocking(move || ConfigSetter::set(&request.path, &request.value))
+            .await
+            .map_err(|e| AppError::InternalError(format!("Config set task failed: {}", e)))??;
+    info!("Set config key {}", updated.path);
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(updated),
+        error: None,
+    }))
+}
+
+/// GET /api/dashboard/config/template
+///
+/// Returns the current configuration with secrets replaced by `${ENV_VAR}`
+/// placeholders, safe to commit and import on other machines.
+pub async fn config_template() -> Result<Json<ApiResponse<serde_json::Value>>, AppError> {
+    let config = read_config(&config_path())?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(ConfigTemplate::from_config(&config)),
+        error: None,
+    }))
+}
+
 /// GET /api/dashboard/chat-url
 ///
-/// Returns the authenticated gateway dashboard URL.
-/// Reads port and auth token from openclaw.json in the wizard config directory and constructs the URL.
-/// The token is placed in the URL fragment (#token=...) which is never sent over HTTP.
-pub async fn get_chat_url() -> Json<ApiResponse<serde_json::Value>> {
-    let path = config_path();
-    let config = match ConfigWriter::read_json::<serde_json::Value>(&path) {
-        Ok(c) => c,
-        Err(e) => {
-            return Json(ApiResponse {
-                success: false,
-                data: None,
-                error: Some(format!("Config not found: {}", e)),
-            });
-        }
-    };
+/// Returns the gateway dashboard URL, authenticated when token auth is on.
+/// Port and auth come from the gateway's ~/.openclaw/openclaw.json, falling
+/// back to the wizard's saved config (`gateway_port`, `auth_mode`, `auth_credential`).
+/// The token is placed in the URL fragment (#token=...) which is never sent
+/// over HTTP, and is also returned as `token`. It is never logged.
+pub async fn get_chat_url() -> Result<Json<ApiResponse<ChatUrlResponse>>, AppError> {
+    let gateway = Platform::openclaw_config_path()
+        .ok()
+        .filter(|path| path.exists())
+        .and_then(|path| ConfigWriter::read_json::<serde_json::Value>(&path).ok())
+        .unwrap_or_default();
+    let wizard = WizardConfigStore::load().ok();
+    if gateway.is_null() && wizard.is_none() {
+        return Err(AppError::NotFound(
+            "No gateway or wizard config found; complete the setup wizard first".to_string(),
+        ));
+    }
 
-    let port = config
-        .get("gateway")
-        .and_then(|g| g.get("port"))
-        .and_then(|p| p.as_u64())
-        .unwrap_or(18789);
+    let response = chat_url(&gateway, wizard.as_ref());
+    info!(
+        "Chat URL resolved (auth mode: {}, token: {})",
+        response.auth_mode,
+        if response.token.is_some() {
+            "[REDACTED]"
+        } else {
+            "none"
+        }
+    );
 
-    let token = config
-        .get("gateway")
-        .and_then(|g| g.get("auth"))
-        .and_then(|a| a.get("token"))
-        .and_then(|t| t.as_str());
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(response),
+        error: None,
+    }))
+}
 
-    let url = match token {
-        Some(t) => format!("http://127.0.0.1:{}/#token={}", port, t),
+/// Build the chat URL from the gateway config, filling gaps from the wizard config
+fn chat_url(gateway: &serde_json::Value, wizard: Option<&WizardConfig>) -> ChatUrlResponse {
+    let port = gateway["gateway"]["port"]
+        .as_u64()
+        .or_else(|| wizard.map(|w| u64::from(w.gateway_port)))
+        .unwrap_or(u64::from(DEFAULT_GATEWAY_PORT));
+
+    let auth_mode = gateway["gateway"]["auth"]["mode"]
+        .as_str()
+        .map(str::to_string)
+        .or_else(|| wizard.map(|w| w.auth_mode.clone()))
+        .unwrap_or_else(|| "none".to_string());
+
+    let token = gateway["gateway"]["auth"]["token"]
+        .as_str()
+        .map(str::to_string)
+        .or_else(|| wizard.and_then(|w| w.auth_credential.clone()))
+        .filter(|t| !t.is_empty() && auth_mode == "token");
+
+    let url = match &token {
+        Some(t) => format!("http://127.0.0.1:{}/#token={}", port, fragment_escape(t)),
         None => format!("http://127.0.0.1:{}/", port),
     };
 
-    Json(ApiResponse {
-        success: true,
-        data: Some(serde_json::json!({ "url": url })),
-        error: None,
-    })
+    ChatUrlResponse {
+        url,
+        auth_mode,
+        token,
+    }
+}
+
+/// Percent-encode everything but RFC 3986 unreserved characters
+fn fragment_escape(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
 }
 
 /// GET /api/dashboard/version
@@ -257,10 +403,14 @@ pub async fn get_version_info() -> Json<ApiResponse<serde_json::Value>> {
         _ => "unknown".to_string(),
     };
 
-    // Check for latest version using npm view
-    let latest_version = match SafeCommand::run("npm", &["view", "openclaw", "version"]) {
-        Ok(output) if output.exit_code == 0 => output.stdout.trim().to_string(),
-        _ => current_version.clone(), // Fallback to current if check fails
+    // Check for latest version using npm view (skipped in offline mode)
+    let latest_version = if OfflineMode::is_enabled() {
+        current_version.clone()
+    } else {
+        match SafeCommand::run("npm", &["view", "openclaw", "version"]) {
+            Ok(output) if output.exit_code == 0 => output.stdout.trim().to_string(),
+            _ => current_version.clone(), // Fallback to current if check fails
+        }
     };
 
     let update_available = current_version != "unknown"
@@ -278,6 +428,52 @@ pub async fn get_version_info() -> Json<ApiResponse<serde_json::Value>> {
     })
 }
 
+// ===== Gateway Credentials =====
+
+/// POST /api/dashboard/auth/rotate
+///
+/// Generates a new gateway credential, writes it to the gateway and wizard
+/// configs, and restarts the gateway so it takes effect. The credential is
+/// returned only in this response (marked no-store) and never logged.
+pub async fn rotate_auth_credential() -> Result<
+    (
+        [(HeaderName, &'static str); 1],
+        Json<ApiResponse<CredentialRotationResponse>>,
+    ),
+    AppError,
+> {
+    let gateway_path = Platform::openclaw_config_path()
+        .map_err(|e| AppError::InternalError(format!("Failed to locate gateway config: {}", e)))?;
+    let credential = CredentialService::generate(DEFAULT_CREDENTIAL_LENGTH);
+    let auth_mode = CredentialService::rotate(&gateway_path, &config_path(), &credential)?;
+    info!("Gateway credential rotated (auth mode: {})", auth_mode);
+
+    let (gateway_restarted, message) = match ServiceManager::restart_gateway() {
+        Ok(response) if response.success => {
+            (true, "Credential rotated and gateway restarted".to_string())
+        }
+        Ok(response) => (false, response.message),
+        Err(e) => (false, format!("Failed to restart gateway: {}", e)),
+    };
+    if !gateway_restarted {
+        warn!("Credential rotated but gateway restart failed: {}", message);
+    }
+
+    Ok((
+        [(header::CACHE_CONTROL, "no-store")],
+        Json(ApiResponse {
+            success: true,
+            data: Some(CredentialRotationResponse {
+                credential,
+                auth_mode,
+                gateway_restarted,
+                message,
+            }),
+            error: None,
+        }),
+    ))
+}
+
 // ===== WhatsApp Connection =====
 
 use crate::services::whatsapp::{WhatsAppProgress, WhatsAppService};
@@ -329,3 +525,56 @@ async fn handle_whatsapp_socket(mut socket: WebSocket) {
     let _ = connect_task.await;
     // WebSocket will close automatically when dropped
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn wizard(auth_mode: &str, credential: Option<&str>) -> WizardConfig {
+        serde_json::from_value(json!({
+            "provider": "anthropic",
+            "auth_type": "api-key",
+            "gateway_port": 4100,
+            "gateway_bind": "127.0.0.1",
+            "auth_mode": auth_mode,
+            "auth_credential": credential,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_chat_url_uses_gateway_token() {
+        let gateway = json!({
+            "gateway": { "port": 18789, "auth": { "mode": "token", "token": "abc+/=" } }
+        });
+        let response = chat_url(&gateway, None);
+        assert_eq!(response.url, "http://127.0.0.1:18789/#token=abc%2B%2F%3D");
+        assert_eq!(response.token.as_deref(), Some("abc+/="));
+        assert_eq!(response.auth_mode, "token");
+    }
+
+    #[test]
+    fn test_chat_url_falls_back_to_wizard_credential() {
+        let response = chat_url(
+            &serde_json::Value::Null,
+            Some(&wizard("token", Some("t0k"))),
+        );
+        assert_eq!(response.url, "http://127.0.0.1:4100/#token=t0k");
+        assert_eq!(response.token.as_deref(), Some("t0k"));
+    }
+
+    #[test]
+    fn test_chat_url_without_token_auth() {
+        let response = chat_url(
+            &serde_json::Value::Null,
+            Some(&wizard("password", Some("hunter2"))),
+        );
+        assert_eq!(response.url, "http://127.0.0.1:4100/");
+        assert_eq!(response.token, None);
+
+        let response = chat_url(&json!({}), None);
+        assert_eq!(response.url, "http://127.0.0.1:18789/");
+        assert_eq!(response.auth_mode, "none");
+    }
+}
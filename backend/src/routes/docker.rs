@@ -2,27 +2,41 @@
 //!
 //! REST endpoints for Docker sandbox container lifecycle:
 //! - GET  /api/docker/status     — Check Docker availability and version
-//! - GET  /api/docker/containers — List managed containers
+//! - GET  /api/docker/containers — List managed containers (?status=&all=&sort=)
 //! - POST /api/docker/create     — Create a new sandbox container
+//! - POST /api/docker/prune      — Remove exited/created sandbox containers
 //! - POST /api/docker/{id}/stop  — Stop a running container
 //! - DELETE /api/docker/{id}     — Remove a container
 //! - GET  /api/docker/{id}/logs  — Fetch container logs
+//! - GET  /ws/docker/{id}/logs   — Stream container logs live over WebSocket
+//! - GET  /ws/docker/events      — Push managed container state changes
 //!
 //! SECURITY:
 //! - All containers created with strict resource limits
-//! - Container limit of 5 enforced at service layer
+//! - Container limit (default 5, OPENCLAW_MAX_CONTAINERS) enforced at service layer
+//! - Images restricted to ~/.openclaw/docker-image-allowlist.json (default: node:*-alpine)
 //! - Docker-not-available returns 200 with available: false (not an error)
 
 use axum::Json;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
 use axum::extract::{Path, Query};
+use axum::http::HeaderMap;
+use axum::response::Response;
+use futures::StreamExt;
 use serde::Deserialize;
+use tokio::sync::broadcast;
+use tracing::{info, warn};
 
 use crate::error::AppError;
 use crate::models::types::{
-    ApiResponse, ContainerInfo, ContainerLogsResponse, DockerCreateRequest, DockerCreateResponse,
-    DockerStatusResponse, EmptyResponse,
+    ApiResponse, ContainerInfo, ContainerListQuery, ContainerLogsResponse, DestructiveAction,
+    DockerCreateRequest, DockerCreateResponse, DockerPruneResponse, DockerStatusResponse,
+    EmptyResponse,
 };
+use crate::routes::confirm::confirm_token;
 use crate::services::DockerService;
+use crate::services::confirmation::ConfirmationService;
+use crate::services::docker_events::DockerEvents;
 
 /// Query parameters for logs endpoint.
 #[derive(Debug, Deserialize)]
@@ -38,7 +52,7 @@ pub struct LogsQuery {
 /// with `available: false` — this is not an error condition since the user
 /// may not have Docker installed.
 pub async fn docker_status() -> Json<DockerStatusResponse> {
-    let service = DockerService::new();
+    let service = DockerService::new().await;
 
     match service.check_available().await {
         Ok(status) => Json(status),
@@ -46,18 +60,26 @@ pub async fn docker_status() -> Json<DockerStatusResponse> {
             available: false,
             version: None,
             containers: vec![],
+            socket: None,
             error: Some("Failed to check Docker status".to_string()),
+            current_count: 0,
+            max_containers: DockerService::max_containers() as u32,
+            api_version: None,
         }),
     }
 }
 
 /// GET /api/docker/containers
 ///
-/// Lists all containers managed by OpenClaw Wizard (filtered by label).
-pub async fn list_containers() -> Result<Json<ApiResponse<Vec<ContainerInfo>>>, AppError> {
-    let service = DockerService::new();
+/// Lists containers managed by OpenClaw Wizard (filtered by label).
+/// Optional `status` filter, `all=false` to hide exited containers, and
+/// `sort` (`running` first by default, or `newest` / `oldest`).
+pub async fn list_containers(
+    Query(query): Query<ContainerListQuery>,
+) -> Result<Json<ApiResponse<Vec<ContainerInfo>>>, AppError> {
+    let service = DockerService::new().await;
 
-    let containers = service.list_containers().await?;
+    let containers = service.list_containers_with(&query).await?;
 
     Ok(Json(ApiResponse {
         success: true,
@@ -69,22 +91,47 @@ pub async fn list_containers() -> Result<Json<ApiResponse<Vec<ContainerInfo>>>,
 /// POST /api/docker/create
 ///
 /// Creates a new sandbox container with strict security limits.
-/// Returns 429 if the container limit (5) is exceeded.
+/// Returns 400 for an invalid name or disallowed image, 409 if the name is taken,
+/// and 429 if the container limit (5) is exceeded.
 pub async fn create_container(
     Json(request): Json<DockerCreateRequest>,
 ) -> Result<Json<DockerCreateResponse>, AppError> {
-    let service = DockerService::new();
+    let service = DockerService::new().await;
 
     let response = service.create_sandbox(&request).await?;
 
     Ok(Json(response))
 }
 
+/// POST /api/docker/prune
+///
+/// Removes managed containers in Exited or Created state so they no longer
+/// count against the container limit.
+/// Requires an `X-Confirm-Token` for `PruneContainers`.
+pub async fn prune_containers(
+    headers: HeaderMap,
+) -> Result<Json<ApiResponse<DockerPruneResponse>>, AppError> {
+    ConfirmationService::consume(
+        DestructiveAction::PruneContainers,
+        None,
+        confirm_token(&headers),
+    )?;
+    let service = DockerService::new().await;
+
+    let result = service.prune_exited().await?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(result),
+        error: None,
+    }))
+}
+
 /// POST /api/docker/{id}/stop
 ///
 /// Stops a running container with a 10-second timeout.
 pub async fn stop_container(Path(id): Path<String>) -> Result<Json<EmptyResponse>, AppError> {
-    let service = DockerService::new();
+    let service = DockerService::new().await;
 
     service.stop_container(&id).await?;
 
@@ -97,8 +144,17 @@ pub async fn stop_container(Path(id): Path<String>) -> Result<Json<EmptyResponse
 /// DELETE /api/docker/{id}
 ///
 /// Force removes a container (also stops it if running).
-pub async fn remove_container(Path(id): Path<String>) -> Result<Json<EmptyResponse>, AppError> {
-    let service = DockerService::new();
+/// Requires an `X-Confirm-Token` for `RemoveContainer` on this id.
+pub async fn remove_container(
+    Path(id): Path<String>,
+    headers: HeaderMap,
+) -> Result<Json<EmptyResponse>, AppError> {
+    ConfirmationService::consume(
+        DestructiveAction::RemoveContainer,
+        Some(&id),
+        confirm_token(&headers),
+    )?;
+    let service = DockerService::new().await;
 
     service.remove_container(&id).await?;
 
@@ -116,10 +172,150 @@ pub async fn container_logs(
     Path(id): Path<String>,
     Query(query): Query<LogsQuery>,
 ) -> Result<Json<ContainerLogsResponse>, AppError> {
-    let service = DockerService::new();
+    let service = DockerService::new().await;
 
     let tail = query.tail.unwrap_or(100);
     let logs = service.get_container_logs(&id, tail).await?;
 
     Ok(Json(logs))
 }
+
+/// GET /ws/docker/{id}/logs
+///
+/// WebSocket upgrade for live container log streaming. Sends the last `tail`
+/// lines (default: 100), then follows new output until the container stops
+/// or the client disconnects.
+pub async fn ws_container_logs(
+    ws: WebSocketUpgrade,
+    Path(id): Path<String>,
+    Query(query): Query<LogsQuery>,
+) -> Response {
+    let tail = query.tail.unwrap_or(100);
+    ws.on_upgrade(move |socket| handle_container_log_socket(socket, id, tail))
+}
+
+/// Forward followed container logs to the WebSocket
+async fn handle_container_log_socket(mut socket: WebSocket, id: String, tail: u32) {
+    info!("Container log WebSocket connection established for {}", id);
+
+    let service = DockerService::new().await;
+    let stream = match service.follow_container_logs(&id, tail) {
+        Ok(stream) => stream,
+        Err(e) => {
+            let error_msg = serde_json::json!({
+                "type": "error",
+                "content": e.to_string(),
+            });
+            let _ = socket
+                .send(Message::Text(error_msg.to_string().into()))
+                .await;
+            return;
+        }
+    };
+    futures::pin_mut!(stream);
+
+    loop {
+        tokio::select! {
+            item = stream.next() => {
+                let msg = match item {
+                    Some(Ok(line)) => serde_json::json!({
+                        "type": "log_line",
+                        "content": line,
+                    }),
+                    Some(Err(e)) => {
+                        warn!("Container log stream error for {}: {}", id, e);
+                        let error_msg = serde_json::json!({
+                            "type": "error",
+                            "content": e.to_string(),
+                        });
+                        let _ = socket
+                            .send(Message::Text(error_msg.to_string().into()))
+                            .await;
+                        break;
+                    }
+                    None => {
+                        let _ = socket
+                            .send(Message::Text(
+                                serde_json::json!({ "type": "end" }).to_string().into(),
+                            ))
+                            .await;
+                        break;
+                    }
+                };
+
+                if socket
+                    .send(Message::Text(msg.to_string().into()))
+                    .await
+                    .is_err()
+                {
+                    break;
+                }
+            }
+            incoming = socket.recv() => {
+                // Client closed or errored: stop following
+                if matches!(incoming, None | Some(Err(_)) | Some(Ok(Message::Close(_)))) {
+                    break;
+                }
+            }
+        }
+    }
+
+    info!("Container log stream ended for {}", id);
+}
+
+/// GET /ws/docker/events
+///
+/// Sends a `snapshot` of the last known state of each managed container,
+/// then a `container_event` whenever one starts, dies, is OOM-killed or is
+/// removed — including changes made outside the wizard.
+pub async fn ws_container_events(ws: WebSocketUpgrade) -> Response {
+    ws.on_upgrade(handle_container_events_socket)
+}
+
+async fn handle_container_events_socket(mut socket: WebSocket) {
+    let mut events = DockerEvents::subscribe();
+
+    let snapshot = serde_json::json!({
+        "type": "snapshot",
+        "events": DockerEvents::snapshot(),
+    });
+    if socket
+        .send(Message::Text(snapshot.to_string().into()))
+        .await
+        .is_err()
+    {
+        return;
+    }
+
+    loop {
+        tokio::select! {
+            event = events.recv() => {
+                let event = match event {
+                    Ok(event) => event,
+                    // Missed some events; the next one still carries current state
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("Docker event subscriber lagged by {} events", skipped);
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+                let msg = serde_json::json!({
+                    "type": "container_event",
+                    "event": event,
+                });
+                if socket
+                    .send(Message::Text(msg.to_string().into()))
+                    .await
+                    .is_err()
+                {
+                    break;
+                }
+            }
+            incoming = socket.recv() => {
+                if matches!(incoming, None | Some(Err(_)) | Some(Ok(Message::Close(_)))) {
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -6,21 +6,29 @@
 //! Endpoints:
 //! - POST /api/intelligence/cost-analysis — Analyze config for cost optimization
 //! - GET  /api/intelligence/security-audit — Run 8 rule-based security checks
+//! - GET  /api/intelligence/cost-history — Get recorded cost analyses over time
 //! - GET  /api/intelligence/pricing — Get static LLM pricing data
 
 use axum::Json;
 
 use crate::error::AppError;
-use crate::models::types::{ApiResponse, CostAnalysis, LlmPricingResponse, SecurityAudit};
+use crate::models::types::{
+    ApiResponse, CostAnalysis, CostHistoryResponse, LlmPricingResponse, SecurityAudit,
+};
 use crate::services::config::ConfigWriter;
 use crate::services::config_analyzer::ConfigAnalyzer;
+use crate::services::offline::OfflineMode;
+use crate::services::platform::Platform;
 use crate::services::security_auditor::SecurityAuditor;
+use tracing::warn;
 
 /// POST /api/intelligence/cost-analysis
 ///
 /// Analyzes the user's OpenClaw configuration for cost optimization
-/// opportunities using the configured AI provider.
+/// opportunities using the configured AI provider. Each result is appended
+/// to the cost history.
 pub async fn analyze_cost() -> Result<Json<ApiResponse<CostAnalysis>>, AppError> {
+    OfflineMode::ensure_online("Cost analysis")?;
     let analyzer = ConfigAnalyzer::from_config().ok_or_else(|| {
         AppError::AiProviderNotConfigured(
             "No AI provider configured. Complete the setup wizard first.".to_string(),
@@ -28,16 +36,20 @@ pub async fn analyze_cost() -> Result<Json<ApiResponse<CostAnalysis>>, AppError>
     })?;
 
     // Read the current config
-    let home =
-        std::env::var("HOME").map_err(|_| AppError::InternalError("HOME not set".to_string()))?;
-    let config_path = std::path::PathBuf::from(format!("{}/.openclaw/openclaw.json", home));
+    let config_path =
+        Platform::openclaw_config_path().map_err(|e| AppError::InternalError(e.to_string()))?;
     let config: serde_json::Value = ConfigWriter::read_json(&config_path)
         .map_err(|e| AppError::InternalError(format!("Failed to read config: {}", e)))?;
 
-    let analysis = analyzer
-        .analyze_cost(&config)
-        .await
-        .map_err(|e| AppError::ConfigAnalysisFailed(e.to_string()))?;
+    // Provider HTTP errors arrive as AppError; everything else is an analysis failure
+    let analysis = analyzer.analyze_cost(&config).await.map_err(|e| {
+        e.downcast::<AppError>()
+            .unwrap_or_else(|e| AppError::ConfigAnalysisFailed(e.to_string()))
+    })?;
+
+    if let Err(e) = ConfigAnalyzer::record_cost_analysis(&analysis) {
+        warn!("Failed to record cost history: {}", e);
+    }
 
     Ok(Json(ApiResponse {
         success: true,
@@ -46,6 +58,20 @@ pub async fn analyze_cost() -> Result<Json<ApiResponse<CostAnalysis>>, AppError>
     }))
 }
 
+/// GET /api/intelligence/cost-history
+///
+/// Returns previously recorded cost analyses, oldest first.
+pub async fn cost_history() -> Result<Json<ApiResponse<CostHistoryResponse>>, AppError> {
+    let entries = ConfigAnalyzer::load_cost_history()
+        .map_err(|e| AppError::InternalError(format!("Failed to read cost history: {}", e)))?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(CostHistoryResponse { entries }),
+        error: None,
+    }))
+}
+
 /// GET /api/intelligence/security-audit
 ///
 /// Runs 8 deterministic rule-based security checks against the config.
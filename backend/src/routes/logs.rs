@@ -1,12 +1,15 @@
 //! # Log Viewing and Analysis Routes
 //!
 //! REST endpoints for recent log retrieval and AI-powered analysis,
-//! plus WebSocket endpoint for real-time log streaming.
+//! plus WebSocket endpoints for real-time log streaming and streaming analysis.
 
 use crate::error::AppError;
-use crate::models::types::{ApiResponse, LogAnalysis, LogAnalysisRequest, LogsResponse};
+use crate::models::types::{
+    ApiResponse, LogAnalysis, LogAnalysisRequest, LogDiagnosis, LogLine, LogsResponse,
+};
+use crate::services::doctor::DoctorService;
 use crate::services::log_analyzer::LogAnalyzer;
-use crate::services::log_service::LogService;
+use crate::services::log_service::{LogService, MAX_RECENT_LINES};
 use axum::{
     Json,
     extract::Query,
@@ -14,7 +17,13 @@ use axum::{
     response::Response,
 };
 use serde::Deserialize;
-use tracing::{info, warn};
+use tracing::{Instrument, info, warn};
+
+/// How many recent lines to scan when auto-selecting an error window
+const ERROR_SCAN_LINES: usize = 500;
+
+/// Buffered tokens between the analysis task and the WebSocket
+const LOG_ANALYSIS_CHANNEL_CAPACITY: usize = 256;
 
 /// Query parameters for recent logs endpoint
 #[derive(Debug, Deserialize)]
@@ -23,55 +32,68 @@ pub struct LogsQuery {
     pub lines: Option<usize>,
     pub level: Option<String>,
     pub search: Option<String>,
+    /// Also read rotated (and gzipped) log files
+    #[serde(default)]
+    pub include_rotated: bool,
 }
 
 /// GET /api/logs/recent
 ///
 /// Returns recent log lines for a service with optional level and search filtering.
+/// `lines` defaults to 200 and is capped at `MAX_RECENT_LINES`;
+/// `include_rotated=true` extends the window into rotated log files.
 pub async fn get_recent_logs(
     Query(params): Query<LogsQuery>,
 ) -> Result<Json<ApiResponse<LogsResponse>>, AppError> {
-    // Validate service name
-    if params.service != "gateway" && params.service != "daemon" {
-        return Err(AppError::BadRequest(
-            "Service must be 'gateway' or 'daemon'".to_string(),
-        ));
-    }
+    LogService::validate_service(&params.service)
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
 
-    let lines = params.lines.unwrap_or(200);
+    let lines = params.lines.unwrap_or(200).min(MAX_RECENT_LINES);
     let level = params.level.as_deref();
     let search = params.search.as_deref();
 
-    match LogService::get_recent_logs(&params.service, lines, level, search) {
-        Ok(response) => Ok(Json(ApiResponse {
-            success: true,
-            data: Some(response),
-            error: None,
-        })),
-        Err(e) => Ok(Json(ApiResponse {
-            success: false,
-            data: None,
-            error: Some(format!("Failed to read logs: {}", e)),
-        })),
-    }
+    let response = LogService::get_recent_logs(
+        &params.service,
+        lines,
+        level,
+        search,
+        params.include_rotated,
+    )
+    .map_err(|e| AppError::LogsNotFound(format!("Failed to read logs: {}", e)))?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(response),
+        error: None,
+    }))
 }
 
 /// POST /api/logs/analyze
 ///
 /// Send log context to the user's configured AI provider for analysis.
+/// If no `log_context` is given, the most recent error cluster is pulled
+/// from the service's logs automatically. Without a configured AI provider,
+/// a rule-based heuristic analysis is returned instead.
 /// Rate limited to 1 request per 10 seconds.
 pub async fn analyze_logs(
     Json(request): Json<LogAnalysisRequest>,
 ) -> Result<Json<ApiResponse<LogAnalysis>>, AppError> {
-    let analyzer = LogAnalyzer::from_config().ok_or_else(|| {
-        AppError::AiProviderNotConfigured(
-            "No AI provider configured. Set up an AI provider in OpenClaw config first."
-                .to_string(),
-        )
-    })?;
+    let log_context = match request.log_context.filter(|c| !c.trim().is_empty()) {
+        Some(context) => context,
+        None => recent_error_context(&request.service)?,
+    };
+
+    let Some(analyzer) = LogAnalyzer::from_config() else {
+        return Ok(Json(ApiResponse {
+            success: true,
+            data: Some(LogAnalyzer::heuristic_analysis(&log_context)),
+            error: None,
+        }));
+    };
 
     match analyzer
-        .analyze_error(&request.log_context, &request.service)
+        .with_model(request.model)
+        .analyze_error(&log_context, &request.service)
         .await
     {
         Ok(analysis) => Ok(Json(ApiResponse {
@@ -83,11 +105,191 @@ pub async fn analyze_logs(
     }
 }
 
+/// Query parameters for the diagnose endpoint
+#[derive(Debug, Deserialize)]
+pub struct DiagnoseQuery {
+    pub service: String,
+    /// Analysis model override; falls back to the configured analysis model
+    pub model: Option<String>,
+}
+
+/// POST /api/logs/diagnose
+///
+/// One-shot incident diagnosis: pulls the service's latest error cluster,
+/// runs `openclaw doctor`, correlates the two, and analyzes both together
+/// with the configured AI provider. Falls back to the heuristic analysis
+/// when no provider is configured or the AI call fails. Missing logs don't
+/// fail the request; the doctor results are analyzed on their own.
+pub async fn diagnose_logs(
+    Query(params): Query<DiagnoseQuery>,
+) -> Result<Json<ApiResponse<LogDiagnosis>>, AppError> {
+    let error_lines = match recent_error_lines(&params.service) {
+        Ok(lines) => lines,
+        Err(AppError::LogsNotFound(reason)) => {
+            info!(
+                "Diagnosing {} without log context: {}",
+                params.service, reason
+            );
+            Vec::new()
+        }
+        Err(e) => return Err(e),
+    };
+
+    let doctor = tokio::task::spawn_blocking(DoctorService::run_diagnostics)
+        .await
+        .map_err(|e| AppError::InternalError(format!("Doctor task failed: {}", e)))?
+        .map_err(|e| AppError::DoctorFailed(format!("Doctor diagnostics failed: {}", e)))?;
+
+    let correlations = LogAnalyzer::correlate(&error_lines, &doctor);
+    let context = LogAnalyzer::diagnosis_context(&error_lines, &doctor, &correlations);
+
+    let ai_analysis = match LogAnalyzer::from_config() {
+        Some(analyzer) => match analyzer
+            .with_model(params.model)
+            .analyze_error(&context, &params.service)
+            .await
+        {
+            Ok(analysis) => Some(analysis),
+            Err(e) => {
+                warn!("AI diagnosis failed, using heuristics: {}", e);
+                None
+            }
+        },
+        None => None,
+    };
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(LogDiagnosis {
+            service: params.service,
+            error_lines,
+            doctor,
+            correlations,
+            ai_analysis: ai_analysis.is_some(),
+            analysis: ai_analysis.unwrap_or_else(|| LogAnalyzer::heuristic_analysis(&context)),
+        }),
+        error: None,
+    }))
+}
+
+/// Pull recent logs for a service and join its latest error cluster
+fn recent_error_context(service: &str) -> Result<String, AppError> {
+    Ok(recent_error_lines(service)?
+        .iter()
+        .map(|line| line.content.as_str())
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+/// Latest error cluster from a service's recent logs
+fn recent_error_lines(service: &str) -> Result<Vec<LogLine>, AppError> {
+    LogService::validate_service(service).map_err(|e| AppError::BadRequest(e.to_string()))?;
+
+    let recent = LogService::get_recent_logs(service, ERROR_SCAN_LINES, None, None, false)
+        .map_err(|e| AppError::LogsNotFound(format!("Failed to read logs: {}", e)))?;
+
+    let window = LogService::error_window(&recent.lines).ok_or_else(|| {
+        AppError::LogsNotFound(format!("No errors found in recent {} logs", service))
+    })?;
+    Ok(window.to_vec())
+}
+
+/// GET /ws/logs/analyze
+///
+/// WebSocket for streaming AI log analysis. The client sends a
+/// LogAnalysisRequest as the first message; the server replies with
+/// `{"type":"token"}` messages as the response arrives, then a final
+/// `{"type":"analysis"}` message carrying the parsed LogAnalysis.
+pub async fn ws_analyze_logs(ws: WebSocketUpgrade) -> Response {
+    let span = tracing::Span::current();
+    ws.on_upgrade(move |socket| handle_analyze_socket(socket).instrument(span))
+}
+
+/// Handle a streaming log analysis WebSocket connection
+async fn handle_analyze_socket(mut socket: WebSocket) {
+    let request = match socket.recv().await {
+        Some(Ok(Message::Text(text))) => match serde_json::from_str::<LogAnalysisRequest>(&text) {
+            Ok(request) => request,
+            Err(e) => {
+                send_json(
+                    &mut socket,
+                    serde_json::json!({
+                        "type": "error",
+                        "content": format!("Invalid analysis request: {}", e)
+                    }),
+                )
+                .await;
+                return;
+            }
+        },
+        _ => {
+            warn!("No analysis request received on log analysis WebSocket");
+            return;
+        }
+    };
+
+    let result = stream_analysis(&mut socket, request).await;
+    let msg = match result {
+        Ok(analysis) => serde_json::json!({ "type": "analysis", "content": analysis }),
+        Err(e) => serde_json::json!({ "type": "error", "content": e.to_string() }),
+    };
+    send_json(&mut socket, msg).await;
+}
+
+/// Run a streaming analysis, forwarding tokens to the socket as they arrive
+async fn stream_analysis(
+    socket: &mut WebSocket,
+    request: LogAnalysisRequest,
+) -> Result<LogAnalysis, AppError> {
+    let log_context = match request.log_context.filter(|c| !c.trim().is_empty()) {
+        Some(context) => context,
+        None => recent_error_context(&request.service)?,
+    };
+
+    let Some(analyzer) = LogAnalyzer::from_config() else {
+        return Ok(LogAnalyzer::heuristic_analysis(&log_context));
+    };
+    let analyzer = analyzer.with_model(request.model);
+
+    let (tx, mut rx) = tokio::sync::mpsc::channel::<String>(LOG_ANALYSIS_CHANNEL_CAPACITY);
+    let service = request.service;
+    let task = tokio::spawn(
+        async move {
+            analyzer
+                .analyze_error_streaming(&log_context, &service, tx)
+                .await
+        }
+        .in_current_span(),
+    );
+
+    while let Some(token) = rx.recv().await {
+        let msg = serde_json::json!({ "type": "token", "content": token });
+        if socket
+            .send(Message::Text(msg.to_string().into()))
+            .await
+            .is_err()
+        {
+            info!("Log analysis WebSocket client disconnected");
+            task.abort();
+            return Err(AppError::InternalError("Client disconnected".to_string()));
+        }
+    }
+
+    task.await
+        .map_err(|e| AppError::InternalError(format!("Analysis task failed: {}", e)))?
+}
+
+/// Send a JSON message over a WebSocket, ignoring send failures
+async fn send_json(socket: &mut WebSocket, msg: serde_json::Value) {
+    let _ = socket.send(Message::Text(msg.to_string().into())).await;
+}
+
 /// GET /ws/logs
 ///
 /// WebSocket upgrade for real-time log streaming.
 pub async fn ws_log_stream(ws: WebSocketUpgrade) -> Response {
-    ws.on_upgrade(handle_log_socket)
+    let span = tracing::Span::current();
+    ws.on_upgrade(move |socket| handle_log_socket(socket).instrument(span))
 }
 
 /// Handle WebSocket log streaming connection
@@ -110,11 +312,10 @@ async fn handle_log_socket(mut socket: WebSocket) {
         }
     };
 
-    // Validate service name
-    if service != "gateway" && service != "daemon" {
+    if let Err(e) = LogService::validate_service(&service) {
         let error_msg = serde_json::json!({
             "type": "error",
-            "content": "Service must be 'gateway' or 'daemon'"
+            "content": e.to_string()
         });
         let _ = socket
             .send(Message::Text(error_msg.to_string().into()))
@@ -125,7 +326,7 @@ async fn handle_log_socket(mut socket: WebSocket) {
     info!("Starting log stream for service: {}", service);
 
     // Start tailing the log file
-    let (mut rx, tail_handle) = match LogService::tail_log_file(&service).await {
+    let mut tail = match LogService::tail_log_file(&service).await {
         Ok(result) => result,
         Err(e) => {
             let error_msg = serde_json::json!({
@@ -139,8 +340,22 @@ async fn handle_log_socket(mut socket: WebSocket) {
         }
     };
 
-    // Forward log lines to WebSocket
-    while let Some(line) = rx.recv().await {
+    // Forward log lines to WebSocket, watching the socket so a close is
+    // noticed even while the log is quiet
+    loop {
+        let line = tokio::select! {
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => {
+                    info!("Log WebSocket client disconnected");
+                    break;
+                }
+                Some(Ok(_)) => continue,
+            },
+            line = tail.lines.recv() => match line {
+                Some(line) => line,
+                None => break,
+            },
+        };
         let parsed = LogService::parse_log_line(&line);
 
         let msg = serde_json::json!({
@@ -160,7 +375,7 @@ async fn handle_log_socket(mut socket: WebSocket) {
         }
     }
 
-    // Clean up: abort the tail task when client disconnects
-    tail_handle.abort();
+    // Dropping the tail kills the `tail` process
+    drop(tail);
     info!("Log stream ended for service: {}", service);
 }
@@ -0,0 +1,207 @@
+//! # Route Manifest
+//!
+//! Machine-readable list of every route the server registers, so clients
+//! can discover endpoints instead of hardcoding URLs:
+//! - GET /api/routes - List all routes with their HTTP method
+//!
+//! `ROUTES` is maintained by hand next to the router in `main.rs`; a test
+//! parses `main.rs` and fails when the two drift apart.
+
+use axum::Json;
+
+use crate::models::types::{ApiResponse, ApiRoute};
+
+/// Every registered route as `(method, path)`, in router order.
+/// WebSocket endpoints are the `GET /ws...` entries.
+pub const ROUTES: &[(&str, &str)] = &[
+    ("GET", "/api/health"),
+    ("GET", "/api/health/live"),
+    ("GET", "/api/health/ready"),
+    ("GET", "/api/routes"),
+    ("GET", "/api/confirm"),
+    ("GET", "/api/system/info"),
+    ("GET", "/api/system/identity"),
+    ("GET", "/api/system/wizard-version"),
+    ("GET", "/api/system/support-bundle"),
+    ("GET", "/api/system/requirements"),
+    ("GET", "/api/system/detect-openclaw"),
+    ("GET", "/api/system/processes"),
+    ("POST", "/api/system/processes/{pid}/kill"),
+    ("POST", "/api/wizard/validate-key"),
+    ("GET", "/api/wizard/generate-credential"),
+    ("POST", "/api/wizard/save-config"),
+    ("GET", "/api/wizard/config-schema"),
+    ("POST", "/api/wizard/install"),
+    ("GET", "/api/wizard/install-plan"),
+    ("POST", "/api/wizard/install/cancel"),
+    ("GET", "/api/wizard/onboard-preview"),
+    ("POST", "/api/wizard/rollback"),
+    ("GET", "/ws/wizard/full-setup"),
+    ("POST", "/api/channels/validate"),
+    ("POST", "/api/channels/validate-all"),
+    ("POST", "/api/channels/{platform}/allowlist"),
+    ("GET", "/api/dashboard/daemon/status"),
+    ("POST", "/api/dashboard/daemon/start"),
+    ("POST", "/api/dashboard/daemon/stop"),
+    ("POST", "/api/dashboard/daemon/restart"),
+    ("GET", "/api/dashboard/health"),
+    ("GET", "/api/dashboard/metrics/history"),
+    ("GET", "/api/dashboard/config"),
+    ("PUT", "/api/dashboard/config"),
+    ("GET", "/api/dashboard/config/template"),
+    ("POST", "/api/dashboard/config/import"),
+    ("GET", "/api/dashboard/config/export"),
+    ("GET", "/api/dashboard/config/reconcile"),
+    ("POST", "/api/dashboard/config/reconcile"),
+    ("POST", "/api/dashboard/config/set"),
+    ("GET", "/api/dashboard/chat-url"),
+    ("POST", "/api/dashboard/auth/rotate"),
+    ("GET", "/api/dashboard/version"),
+    ("GET", "/ws"),
+    ("POST", "/api/remote/test-connection"),
+    ("GET", "/api/remote/host-key"),
+    ("POST", "/api/remote/trust-host"),
+    ("GET", "/ws/remote/install"),
+    ("GET", "/api/docker/status"),
+    ("GET", "/api/docker/containers"),
+    ("POST", "/api/docker/create"),
+    ("POST", "/api/docker/prune"),
+    ("POST", "/api/docker/{id}/stop"),
+    ("DELETE", "/api/docker/{id}"),
+    ("GET", "/api/docker/{id}/logs"),
+    ("GET", "/ws/docker/{id}/logs"),
+    ("GET", "/ws/docker/events"),
+    ("GET", "/api/skills/search"),
+    ("GET", "/api/skills/installed"),
+    ("GET", "/api/skills/recommended"),
+    ("POST", "/api/skills/install"),
+    ("GET", "/ws/skills/install"),
+    ("POST", "/api/skills/install-batch"),
+    ("GET", "/ws/skills/install-batch"),
+    ("POST", "/api/skills/uninstall-batch"),
+    ("POST", "/api/skills/scan"),
+    ("GET", "/api/skills/{name}"),
+    ("DELETE", "/api/skills/{name}"),
+    ("GET", "/api/skills/{name}/audit"),
+    ("GET", "/api/services/status"),
+    ("POST", "/api/services/gateway/start"),
+    ("POST", "/api/services/gateway/stop"),
+    ("POST", "/api/services/gateway/restart"),
+    ("POST", "/api/services/daemon/start"),
+    ("POST", "/api/services/daemon/stop"),
+    ("POST", "/api/services/daemon/restart"),
+    ("GET", "/api/services/doctor"),
+    ("GET", "/ws/services/upgrade"),
+    ("GET", "/api/logs/recent"),
+    ("POST", "/api/logs/analyze"),
+    ("POST", "/api/logs/diagnose"),
+    ("GET", "/ws/logs"),
+    ("GET", "/ws/logs/analyze"),
+    ("GET", "/ws/whatsapp/connect"),
+    ("POST", "/api/intelligence/cost-analysis"),
+    ("GET", "/api/intelligence/cost-history"),
+    ("GET", "/api/intelligence/security-audit"),
+    ("GET", "/api/intelligence/pricing"),
+    ("GET", "/api/multi-server/servers"),
+    ("POST", "/api/multi-server/servers"),
+    ("POST", "/api/multi-server/import-ssh-config"),
+    ("DELETE", "/api/multi-server/servers/{id}"),
+    ("POST", "/api/multi-server/servers/{id}/test"),
+    ("POST", "/api/multi-server/test-all"),
+    ("POST", "/api/multi-server/rollback/{id}"),
+    ("POST", "/api/multi-server/rollback-batch"),
+    ("GET", "/api/multi-server/history"),
+    ("GET", "/ws/multi-server/deploy"),
+];
+
+/// GET /api/routes
+///
+/// List every API and WebSocket route the server exposes.
+pub async fn list_routes() -> Json<ApiResponse<Vec<ApiRoute>>> {
+    Json(ApiResponse {
+        success: true,
+        data: Some(manifest()),
+        error: None,
+    })
+}
+
+/// Build the typed manifest from `ROUTES`
+fn manifest() -> Vec<ApiRoute> {
+    ROUTES
+        .iter()
+        .map(|(method, path)| ApiRoute {
+            method: method.to_string(),
+            path: path.to_string(),
+            websocket: path.starts_with("/ws"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+
+    /// `(method, path)` pairs registered with `.route(...)` in main.rs
+    fn router_routes() -> Vec<(String, String)> {
+        let source = include_str!("../main.rs");
+        let path_re = Regex::new(r#""([^"]+)""#).unwrap();
+        let method_re = Regex::new(r"\b(get|post|put|patch|delete)\(").unwrap();
+
+        let mut routes = Vec::new();
+        let mut rest = source;
+        while let Some(start) = rest.find(".route(") {
+            rest = &rest[start + ".route(".len()..];
+            // Arguments run to the matching close paren
+            let mut depth = 1;
+            let end = rest
+                .char_indices()
+                .find_map(|(i, c)| {
+                    match c {
+                        '(' => depth += 1,
+                        ')' => depth -= 1,
+                        _ => {}
+                    }
+                    (depth == 0).then_some(i)
+                })
+                .expect("unbalanced .route( call in main.rs");
+            let args = &rest[..end];
+            let path = &path_re.captures(args).expect("route without a path")[1];
+            for method in method_re.captures_iter(args) {
+                routes.push((method[1].to_uppercase(), path.to_string()));
+            }
+            rest = &rest[end..];
+        }
+        routes
+    }
+
+    #[test]
+    fn test_manifest_matches_router() {
+        let manifest: Vec<(String, String)> = ROUTES
+            .iter()
+            .map(|(method, path)| (method.to_string(), path.to_string()))
+            .collect();
+        let router = router_routes();
+        assert!(!router.is_empty());
+
+        let missing: Vec<_> = router.iter().filter(|r| !manifest.contains(r)).collect();
+        let stale: Vec<_> = manifest.iter().filter(|r| !router.contains(r)).collect();
+        assert!(
+            missing.is_empty() && stale.is_empty(),
+            "ROUTES is out of sync with main.rs; missing: {:?}, not in router: {:?}",
+            missing,
+            stale
+        );
+        assert_eq!(manifest.len(), router.len(), "duplicate entries in ROUTES");
+    }
+
+    #[test]
+    fn test_manifest_flags_websockets() {
+        let routes = manifest();
+        let ws = routes.iter().find(|r| r.path == "/ws/logs").unwrap();
+        assert!(ws.websocket);
+        let health = routes.iter().find(|r| r.path == "/api/health").unwrap();
+        assert_eq!(health.method, "GET");
+        assert!(!health.websocket);
+    }
+}
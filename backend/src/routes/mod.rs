@@ -1,9 +1,11 @@
 pub mod api;
 pub mod channels;
+pub mod confirm;
 pub mod dashboard;
 pub mod docker;
 pub mod intelligence;
 pub mod logs;
+pub mod manifest;
 pub mod multi_server;
 pub mod remote;
 pub mod services;
@@ -6,9 +6,13 @@
 //! Endpoints:
 //! - GET    /api/multi-server/servers — List all server targets
 //! - POST   /api/multi-server/servers — Add a new server target
+//! - POST   /api/multi-server/import-ssh-config — Add servers from ~/.ssh/config
 //! - DELETE /api/multi-server/servers/{id} — Remove a server target
 //! - POST   /api/multi-server/servers/{id}/test — Test server connection
+//! - POST   /api/multi-server/test-all — Test all (or selected) servers concurrently
 //! - POST   /api/multi-server/rollback/{id} — Rollback a deployed server
+//! - POST   /api/multi-server/rollback-batch — Rollback several servers concurrently
+//! - GET    /api/multi-server/history — Past deployment runs and per-server results
 //! - GET    /ws/multi-server/deploy — WebSocket for deployment progress
 
 use axum::{
@@ -17,17 +21,23 @@ use axum::{
         Path,
         ws::{Message, WebSocket, WebSocketUpgrade},
     },
+    http::HeaderMap,
     response::Response,
 };
 use tokio::sync::mpsc;
-use tracing::{error, info, warn};
+use tracing::{Instrument, error, info, warn};
 
 use crate::error::AppError;
 use crate::models::types::{
-    ApiResponse, EmptyResponse, MultiServerDeployRequest, MultiServerProgress, ServerDeployResult,
-    ServerListResponse, ServerTarget, ServerTestResult, WizardConfig, WsMessage,
+    ApiResponse, DeploymentHistoryResponse, DestructiveAction, EmptyResponse,
+    MultiServerDeployRequest, MultiServerProgress, RollbackBatchRequest, ServerDeployResult,
+    ServerListResponse, ServerTarget, ServerTestResult, SshConfigImportResponse,
+    TestAllServersRequest, WsMessage,
 };
+use crate::routes::confirm::confirm_token;
+use crate::services::confirmation::ConfirmationService;
 use crate::services::multi_server::MultiServerOrchestrator;
+use crate::services::wizard_config::WizardConfigStore;
 
 /// GET /api/multi-server/servers
 ///
@@ -59,6 +69,21 @@ pub async fn add_server(
     }))
 }
 
+/// POST /api/multi-server/import-ssh-config
+///
+/// Add the Host entries from `~/.ssh/config` as server targets, skipping
+/// hosts that are already in the list.
+pub async fn import_ssh_config() -> Result<Json<ApiResponse<SshConfigImportResponse>>, AppError> {
+    let response = MultiServerOrchestrator::import_ssh_config()
+        .map_err(|e| AppError::BadRequest(format!("Failed to import SSH config: {:#}", e)))?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(response),
+        error: None,
+    }))
+}
+
 /// DELETE /api/multi-server/servers/{id}
 ///
 /// Remove a server target by ID.
@@ -89,12 +114,37 @@ pub async fn test_server(
     }))
 }
 
+/// POST /api/multi-server/test-all
+///
+/// Test SSH connections to all servers, or to `server_ids` when given, in parallel.
+pub async fn test_all_servers(
+    request: Option<Json<TestAllServersRequest>>,
+) -> Result<Json<ApiResponse<Vec<ServerTestResult>>>, AppError> {
+    let server_ids = request.and_then(|Json(r)| r.server_ids);
+    let results = MultiServerOrchestrator::test_all_servers(server_ids)
+        .await
+        .map_err(|e| AppError::InternalError(format!("Failed to test servers: {}", e)))?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(results),
+        error: None,
+    }))
+}
+
 /// POST /api/multi-server/rollback/{id}
 ///
 /// Rollback a deployed server (stop daemon, remove config, uninstall).
+/// Requires an `X-Confirm-Token` for `RollbackServer` on this id.
 pub async fn rollback_server(
     Path(id): Path<String>,
+    headers: HeaderMap,
 ) -> Result<Json<ApiResponse<ServerDeployResult>>, AppError> {
+    ConfirmationService::consume(
+        DestructiveAction::RollbackServer,
+        Some(&id),
+        confirm_token(&headers),
+    )?;
     let result = MultiServerOrchestrator::rollback_server(&id)
         .await
         .map_err(|e| AppError::DeploymentFailed(e.to_string()))?;
@@ -106,12 +156,57 @@ pub async fn rollback_server(
     }))
 }
 
+/// POST /api/multi-server/rollback-batch
+///
+/// Rollback every listed server (e.g. all servers from a failed deployment batch).
+/// Requires an `X-Confirm-Token` for `RollbackServers`.
+pub async fn rollback_batch(
+    headers: HeaderMap,
+    Json(request): Json<RollbackBatchRequest>,
+) -> Result<Json<ApiResponse<Vec<ServerDeployResult>>>, AppError> {
+    ConfirmationService::consume(
+        DestructiveAction::RollbackServers,
+        None,
+        confirm_token(&headers),
+    )?;
+    if request.server_ids.is_empty() {
+        return Err(AppError::BadRequest(
+            "No servers selected for rollback".into(),
+        ));
+    }
+
+    let results = MultiServerOrchestrator::rollback_batch(request.server_ids).await;
+
+    Ok(Json(ApiResponse {
+        success: results.iter().all(|r| r.success),
+        data: Some(results),
+        error: None,
+    }))
+}
+
+/// GET /api/multi-server/history
+///
+/// Returns recorded deployment runs, oldest first.
+pub async fn get_deploy_history() -> Result<Json<ApiResponse<DeploymentHistoryResponse>>, AppError>
+{
+    let deployments = MultiServerOrchestrator::load_deploy_history().map_err(|e| {
+        AppError::InternalError(format!("Failed to load deployment history: {}", e))
+    })?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(DeploymentHistoryResponse { deployments }),
+        error: None,
+    }))
+}
+
 /// GET /ws/multi-server/deploy — WebSocket upgrade handler
 ///
 /// Expects first message to be a JSON MultiServerDeployRequest.
 /// Streams MultiServerProgress messages per server during deployment.
 pub async fn ws_multi_server_deploy(ws: WebSocketUpgrade) -> Response {
-    ws.on_upgrade(handle_multi_server_deploy_socket)
+    let span = tracing::Span::current();
+    ws.on_upgrade(move |socket| handle_multi_server_deploy_socket(socket).instrument(span))
 }
 
 /// Handle the multi-server deployment WebSocket connection
@@ -161,10 +256,10 @@ async fn handle_multi_server_deploy_socket(mut socket: WebSocket) {
     );
 
     // Load saved WizardConfig from disk
-    let config = match load_wizard_config_for_deploy() {
+    let config = match WizardConfigStore::load() {
         Ok(config) => config,
         Err(e) => {
-            error!("Failed to load wizard config: {}", e);
+            error!("Failed to load wizard config: {:#}", e);
             let _ = send_deploy_error(
                 &mut socket,
                 "Failed to load wizard configuration. Complete the setup wizard first.",
@@ -179,16 +274,19 @@ async fn handle_multi_server_deploy_socket(mut socket: WebSocket) {
 
     // Spawn the deployment task
     let server_ids = deploy_request.server_ids.clone();
-    tokio::spawn(async move {
-        let results = MultiServerOrchestrator::deploy_to_servers(server_ids, config, tx).await;
-
-        // Results are already stored in the server list by the orchestrator
-        info!(
-            "Multi-server deployment complete: {}/{} successful",
-            results.iter().filter(|r| r.success).count(),
-            results.len()
-        );
-    });
+    tokio::spawn(
+        async move {
+            let results = MultiServerOrchestrator::deploy_to_servers(server_ids, config, tx).await;
+
+            // Results are already stored in the server list by the orchestrator
+            info!(
+                "Multi-server deployment complete: {}/{} successful",
+                results.iter().filter(|r| r.success).count(),
+                results.len()
+            );
+        }
+        .in_current_span(),
+    );
 
     // Forward progress updates to WebSocket
     while let Some(progress) = rx.recv().await {
@@ -233,56 +331,3 @@ async fn send_deploy_error(socket: &mut WebSocket, message: &str) -> Result<(),
         .await
         .map_err(|_| ())
 }
-
-/// Load WizardConfig from saved openclaw.json for deployment
-fn load_wizard_config_for_deploy() -> anyhow::Result<WizardConfig> {
-    use crate::services::platform::Platform;
-
-    let config_dir = Platform::config_dir()
-        .map_err(|e| anyhow::anyhow!("Failed to find config directory: {}", e))?;
-    let config_path = config_dir.join("openclaw.json");
-
-    let config_str = std::fs::read_to_string(&config_path)
-        .map_err(|e| anyhow::anyhow!("Config not found at {}: {}", config_path.display(), e))?;
-
-    let raw: serde_json::Value = serde_json::from_str(&config_str)
-        .map_err(|e| anyhow::anyhow!("Invalid config JSON: {}", e))?;
-
-    let provider = raw["ai"]["provider"]
-        .as_str()
-        .unwrap_or("anthropic")
-        .to_string();
-    let api_key = raw["ai"]["apiKey"].as_str().unwrap_or("").to_string();
-    let auth_type = match raw["ai"]["auth"].as_str().unwrap_or("api-key") {
-        "token" => "setup-token".to_string(),
-        other => other.to_string(),
-    };
-    let gateway_port = raw["gateway"]["port"].as_u64().unwrap_or(3000) as u16;
-    let gateway_bind = raw["gateway"]["bind"]
-        .as_str()
-        .unwrap_or("127.0.0.1")
-        .to_string();
-    let auth_mode = raw["gateway"]["auth"]["mode"]
-        .as_str()
-        .unwrap_or("none")
-        .to_string();
-    let auth_credential = raw["gateway"]["auth"]["credential"]
-        .as_str()
-        .map(|s| s.to_string());
-
-    Ok(WizardConfig {
-        provider,
-        api_key,
-        auth_type,
-        gateway_port,
-        gateway_bind,
-        auth_mode,
-        auth_credential,
-        channels: None,
-        base_url: None,
-        model_id: None,
-        compatibility: None,
-        account_id: None,
-        gateway_id: None,
-    })
-}
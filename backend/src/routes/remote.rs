@@ -4,28 +4,36 @@
 //!
 //! Endpoints:
 //! - POST /api/remote/test-connection — Test SSH connection and store credentials
+//! - GET  /api/remote/host-key — Fetch a server's host key fingerprints for confirmation
+//! - POST /api/remote/trust-host — Add an approved host key to ~/.ssh/known_hosts
 //! - GET  /ws/remote/install — WebSocket for streaming installation progress
 //!
 //! SECURITY:
 //! - SSH key paths are stored in keychain, not passed via WebSocket
-//! - Input validation on hostname and username
+//! - Host, username and key path validated before any SSH command runs
 //! - Never logs SSH credentials
 
 use axum::{
     Json,
-    extract::ws::{Message, WebSocket, WebSocketUpgrade},
+    extract::{
+        Query,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
     response::Response,
 };
 use tokio::sync::mpsc;
-use tracing::{error, info, warn};
+use tracing::{Instrument, error, info, warn};
 
 use crate::error::AppError;
+use crate::models::types::{HostKeyInfo, HostKeyQuery, HostKeyResponse, TrustHostRequest};
 use crate::models::{
-    RemoteInstallRequest, RemoteSetupProgress, SshConnection, SshConnectionRequest,
-    SshConnectionResponse, WizardConfig, WsMessage,
+    ApiResponse, RemoteInstallRequest, RemoteSetupProgress, SshConnection, SshConnectionRequest,
+    SshConnectionResponse, WsMessage,
 };
-use crate::services::remote::RemoteService;
-use crate::services::ssh::SshService;
+use crate::services::installer::InstallerService;
+use crate::services::remote::{RemoteConfigSource, RemoteService};
+use crate::services::ssh::{DEFAULT_SSH_PORT, SshService};
+use crate::services::wizard_config::WizardConfigStore;
 
 /// POST /api/remote/test-connection
 ///
@@ -34,6 +42,9 @@ use crate::services::ssh::SshService;
 pub async fn test_ssh_connection(
     Json(request): Json<SshConnectionRequest>,
 ) -> Result<Json<SshConnectionResponse>, AppError> {
+    SshService::validate_ssh_request(&request.host, &request.username, Some(&request.key_path))
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+
     let ssh_service = SshService::new();
 
     // Store SSH key path in keychain before testing
@@ -91,12 +102,70 @@ pub async fn test_ssh_connection(
     }
 }
 
+/// GET /api/remote/host-key?host=...&port=...
+///
+/// Returns the host key fingerprints a server offers so the user can verify
+/// them before the first strict-checked connection.
+pub async fn get_host_key(
+    Query(query): Query<HostKeyQuery>,
+) -> Result<Json<ApiResponse<HostKeyResponse>>, AppError> {
+    let port = query.port.unwrap_or(DEFAULT_SSH_PORT);
+    SshService::validate_host(&query.host).map_err(|e| AppError::BadRequest(e.to_string()))?;
+
+    let ssh_service = SshService::new();
+    let keys = ssh_service
+        .fetch_host_keys(&query.host, port)
+        .await
+        .map_err(|e| AppError::SshConnectionFailed(e.to_string()))?;
+    let known = ssh_service
+        .is_known_host(&query.host, port)
+        .unwrap_or(false);
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(HostKeyResponse {
+            host: query.host,
+            port,
+            keys,
+            known,
+        }),
+        error: None,
+    }))
+}
+
+/// POST /api/remote/trust-host
+///
+/// Adds the host key matching the user-approved fingerprint to known_hosts.
+pub async fn trust_host(
+    Json(request): Json<TrustHostRequest>,
+) -> Result<Json<ApiResponse<HostKeyInfo>>, AppError> {
+    SshService::validate_host(&request.host).map_err(|e| AppError::BadRequest(e.to_string()))?;
+    if !request.fingerprint.starts_with("SHA256:") {
+        return Err(AppError::BadRequest(
+            "Fingerprint must be an OpenSSH SHA256 fingerprint".into(),
+        ));
+    }
+
+    let port = request.port.unwrap_or(DEFAULT_SSH_PORT);
+    let key = SshService::new()
+        .trust_host(&request.host, port, &request.fingerprint)
+        .await
+        .map_err(|e| AppError::SshConnectionFailed(e.to_string()))?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(key),
+        error: None,
+    }))
+}
+
 /// GET /ws/remote/install — WebSocket upgrade handler
 ///
 /// Expects first message to be a JSON RemoteInstallRequest wrapped in WsMessage.
 /// Streams RemoteSetupProgress messages back to the client.
 pub async fn ws_remote_install(ws: WebSocketUpgrade) -> Response {
-    ws.on_upgrade(handle_remote_install_socket)
+    let span = tracing::Span::current();
+    ws.on_upgrade(move |socket| handle_remote_install_socket(socket).instrument(span))
 }
 
 /// Handle the remote installation WebSocket connection
@@ -143,22 +212,50 @@ async fn handle_remote_install_socket(mut socket: WebSocket) {
         }
     };
 
+    if let Err(e) =
+        SshService::validate_ssh_request(&install_request.host, &install_request.username, None)
+    {
+        warn!("Rejected remote install request: {}", e);
+        let _ = send_error_message(&mut socket, &e.to_string()).await;
+        return;
+    }
+
+    if let Err(e) =
+        InstallerService::openclaw_package_spec(install_request.openclaw_version.as_deref())
+    {
+        warn!("Rejected remote install request: {}", e);
+        let _ = send_error_message(&mut socket, &e.to_string()).await;
+        return;
+    }
+
     info!(
         "Starting remote installation on {}@{}",
         install_request.username, install_request.host
     );
 
-    // Load saved WizardConfig from disk for the installation
-    let config = match load_wizard_config() {
-        Ok(config) => config,
-        Err(e) => {
-            error!("Failed to load wizard config: {}", e);
-            let _ = send_error_message(
-                &mut socket,
-                "Failed to load wizard configuration. Complete the setup wizard first.",
-            )
-            .await;
-            return;
+    // Either copy the local openclaw.json verbatim or rebuild it from the
+    // saved WizardConfig
+    let source = if install_request.use_local_config.unwrap_or(false) {
+        match RemoteService::load_local_config() {
+            Ok(config) => RemoteConfigSource::Local(config),
+            Err(e) => {
+                error!("Failed to load local config: {:#}", e);
+                let _ = send_error_message(&mut socket, &format!("{:#}", e)).await;
+                return;
+            }
+        }
+    } else {
+        match WizardConfigStore::load() {
+            Ok(config) => RemoteConfigSource::Wizard(Box::new(config)),
+            Err(e) => {
+                error!("Failed to load wizard config: {:#}", e);
+                let _ = send_error_message(
+                    &mut socket,
+                    "Failed to load wizard configuration. Complete the setup wizard first.",
+                )
+                .await;
+                return;
+            }
         }
     };
 
@@ -168,17 +265,27 @@ async fn handle_remote_install_socket(mut socket: WebSocket) {
     // Spawn the installation task
     let host = install_request.host.clone();
     let username = install_request.username.clone();
+    let openclaw_version = install_request.openclaw_version.clone();
 
-    tokio::spawn(async move {
-        let remote_service = RemoteService::new();
-        if let Err(e) = remote_service
-            .install_openclaw_remote(&host, &username, config, tx.clone())
-            .await
-        {
-            error!("Remote installation failed: {}", e);
-            // Error progress already sent by RemoteService stages
+    tokio::spawn(
+        async move {
+            let remote_service = RemoteService::new();
+            if let Err(e) = remote_service
+                .install_openclaw_remote_from(
+                    &host,
+                    &username,
+                    source,
+                    openclaw_version.as_deref(),
+                    tx.clone(),
+                )
+                .await
+            {
+                error!("Remote installation failed: {}", e);
+                // Error progress already sent by RemoteService stages
+            }
         }
-    });
+        .in_current_span(),
+    );
 
     // Forward progress updates to WebSocket (identical pattern to v1.0 ws.rs)
     while let Some(progress) = rx.recv().await {
@@ -208,6 +315,7 @@ async fn send_error_message(socket: &mut WebSocket, message: &str) -> Result<(),
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap_or_default()
             .as_secs(),
+        elapsed_ms: None,
     };
 
     let response = WsMessage {
@@ -221,60 +329,3 @@ async fn send_error_message(socket: &mut WebSocket, message: &str) -> Result<(),
         .await
         .map_err(|_| ())
 }
-
-/// Load WizardConfig from the saved openclaw.json config file
-///
-/// Reads the OpenClaw config and maps it back into WizardConfig format.
-/// Falls back to defaults for fields not present in saved config.
-fn load_wizard_config() -> anyhow::Result<WizardConfig> {
-    use crate::services::platform::Platform;
-
-    let config_dir = Platform::config_dir()
-        .map_err(|e| anyhow::anyhow!("Failed to find config directory: {}", e))?;
-    let config_path = config_dir.join("openclaw.json");
-
-    let config_str = std::fs::read_to_string(&config_path)
-        .map_err(|e| anyhow::anyhow!("Config not found at {}: {}", config_path.display(), e))?;
-
-    let raw: serde_json::Value = serde_json::from_str(&config_str)
-        .map_err(|e| anyhow::anyhow!("Invalid config JSON: {}", e))?;
-
-    // Map OpenClaw config format back to WizardConfig
-    let provider = raw["ai"]["provider"]
-        .as_str()
-        .unwrap_or("anthropic")
-        .to_string();
-    let api_key = raw["ai"]["apiKey"].as_str().unwrap_or("").to_string();
-    let auth_type = match raw["ai"]["auth"].as_str().unwrap_or("api-key") {
-        "token" => "setup-token".to_string(),
-        other => other.to_string(),
-    };
-    let gateway_port = raw["gateway"]["port"].as_u64().unwrap_or(3000) as u16;
-    let gateway_bind = raw["gateway"]["bind"]
-        .as_str()
-        .unwrap_or("127.0.0.1")
-        .to_string();
-    let auth_mode = raw["gateway"]["auth"]["mode"]
-        .as_str()
-        .unwrap_or("none")
-        .to_string();
-    let auth_credential = raw["gateway"]["auth"]["credential"]
-        .as_str()
-        .map(|s| s.to_string());
-
-    Ok(WizardConfig {
-        provider,
-        api_key,
-        auth_type,
-        gateway_port,
-        gateway_bind,
-        auth_mode,
-        auth_credential,
-        channels: None,
-        base_url: None,
-        model_id: None,
-        compatibility: None,
-        account_id: None,
-        gateway_id: None,
-    })
-}
@@ -4,10 +4,20 @@
 //! and OpenClaw doctor diagnostics. Phase 7 replacement for dashboard
 //! daemon routes with finer-grained service control.
 
-use crate::models::types::{ApiResponse, DoctorReport, ServiceActionResponse, ServicesStatus};
+use crate::error::AppError;
+use crate::models::types::{
+    ApiResponse, DoctorReport, InstallProgress, ServiceActionResponse, ServicesStatus, WsMessage,
+};
 use crate::services::doctor::DoctorService;
 use crate::services::service_manager::ServiceManager;
-use axum::Json;
+use crate::services::upgrader::UpgradeService;
+use axum::{
+    Json,
+    extract::ws::{Message, WebSocket, WebSocketUpgrade},
+    response::Response,
+};
+use tokio::sync::mpsc;
+use tracing::{Instrument, error, info, warn};
 
 /// GET /api/services/status
 ///
@@ -23,139 +33,135 @@ pub async fn services_status() -> Json<ApiResponse<ServicesStatus>> {
 }
 
 /// POST /api/services/gateway/start
-pub async fn start_gateway() -> Json<ApiResponse<ServiceActionResponse>> {
-    match ServiceManager::start_gateway() {
-        Ok(response) => Json(ApiResponse {
-            success: response.success,
-            data: Some(response),
-            error: None,
-        }),
-        Err(e) => Json(ApiResponse {
-            success: false,
-            data: Some(ServiceActionResponse {
-                success: false,
-                service: "gateway".to_string(),
-                message: format!("Failed to start gateway: {}", e),
-            }),
-            error: Some(e.to_string()),
-        }),
-    }
+pub async fn start_gateway() -> Result<Json<ApiResponse<ServiceActionResponse>>, AppError> {
+    let response = ServiceManager::start_gateway()
+        .map_err(|e| AppError::InternalError(format!("Failed to start gateway: {}", e)))?;
+
+    Ok(Json(ApiResponse {
+        success: response.success,
+        data: Some(response),
+        error: None,
+    }))
 }
 
 /// POST /api/services/gateway/stop
-pub async fn stop_gateway() -> Json<ApiResponse<ServiceActionResponse>> {
-    match ServiceManager::stop_gateway() {
-        Ok(response) => Json(ApiResponse {
-            success: response.success,
-            data: Some(response),
-            error: None,
-        }),
-        Err(e) => Json(ApiResponse {
-            success: false,
-            data: Some(ServiceActionResponse {
-                success: false,
-                service: "gateway".to_string(),
-                message: format!("Failed to stop gateway: {}", e),
-            }),
-            error: Some(e.to_string()),
-        }),
-    }
+pub async fn stop_gateway() -> Result<Json<ApiResponse<ServiceActionResponse>>, AppError> {
+    let response = ServiceManager::stop_gateway()
+        .map_err(|e| AppError::InternalError(format!("Failed to stop gateway: {}", e)))?;
+
+    Ok(Json(ApiResponse {
+        success: response.success,
+        data: Some(response),
+        error: None,
+    }))
 }
 
 /// POST /api/services/gateway/restart
-pub async fn restart_gateway() -> Json<ApiResponse<ServiceActionResponse>> {
-    match ServiceManager::restart_gateway() {
-        Ok(response) => Json(ApiResponse {
-            success: response.success,
-            data: Some(response),
-            error: None,
-        }),
-        Err(e) => Json(ApiResponse {
-            success: false,
-            data: Some(ServiceActionResponse {
-                success: false,
-                service: "gateway".to_string(),
-                message: format!("Failed to restart gateway: {}", e),
-            }),
-            error: Some(e.to_string()),
-        }),
-    }
+pub async fn restart_gateway() -> Result<Json<ApiResponse<ServiceActionResponse>>, AppError> {
+    let response = ServiceManager::restart_gateway()
+        .map_err(|e| AppError::InternalError(format!("Failed to restart gateway: {}", e)))?;
+
+    Ok(Json(ApiResponse {
+        success: response.success,
+        data: Some(response),
+        error: None,
+    }))
 }
 
 /// POST /api/services/daemon/start
-pub async fn start_daemon() -> Json<ApiResponse<ServiceActionResponse>> {
-    match ServiceManager::start_daemon() {
-        Ok(response) => Json(ApiResponse {
-            success: response.success,
-            data: Some(response),
-            error: None,
-        }),
-        Err(e) => Json(ApiResponse {
-            success: false,
-            data: Some(ServiceActionResponse {
-                success: false,
-                service: "daemon".to_string(),
-                message: format!("Failed to start daemon: {}", e),
-            }),
-            error: Some(e.to_string()),
-        }),
-    }
+pub async fn start_daemon() -> Result<Json<ApiResponse<ServiceActionResponse>>, AppError> {
+    let response = ServiceManager::start_daemon()
+        .map_err(|e| AppError::InternalError(format!("Failed to start daemon: {}", e)))?;
+
+    Ok(Json(ApiResponse {
+        success: response.success,
+        data: Some(response),
+        error: None,
+    }))
 }
 
 /// POST /api/services/daemon/stop
-pub async fn stop_daemon() -> Json<ApiResponse<ServiceActionResponse>> {
-    match ServiceManager::stop_daemon() {
-        Ok(response) => Json(ApiResponse {
-            success: response.success,
-            data: Some(response),
-            error: None,
-        }),
-        Err(e) => Json(ApiResponse {
-            success: false,
-            data: Some(ServiceActionResponse {
-                success: false,
-                service: "daemon".to_string(),
-                message: format!("Failed to stop daemon: {}", e),
-            }),
-            error: Some(e.to_string()),
-        }),
-    }
+pub async fn stop_daemon() -> Result<Json<ApiResponse<ServiceActionResponse>>, AppError> {
+    let response = ServiceManager::stop_daemon()
+        .map_err(|e| AppError::InternalError(format!("Failed to stop daemon: {}", e)))?;
+
+    Ok(Json(ApiResponse {
+        success: response.success,
+        data: Some(response),
+        error: None,
+    }))
 }
 
 /// POST /api/services/daemon/restart
-pub async fn restart_daemon() -> Json<ApiResponse<ServiceActionResponse>> {
-    match ServiceManager::restart_daemon() {
-        Ok(response) => Json(ApiResponse {
-            success: response.success,
-            data: Some(response),
-            error: None,
-        }),
-        Err(e) => Json(ApiResponse {
-            success: false,
-            data: Some(ServiceActionResponse {
-                success: false,
-                service: "daemon".to_string(),
-                message: format!("Failed to restart daemon: {}", e),
-            }),
-            error: Some(e.to_string()),
-        }),
-    }
+pub async fn restart_daemon() -> Result<Json<ApiResponse<ServiceActionResponse>>, AppError> {
+    let response = ServiceManager::restart_daemon()
+        .map_err(|e| AppError::InternalError(format!("Failed to restart daemon: {}", e)))?;
+
+    Ok(Json(ApiResponse {
+        success: response.success,
+        data: Some(response),
+        error: None,
+    }))
 }
 
 /// GET /api/services/doctor
 ///
 /// Run OpenClaw doctor diagnostics and return structured results.
-pub async fn run_doctor() -> Json<ApiResponse<DoctorReport>> {
-    match DoctorService::run_diagnostics() {
-        Ok(report) => Json(ApiResponse {
-            success: true,
-            data: Some(report),
-            error: None,
-        }),
-        Err(e) => Json(ApiResponse {
-            success: false,
-            data: None,
-            error: Some(format!("Doctor diagnostics failed: {}", e)),
-        }),
+pub async fn run_doctor() -> Result<Json<ApiResponse<DoctorReport>>, AppError> {
+    let report = DoctorService::run_diagnostics()
+        .map_err(|e| AppError::DoctorFailed(format!("Doctor diagnostics failed: {}", e)))?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(report),
+        error: None,
+    }))
+}
+
+/// GET /ws/services/upgrade
+///
+/// WebSocket that starts an OpenClaw upgrade as soon as it connects: backs up
+/// the config, updates the npm package, runs `openclaw doctor --fix`, and
+/// restarts the gateway. Streams `upgrade-progress` messages until done.
+pub async fn ws_upgrade(ws: WebSocketUpgrade) -> Response {
+    let span = tracing::Span::current();
+    ws.on_upgrade(move |socket| handle_upgrade_socket(socket).instrument(span))
+}
+
+/// Run the upgrade and forward its progress to the WebSocket
+async fn handle_upgrade_socket(mut socket: WebSocket) {
+    info!("Starting upgrade from /ws/services/upgrade");
+
+    let (tx, mut rx) = mpsc::channel::<InstallProgress>(100);
+
+    tokio::spawn(
+        async move {
+            if let Err(e) = UpgradeService::run_upgrade(tx.clone()).await {
+                error!("Upgrade failed: {}", e);
+                let _ = tx
+                    .send(InstallProgress {
+                        stage: "upgrade".into(),
+                        status: "failed".into(),
+                        message: "Upgrade failed".into(),
+                        error: Some(e.to_string()),
+                        ..Default::default()
+                    })
+                    .await;
+            }
+        }
+        .in_current_span(),
+    );
+
+    while let Some(progress) = rx.recv().await {
+        let response = WsMessage {
+            msg_type: "upgrade-progress".into(),
+            payload: serde_json::to_value(&progress).unwrap_or_default(),
+        };
+
+        let json = serde_json::to_string(&response).unwrap_or_default();
+        if socket.send(Message::Text(json.into())).await.is_err() {
+            warn!("Failed to send upgrade progress update, client disconnected");
+            break;
+        }
     }
 }
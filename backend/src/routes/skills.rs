@@ -3,12 +3,18 @@
 //! REST endpoints for skills discovery, installation, and security scanning:
 //! - GET    /api/skills/search    - Search ClawHub for skills
 //! - GET    /api/skills/installed - List locally installed skills
+//! - GET    /api/skills/recommended - Curated skills for new users
 //! - POST   /api/skills/install   - Install a skill (with optional VT scan)
+//! - GET    /ws/skills/install    - Install a skill, streaming scan and npm output
+//! - POST   /api/skills/install-batch - Install several skills in order
+//! - GET    /ws/skills/install-batch  - Batch install, streaming per-skill progress
+//! - POST   /api/skills/uninstall-batch - Uninstall several skills, each confirmed
 //! - POST   /api/skills/scan      - Scan a skill package with VirusTotal
 //! - GET    /api/skills/{name}    - Get skill details
+//! - GET    /api/skills/{name}/audit - Audit an installed skill's dependencies
 //! - DELETE /api/skills/{name}    - Uninstall a skill
 //!
-//! IMPORTANT: Literal routes (search, installed) must be registered BEFORE
+//! IMPORTANT: Literal routes (search, installed, recommended) must be registered BEFORE
 //! the {name} parameter route to avoid path capture conflicts.
 //!
 //! SECURITY:
@@ -17,15 +23,24 @@
 //! - VT not configured returns None (not an error)
 
 use axum::Json;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
 use axum::extract::{Path, Query};
+use axum::http::HeaderMap;
+use axum::response::Response;
 use serde::Deserialize;
+use tokio::sync::mpsc;
+use tracing::{Instrument, error, info, warn};
 
 use crate::error::AppError;
 use crate::models::types::{
-    ApiResponse, EmptyResponse, InstalledSkill, ScanRequest, ScanResult, SkillInstallRequest,
-    SkillInstallResponse, SkillMetadata, SkillSearchResponse,
+    ApiResponse, DestructiveAction, EmptyResponse, InstallProgress, InstalledSkill,
+    RecommendedSkillsResponse, ScanRequest, ScanResult, SkillAudit, SkillBatchInstallRequest,
+    SkillBatchProgress, SkillBatchSummary, SkillBatchUninstallRequest, SkillBatchUninstallSummary,
+    SkillInstallRequest, SkillInstallResponse, SkillMetadata, SkillSearchResponse, WsMessage,
 };
+use crate::routes::confirm::confirm_token;
 use crate::services::SkillsService;
+use crate::services::confirmation::ConfirmationService;
 
 /// Query parameters for skill search endpoint.
 #[derive(Debug, Deserialize)]
@@ -59,6 +74,28 @@ pub async fn search_skills(
     }))
 }
 
+/// Query parameters for the installed skills endpoint.
+#[derive(Debug, Deserialize)]
+pub struct InstalledSkillsQuery {
+    /// Include a dependency vulnerability summary per skill (slower)
+    pub audit: Option<bool>,
+}
+
+/// GET /api/skills/recommended
+///
+/// Curated skills worth installing on a new setup, each with a short
+/// rationale and current registry metadata. Never fails: without a
+/// registry connection the entries come back without `details`.
+pub async fn recommended_skills() -> Json<ApiResponse<RecommendedSkillsResponse>> {
+    let response = SkillsService::new().recommended().await;
+
+    Json(ApiResponse {
+        success: true,
+        data: Some(response),
+        error: None,
+    })
+}
+
 /// GET /api/skills/{name}
 ///
 /// Get detailed metadata for a specific skill.
@@ -82,7 +119,8 @@ pub async fn skill_details(
 /// Install a skill via npm. If VirusTotal API key is configured,
 /// the package is scanned before installation.
 ///
-/// Returns 403 if VirusTotal detects the package as malicious.
+/// Returns 403 if VirusTotal detects the package as malicious, unless
+/// `force` is set: then it installs and the response carries a `warning`.
 /// Returns install result with optional scan result.
 pub async fn install_skill(
     Json(request): Json<SkillInstallRequest>,
@@ -90,16 +128,278 @@ pub async fn install_skill(
     let service = SkillsService::new();
 
     let response = service
-        .install_skill(&request.name, request.version.as_deref())
+        .install_skill(
+            &request.name,
+            request.version.as_deref(),
+            request.force.unwrap_or(false),
+        )
         .await?;
 
     Ok(Json(response))
 }
 
+/// GET /ws/skills/install
+///
+/// WebSocket variant of POST /api/skills/install. The client sends
+/// `{"msg_type": "start-skill-install", "payload": SkillInstallRequest}`;
+/// the server streams `skill-install-progress` messages (VT scan, npm output
+/// lines, heartbeats) and finishes with a `skill-install-result` carrying the
+/// `SkillInstallResponse`. Blocked or failed installs end with a failed
+/// progress message instead.
+pub async fn ws_skill_install(ws: WebSocketUpgrade) -> Response {
+    let span = tracing::Span::current();
+    ws.on_upgrade(move |socket| handle_skill_install_socket(socket).instrument(span))
+}
+
+/// Handle the skill install WebSocket connection
+async fn handle_skill_install_socket(mut socket: WebSocket) {
+    let request = match socket.recv().await {
+        Some(Ok(Message::Text(text))) => match parse_start_message(&text) {
+            Ok(request) => request,
+            Err(message) => {
+                warn!("Rejected skill install request: {}", message);
+                let _ =
+                    send_message(&mut socket, "skill-install-progress", &failed(&message)).await;
+                return;
+            }
+        },
+        Some(Ok(Message::Close(_))) | None => {
+            info!("Skill install WebSocket closed before install request");
+            return;
+        }
+        _ => {
+            warn!("Unexpected WebSocket message type");
+            return;
+        }
+    };
+
+    info!("Starting streamed install of skill '{}'", request.name);
+
+    let (tx, mut rx) = mpsc::channel::<InstallProgress>(100);
+    let install = tokio::spawn(
+        async move {
+            SkillsService::new()
+                .install_skill_with_progress(
+                    &request.name,
+                    request.version.as_deref(),
+                    request.force.unwrap_or(false),
+                    Some(&tx),
+                )
+                .await
+        }
+        .in_current_span(),
+    );
+
+    // Forward progress until the install task drops its sender
+    while let Some(progress) = rx.recv().await {
+        if send_message(&mut socket, "skill-install-progress", &progress)
+            .await
+            .is_err()
+        {
+            warn!("Failed to send skill install progress, client disconnected");
+            return;
+        }
+    }
+
+    match install.await {
+        Ok(Ok(response)) => {
+            let _ = send_message(&mut socket, "skill-install-result", &response).await;
+        }
+        Ok(Err(e @ AppError::SkillBlocked(_))) => {
+            // The scan stage already reported the block
+            info!("Streamed skill install blocked: {}", e);
+        }
+        Ok(Err(e)) => {
+            error!("Streamed skill install failed: {}", e);
+            let _ = send_message(
+                &mut socket,
+                "skill-install-progress",
+                &failed(&e.to_string()),
+            )
+            .await;
+        }
+        Err(e) => {
+            error!("Skill install task panicked: {}", e);
+            let _ = send_message(
+                &mut socket,
+                "skill-install-progress",
+                &failed("Skill installation was interrupted"),
+            )
+            .await;
+        }
+    }
+}
+
+/// POST /api/skills/install-batch
+///
+/// Install the listed skills one after another (VirusTotal's rate limit
+/// rules out parallel scans). A blocked or failed skill doesn't stop the
+/// rest; the summary reports each skill as installed, blocked or failed.
+/// Returns 400 for an empty list, a blank name, or more than 50 skills.
+pub async fn install_skill_batch(
+    Json(request): Json<SkillBatchInstallRequest>,
+) -> Result<Json<ApiResponse<SkillBatchSummary>>, AppError> {
+    let summary = SkillsService::new()
+        .install_batch(&request.skills, None)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(summary),
+        error: None,
+    }))
+}
+
+/// GET /ws/skills/install-batch
+///
+/// WebSocket variant of POST /api/skills/install-batch. The client sends
+/// `{"msg_type": "start-skill-batch-install", "payload": SkillBatchInstallRequest}`;
+/// the server streams `skill-batch-progress` messages (`SkillBatchProgress`,
+/// with a "skill-batch" stage update after each skill) and finishes with a
+/// `skill-batch-result` carrying the `SkillBatchSummary`. A rejected request
+/// or a batch that couldn't run ends with `skill-batch-error` carrying a
+/// failed `InstallProgress` instead.
+pub async fn ws_skill_batch_install(ws: WebSocketUpgrade) -> Response {
+    let span = tracing::Span::current();
+    ws.on_upgrade(move |socket| handle_skill_batch_socket(socket).instrument(span))
+}
+
+/// Handle the batch install WebSocket connection
+async fn handle_skill_batch_socket(mut socket: WebSocket) {
+    let request = match socket.recv().await {
+        Some(Ok(Message::Text(text))) => match parse_batch_start_message(&text) {
+            Ok(request) => request,
+            Err(message) => {
+                warn!("Rejected skill batch install request: {}", message);
+                let _ = send_message(&mut socket, "skill-batch-error", &failed(&message)).await;
+                return;
+            }
+        },
+        Some(Ok(Message::Close(_))) | None => {
+            info!("Skill batch WebSocket closed before install request");
+            return;
+        }
+        _ => {
+            warn!("Unexpected WebSocket message type");
+            return;
+        }
+    };
+
+    info!("Starting batch install of {} skills", request.skills.len());
+
+    let (tx, mut rx) = mpsc::channel::<SkillBatchProgress>(100);
+    let install = tokio::spawn(
+        async move {
+            SkillsService::new()
+                .install_batch(&request.skills, Some(&tx))
+                .await
+        }
+        .in_current_span(),
+    );
+
+    while let Some(progress) = rx.recv().await {
+        if send_message(&mut socket, "skill-batch-progress", &progress)
+            .await
+            .is_err()
+        {
+            // Dropping the receiver makes the batch stop after the current skill
+            warn!("Failed to send skill batch progress, client disconnected");
+            return;
+        }
+    }
+
+    match install.await {
+        Ok(Ok(summary)) => {
+            let _ = send_message(&mut socket, "skill-batch-result", &summary).await;
+        }
+        Ok(Err(e)) => {
+            error!("Skill batch install failed: {}", e);
+            let _ = send_message(&mut socket, "skill-batch-error", &failed(&e.to_string())).await;
+        }
+        Err(e) => {
+            error!("Skill batch install task panicked: {}", e);
+            let _ = send_message(
+                &mut socket,
+                "skill-batch-error",
+                &failed("Skill batch installation was interrupted"),
+            )
+            .await;
+        }
+    }
+}
+
+/// Parse the first client message into an install request
+fn parse_start_message(text: &str) -> Result<SkillInstallRequest, String> {
+    let request: SkillInstallRequest = parse_payload(text, "start-skill-install")
+        .map_err(|e| e.unwrap_or_else(|| "Invalid skill install request format".to_string()))?;
+    if request.name.trim().is_empty() {
+        return Err("Skill name is required".to_string());
+    }
+    Ok(request)
+}
+
+/// Parse the first client message into a batch install request
+fn parse_batch_start_message(text: &str) -> Result<SkillBatchInstallRequest, String> {
+    let request: SkillBatchInstallRequest = parse_payload(text, "start-skill-batch-install")
+        .map_err(|e| e.unwrap_or_else(|| "Invalid skill batch request format".to_string()))?;
+    SkillsService::validate_batch(&request.skills).map_err(|e| e.to_string())?;
+    Ok(request)
+}
+
+/// Payload of a `msg_type` message; `Err(None)` means the payload didn't parse
+fn parse_payload<T: serde::de::DeserializeOwned>(
+    text: &str,
+    msg_type: &str,
+) -> Result<T, Option<String>> {
+    let ws_msg: WsMessage =
+        serde_json::from_str(text).map_err(|_| Some("Invalid JSON message format".to_string()))?;
+    if ws_msg.msg_type != msg_type {
+        return Err(Some(format!(
+            "Expected '{}', got '{}'",
+            msg_type, ws_msg.msg_type
+        )));
+    }
+    serde_json::from_value(ws_msg.payload).map_err(|_| None)
+}
+
+/// Failed progress update for errors outside the install stages
+fn failed(message: &str) -> InstallProgress {
+    InstallProgress {
+        stage: "error".into(),
+        status: "failed".into(),
+        message: message.to_string(),
+        error: Some(message.to_string()),
+        ..Default::default()
+    }
+}
+
+/// Send a typed message over the WebSocket
+async fn send_message(
+    socket: &mut WebSocket,
+    msg_type: &str,
+    payload: &impl serde::Serialize,
+) -> Result<(), axum::Error> {
+    let message = WsMessage {
+        msg_type: msg_type.to_string(),
+        payload: serde_json::to_value(payload).unwrap_or_default(),
+    };
+    let json = serde_json::to_string(&message).unwrap_or_default();
+    socket.send(Message::Text(json.into())).await
+}
+
 /// DELETE /api/skills/{name}
 ///
 /// Uninstall a skill via npm.
-pub async fn uninstall_skill(Path(name): Path<String>) -> Result<Json<EmptyResponse>, AppError> {
+/// Requires an `X-Confirm-Token` for `UninstallSkill` on this name.
+pub async fn uninstall_skill(
+    Path(name): Path<String>,
+    headers: HeaderMap,
+) -> Result<Json<EmptyResponse>, AppError> {
+    ConfirmationService::consume(
+        DestructiveAction::UninstallSkill,
+        Some(&name),
+        confirm_token(&headers),
+    )?;
     let service = SkillsService::new();
 
     service.uninstall_skill(&name).await?;
@@ -110,14 +410,49 @@ pub async fn uninstall_skill(Path(name): Path<String>) -> Result<Json<EmptyRespo
     }))
 }
 
+/// POST /api/skills/uninstall-batch
+///
+/// Uninstall the listed skills one after another. Each entry carries its own
+/// `UninstallSkill` token for that name (one header can't hold them all);
+/// every token is checked before anything is removed. A failed uninstall
+/// doesn't stop the rest. Returns 400 for an empty list, a blank name, or
+/// more than 50 skills.
+pub async fn uninstall_skill_batch(
+    Json(request): Json<SkillBatchUninstallRequest>,
+) -> Result<Json<ApiResponse<SkillBatchUninstallSummary>>, AppError> {
+    let names: Vec<&str> = request.skills.iter().map(|s| s.name.as_str()).collect();
+    SkillsService::validate_uninstall_batch(&names)?;
+    for skill in &request.skills {
+        ConfirmationService::consume(
+            DestructiveAction::UninstallSkill,
+            Some(&skill.name),
+            Some(&skill.confirm_token),
+        )?;
+    }
+
+    let summary = SkillsService::new().uninstall_batch(&names).await;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(summary),
+        error: None,
+    }))
+}
+
 /// GET /api/skills/installed
 ///
 /// List all locally installed OpenClaw skills.
 /// Filters npm global packages for those with openclaw-skill keyword.
-pub async fn list_installed() -> Result<Json<ApiResponse<Vec<InstalledSkill>>>, AppError> {
+/// Query param: audit=true adds a vulnerability summary to each skill.
+pub async fn list_installed(
+    Query(query): Query<InstalledSkillsQuery>,
+) -> Result<Json<ApiResponse<Vec<InstalledSkill>>>, AppError> {
     let service = SkillsService::new();
 
-    let skills = service.list_installed().await?;
+    let mut skills = service.list_installed().await?;
+    if query.audit.unwrap_or(false) {
+        service.attach_audits(&mut skills).await;
+    }
 
     Ok(Json(ApiResponse {
         success: true,
@@ -126,9 +461,27 @@ pub async fn list_installed() -> Result<Json<ApiResponse<Vec<InstalledSkill>>>,
     }))
 }
 
+/// GET /api/skills/{name}/audit
+///
+/// Audit an installed skill's dependencies for known vulnerabilities.
+/// Returns 404 if the skill is not installed.
+pub async fn audit_skill(
+    Path(name): Path<String>,
+) -> Result<Json<ApiResponse<SkillAudit>>, AppError> {
+    let service = SkillsService::new();
+
+    let audit = service.audit_skill(&name).await?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(audit),
+        error: None,
+    }))
+}
+
 /// POST /api/skills/scan
 ///
-/// Scan a skill package with VirusTotal.
+/// Scan a skill package with VirusTotal, including which engines flagged it.
 /// Returns None if VT API key is not configured (not an error).
 pub async fn scan_skill(
     Json(request): Json<ScanRequest>,
@@ -160,3 +513,60 @@ fn parse_category(s: &str) -> Option<crate::models::types::SkillCategory> {
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_start_message() {
+        let request = parse_start_message(
+            r#"{"msg_type":"start-skill-install","payload":{"name":"@openclaw/skill-web","version":"1.2.0"}}"#,
+        )
+        .unwrap();
+        assert_eq!(request.name, "@openclaw/skill-web");
+        assert_eq!(request.version.as_deref(), Some("1.2.0"));
+        assert_eq!(request.force, None);
+
+        let forced = parse_start_message(
+            r#"{"msg_type":"start-skill-install","payload":{"name":"bundled-bin","force":true}}"#,
+        )
+        .unwrap();
+        assert_eq!(forced.force, Some(true));
+
+        let err = parse_start_message(r#"{"msg_type":"start-install","payload":{}}"#).unwrap_err();
+        assert!(err.contains("start-skill-install"));
+        assert!(parse_start_message("not json").is_err());
+        assert!(
+            parse_start_message(r#"{"msg_type":"start-skill-install","payload":{"name":" "}}"#)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_batch_start_message() {
+        let request = parse_batch_start_message(
+            r#"{"msg_type":"start-skill-batch-install","payload":{"skills":[{"name":"a","version":null},{"name":"b","version":"2.0.0"}]}}"#,
+        )
+        .unwrap();
+        assert_eq!(request.skills.len(), 2);
+        assert_eq!(request.skills[1].version.as_deref(), Some("2.0.0"));
+
+        let err = parse_batch_start_message(
+            r#"{"msg_type":"start-skill-install","payload":{"skills":[]}}"#,
+        )
+        .unwrap_err();
+        assert!(err.contains("start-skill-batch-install"));
+        assert!(
+            parse_batch_start_message(
+                r#"{"msg_type":"start-skill-batch-install","payload":{"skills":[]}}"#
+            )
+            .unwrap_err()
+            .contains("No skills")
+        );
+        assert!(
+            parse_batch_start_message(r#"{"msg_type":"start-skill-batch-install","payload":{}}"#)
+                .is_err()
+        );
+    }
+}
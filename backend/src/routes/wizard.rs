@@ -1,18 +1,60 @@
 //! # Wizard API Routes
 //!
-//! Endpoints for the setup wizard: API key validation and config save.
+//! Endpoints for the setup wizard: API key validation, credential
+//! generation, config save and the streamed full setup.
 
-use axum::Json;
+use axum::{
+    Json,
+    extract::{
+        Query,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    http::{HeaderMap, HeaderName, header},
+    response::Response,
+};
+use serde::Deserialize;
+use tokio::sync::mpsc;
+use tracing::{Instrument, error, info, warn};
 
 use crate::error::AppError;
+use crate::models::types::{
+    DestructiveAction, GeneratedCredential, InstallCancelRequest, InstallCancelResponse,
+    InstallPlan,
+};
 use crate::models::{
-    ApiKeyValidationRequest, ApiKeyValidationResponse, ApiResponse, EmptyResponse, InstallRequest,
-    RollbackResult, WizardConfig,
+    ApiKeyValidationRequest, ApiKeyValidationResponse, ApiResponse, EmptyResponse, InstallProgress,
+    InstallRequest, OnboardPreview, RollbackResult, WizardConfig, WsMessage,
 };
-use crate::services::{RollbackService, config::ConfigWriter, platform::Platform};
+use crate::routes::confirm::confirm_token;
+use crate::services::{
+    RollbackService,
+    config::ConfigWriter,
+    config_schema::ConfigSchema,
+    confirmation::ConfirmationService,
+    credentials::{CredentialService, DEFAULT_CREDENTIAL_LENGTH},
+    installer::{GatewaySetup, InstallerService},
+    offline::OfflineMode,
+    platform::Platform,
+    reconcile::ConfigReconciler,
+    setup::SetupService,
+    wizard_config::CURRENT_SCHEMA_VERSION,
+};
+
+/// Key prefixes of providers whose keys have a recognisable shape, most
+/// specific first so `sk-ant-` and `sk-or-` are told apart from `sk-`
+const KEY_PREFIXES: &[(&str, &str, &str)] = &[
+    ("anthropic", "Anthropic", "sk-ant-"),
+    ("openrouter", "OpenRouter", "sk-or-"),
+    ("openai", "OpenAI", "sk-"),
+    ("xai", "xAI", "xai-"),
+    ("google", "Google Gemini", "AIza"),
+    ("huggingface", "Hugging Face", "hf_"),
+];
 
 /// Validate API key or setup token by testing against provider API.
-/// Anthropic and OpenAI get full API validation; all other providers get format validation only.
+/// Every key is format-checked first, so malformed keys fail without a
+/// network call; Anthropic and OpenAI keys that pass then get full API
+/// validation. In offline mode every provider gets format validation only.
 pub async fn validate_api_key(
     Json(request): Json<ApiKeyValidationRequest>,
 ) -> Json<ApiResponse<ApiKeyValidationResponse>> {
@@ -22,13 +64,13 @@ pub async fn validate_api_key(
             valid: true,
             error: None,
         },
-        // Anthropic: full API validation
         ("anthropic", "setup-token") => validate_anthropic_setup_token(&request.api_key),
-        ("anthropic", _) => validate_anthropic_key(&request.api_key).await,
-        // OpenAI: full API validation
-        ("openai", _) => validate_openai_key(&request.api_key).await,
-        // All other providers: format validation (non-empty, min length)
-        _ => validate_generic_key(&request.api_key),
+        (provider, _) => match validate_key_format(provider, &request.api_key) {
+            format if !format.valid || OfflineMode::is_enabled() => format,
+            _ if provider == "anthropic" => validate_anthropic_key(&request.api_key).await,
+            _ if provider == "openai" => validate_openai_key(&request.api_key).await,
+            format => format,
+        },
     };
 
     Json(ApiResponse {
@@ -125,6 +167,43 @@ fn validate_generic_key(api_key: &str) -> ApiKeyValidationResponse {
     }
 }
 
+/// Offline checks for a provider's key: the generic checks, no whitespace,
+/// and the provider's key prefix when it has one
+fn validate_key_format(provider: &str, api_key: &str) -> ApiKeyValidationResponse {
+    let generic = validate_generic_key(api_key);
+    if !generic.valid {
+        return generic;
+    }
+    let invalid = |error: String| ApiKeyValidationResponse {
+        valid: false,
+        error: Some(error),
+    };
+
+    if api_key.chars().any(char::is_whitespace) {
+        return invalid(
+            "API key contains spaces or line breaks; copy it again without them".to_string(),
+        );
+    }
+
+    let Some((_, name, prefix)) = KEY_PREFIXES.iter().find(|(id, _, _)| *id == provider) else {
+        return generic;
+    };
+    let looks_like = KEY_PREFIXES
+        .iter()
+        .find(|(_, _, prefix)| api_key.starts_with(prefix));
+    match looks_like {
+        Some((id, _, _)) if *id == provider => generic,
+        Some((_, other, _)) => invalid(format!(
+            "This doesn't look like an API key for {}: it looks like one for {}. Check the selected provider.",
+            name, other
+        )),
+        None => invalid(format!(
+            "This doesn't look like an API key for {}: {} keys start with '{}'",
+            name, name, prefix
+        )),
+    }
+}
+
 /// Validate OpenAI API key
 async fn validate_openai_key(api_key: &str) -> ApiKeyValidationResponse {
     let client = reqwest::Client::new();
@@ -160,10 +239,65 @@ async fn validate_openai_key(api_key: &str) -> ApiKeyValidationResponse {
     }
 }
 
+/// Query parameters for the credential generator
+#[derive(Debug, Deserialize)]
+pub struct GenerateCredentialQuery {
+    /// Credential length (16-256, default 32)
+    pub length: Option<usize>,
+}
+
+/// GET /api/wizard/generate-credential?length=32
+///
+/// Returns a random alphanumeric credential for `auth_credential`. The
+/// minimum length matches the security audit's weak-credential check.
+pub async fn generate_credential(
+    Query(query): Query<GenerateCredentialQuery>,
+) -> Result<
+    (
+        [(HeaderName, &'static str); 1],
+        Json<ApiResponse<GeneratedCredential>>,
+    ),
+    AppError,
+> {
+    let length = CredentialService::checked_length(query.length)?;
+
+    Ok((
+        [(header::CACHE_CONTROL, "no-store")],
+        Json(ApiResponse {
+            success: true,
+            data: Some(GeneratedCredential {
+                credential: CredentialService::generate(length),
+                length: length as u32,
+            }),
+            error: None,
+        }),
+    ))
+}
+
+/// GET /api/wizard/config-schema
+///
+/// JSON Schema for the `save-config` body, for client-side validation.
+pub async fn config_schema() -> Json<ApiResponse<serde_json::Value>> {
+    Json(ApiResponse {
+        success: true,
+        data: Some(ConfigSchema::wizard_config()),
+        error: None,
+    })
+}
+
 /// Save wizard configuration to openclaw.json
-pub async fn save_config(Json(config): Json<WizardConfig>) -> Json<ApiResponse<EmptyResponse>> {
+///
+/// Token auth without a credential gets a generated one.
+pub async fn save_config(
+    Json(mut config): Json<WizardConfig>,
+) -> Result<Json<ApiResponse<EmptyResponse>>, AppError> {
+    if config.auth_mode == "token" && config.auth_credential.as_deref().is_none_or(str::is_empty) {
+        config.auth_credential = Some(CredentialService::generate(DEFAULT_CREDENTIAL_LENGTH));
+    }
+
     // Build wizard's internal config (preserves all wizard fields)
     let wizard_config = serde_json::json!({
+        "schema_version": CURRENT_SCHEMA_VERSION,
         "provider": config.provider,
         "auth_type": config.auth_type,
         "api_key": config.api_key,
@@ -177,108 +311,43 @@ pub async fn save_config(Json(config): Json<WizardConfig>) -> Json<ApiResponse<E
         "compatibility": config.compatibility,
         "account_id": config.account_id,
         "gateway_id": config.gateway_id,
+        "log_dir": config.log_dir,
+        "analysis_model": config.analysis_model,
     });
 
     // Save to wizard's own config dir
-    let config_dir = match Platform::config_dir() {
-        Ok(dir) => dir,
-        Err(e) => {
-            return Json(ApiResponse {
-                success: false,
-                data: None,
-                error: Some(format!("Failed to determine config directory: {}", e)),
-            });
-        }
-    };
-
-    let config_path = config_dir.join("openclaw.json");
-    if let Err(e) = ConfigWriter::write_json(&config_path, &wizard_config) {
-        return Json(ApiResponse {
-            success: false,
-            data: None,
-            error: Some(format!("Failed to write config: {}", e)),
-        });
-    }
+    let config_path = Platform::wizard_config_path().map_err(|e| {
+        AppError::InternalError(format!("Failed to determine config directory: {}", e))
+    })?;
+    ConfigWriter::write_json(&config_path, &wizard_config)
+        .map_err(|e| AppError::InternalError(format!("Failed to write config: {}", e)))?;
 
-    // Build OpenClaw gateway-compatible config format
-    // gateway.bind must be "loopback" not "127.0.0.1", gateway.auth uses "token" not "credential"
-    let bind_value = match config.gateway_bind.as_str() {
-        "127.0.0.1" | "localhost" | "loopback" => "loopback",
-        "0.0.0.0" | "all" => "all",
-        other => other,
-    };
+    // Deploy to the gateway config, merging with what's already there
+    ConfigReconciler::deploy(&config).map_err(|e| AppError::InternalError(format!("{:#}", e)))?;
 
-    let mut gateway_config = serde_json::json!({
-        "gateway": {
-            "mode": "local",
-            "port": config.gateway_port,
-            "bind": bind_value,
-            "auth": {
-                "mode": config.auth_mode,
-                "token": config.auth_credential,
-            }
-        }
-    });
-
-    // Add channels if present
-    if let Some(channels) = &config.channels {
-        let mut channels_obj = serde_json::Map::new();
-        for channel in channels {
-            let mut channel_config = serde_json::Map::new();
-            channel_config.insert("enabled".to_string(), serde_json::json!(channel.enabled));
-            channel_config.insert("dmPolicy".to_string(), serde_json::json!(channel.dm_policy));
-            channel_config.insert(
-                "allowFrom".to_string(),
-                serde_json::json!(channel.allowed_users),
-            );
-
-            if let Some(ref token) = channel.bot_token {
-                channel_config.insert("botToken".to_string(), serde_json::json!(token));
-            }
-            if let Some(ref token) = channel.app_token {
-                channel_config.insert("appToken".to_string(), serde_json::json!(token));
-            }
-
-            channels_obj.insert(
-                channel.platform.clone(),
-                serde_json::Value::Object(channel_config),
-            );
-        }
-        gateway_config.as_object_mut().unwrap().insert(
-            "channels".to_string(),
-            serde_json::Value::Object(channels_obj),
-        );
-    }
-
-    // Deploy to ~/.openclaw/openclaw.json, merging with existing config
-    if let Ok(home) = Platform::home_dir() {
-        let openclaw_dir = home.join(".openclaw");
-        let _ = std::fs::create_dir_all(&openclaw_dir);
-        let target_path = openclaw_dir.join("openclaw.json");
-
-        // Read existing config to preserve meta/commands/agents sections
-        let mut existing: serde_json::Value =
-            ConfigWriter::read_json(&target_path).unwrap_or_else(|_| serde_json::json!({}));
-
-        // Merge gateway and channels into existing config
-        if let (Some(existing_obj), Some(new_obj)) =
-            (existing.as_object_mut(), gateway_config.as_object())
-        {
-            for (key, value) in new_obj {
-                existing_obj.insert(key.clone(), value.clone());
-            }
-        }
-
-        let _ = ConfigWriter::write_json(&target_path, &existing);
-    }
-
-    Json(ApiResponse {
+    Ok(Json(ApiResponse {
         success: true,
         data: Some(EmptyResponse {
             success: true,
             error: None,
         }),
         error: None,
+    }))
+}
+
+/// GET /api/wizard/install-plan
+///
+/// Ordered stages an install with these flags will run, so the UI can
+/// render its checklist before progress starts streaming.
+pub async fn install_plan(Query(request): Query<InstallRequest>) -> Json<ApiResponse<InstallPlan>> {
+    Json(ApiResponse {
+        success: true,
+        data: Some(InstallerService::install_plan(
+            request.install_node,
+            request.install_openclaw,
+            GatewaySetup::from(&request),
+        )),
+        error: None,
     })
 }
 
@@ -296,10 +365,205 @@ pub async fn start_install(
     })
 }
 
+/// POST /api/wizard/install/cancel
+///
+/// Cancel a running install by id. Child processes are terminated and a
+/// `cancelled` progress message is sent on the install's WebSocket stream
+/// once they have exited. Returns 404 if no install with that id is running.
+pub async fn cancel_install(
+    Json(request): Json<InstallCancelRequest>,
+) -> Result<Json<ApiResponse<InstallCancelResponse>>, AppError> {
+    if !InstallerService::cancel_install(&request.install_id).await {
+        return Err(AppError::NotFound(format!(
+            "No running install with id '{}'",
+            request.install_id
+        )));
+    }
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(InstallCancelResponse {
+            install_id: request.install_id,
+        }),
+        error: None,
+    }))
+}
+
+/// GET /ws/wizard/full-setup
+///
+/// Guided first-time setup in one stream. The client sends
+/// `{"msg_type": "start-full-setup", "payload": InstallRequest}`; the server
+/// installs, runs `openclaw doctor --fix`, starts the gateway and probes its
+/// health, streaming `full-setup-progress` messages. Stage transitions and
+/// the final result are progress messages with stage `setup`.
+pub async fn ws_full_setup(ws: WebSocketUpgrade) -> Response {
+    let span = tracing::Span::current();
+    ws.on_upgrade(move |socket| handle_full_setup_socket(socket).instrument(span))
+}
+
+/// Run the full setup and forward its progress to the WebSocket
+async fn handle_full_setup_socket(mut socket: WebSocket) {
+    let request = match socket.recv().await {
+        Some(Ok(Message::Text(text))) => match parse_full_setup_message(&text) {
+            Ok(request) => request,
+            Err(message) => {
+                warn!("Rejected full setup request: {}", message);
+                let progress = InstallProgress {
+                    stage: "setup".into(),
+                    status: "failed".into(),
+                    message: message.clone(),
+                    error: Some(message),
+                    ..Default::default()
+                };
+                let _ = send_progress(&mut socket, &progress).await;
+                return;
+            }
+        },
+        Some(Ok(Message::Close(_))) | None => {
+            info!("Full setup WebSocket closed before setup request");
+            return;
+        }
+        _ => {
+            warn!("Unexpected WebSocket message type");
+            return;
+        }
+    };
+
+    info!(
+        "Starting full setup: node={}, openclaw={}",
+        request.install_node, request.install_openclaw
+    );
+
+    let gateway = GatewaySetup::from(&request);
+    let (tx, mut rx) = mpsc::channel::<InstallProgress>(100);
+    tokio::spawn(
+        async move {
+            if let Err(e) = SetupService::run_full_setup(
+                request.install_node,
+                request.install_openclaw,
+                request.openclaw_version,
+                gateway,
+                tx,
+            )
+            .await
+            {
+                error!("Full setup failed: {}", e);
+            }
+        }
+        .in_current_span(),
+    );
+
+    while let Some(progress) = rx.recv().await {
+        if send_progress(&mut socket, &progress).await.is_err() {
+            warn!("Failed to send full setup progress, client disconnected");
+            break;
+        }
+    }
+}
+
+/// Parse the first client message into an install request
+fn parse_full_setup_message(text: &str) -> Result<InstallRequest, String> {
+    let ws_msg: WsMessage =
+        serde_json::from_str(text).map_err(|_| "Invalid JSON message format".to_string())?;
+    if ws_msg.msg_type != "start-full-setup" {
+        return Err(format!(
+            "Expected 'start-full-setup', got '{}'",
+            ws_msg.msg_type
+        ));
+    }
+    serde_json::from_value(ws_msg.payload).map_err(|_| "Invalid install request format".to_string())
+}
+
+/// Send a `full-setup-progress` message
+async fn send_progress(
+    socket: &mut WebSocket,
+    progress: &InstallProgress,
+) -> Result<(), axum::Error> {
+    let message = WsMessage {
+        msg_type: "full-setup-progress".into(),
+        payload: serde_json::to_value(progress).unwrap_or_default(),
+    };
+    let json = serde_json::to_string(&message).unwrap_or_default();
+    socket.send(Message::Text(json.into())).await
+}
+
+/// Preview the `openclaw onboard` command that install would run for the saved config.
+/// Nothing is executed; secrets are masked in the returned argv.
+pub async fn onboard_preview() -> Result<Json<ApiResponse<OnboardPreview>>, AppError> {
+    // A missing wizard config arrives as AppError::NotFound
+    let preview = InstallerService::onboard_preview().await.map_err(|e| {
+        e.downcast::<AppError>()
+            .unwrap_or_else(|e| AppError::InternalError(e.to_string()))
+    })?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(preview),
+        error: None,
+    }))
+}
+
 /// Rollback installation by reversing stages: stop daemon, remove config, uninstall
-pub async fn rollback_installation() -> Result<Json<RollbackResult>, AppError> {
+///
+/// Requires an `X-Confirm-Token` for `RollbackInstallation`.
+pub async fn rollback_installation(headers: HeaderMap) -> Result<Json<RollbackResult>, AppError> {
+    ConfirmationService::consume(
+        DestructiveAction::RollbackInstallation,
+        None,
+        confirm_token(&headers),
+    )?;
     let result = RollbackService::rollback_local()
         .await
         .map_err(|e| AppError::InternalError(e.to_string()))?;
     Ok(Json(result))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format_error(provider: &str, key: &str) -> Option<String> {
+        validate_key_format(provider, key).error
+    }
+
+    #[test]
+    fn test_key_format_accepts_matching_prefixes() {
+        assert_eq!(format_error("anthropic", "sk-ant-api03-abcdef123456"), None);
+        assert_eq!(format_error("openai", "sk-proj-abcdef123456"), None);
+        assert_eq!(format_error("openrouter", "sk-or-v1-abcdef123456"), None);
+        assert_eq!(format_error("xai", "xai-abcdef1234567890"), None);
+        // Providers without a known key shape only get the generic checks
+        assert_eq!(format_error("moonshot", "anything-long-enough"), None);
+    }
+
+    #[test]
+    fn test_key_format_rejects_wrong_prefix() {
+        let error = format_error("anthropic", "abc-1234567890").unwrap();
+        assert!(error.starts_with("This doesn't look like an API key for Anthropic"));
+        assert!(error.contains("'sk-ant-'"));
+
+        let error = format_error("openai", "sk-ant-api03-abcdef123456").unwrap();
+        assert!(error.contains("looks like one for Anthropic. Check the selected provider"));
+
+        let error = format_error("google", "sk-proj-abcdef123456").unwrap();
+        assert!(error.contains("looks like one for OpenAI"));
+    }
+
+    #[test]
+    fn test_key_format_rejects_whitespace_and_short_keys() {
+        assert!(
+            format_error("openai", "sk-proj-abc def123456")
+                .unwrap()
+                .contains("spaces")
+        );
+        assert!(format_error("openai", "sk-proj-abc\n").is_some());
+        assert_eq!(
+            format_error("anthropic", "").as_deref(),
+            Some("API key is required")
+        );
+        assert_eq!(
+            format_error("anthropic", "sk-ant-").as_deref(),
+            Some("API key appears too short")
+        );
+    }
+}
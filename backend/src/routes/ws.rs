@@ -3,16 +3,19 @@ use axum::{
     response::Response,
 };
 use tokio::sync::mpsc;
-use tracing::{error, info, warn};
+use tracing::{Instrument, error, info, warn};
 
+use crate::models::types::{DestructiveAction, UninstallRequest};
 use crate::models::{InstallProgress, InstallRequest, WsMessage};
-use crate::services::installer::InstallerService;
+use crate::services::confirmation::ConfirmationService;
+use crate::services::installer::{GatewaySetup, InstallerService};
 use crate::services::uninstaller::UninstallService;
 use crate::services::upgrader::UpgradeService;
 
 /// WebSocket upgrade handler
 pub async fn ws_handler(ws: WebSocketUpgrade) -> Response {
-    ws.on_upgrade(handle_socket)
+    let span = tracing::Span::current();
+    ws.on_upgrade(move |socket| handle_socket(socket).instrument(span))
 }
 
 /// Handle WebSocket connection
@@ -40,30 +43,57 @@ async fn handle_socket(mut socket: WebSocket) {
                                     // Create channel for progress updates
                                     let (tx, mut rx) = mpsc::channel::<InstallProgress>(100);
 
-                                    // Spawn installation task
-                                    let install_node = install_request.install_node;
-                                    let install_openclaw = install_request.install_openclaw;
-                                    tokio::spawn(async move {
-                                        if let Err(e) = InstallerService::run_install(
-                                            install_node,
-                                            install_openclaw,
-                                            tx.clone(),
-                                        )
-                                        .await
+                                    // Spawn installation task, registered for cancellation
+                                    let gateway = GatewaySetup::from(&install_request);
+                                    let install_id = install_request
+                                        .install_id
+                                        .filter(|id| !id.is_empty())
+                                        .unwrap_or_else(InstallerService::generate_install_id);
+                                    if let Err(e) = InstallerService::spawn_install(
+                                        install_id.clone(),
+                                        install_request.install_node,
+                                        install_request.install_openclaw,
+                                        install_request.openclaw_version,
+                                        gateway,
+                                        tx,
+                                    ) {
+                                        warn!("Install not started: {}", e);
+                                        let response = WsMessage {
+                                            msg_type: "install-progress".into(),
+                                            payload: serde_json::to_value(InstallProgress {
+                                                stage: "install".into(),
+                                                status: "failed".into(),
+                                                message: "Installation not started".into(),
+                                                error: Some(e.to_string()),
+                                                ..Default::default()
+                                            })
+                                            .unwrap_or_default(),
+                                        };
+                                        let response_json =
+                                            serde_json::to_string(&response).unwrap_or_default();
+                                        if socket
+                                            .send(Message::Text(response_json.into()))
+                                            .await
+                                            .is_err()
                                         {
-                                            error!("Installation failed: {}", e);
-                                            // Send final error message
-                                            let _ = tx
-                                                .send(InstallProgress {
-                                                    stage: "error".into(),
-                                                    status: "failed".into(),
-                                                    message: "Installation failed".into(),
-                                                    error: Some(e.to_string()),
-                                                    ..Default::default()
-                                                })
-                                                .await;
+                                            break;
                                         }
-                                    });
+                                        continue;
+                                    }
+
+                                    let started = WsMessage {
+                                        msg_type: "install-started".into(),
+                                        payload: serde_json::json!({ "install_id": install_id }),
+                                    };
+                                    let started_json =
+                                        serde_json::to_string(&started).unwrap_or_default();
+                                    if socket
+                                        .send(Message::Text(started_json.into()))
+                                        .await
+                                        .is_err()
+                                    {
+                                        warn!("Failed to send install-started message");
+                                    }
 
                                     // Forward progress updates to WebSocket
                                     while let Some(progress) = rx.recv().await {
@@ -90,24 +120,59 @@ async fn handle_socket(mut socket: WebSocket) {
                                 }
                             }
                         } else if ws_msg.msg_type == "start-uninstall" {
+                            let request: UninstallRequest =
+                                serde_json::from_value(ws_msg.payload).unwrap_or_default();
+                            if let Err(e) = ConfirmationService::consume(
+                                DestructiveAction::UninstallOpenClaw,
+                                None,
+                                request.confirm_token.as_deref(),
+                            ) {
+                                warn!("Uninstall not confirmed: {}", e);
+                                let response = WsMessage {
+                                    msg_type: "uninstall-progress".into(),
+                                    payload: serde_json::to_value(InstallProgress {
+                                        stage: "uninstall".into(),
+                                        status: "failed".into(),
+                                        message: "Uninstall not confirmed".into(),
+                                        error: Some(e.to_string()),
+                                        ..Default::default()
+                                    })
+                                    .unwrap_or_default(),
+                                };
+                                let response_json =
+                                    serde_json::to_string(&response).unwrap_or_default();
+                                if socket
+                                    .send(Message::Text(response_json.into()))
+                                    .await
+                                    .is_err()
+                                {
+                                    break;
+                                }
+                                continue;
+                            }
                             info!("Starting uninstall");
 
                             let (tx, mut rx) = mpsc::channel::<InstallProgress>(100);
 
-                            tokio::spawn(async move {
-                                if let Err(e) = UninstallService::run_uninstall(tx.clone()).await {
-                                    error!("Uninstall failed: {}", e);
-                                    let _ = tx
-                                        .send(InstallProgress {
-                                            stage: "uninstall".into(),
-                                            status: "failed".into(),
-                                            message: "Uninstall failed".into(),
-                                            error: Some(e.to_string()),
-                                            ..Default::default()
-                                        })
-                                        .await;
+                            tokio::spawn(
+                                async move {
+                                    if let Err(e) =
+                                        UninstallService::run_uninstall(tx.clone()).await
+                                    {
+                                        error!("Uninstall failed: {}", e);
+                                        let _ = tx
+                                            .send(InstallProgress {
+                                                stage: "uninstall".into(),
+                                                status: "failed".into(),
+                                                message: "Uninstall failed".into(),
+                                                error: Some(e.to_string()),
+                                                ..Default::default()
+                                            })
+                                            .await;
+                                    }
                                 }
-                            });
+                                .in_current_span(),
+                            );
 
                             while let Some(progress) = rx.recv().await {
                                 let response = WsMessage {
@@ -131,20 +196,23 @@ async fn handle_socket(mut socket: WebSocket) {
 
                             let (tx, mut rx) = mpsc::channel::<InstallProgress>(100);
 
-                            tokio::spawn(async move {
-                                if let Err(e) = UpgradeService::run_upgrade(tx.clone()).await {
-                                    error!("Upgrade failed: {}", e);
-                                    let _ = tx
-                                        .send(InstallProgress {
-                                            stage: "upgrade".into(),
-                                            status: "failed".into(),
-                                            message: "Upgrade failed".into(),
-                                            error: Some(e.to_string()),
-                                            ..Default::default()
-                                        })
-                                        .await;
+                            tokio::spawn(
+                                async move {
+                                    if let Err(e) = UpgradeService::run_upgrade(tx.clone()).await {
+                                        error!("Upgrade failed: {}", e);
+                                        let _ = tx
+                                            .send(InstallProgress {
+                                                stage: "upgrade".into(),
+                                                status: "failed".into(),
+                                                message: "Upgrade failed".into(),
+                                                error: Some(e.to_string()),
+                                                ..Default::default()
+                                            })
+                                            .await;
+                                    }
                                 }
-                            });
+                                .in_current_span(),
+                            );
 
                             while let Some(progress) = rx.recv().await {
                                 let response = WsMessage {
@@ -0,0 +1,335 @@
+//! # Channel Allowlists
+//!
+//! Adds or removes a single user in a channel's DM allowlist without
+//! rewriting the whole config. The wizard config's `allowed_users` is
+//! edited in place (so `${ENV_VAR}` placeholders elsewhere in the file
+//! survive) and the new list is applied to the gateway with
+//! `ConfigSetter`, i.e. `openclaw config set channels.<platform>.allowFrom`.
+//!
+//! User IDs are checked against each platform's format so a typo doesn't
+//! silently lock the intended user out.
+
+use regex::Regex;
+use serde_json::{Value, json};
+use std::path::Path;
+use std::sync::LazyLock;
+use tracing::info;
+
+use crate::error::AppError;
+use crate::models::types::ChannelAllowlistResponse;
+use crate::services::config::ConfigWriter;
+use crate::services::config_set::ConfigSetter;
+use crate::services::platform::Platform;
+
+/// Platforms with a DM allowlist, and the ID format each expects
+const PLATFORM_ID_FORMATS: &[(&str, &str, &str)] = &[
+    (
+        "telegram",
+        r"^(\d{5,15}|@[A-Za-z][A-Za-z0-9_]{4,31})$",
+        "a numeric Telegram user ID or @username",
+    ),
+    ("discord", r"^\d{17,20}$", "a numeric Discord user ID"),
+    (
+        "slack",
+        r"^[UW][A-Z0-9]{6,12}$",
+        "a Slack member ID like U0123ABCD",
+    ),
+    (
+        "whatsapp",
+        r"^\+[1-9]\d{6,14}$",
+        "a phone number in international format like +15551234567",
+    ),
+];
+
+static ID_PATTERNS: LazyLock<Vec<(&'static str, Regex)>> = LazyLock::new(|| {
+    PLATFORM_ID_FORMATS
+        .iter()
+        .map(|(platform, pattern, _)| (*platform, Regex::new(pattern).unwrap()))
+        .collect()
+});
+
+/// Whether a user is being added or removed
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Action {
+    Add,
+    Remove,
+}
+
+/// Edits channel allowlists in the wizard and gateway configs
+pub struct ChannelAllowlist;
+
+impl ChannelAllowlist {
+    /// Add or remove `user_id` in `platform`'s allowlist and apply it
+    ///
+    /// Runs `openclaw config set`, so call it off the async runtime.
+    pub fn update(
+        platform: &str,
+        action: &str,
+        user_id: &str,
+    ) -> Result<ChannelAllowlistResponse, AppError> {
+        let platform = platform.to_lowercase();
+        let action = Self::parse_action(action)?;
+        let user_id = Self::validate_user_id(&platform, user_id)?;
+
+        let wizard_path = Platform::wizard_config_path().ok();
+        let mut wizard: Option<Value> = wizard_path
+            .as_deref()
+            .and_then(|path| ConfigWriter::read_json(path).ok());
+        let gateway: Option<Value> = Platform::openclaw_config_path()
+            .ok()
+            .and_then(|path| ConfigWriter::read_json(&path).ok());
+
+        let current =
+            Self::current_list(wizard.as_ref(), gateway.as_ref(), &platform).ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "No {} channel is configured; add it in the setup wizard first",
+                    platform
+                ))
+            })?;
+        let (allowed_users, changed) = Self::apply(current, action, &user_id);
+
+        if changed {
+            ConfigSetter::set(
+                &format!("channels.{}.allowFrom", platform),
+                &json!(allowed_users),
+            )?;
+            if let (Some(path), Some(config)) = (wizard_path.as_deref(), wizard.as_mut())
+                && Self::set_wizard_list(config, &platform, &allowed_users)
+            {
+                Self::write(path, config)?;
+            }
+            info!(
+                "{} allowlist: {:?} {} ({} users)",
+                platform,
+                action,
+                user_id,
+                allowed_users.len()
+            );
+        }
+
+        Ok(ChannelAllowlistResponse {
+            platform,
+            allowed_users,
+            changed,
+        })
+    }
+
+    fn parse_action(action: &str) -> Result<Action, AppError> {
+        match action.to_lowercase().as_str() {
+            "add" => Ok(Action::Add),
+            "remove" => Ok(Action::Remove),
+            other => Err(AppError::BadRequest(format!(
+                "Unknown allowlist action {:?}; expected \"add\" or \"remove\"",
+                other
+            ))),
+        }
+    }
+
+    /// The trimmed user ID, if it fits the platform's format
+    fn validate_user_id(platform: &str, user_id: &str) -> Result<String, AppError> {
+        let (_, pattern) = ID_PATTERNS
+            .iter()
+            .find(|(name, _)| *name == platform)
+            .ok_or_else(|| {
+                let known: Vec<&str> = PLATFORM_ID_FORMATS.iter().map(|(p, _, _)| *p).collect();
+                AppError::BadRequest(format!(
+                    "Unsupported platform: {}; expected one of {}",
+                    platform,
+                    known.join(", ")
+                ))
+            })?;
+
+        let user_id = user_id.trim();
+        if !pattern.is_match(user_id) {
+            let expected = PLATFORM_ID_FORMATS
+                .iter()
+                .find(|(name, _, _)| *name == platform)
+                .map(|(_, _, expected)| *expected)
+                .unwrap_or("a valid user ID");
+            return Err(AppError::BadRequest(format!(
+                "Invalid {} user ID {:?}: expected {}",
+                platform, user_id, expected
+            )));
+        }
+        Ok(user_id.to_string())
+    }
+
+    /// Current allowlist: the wizard config's channel entry, else the
+    /// gateway's `channels.<platform>.allowFrom`. None if neither has the channel.
+    fn current_list(
+        wizard: Option<&Value>,
+        gateway: Option<&Value>,
+        platform: &str,
+    ) -> Option<Vec<String>> {
+        let strings = |list: Option<&Value>| -> Vec<String> {
+            list.and_then(Value::as_array)
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter_map(|item| item.as_str().map(String::from))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        let wizard_channel = wizard
+            .and_then(|config| config.get("channels"))
+            .and_then(Value::as_array)
+            .and_then(|channels| {
+                channels.iter().find(|channel| {
+                    channel
+                        .get("platform")
+                        .and_then(Value::as_str)
+                        .is_some_and(|p| p.eq_ignore_ascii_case(platform))
+                })
+            });
+        if let Some(channel) = wizard_channel {
+            return Some(strings(channel.get("allowed_users")));
+        }
+
+        gateway
+            .and_then(|config| config.get("channels"))
+            .and_then(|channels| channels.get(platform))
+            .map(|channel| strings(channel.get("allowFrom")))
+    }
+
+    /// The list after the action, and whether it changed
+    fn apply(mut list: Vec<String>, action: Action, user_id: &str) -> (Vec<String>, bool) {
+        let present = list.iter().any(|existing| existing == user_id);
+        match action {
+            Action::Add if !present => {
+                list.push(user_id.to_string());
+                (list, true)
+            }
+            Action::Remove if present => {
+                list.retain(|existing| existing != user_id);
+                (list, true)
+            }
+            _ => (list, false),
+        }
+    }
+
+    /// Replace the channel's `allowed_users` in a raw wizard config,
+    /// returning whether the channel was found
+    fn set_wizard_list(config: &mut Value, platform: &str, list: &[String]) -> bool {
+        let channel = config
+            .get_mut("channels")
+            .and_then(Value::as_array_mut)
+            .and_then(|channels| {
+                channels.iter_mut().find(|channel| {
+                    channel
+                        .get("platform")
+                        .and_then(Value::as_str)
+                        .is_some_and(|p| p.eq_ignore_ascii_case(platform))
+                })
+            });
+        match channel.and_then(Value::as_object_mut) {
+            Some(channel) => {
+                channel.insert("allowed_users".to_string(), json!(list));
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn write(path: &Path, config: &Value) -> Result<(), AppError> {
+        ConfigWriter::write_json(path, config).map_err(|e| {
+            AppError::InternalError(format!("Failed to write {}: {}", path.display(), e))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_user_id_per_platform() {
+        assert!(ChannelAllowlist::validate_user_id("telegram", "123456789").is_ok());
+        assert!(ChannelAllowlist::validate_user_id("telegram", "@alice_bot").is_ok());
+        assert!(ChannelAllowlist::validate_user_id("discord", "80351110224678912").is_ok());
+        assert!(ChannelAllowlist::validate_user_id("slack", "U0123ABCD").is_ok());
+        assert_eq!(
+            ChannelAllowlist::validate_user_id("whatsapp", " +15551234567 ").unwrap(),
+            "+15551234567"
+        );
+
+        assert!(ChannelAllowlist::validate_user_id("discord", "alice").is_err());
+        assert!(ChannelAllowlist::validate_user_id("slack", "u0123abcd").is_err());
+        assert!(ChannelAllowlist::validate_user_id("whatsapp", "5551234567").is_err());
+        let err = ChannelAllowlist::validate_user_id("irc", "alice").unwrap_err();
+        assert!(matches!(err, AppError::BadRequest(msg) if msg.contains("Unsupported platform")));
+    }
+
+    #[test]
+    fn test_parse_action() {
+        assert_eq!(ChannelAllowlist::parse_action("add").unwrap(), Action::Add);
+        assert_eq!(
+            ChannelAllowlist::parse_action("REMOVE").unwrap(),
+            Action::Remove
+        );
+        assert!(ChannelAllowlist::parse_action("toggle").is_err());
+    }
+
+    #[test]
+    fn test_apply_is_idempotent() {
+        let list = vec!["1".to_string()];
+        let (added, changed) = ChannelAllowlist::apply(list.clone(), Action::Add, "2");
+        assert_eq!(
+            (added.as_slice(), changed),
+            (&["1".to_string(), "2".to_string()][..], true)
+        );
+        assert!(!ChannelAllowlist::apply(list.clone(), Action::Add, "1").1);
+
+        let (removed, changed) = ChannelAllowlist::apply(list.clone(), Action::Remove, "1");
+        assert!(removed.is_empty() && changed);
+        assert!(!ChannelAllowlist::apply(list, Action::Remove, "3").1);
+    }
+
+    #[test]
+    fn test_current_list_prefers_wizard_config() {
+        let wizard = json!({
+            "channels": [{ "platform": "Telegram", "allowed_users": ["111111"] }]
+        });
+        let gateway = json!({
+            "channels": {
+                "telegram": { "allowFrom": ["222222"] },
+                "discord": { "allowFrom": ["80351110224678912"] }
+            }
+        });
+        assert_eq!(
+            ChannelAllowlist::current_list(Some(&wizard), Some(&gateway), "telegram"),
+            Some(vec!["111111".to_string()])
+        );
+        assert_eq!(
+            ChannelAllowlist::current_list(Some(&wizard), Some(&gateway), "discord"),
+            Some(vec!["80351110224678912".to_string()])
+        );
+        assert_eq!(
+            ChannelAllowlist::current_list(Some(&wizard), Some(&gateway), "slack"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_set_wizard_list_keeps_other_fields() {
+        let mut config = json!({
+            "api_key": "${OPENCLAW_API_KEY}",
+            "channels": [{ "platform": "slack", "bot_token": "${SLACK}", "allowed_users": [] }]
+        });
+        let list = vec!["U0123ABCD".to_string()];
+        assert!(ChannelAllowlist::set_wizard_list(
+            &mut config,
+            "slack",
+            &list
+        ));
+        assert_eq!(config["channels"][0]["allowed_users"], json!(["U0123ABCD"]));
+        assert_eq!(config["channels"][0]["bot_token"], "${SLACK}");
+        assert_eq!(config["api_key"], "${OPENCLAW_API_KEY}");
+        assert!(!ChannelAllowlist::set_wizard_list(
+            &mut config,
+            "discord",
+            &list
+        ));
+    }
+}
@@ -5,14 +5,31 @@
 //! This prevents shell injection attacks.
 
 use anyhow::{Context, Result};
-use std::process::Command;
+use std::process::{Command, ExitStatus};
+
+/// Environment that turns off colored output in Node CLIs (chalk, npm,
+/// openclaw), so parsed output doesn't carry escape codes
+pub const NO_COLOR_ENV: [(&str, &str); 2] = [("NO_COLOR", "1"), ("FORCE_COLOR", "0")];
 
 /// Output from a command execution
 #[derive(Debug, Clone)]
 pub struct CommandOutput {
     pub stdout: String,
     pub stderr: String,
+    /// Exit code, or -1 when the process was terminated by a signal
     pub exit_code: i32,
+    /// Signal that terminated the process (Unix only)
+    pub signal: Option<i32>,
+}
+
+impl CommandOutput {
+    /// "exit code N" or "killed by SIGKILL (likely out of memory)"
+    pub fn describe_exit(&self) -> String {
+        exit_description(
+            Some(self.exit_code).filter(|_| self.signal.is_none()),
+            self.signal,
+        )
+    }
 }
 
 /// Safe command executor that prevents shell injection
@@ -30,6 +47,7 @@ impl SafeCommand {
 
         let output = Command::new(program)
             .args(args)
+            .envs(NO_COLOR_ENV)
             .output()
             .with_context(|| format!("Failed to execute command: {}", program))?;
 
@@ -37,6 +55,7 @@ impl SafeCommand {
             stdout: String::from_utf8_lossy(&output.stdout).to_string(),
             stderr: String::from_utf8_lossy(&output.stderr).to_string(),
             exit_code: output.status.code().unwrap_or(-1),
+            signal: exit_signal(&output.status),
         })
     }
 
@@ -49,9 +68,9 @@ impl SafeCommand {
 
         if output.exit_code != 0 {
             anyhow::bail!(
-                "Command '{}' failed with exit code {}: {}",
+                "Command '{}' failed with {}: {}",
                 program,
-                output.exit_code,
+                output.describe_exit(),
                 output.stderr.trim()
             );
         }
@@ -94,6 +113,58 @@ impl SafeCommand {
     }
 }
 
+/// Signal that terminated the process, if it didn't exit normally
+#[cfg(unix)]
+pub fn exit_signal(status: &ExitStatus) -> Option<i32> {
+    use std::os::unix::process::ExitStatusExt;
+    status.signal()
+}
+
+/// Signal that terminated the process, if it didn't exit normally
+#[cfg(not(unix))]
+pub fn exit_signal(_status: &ExitStatus) -> Option<i32> {
+    None
+}
+
+/// Describe how a child process ended, for error messages
+pub fn describe_status(status: &ExitStatus) -> String {
+    exit_description(status.code(), exit_signal(status))
+}
+
+/// A signal termination wins over the exit code; SIGKILL is almost always
+/// the kernel OOM killer on the small VPSes installs run on
+fn exit_description(code: Option<i32>, signal: Option<i32>) -> String {
+    match (signal, code) {
+        (Some(9), _) => "killed by SIGKILL (likely out of memory)".to_string(),
+        (Some(15), _) => "terminated by SIGTERM".to_string(),
+        (Some(2), _) => "interrupted by SIGINT".to_string(),
+        (Some(11), _) => "crashed with SIGSEGV".to_string(),
+        (Some(sig), _) => format!("killed by signal {}", sig),
+        (None, Some(code)) => format!("exit code {}", code),
+        (None, None) => "unknown exit status".to_string(),
+    }
+}
+
+/// Strip ANSI escape sequences from text
+pub fn strip_ansi(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch == '\x1b' {
+            // Skip until we hit a letter (end of escape sequence)
+            while let Some(&next) = chars.peek() {
+                chars.next();
+                if next.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            result.push(ch);
+        }
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,6 +199,42 @@ mod tests {
         assert!(err.to_string().contains("failed") || err.to_string().contains("exit code"));
     }
 
+    #[test]
+    fn test_exit_description_prefers_signal() {
+        assert_eq!(exit_description(Some(1), None), "exit code 1");
+        assert_eq!(
+            exit_description(None, Some(9)),
+            "killed by SIGKILL (likely out of memory)"
+        );
+        assert_eq!(exit_description(None, Some(6)), "killed by signal 6");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_captures_signal() {
+        let output = SafeCommand::run("sh", &["-c", "kill -9 $$"]).unwrap();
+        assert_eq!(output.signal, Some(9));
+        assert_eq!(output.exit_code, -1);
+        assert!(output.describe_exit().contains("out of memory"));
+
+        let output = SafeCommand::run("sh", &["-c", "exit 3"]).unwrap();
+        assert_eq!(output.signal, None);
+        assert_eq!(output.describe_exit(), "exit code 3");
+    }
+
+    #[test]
+    fn test_strip_ansi() {
+        let text = "\x1b[32mPASS\x1b[0m: Node installed";
+        assert_eq!(strip_ansi(text), "PASS: Node installed");
+        assert_eq!(strip_ansi("plain"), "plain");
+    }
+
+    #[test]
+    fn test_run_disables_color() {
+        let output = SafeCommand::run("sh", &["-c", "echo $NO_COLOR$FORCE_COLOR"]).unwrap();
+        assert_eq!(output.stdout.trim(), "10");
+    }
+
     #[test]
     fn test_check_node_version() {
         let result = SafeCommand::check_node_version();
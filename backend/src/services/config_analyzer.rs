@@ -2,11 +2,14 @@
 //!
 //! Uses the user's configured AI provider (Anthropic or OpenAI) to analyze
 //! their OpenClaw configuration for cost optimization opportunities.
-//! Reuses LogAnalyzer's secret redaction before sending config to external APIs.
+//! Secrets are redacted (`services::redact`) before the config is sent to external APIs.
 
-use crate::models::types::{CostAnalysis, LlmModelPricing, LlmPricingResponse};
+use crate::models::types::{CostAnalysis, CostHistoryEntry, LlmModelPricing, LlmPricingResponse};
 use crate::services::config::ConfigWriter;
 use crate::services::log_analyzer::LogAnalyzer;
+use crate::services::offline::OfflineMode;
+use crate::services::platform::Platform;
+use crate::services::redact::redact_value;
 use anyhow::Result;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -18,9 +21,30 @@ const RATE_LIMIT_SECONDS: u64 = 10;
 /// Global rate limiter: tracks last cost analysis request timestamp
 static LAST_COST_ANALYSIS: AtomicU64 = AtomicU64::new(0);
 
+/// Cost analysis history file, relative to ~/.openclaw
+const COST_HISTORY_FILE: &str = "cost-history.json";
+
+/// Maximum number of analyses kept in the history file
+const MAX_COST_HISTORY: usize = 365;
+
+/// Monthly usage assumed when costing recommendations (millions of tokens)
+const ASSUMED_INPUT_MILLIONS: f64 = 1.0;
+const ASSUMED_OUTPUT_MILLIONS: f64 = 0.5;
+
+/// Summary prefix of the fallback analysis returned for unparseable AI output
+const UNPARSEABLE_SUMMARY: &str = "Unable to parse AI response.";
+
+/// Default analysis model for Anthropic
+pub const DEFAULT_ANTHROPIC_MODEL: &str = "claude-sonnet-4-20250514";
+
+/// Default analysis model for OpenAI
+pub const DEFAULT_OPENAI_MODEL: &str = "gpt-4o-mini";
+
 pub struct ConfigAnalyzer {
     provider: String,
     api_key: String,
+    /// Analysis model override; None uses the provider default
+    model: Option<String>,
     http_client: reqwest::Client,
 }
 
@@ -28,12 +52,13 @@ impl ConfigAnalyzer {
     /// Create a ConfigAnalyzer from the user's saved openclaw.json config
     ///
     /// Reads from the wizard's config which contains the provider and API key.
-    /// Returns None if no AI provider is configured.
+    /// Returns None if no AI provider is configured or in offline mode.
     pub fn from_config() -> Option<Self> {
-        use crate::services::platform::Platform;
-
+        if OfflineMode::is_enabled() {
+            return None;
+        }
         // Try wizard config first (has provider + API key)
-        let wizard_config_path = Platform::config_dir().ok()?.join("openclaw.json");
+        let wizard_config_path = Platform::wizard_config_path().ok()?;
 
         if let Ok(config) = ConfigWriter::read_json(&wizard_config_path)
             && let Some((provider, api_key)) = Self::extract_ai_config(&config)
@@ -43,13 +68,13 @@ impl ConfigAnalyzer {
             return Some(Self {
                 provider,
                 api_key,
+                model: configured_analysis_model(),
                 http_client: reqwest::Client::new(),
             });
         }
 
         // Fallback to OpenClaw config (for backwards compatibility)
-        let home = std::env::var("HOME").ok()?;
-        let openclaw_config_path = PathBuf::from(format!("{}/.openclaw/openclaw.json", home));
+        let openclaw_config_path = Platform::openclaw_config_path().ok()?;
 
         let config: serde_json::Value = ConfigWriter::read_json(&openclaw_config_path).ok()?;
         let (provider, api_key) = Self::extract_ai_config(&config)?;
@@ -61,10 +86,47 @@ impl ConfigAnalyzer {
         Some(Self {
             provider,
             api_key,
+            model: configured_analysis_model(),
             http_client: reqwest::Client::new(),
         })
     }
 
+    /// Resolve the model to use for an analysis request
+    ///
+    /// Uses the requested model if given, otherwise the provider default.
+    /// A model that appears in the pricing list under a different provider
+    /// is rejected; models missing from the list are allowed since the list
+    /// only covers the common ones.
+    pub fn resolve_model(
+        provider: &str,
+        requested: Option<&str>,
+    ) -> std::result::Result<String, String> {
+        let provider_lower = provider.to_lowercase();
+        let requested = requested.map(str::trim).filter(|m| !m.is_empty());
+
+        let Some(model) = requested.or(match provider_lower.as_str() {
+            "anthropic" => Some(DEFAULT_ANTHROPIC_MODEL),
+            "openai" => Some(DEFAULT_OPENAI_MODEL),
+            _ => None,
+        }) else {
+            return Err(format!("Unsupported AI provider: {}", provider));
+        };
+
+        if let Some(known) = Self::get_pricing()
+            .models
+            .into_iter()
+            .find(|m| m.model == model)
+            && known.provider.to_lowercase() != provider_lower
+        {
+            return Err(format!(
+                "Model '{}' is a {} model and cannot be used with the {} provider",
+                model, known.provider, provider
+            ));
+        }
+
+        Ok(model.to_string())
+    }
+
     /// Analyze configuration for cost optimization opportunities
     ///
     /// Redacts secrets before sending to AI provider. Rate-limited to
@@ -87,9 +149,8 @@ impl ConfigAnalyzer {
         // Update last request timestamp
         LAST_COST_ANALYSIS.store(now, Ordering::Relaxed);
 
-        // Redact secrets using LogAnalyzer's shared method
-        let config_str = serde_json::to_string_pretty(config).unwrap_or_else(|_| "{}".to_string());
-        let redacted = LogAnalyzer::redact_secrets(&config_str);
+        let redacted = serde_json::to_string_pretty(&redact_value(config))
+            .unwrap_or_else(|_| "{}".to_string());
 
         // Get pricing reference
         let pricing = Self::get_pricing();
@@ -103,7 +164,7 @@ impl ConfigAnalyzer {
              Available LLM pricing (per million tokens):\n```json\n{}\n```\n\n\
              For each recommendation, provide:\n\
              - current_model: the model currently in use\n\
-             - recommended_model: a cheaper alternative\n\
+             - recommended_model: a cheaper alternative, which MUST be one of the models in the pricing list above\n\
              - current_cost_monthly: estimated monthly cost (assume 1M input + 500K output tokens/month)\n\
              - recommended_cost_monthly: estimated cost with the alternative\n\
              - savings_monthly: dollar savings per month\n\
@@ -117,14 +178,87 @@ impl ConfigAnalyzer {
             redacted, pricing_str
         );
 
-        match self.provider.to_lowercase().as_str() {
-            "anthropic" => self.call_anthropic(&prompt).await,
-            "openai" => self.call_openai(&prompt).await,
+        let model = Self::resolve_model(&self.provider, self.model.as_deref())
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        let analysis = match self.provider.to_lowercase().as_str() {
+            "anthropic" => self.call_anthropic(&prompt, &model).await,
+            "openai" => self.call_openai(&prompt, &model).await,
             _ => Err(anyhow::anyhow!(
                 "Unsupported AI provider: {}",
                 self.provider
             )),
+        }?;
+
+        Ok(Self::ground_recommendations(analysis, &pricing.models))
+    }
+
+    /// Keep recommendations grounded in the pricing list
+    ///
+    /// Drops recommendations whose `recommended_model` isn't a known model,
+    /// recomputes costs from list prices where both models are known, drops
+    /// any that no longer save money, and recomputes the totals. A note about
+    /// discarded recommendations is appended to the summary.
+    pub fn ground_recommendations(
+        mut analysis: CostAnalysis,
+        pricing: &[LlmModelPricing],
+    ) -> CostAnalysis {
+        let find = |name: &str| pricing.iter().find(|m| m.model.eq_ignore_ascii_case(name));
+        let monthly = |m: &LlmModelPricing| {
+            m.input_per_million * ASSUMED_INPUT_MILLIONS
+                + m.output_per_million * ASSUMED_OUTPUT_MILLIONS
+        };
+
+        let before = analysis.recommendations.len();
+        analysis.recommendations.retain_mut(|rec| {
+            let Some(recommended) = find(&rec.recommended_model) else {
+                return false;
+            };
+            rec.recommended_model = recommended.model.clone();
+            rec.recommended_cost_monthly = monthly(recommended);
+            if let Some(current) = find(&rec.current_model) {
+                rec.current_model = current.model.clone();
+                rec.current_cost_monthly = monthly(current);
+            }
+            rec.savings_monthly = rec.current_cost_monthly - rec.recommended_cost_monthly;
+            rec.savings_percent = if rec.current_cost_monthly > 0.0 {
+                rec.savings_monthly / rec.current_cost_monthly * 100.0
+            } else {
+                0.0
+            };
+            rec.savings_monthly > 0.0
+        });
+
+        if analysis.recommendations.is_empty() && before == 0 {
+            return analysis;
+        }
+
+        analysis.total_current_monthly = analysis
+            .recommendations
+            .iter()
+            .map(|r| r.current_cost_monthly)
+            .sum();
+        analysis.total_recommended_monthly = analysis
+            .recommendations
+            .iter()
+            .map(|r| r.recommended_cost_monthly)
+            .sum();
+        analysis.total_savings_monthly =
+            analysis.total_current_monthly - analysis.total_recommended_monthly;
+
+        let dropped = before - analysis.recommendations.len();
+        if dropped > 0 {
+            let note = format!(
+                "{} recommendation(s) referencing unknown models or without savings were discarded.",
+                dropped
+            );
+            analysis.summary = Some(match analysis.summary.take() {
+                Some(summary) if !summary.is_empty() => format!("{} {}", summary, note),
+                _ => note,
+            });
         }
+
+        analysis
     }
 
     /// Return static LLM pricing data for reference
@@ -193,9 +327,9 @@ impl ConfigAnalyzer {
     }
 
     /// Call Anthropic Messages API for cost analysis
-    async fn call_anthropic(&self, prompt: &str) -> Result<CostAnalysis> {
+    async fn call_anthropic(&self, prompt: &str, model: &str) -> Result<CostAnalysis> {
         let body = serde_json::json!({
-            "model": "claude-sonnet-4-20250514",
+            "model": model,
             "max_tokens": 2048,
             "messages": [{
                 "role": "user",
@@ -213,6 +347,12 @@ impl ConfigAnalyzer {
             .send()
             .await?;
 
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(LogAnalyzer::provider_error("Anthropic", status, &body).into());
+        }
+
         let response_body: serde_json::Value = response.json().await?;
 
         let text = response_body["content"][0]["text"]
@@ -223,9 +363,9 @@ impl ConfigAnalyzer {
     }
 
     /// Call OpenAI Chat Completions API for cost analysis
-    async fn call_openai(&self, prompt: &str) -> Result<CostAnalysis> {
+    async fn call_openai(&self, prompt: &str, model: &str) -> Result<CostAnalysis> {
         let body = serde_json::json!({
-            "model": "gpt-4o-mini",
+            "model": model,
             "max_tokens": 2048,
             "messages": [{
                 "role": "user",
@@ -242,6 +382,12 @@ impl ConfigAnalyzer {
             .send()
             .await?;
 
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(LogAnalyzer::provider_error("OpenAI", status, &body).into());
+        }
+
         let response_body: serde_json::Value = response.json().await?;
 
         let text = response_body["choices"][0]["message"]["content"]
@@ -279,7 +425,8 @@ impl ConfigAnalyzer {
                     total_savings_monthly: 0.0,
                     analysis_date: now,
                     summary: Some(format!(
-                        "Unable to parse AI response. Raw response: {}",
+                        "{} Raw response: {}",
+                        UNPARSEABLE_SUMMARY,
                         text.chars().take(200).collect::<String>()
                     )),
                 })
@@ -287,6 +434,67 @@ impl ConfigAnalyzer {
         }
     }
 
+    /// Load the recorded cost analyses from ~/.openclaw/cost-history.json
+    ///
+    /// Returns an empty list if no analysis has been recorded yet.
+    pub fn load_cost_history() -> Result<Vec<CostHistoryEntry>> {
+        let path = Self::cost_history_path()?;
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        ConfigWriter::read_json(&path)
+    }
+
+    /// Append an analysis to the cost history, keeping the newest entries
+    ///
+    /// Fallback analyses (unparseable AI output) are skipped so they don't
+    /// show up as a drop to zero on the chart. Analyses whose recommendations
+    /// were all discarded by `ground_recommendations` are still recorded.
+    pub fn record_cost_analysis(analysis: &CostAnalysis) -> Result<()> {
+        let Some(entry) = Self::history_entry(analysis, chrono::Utc::now().timestamp() as u64)
+        else {
+            return Ok(());
+        };
+
+        let mut history = Self::load_cost_history()?;
+        history.push(entry);
+        let excess = history.len().saturating_sub(MAX_COST_HISTORY);
+        history.drain(..excess);
+
+        ConfigWriter::write_json(&Self::cost_history_path()?, &history)
+    }
+
+    /// Summarize an analysis as a history entry; None for fallback results
+    fn history_entry(analysis: &CostAnalysis, recorded_at: u64) -> Option<CostHistoryEntry> {
+        let unparseable = analysis
+            .summary
+            .as_deref()
+            .is_some_and(|s| s.starts_with(UNPARSEABLE_SUMMARY));
+        if unparseable && analysis.recommendations.is_empty() {
+            return None;
+        }
+
+        Some(CostHistoryEntry {
+            analysis_date: analysis.analysis_date.clone(),
+            recorded_at,
+            total_current_monthly: analysis.total_current_monthly,
+            total_recommended_monthly: analysis.total_recommended_monthly,
+            total_savings_monthly: analysis.total_savings_monthly,
+            recommendation_count: analysis.recommendations.len() as u32,
+        })
+    }
+
+    /// Get the path to ~/.openclaw/cost-history.json
+    fn cost_history_path() -> Result<PathBuf> {
+        Self::cost_history_path_in(Platform::openclaw_dir())
+    }
+
+    /// The cost history path inside a resolved `~/.openclaw`, failing when
+    /// the directory couldn't be resolved
+    fn cost_history_path_in(openclaw_dir: Result<PathBuf>) -> Result<PathBuf> {
+        Ok(openclaw_dir?.join(COST_HISTORY_FILE))
+    }
+
     /// Extract AI provider and API key from config (same pattern as LogAnalyzer)
     fn extract_ai_config(config: &serde_json::Value) -> Option<(String, String)> {
         // Pattern 1: ai.provider / ai.apiKey
@@ -335,17 +543,149 @@ impl ConfigAnalyzer {
 
         None
     }
+}
 
-    /// Reset rate limiter (for testing)
-    #[cfg(test)]
-    pub fn reset_rate_limit() {
-        LAST_COST_ANALYSIS.store(0, Ordering::Relaxed);
-    }
+/// Read the `analysis_model` override from the wizard's saved config
+pub fn configured_analysis_model() -> Option<String> {
+    let path = Platform::wizard_config_path().ok()?;
+    let config: serde_json::Value = ConfigWriter::read_json(&path).ok()?;
+    config
+        .get("analysis_model")
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::types::CostRecommendation;
+
+    fn recommendation(current: &str, recommended: &str) -> CostRecommendation {
+        CostRecommendation {
+            current_model: current.to_string(),
+            recommended_model: recommended.to_string(),
+            current_cost_monthly: 100.0,
+            recommended_cost_monthly: 1.0,
+            savings_monthly: 99.0,
+            savings_percent: 99.0,
+            use_case: "chat".to_string(),
+            rationale: "cheaper".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_ground_recommendations_drops_unknown_models() {
+        let pricing = ConfigAnalyzer::get_pricing().models;
+        let analysis = CostAnalysis {
+            recommendations: vec![
+                recommendation("gpt-4o", "GPT-4o-Mini"),
+                recommendation("gpt-4o", "gpt-5-nano-turbo"),
+            ],
+            total_current_monthly: 200.0,
+            total_recommended_monthly: 2.0,
+            total_savings_monthly: 198.0,
+            analysis_date: "2026-03-01".to_string(),
+            summary: Some("Switch models.".to_string()),
+        };
+
+        let grounded = ConfigAnalyzer::ground_recommendations(analysis, &pricing);
+        assert_eq!(grounded.recommendations.len(), 1);
+
+        let rec = &grounded.recommendations[0];
+        assert_eq!(rec.recommended_model, "gpt-4o-mini");
+        // gpt-4o: 2.50 + 10.0 * 0.5; gpt-4o-mini: 0.15 + 0.60 * 0.5
+        assert!((rec.current_cost_monthly - 7.5).abs() < 1e-9);
+        assert!((rec.recommended_cost_monthly - 0.45).abs() < 1e-9);
+        assert!((grounded.total_savings_monthly - 7.05).abs() < 1e-9);
+        assert!(grounded.summary.unwrap().contains("1 recommendation(s)"));
+    }
+
+    #[test]
+    fn test_ground_recommendations_drops_non_savings() {
+        let pricing = ConfigAnalyzer::get_pricing().models;
+        let analysis = CostAnalysis {
+            recommendations: vec![recommendation("gpt-4o-mini", "claude-sonnet-4-20250514")],
+            total_current_monthly: 100.0,
+            total_recommended_monthly: 1.0,
+            total_savings_monthly: 99.0,
+            analysis_date: "2026-03-01".to_string(),
+            summary: None,
+        };
+
+        let grounded = ConfigAnalyzer::ground_recommendations(analysis, &pricing);
+        assert!(grounded.recommendations.is_empty());
+        assert_eq!(grounded.total_savings_monthly, 0.0);
+        assert!(grounded.summary.is_some());
+
+        // The run is still recorded, with nothing recommended
+        let entry = ConfigAnalyzer::history_entry(&grounded, 0).unwrap();
+        assert_eq!(entry.recommendation_count, 0);
+        assert_eq!(entry.total_savings_monthly, 0.0);
+    }
+
+    #[test]
+    fn test_history_entry_summarizes_analysis() {
+        let analysis = ConfigAnalyzer::parse_cost_response(
+            r#"{"recommendations": [], "total_current_monthly": 12.5,
+                "total_recommended_monthly": 4.0, "total_savings_monthly": 8.5,
+                "analysis_date": "2026-03-01", "summary": null}"#,
+        )
+        .unwrap();
+
+        let entry = ConfigAnalyzer::history_entry(&analysis, 1_700_000_000).unwrap();
+        assert_eq!(entry.analysis_date, "2026-03-01");
+        assert_eq!(entry.recorded_at, 1_700_000_000);
+        assert_eq!(entry.total_savings_monthly, 8.5);
+        assert_eq!(entry.recommendation_count, 0);
+    }
+
+    #[test]
+    fn test_cost_history_path_without_home() {
+        let openclaw_dir = Platform::home_dir_from(None, None).map(|h| h.join(".openclaw"));
+        let err = ConfigAnalyzer::cost_history_path_in(openclaw_dir).unwrap_err();
+        assert!(err.to_string().contains("HOME"));
+
+        let path = ConfigAnalyzer::cost_history_path_in(Ok(PathBuf::from("/home/me/.openclaw")));
+        assert_eq!(
+            path.unwrap(),
+            PathBuf::from("/home/me/.openclaw/cost-history.json")
+        );
+    }
+
+    #[test]
+    fn test_history_entry_skips_fallback_analysis() {
+        let analysis = ConfigAnalyzer::parse_cost_response("not json").unwrap();
+        assert!(ConfigAnalyzer::history_entry(&analysis, 0).is_none());
+    }
+
+    #[test]
+    fn test_resolve_model_defaults_per_provider() {
+        assert_eq!(
+            ConfigAnalyzer::resolve_model("anthropic", None).unwrap(),
+            DEFAULT_ANTHROPIC_MODEL
+        );
+        assert_eq!(
+            ConfigAnalyzer::resolve_model("OpenAI", Some("  ")).unwrap(),
+            DEFAULT_OPENAI_MODEL
+        );
+        assert!(ConfigAnalyzer::resolve_model("mistral", None).is_err());
+    }
+
+    #[test]
+    fn test_resolve_model_validates_against_pricing() {
+        assert_eq!(
+            ConfigAnalyzer::resolve_model("openai", Some("gpt-4o")).unwrap(),
+            "gpt-4o"
+        );
+        let err = ConfigAnalyzer::resolve_model("anthropic", Some("gpt-4o")).unwrap_err();
+        assert!(err.contains("OpenAI"));
+        // Models missing from the pricing list are passed through
+        assert_eq!(
+            ConfigAnalyzer::resolve_model("anthropic", Some("claude-opus-4-1")).unwrap(),
+            "claude-opus-4-1"
+        );
+    }
 
     #[test]
     fn test_get_pricing_has_models() {
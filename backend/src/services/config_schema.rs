@@ -0,0 +1,207 @@
+//! # Wizard Config Schema
+//!
+//! Hand-maintained JSON Schema (draft 2020-12) for `WizardConfig` and
+//! `ChannelConfig`, served to the frontend so it validates with the same
+//! rules the server deserializes with. The tests check the property and
+//! `required` lists against the structs, so a new field fails the tests
+//! until it is added here.
+
+use serde_json::{Value, json};
+
+/// `auth_type` values understood by the provider mapping
+const AUTH_TYPES: &[&str] = &["api-key", "setup-token", "oauth", "skip"];
+
+/// `compatibility` values for the Custom provider
+const COMPATIBILITY_MODES: &[&str] = &["openai", "anthropic"];
+
+/// JSON Schema builder for the wizard config
+pub struct ConfigSchema;
+
+impl ConfigSchema {
+    /// Schema for the body of `POST /api/wizard/save-config`
+    pub fn wizard_config() -> Value {
+        let compatibility: Vec<Value> = COMPATIBILITY_MODES
+            .iter()
+            .map(|mode| json!(mode))
+            .chain([Value::Null])
+            .collect();
+
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "WizardConfig",
+            "type": "object",
+            "required": ["provider", "auth_type", "gateway_port", "gateway_bind", "auth_mode"],
+            "properties": {
+                "schema_version": {
+                    "type": ["integer", "null"],
+                    "minimum": 0,
+                    "description": "Saved config layout version; older files are migrated on read"
+                },
+                "provider": { "type": "string", "minLength": 1 },
+                "api_key": { "type": "string", "default": "" },
+                "auth_type": { "type": "string", "enum": AUTH_TYPES },
+                "gateway_port": { "type": "integer", "minimum": 1, "maximum": 65535 },
+                "gateway_bind": {
+                    "type": "string",
+                    "minLength": 1,
+                    "description": "127.0.0.1/localhost/loopback, 0.0.0.0/all, or an address"
+                },
+                "auth_mode": {
+                    "type": "string",
+                    "minLength": 1,
+                    "description": "\"token\" generates a credential when none is given"
+                },
+                "auth_credential": { "type": ["string", "null"] },
+                "channels": {
+                    "type": ["array", "null"],
+                    "items": { "$ref": "#/$defs/ChannelConfig" }
+                },
+                "base_url": { "type": ["string", "null"] },
+                "model_id": { "type": ["string", "null"] },
+                "compatibility": {
+                    "enum": compatibility
+                },
+                "account_id": {
+                    "type": ["string", "null"],
+                    "description": "Cloudflare account ID"
+                },
+                "gateway_id": {
+                    "type": ["string", "null"],
+                    "description": "Cloudflare gateway ID"
+                },
+                "log_dir": { "type": ["string", "null"] },
+                "analysis_model": { "type": ["string", "null"] }
+            },
+            "$defs": {
+                "ChannelConfig": Self::channel_config()
+            }
+        })
+    }
+
+    /// Schema for one entry of `WizardConfig.channels`
+    fn channel_config() -> Value {
+        json!({
+            "type": "object",
+            "required": ["platform", "enabled", "dm_policy", "allowed_users"],
+            "properties": {
+                "platform": {
+                    "type": "string",
+                    "examples": ["whatsapp", "telegram", "discord", "slack"]
+                },
+                "enabled": { "type": "boolean" },
+                "bot_token": { "type": ["string", "null"] },
+                "app_token": {
+                    "type": ["string", "null"],
+                    "description": "Slack only"
+                },
+                "dm_policy": { "type": "string", "default": "allowlist" },
+                "allowed_users": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "User IDs or phone numbers"
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::WizardConfig;
+    use crate::models::types::ChannelConfig;
+    use std::collections::BTreeSet;
+
+    fn full_channel() -> Value {
+        json!({
+            "platform": "slack",
+            "enabled": true,
+            "bot_token": "xoxb-1",
+            "app_token": "xapp-1",
+            "dm_policy": "allowlist",
+            "allowed_users": ["U1"],
+        })
+    }
+
+    fn full_config() -> Value {
+        json!({
+            "schema_version": 1,
+            "provider": "custom",
+            "api_key": "sk-test",
+            "auth_type": "api-key",
+            "gateway_port": 18789,
+            "gateway_bind": "127.0.0.1",
+            "auth_mode": "token",
+            "auth_credential": "t0k",
+            "channels": [full_channel()],
+            "base_url": "http://localhost:8000/v1",
+            "model_id": "llama",
+            "compatibility": "openai",
+            "account_id": "acct",
+            "gateway_id": "gw",
+            "log_dir": "/var/log/openclaw",
+            "analysis_model": "gpt-4o-mini",
+        })
+    }
+
+    fn keys(value: &Value) -> BTreeSet<String> {
+        value.as_object().unwrap().keys().cloned().collect()
+    }
+
+    fn required(schema: &Value) -> BTreeSet<String> {
+        schema["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_str().unwrap().to_string())
+            .collect()
+    }
+
+    /// Fields serde can't do without, found by dropping each one in turn
+    fn mandatory_fields<T: serde::de::DeserializeOwned>(full: &Value) -> BTreeSet<String> {
+        keys(full)
+            .into_iter()
+            .filter(|key| {
+                let mut partial = full.clone();
+                partial.as_object_mut().unwrap().remove(key);
+                serde_json::from_value::<T>(partial).is_err()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_schema_properties_match_wizard_config() {
+        let schema = ConfigSchema::wizard_config();
+        let config: WizardConfig = serde_json::from_value(full_config()).unwrap();
+        let serialized = serde_json::to_value(&config).unwrap();
+
+        assert_eq!(keys(&schema["properties"]), keys(&serialized));
+        assert_eq!(
+            required(&schema),
+            mandatory_fields::<WizardConfig>(&full_config())
+        );
+    }
+
+    #[test]
+    fn test_schema_properties_match_channel_config() {
+        let schema = &ConfigSchema::wizard_config()["$defs"]["ChannelConfig"];
+        let channel: ChannelConfig = serde_json::from_value(full_channel()).unwrap();
+        let serialized = serde_json::to_value(&channel).unwrap();
+
+        assert_eq!(keys(&schema["properties"]), keys(&serialized));
+        assert_eq!(
+            required(schema),
+            mandatory_fields::<ChannelConfig>(&full_channel())
+        );
+    }
+
+    #[test]
+    fn test_compatibility_allows_null() {
+        let schema = ConfigSchema::wizard_config();
+        let allowed = schema["properties"]["compatibility"]["enum"]
+            .as_array()
+            .unwrap();
+        assert!(allowed.contains(&Value::Null));
+        assert!(allowed.contains(&json!("anthropic")));
+    }
+}
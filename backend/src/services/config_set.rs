@@ -0,0 +1,307 @@
+//! # Config Key Setter
+//!
+//! Sets a single gateway config key with `openclaw config set`, for
+//! dashboard tweaks that shouldn't rewrite the whole file. Only the keys in
+//! `ALLOWED_KEYS` can be written, and each value is type-checked against
+//! the key first, so the endpoint can't be used for arbitrary writes.
+//! Turning gateway auth off or binding the gateway beyond loopback also needs
+//! a `WeakenGatewayAccess` confirmation (see `weakens_access`).
+
+use serde_json::Value;
+use std::net::IpAddr;
+
+use crate::error::AppError;
+use crate::models::types::ConfigSetResponse;
+use crate::services::command::SafeCommand;
+use crate::services::config::ConfigWriter;
+use crate::services::credentials::{MAX_CREDENTIAL_LENGTH, MIN_CREDENTIAL_LENGTH};
+use crate::services::platform::Platform;
+
+/// Value shape a settable key accepts
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum KeyKind {
+    Port,
+    Bool,
+    Bind,
+    Secret,
+    /// Gateway token or password, held to the CredentialService length bounds
+    Credential,
+    StringList,
+    OneOf(&'static [&'static str]),
+}
+
+/// Settable keys; `*` matches one channel name segment
+const ALLOWED_KEYS: &[(&str, KeyKind)] = &[
+    ("gateway.port", KeyKind::Port),
+    ("gateway.bind", KeyKind::Bind),
+    (
+        "gateway.auth.mode",
+        KeyKind::OneOf(&["token", "password", "none"]),
+    ),
+    ("gateway.auth.token", KeyKind::Credential),
+    ("gateway.auth.password", KeyKind::Credential),
+    ("channels.*.enabled", KeyKind::Bool),
+    (
+        "channels.*.dmPolicy",
+        KeyKind::OneOf(&["pairing", "allowlist", "open", "disabled"]),
+    ),
+    ("channels.*.allowFrom", KeyKind::StringList),
+    ("channels.*.botToken", KeyKind::Secret),
+    ("channels.*.appToken", KeyKind::Secret),
+];
+
+/// Named `gateway.bind` modes; an IP address is accepted as well
+const BIND_MODES: &[&str] = &["loopback", "lan", "all"];
+
+/// Shown instead of a secret value in responses
+const MASKED: &str = "********";
+
+pub struct ConfigSetter;
+
+impl ConfigSetter {
+    /// Validate and set `path`, then read the value back from the gateway config
+    pub fn set(path: &str, value: &Value) -> Result<ConfigSetResponse, AppError> {
+        let kind = Self::validate(path, value)?;
+
+        let arg = match value {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        // `--` so a value starting with '-' isn't read as an option
+        let output = SafeCommand::run("openclaw", &["config", "set", "--", path, &arg])
+            .map_err(|e| AppError::InternalError(format!("Failed to run openclaw: {}", e)))?;
+        if output.exit_code != 0 {
+            return Err(AppError::InternalError(format!(
+                "openclaw config set {} failed: {}",
+                path,
+                output.stderr.trim()
+            )));
+        }
+
+        let config_path =
+            Platform::openclaw_config_path().map_err(|e| AppError::InternalError(e.to_string()))?;
+        let config: Value = ConfigWriter::read_json(&config_path).map_err(|e| {
+            AppError::InternalError(format!("Failed to read {}: {}", config_path.display(), e))
+        })?;
+        let updated = Self::lookup(&config, path).cloned().unwrap_or(Value::Null);
+
+        Ok(ConfigSetResponse {
+            path: path.to_string(),
+            value: Self::display_value(kind, updated),
+        })
+    }
+
+    /// Whether setting `path` to `value` turns off gateway auth or exposes
+    /// the gateway beyond loopback, so the caller must confirm it first
+    pub fn weakens_access(path: &str, value: &Value) -> bool {
+        if Self::validate(path, value).is_err() {
+            return false;
+        }
+        match (path, value.as_str()) {
+            ("gateway.auth.mode", Some(mode)) => mode == "none",
+            ("gateway.bind", Some(bind)) => !Self::is_loopback_bind(bind),
+            _ => false,
+        }
+    }
+
+    pub(crate) fn is_loopback_bind(bind: &str) -> bool {
+        bind == "loopback" || bind.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+    }
+
+    /// Check `path` is settable and `value` fits it
+    fn validate(path: &str, value: &Value) -> Result<KeyKind, AppError> {
+        let kind = Self::key_kind(path).ok_or_else(|| {
+            let known: Vec<&str> = ALLOWED_KEYS.iter().map(|(key, _)| *key).collect();
+            AppError::BadRequest(format!(
+                "Config key {:?} can't be set here; allowed keys: {}",
+                path,
+                known.join(", ")
+            ))
+        })?;
+
+        let valid = match kind {
+            KeyKind::Port => value
+                .as_u64()
+                .is_some_and(|port| (1..=65535).contains(&port)),
+            KeyKind::Bool => value.is_boolean(),
+            KeyKind::Secret => value.as_str().is_some_and(|s| !s.trim().is_empty()),
+            KeyKind::Credential => value.as_str().is_some_and(|s| {
+                (MIN_CREDENTIAL_LENGTH..=MAX_CREDENTIAL_LENGTH).contains(&s.chars().count())
+            }),
+            KeyKind::Bind => value
+                .as_str()
+                .is_some_and(|s| BIND_MODES.contains(&s) || s.parse::<IpAddr>().is_ok()),
+            KeyKind::StringList => value
+                .as_array()
+                .is_some_and(|items| items.iter().all(Value::is_string)),
+            KeyKind::OneOf(options) => value.as_str().is_some_and(|s| options.contains(&s)),
+        };
+        if !valid {
+            return Err(AppError::BadRequest(format!(
+                "Invalid value for {}: expected {}",
+                path,
+                Self::describe(kind)
+            )));
+        }
+        Ok(kind)
+    }
+
+    /// Kind of a settable key, None when it isn't allowlisted
+    fn key_kind(path: &str) -> Option<KeyKind> {
+        let segments: Vec<&str> = path.split('.').collect();
+        ALLOWED_KEYS.iter().find_map(|(pattern, kind)| {
+            let pattern: Vec<&str> = pattern.split('.').collect();
+            let matches = pattern.len() == segments.len()
+                && pattern.iter().zip(&segments).all(|(p, s)| {
+                    if *p == "*" {
+                        Self::is_channel_name(s)
+                    } else {
+                        p == s
+                    }
+                });
+            matches.then_some(*kind)
+        })
+    }
+
+    fn is_channel_name(segment: &str) -> bool {
+        !segment.is_empty()
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+    }
+
+    fn describe(kind: KeyKind) -> String {
+        match kind {
+            KeyKind::Port => "a port number (1-65535)".to_string(),
+            KeyKind::Bool => "true or false".to_string(),
+            KeyKind::Secret => "a non-empty string".to_string(),
+            KeyKind::Credential => format!(
+                "a string of {}-{} characters",
+                MIN_CREDENTIAL_LENGTH, MAX_CREDENTIAL_LENGTH
+            ),
+            KeyKind::Bind => format!("one of: {}, or an IP address", BIND_MODES.join(", ")),
+            KeyKind::StringList => "an array of strings".to_string(),
+            KeyKind::OneOf(options) => format!("one of: {}", options.join(", ")),
+        }
+    }
+
+    fn lookup<'a>(config: &'a Value, path: &str) -> Option<&'a Value> {
+        path.split('.')
+            .try_fold(config, |node, segment| node.get(segment))
+    }
+
+    fn display_value(kind: KeyKind, value: Value) -> Value {
+        match (kind, &value) {
+            (KeyKind::Secret | KeyKind::Credential, Value::String(s)) if !s.is_empty() => {
+                Value::String(MASKED.into())
+            }
+            _ => value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_key_kind_allowlist() {
+        assert_eq!(ConfigSetter::key_kind("gateway.port"), Some(KeyKind::Port));
+        assert_eq!(
+            ConfigSetter::key_kind("channels.telegram.enabled"),
+            Some(KeyKind::Bool)
+        );
+        assert_eq!(ConfigSetter::key_kind("gateway"), None);
+        assert_eq!(ConfigSetter::key_kind("gateway.port.extra"), None);
+        assert_eq!(ConfigSetter::key_kind("meta.lastTouchedVersion"), None);
+        assert_eq!(ConfigSetter::key_kind("channels.Tele gram.enabled"), None);
+        assert_eq!(ConfigSetter::key_kind("channels..enabled"), None);
+    }
+
+    #[test]
+    fn test_validate_checks_value_type() {
+        assert!(ConfigSetter::validate("gateway.port", &json!(18789)).is_ok());
+        assert!(ConfigSetter::validate("gateway.port", &json!(0)).is_err());
+        assert!(ConfigSetter::validate("gateway.port", &json!("18789")).is_err());
+        assert!(ConfigSetter::validate("channels.slack.enabled", &json!(true)).is_ok());
+        assert!(ConfigSetter::validate("channels.slack.allowFrom", &json!(["U1"])).is_ok());
+        assert!(ConfigSetter::validate("channels.slack.allowFrom", &json!([1])).is_err());
+        assert!(ConfigSetter::validate("channels.slack.dmPolicy", &json!("allowlist")).is_ok());
+        assert!(ConfigSetter::validate("channels.slack.dmPolicy", &json!("anyone")).is_err());
+
+        assert!(ConfigSetter::validate("gateway.auth.mode", &json!("password")).is_ok());
+        assert!(ConfigSetter::validate("gateway.bind", &json!("lan")).is_ok());
+        assert!(ConfigSetter::validate("gateway.bind", &json!("192.168.1.20")).is_ok());
+        assert!(ConfigSetter::validate("gateway.bind", &json!("anywhere")).is_err());
+
+        // Gateway credentials can't be shortened below the generated length
+        assert!(ConfigSetter::validate("gateway.auth.token", &json!("x")).is_err());
+        let credential = "c".repeat(MIN_CREDENTIAL_LENGTH);
+        assert!(ConfigSetter::validate("gateway.auth.token", &json!(credential)).is_ok());
+        assert!(ConfigSetter::validate("gateway.auth.password", &json!(credential)).is_ok());
+        let too_long = "c".repeat(MAX_CREDENTIAL_LENGTH + 1);
+        assert!(ConfigSetter::validate("gateway.auth.password", &json!(too_long)).is_err());
+        assert!(ConfigSetter::validate("channels.slack.botToken", &json!("xoxb")).is_ok());
+
+        let err = ConfigSetter::validate("tools.exec", &json!("rm")).unwrap_err();
+        assert!(matches!(err, AppError::BadRequest(msg) if msg.contains("gateway.port")));
+    }
+
+    #[test]
+    fn test_weakens_access() {
+        assert!(ConfigSetter::weakens_access(
+            "gateway.auth.mode",
+            &json!("none")
+        ));
+        assert!(!ConfigSetter::weakens_access(
+            "gateway.auth.mode",
+            &json!("password")
+        ));
+        assert!(ConfigSetter::weakens_access("gateway.bind", &json!("lan")));
+        assert!(ConfigSetter::weakens_access(
+            "gateway.bind",
+            &json!("0.0.0.0")
+        ));
+        assert!(!ConfigSetter::weakens_access(
+            "gateway.bind",
+            &json!("loopback")
+        ));
+        assert!(!ConfigSetter::weakens_access(
+            "gateway.bind",
+            &json!("127.0.0.1")
+        ));
+        assert!(!ConfigSetter::weakens_access("gateway.bind", &json!("::1")));
+        // Invalid values are rejected by validation, not by confirmation
+        assert!(!ConfigSetter::weakens_access(
+            "gateway.bind",
+            &json!("anywhere")
+        ));
+        assert!(!ConfigSetter::weakens_access("gateway.port", &json!(80)));
+    }
+
+    #[test]
+    fn test_lookup_and_masking() {
+        let config = json!({ "gateway": { "port": 4100, "auth": { "token": "t0k" } } });
+        assert_eq!(
+            ConfigSetter::lookup(&config, "gateway.port"),
+            Some(&json!(4100))
+        );
+        assert_eq!(
+            ConfigSetter::lookup(&config, "channels.slack.enabled"),
+            None
+        );
+
+        let token = ConfigSetter::lookup(&config, "gateway.auth.token")
+            .cloned()
+            .unwrap();
+        assert_eq!(
+            ConfigSetter::display_value(KeyKind::Credential, token),
+            json!(MASKED)
+        );
+        assert_eq!(
+            ConfigSetter::display_value(KeyKind::Port, json!(4100)),
+            json!(4100)
+        );
+    }
+}
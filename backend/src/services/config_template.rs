@@ -0,0 +1,316 @@
+//! # Config Templates
+//!
+//! Turns a config into a shareable template by replacing secret values with
+//! `${ENV_VAR}` placeholders, and resolves those placeholders from the
+//! environment when a template is imported or applied.
+//!
+//! Placeholder names are derived from the value's path, e.g.
+//! `channels.telegram.botToken` → `${OPENCLAW_CHANNELS_TELEGRAM_BOT_TOKEN}`.
+//! Only names in that `OPENCLAW_*` namespace are read from the environment,
+//! so a crafted template can't pull unrelated host secrets into the config.
+
+use regex::Regex;
+use serde_json::Value;
+use std::fmt;
+use std::sync::OnceLock;
+
+use crate::services::redact::is_secret_key;
+
+/// Prefix for generated environment variable names
+const ENV_PREFIX: &str = "OPENCLAW";
+
+/// Why a template's placeholders couldn't be resolved from the environment
+#[derive(Debug, PartialEq)]
+pub enum PlaceholderError {
+    /// Names outside the `OPENCLAW_*` namespace; these are never read
+    NotAllowed(Vec<String>),
+    /// `OPENCLAW_*` names that aren't set
+    Missing(Vec<String>),
+}
+
+impl fmt::Display for PlaceholderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotAllowed(names) => write!(
+                f,
+                "Config placeholders must be {}_* variables: {}",
+                ENV_PREFIX,
+                names.join(", ")
+            ),
+            Self::Missing(names) => write!(
+                f,
+                "Missing environment variables for config placeholders: {}",
+                names.join(", ")
+            ),
+        }
+    }
+}
+
+/// Config template helpers
+pub struct ConfigTemplate;
+
+impl ConfigTemplate {
+    /// Copy of `config` with every secret string replaced by a placeholder
+    pub fn from_config(config: &Value) -> Value {
+        let mut template = config.clone();
+        Self::redact(&mut template, &mut Vec::new());
+        template
+    }
+
+    /// Replace `${NAME}` placeholders using `lookup`.
+    ///
+    /// Returns the names that `lookup` couldn't resolve as the error, sorted
+    /// and deduplicated.
+    pub fn resolve_with(
+        config: &Value,
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<Value, Vec<String>> {
+        let mut resolved = config.clone();
+        let mut missing = Vec::new();
+        Self::substitute(&mut resolved, &lookup, &mut missing);
+
+        if missing.is_empty() {
+            Ok(resolved)
+        } else {
+            missing.sort();
+            missing.dedup();
+            Err(missing)
+        }
+    }
+
+    /// Replace `${NAME}` placeholders from the process environment
+    ///
+    /// Only `OPENCLAW_*` names (the ones `from_config` generates) are looked
+    /// up; any other name rejects the whole config.
+    pub fn resolve_env(config: &Value) -> Result<Value, PlaceholderError> {
+        Self::resolve_allowed(config, |name| std::env::var(name).ok())
+    }
+
+    /// `resolve_with`, restricted to the `OPENCLAW_*` namespace
+    fn resolve_allowed(
+        config: &Value,
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<Value, PlaceholderError> {
+        Self::resolve_with(config, |name| {
+            Self::is_allowed_name(name).then(|| lookup(name)).flatten()
+        })
+        .map_err(|unresolved| {
+            let (missing, not_allowed): (Vec<String>, Vec<String>) = unresolved
+                .into_iter()
+                .partition(|name| Self::is_allowed_name(name));
+            if not_allowed.is_empty() {
+                PlaceholderError::Missing(missing)
+            } else {
+                PlaceholderError::NotAllowed(not_allowed)
+            }
+        })
+    }
+
+    /// Whether `name` is in the namespace `env_var_name` generates
+    fn is_allowed_name(name: &str) -> bool {
+        name.strip_prefix(ENV_PREFIX)
+            .is_some_and(|rest| rest.starts_with('_'))
+    }
+
+    fn redact(value: &mut Value, path: &mut Vec<String>) {
+        match value {
+            Value::Object(map) => {
+                for (key, child) in map.iter_mut() {
+                    path.push(key.clone());
+                    let is_plain_secret = is_secret_key(key)
+                        && child
+                            .as_str()
+                            .is_some_and(|s| !s.is_empty() && !Self::is_placeholder(s));
+                    if is_plain_secret {
+                        *child = Value::String(format!("${{{}}}", Self::env_var_name(path)));
+                    } else {
+                        Self::redact(child, path);
+                    }
+                    path.pop();
+                }
+            }
+            Value::Array(items) => {
+                for (i, child) in items.iter_mut().enumerate() {
+                    path.push(i.to_string());
+                    Self::redact(child, path);
+                    path.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn substitute(
+        value: &mut Value,
+        lookup: &impl Fn(&str) -> Option<String>,
+        missing: &mut Vec<String>,
+    ) {
+        match value {
+            Value::String(s) if s.contains("${") => {
+                let replaced = placeholder_regex().replace_all(s, |caps: &regex::Captures| {
+                    lookup(&caps[1]).unwrap_or_else(|| {
+                        missing.push(caps[1].to_string());
+                        String::new()
+                    })
+                });
+                *s = replaced.into_owned();
+            }
+            Value::Object(map) => {
+                for child in map.values_mut() {
+                    Self::substitute(child, lookup, missing);
+                }
+            }
+            Value::Array(items) => {
+                for child in items {
+                    Self::substitute(child, lookup, missing);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn is_placeholder(value: &str) -> bool {
+        placeholder_regex()
+            .find(value)
+            .is_some_and(|m| m.start() == 0 && m.end() == value.len())
+    }
+
+    /// `["channels", "telegram", "botToken"]` → `OPENCLAW_CHANNELS_TELEGRAM_BOT_TOKEN`
+    fn env_var_name(path: &[String]) -> String {
+        let mut name = String::from(ENV_PREFIX);
+        for segment in path {
+            name.push('_');
+            let mut prev_lower = false;
+            for c in segment.chars() {
+                if c.is_ascii_uppercase() && prev_lower {
+                    name.push('_');
+                }
+                prev_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+                if c.is_ascii_alphanumeric() {
+                    name.push(c.to_ascii_uppercase());
+                } else {
+                    name.push('_');
+                }
+            }
+        }
+        name
+    }
+}
+
+fn placeholder_regex() -> &'static Regex {
+    static PLACEHOLDER: OnceLock<Regex> = OnceLock::new();
+    PLACEHOLDER.get_or_init(|| Regex::new(r"\$\{([A-Za-z_][A-Za-z0-9_]*)\}").unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sample_config() -> Value {
+        json!({
+            "provider": "anthropic",
+            "api_key": "sk-ant-secret",
+            "gateway_port": 18789,
+            "auth_credential": "gw-token",
+            "channels": [
+                { "platform": "telegram", "bot_token": "123:abc", "allowed_users": ["alice"] }
+            ],
+            "gateway": { "auth": { "mode": "token", "token": "t0k" } },
+        })
+    }
+
+    #[test]
+    fn test_template_replaces_secrets_only() {
+        let template = ConfigTemplate::from_config(&sample_config());
+        assert_eq!(template["api_key"], "${OPENCLAW_API_KEY}");
+        assert_eq!(template["auth_credential"], "${OPENCLAW_AUTH_CREDENTIAL}");
+        assert_eq!(
+            template["channels"][0]["bot_token"],
+            "${OPENCLAW_CHANNELS_0_BOT_TOKEN}"
+        );
+        assert_eq!(
+            template["gateway"]["auth"]["token"],
+            "${OPENCLAW_GATEWAY_AUTH_TOKEN}"
+        );
+        assert_eq!(template["provider"], "anthropic");
+        assert_eq!(template["gateway_port"], 18789);
+        assert_eq!(template["channels"][0]["allowed_users"][0], "alice");
+    }
+
+    #[test]
+    fn test_env_var_name_splits_camel_case() {
+        let path: Vec<String> = ["channels", "telegram", "botToken"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            ConfigTemplate::env_var_name(&path),
+            "OPENCLAW_CHANNELS_TELEGRAM_BOT_TOKEN"
+        );
+    }
+
+    #[test]
+    fn test_resolve_round_trips_template() {
+        let config = sample_config();
+        let template = ConfigTemplate::from_config(&config);
+        let env = |name: &str| match name {
+            "OPENCLAW_API_KEY" => Some("sk-ant-secret".to_string()),
+            "OPENCLAW_AUTH_CREDENTIAL" => Some("gw-token".to_string()),
+            "OPENCLAW_CHANNELS_0_BOT_TOKEN" => Some("123:abc".to_string()),
+            "OPENCLAW_GATEWAY_AUTH_TOKEN" => Some("t0k".to_string()),
+            _ => None,
+        };
+        assert_eq!(
+            ConfigTemplate::resolve_with(&template, env).unwrap(),
+            config
+        );
+    }
+
+    #[test]
+    fn test_resolve_reports_missing_variables() {
+        let template = json!({
+            "api_key": "${KEY_A}",
+            "nested": { "url": "https://${HOST}/v1", "again": "${KEY_A}" },
+        });
+        let missing = ConfigTemplate::resolve_with(&template, |_| None).unwrap_err();
+        assert_eq!(missing, vec!["HOST".to_string(), "KEY_A".to_string()]);
+
+        let resolved =
+            ConfigTemplate::resolve_with(&template, |name| Some(name.to_lowercase())).unwrap();
+        assert_eq!(resolved["nested"]["url"], "https://host/v1");
+    }
+
+    #[test]
+    fn test_resolve_env_rejects_names_outside_namespace() {
+        let template = json!({
+            "api_key": "${AWS_SECRET_ACCESS_KEY}",
+            "auth_credential": "${OPENCLAW_AUTH_CREDENTIAL}",
+            "home": "${OPENCLAWX}",
+        });
+        let looked_up = std::cell::RefCell::new(Vec::new());
+        let err = ConfigTemplate::resolve_allowed(&template, |name| {
+            looked_up.borrow_mut().push(name.to_string());
+            Some("value".to_string())
+        })
+        .unwrap_err();
+        assert_eq!(
+            err,
+            PlaceholderError::NotAllowed(vec![
+                "AWS_SECRET_ACCESS_KEY".to_string(),
+                "OPENCLAWX".to_string()
+            ])
+        );
+        assert_eq!(*looked_up.borrow(), ["OPENCLAW_AUTH_CREDENTIAL"]);
+
+        assert_eq!(
+            ConfigTemplate::resolve_env(&json!({ "path": "${PATH}" })).unwrap_err(),
+            PlaceholderError::NotAllowed(vec!["PATH".to_string()])
+        );
+        assert_eq!(
+            ConfigTemplate::resolve_allowed(&json!({ "k": "${OPENCLAW_API_KEY}" }), |_| None)
+                .unwrap_err(),
+            PlaceholderError::Missing(vec!["OPENCLAW_API_KEY".to_string()])
+        );
+    }
+}
@@ -0,0 +1,178 @@
+//! # Destructive-Action Confirmation
+//!
+//! Irreversible endpoints (container removal and pruning, skill and
+//! OpenClaw uninstall, rollbacks, process kills)
+//! require a token issued by GET /api/confirm, so a single stray request
+//! can't wipe state. Tokens are single-use, bound to one action and target,
+//! and expire after CONFIRMATION_TTL.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::error::AppError;
+use crate::models::types::{ConfirmationToken, DestructiveAction};
+
+/// How long an issued token stays valid
+const CONFIRMATION_TTL: Duration = Duration::from_secs(60);
+
+/// Request header carrying the confirmation token
+pub const CONFIRM_TOKEN_HEADER: &str = "x-confirm-token";
+
+/// An issued, not yet used token
+struct PendingConfirmation {
+    action: DestructiveAction,
+    target: Option<String>,
+    expires_at: Instant,
+}
+
+/// Outstanding tokens keyed by token value
+static PENDING: LazyLock<Mutex<HashMap<String, PendingConfirmation>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Issues and checks confirmation tokens
+pub struct ConfirmationService;
+
+impl ConfirmationService {
+    /// Whether `action` applies to a specific container, skill, server or process
+    pub fn requires_target(action: DestructiveAction) -> bool {
+        matches!(
+            action,
+            DestructiveAction::RemoveContainer
+                | DestructiveAction::UninstallSkill
+                | DestructiveAction::RollbackServer
+                | DestructiveAction::KillProcess
+        )
+    }
+
+    /// Issue a token authorizing one `action` on `target`
+    pub fn issue(
+        action: DestructiveAction,
+        target: Option<String>,
+    ) -> Result<ConfirmationToken, AppError> {
+        let target = target.filter(|t| !t.trim().is_empty());
+        if Self::requires_target(action) && target.is_none() {
+            return Err(AppError::BadRequest(format!(
+                "{:?} needs a target to confirm",
+                action
+            )));
+        }
+
+        let token = uuid::Uuid::new_v4().simple().to_string();
+        let mut pending = PENDING.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        pending.retain(|_, p| p.expires_at > now);
+        pending.insert(
+            token.clone(),
+            PendingConfirmation {
+                action,
+                target: target.clone(),
+                expires_at: now + CONFIRMATION_TTL,
+            },
+        );
+
+        Ok(ConfirmationToken {
+            token,
+            action,
+            target,
+            expires_in_secs: CONFIRMATION_TTL.as_secs(),
+        })
+    }
+
+    /// Use up `token` for `action` on `target`.
+    ///
+    /// Fails with ConfirmationRequired when the token is missing, unknown,
+    /// expired, or was issued for a different action or target. A mismatched
+    /// token is left in place so the right request can still use it.
+    pub fn consume(
+        action: DestructiveAction,
+        target: Option<&str>,
+        token: Option<&str>,
+    ) -> Result<(), AppError> {
+        let Some(token) = token.filter(|t| !t.is_empty()) else {
+            return Err(AppError::ConfirmationRequired(format!(
+                "{:?} is irreversible: request a token from GET /api/confirm and send it in the X-Confirm-Token header",
+                action
+            )));
+        };
+
+        let mut pending = PENDING.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        pending.retain(|_, p| p.expires_at > now);
+
+        match pending.get(token) {
+            None => Err(AppError::ConfirmationRequired(
+                "Confirmation token is invalid or expired".into(),
+            )),
+            Some(p) if p.action != action || p.target.as_deref() != target => {
+                Err(AppError::ConfirmationRequired(
+                    "Confirmation token was issued for a different action".into(),
+                ))
+            }
+            Some(_) => {
+                pending.remove(token);
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_is_single_use() {
+        let issued =
+            ConfirmationService::issue(DestructiveAction::RemoveContainer, Some("c-1".into()))
+                .unwrap();
+        assert_eq!(issued.expires_in_secs, CONFIRMATION_TTL.as_secs());
+
+        let action = DestructiveAction::RemoveContainer;
+        assert!(ConfirmationService::consume(action, Some("c-1"), Some(&issued.token)).is_ok());
+        assert!(matches!(
+            ConfirmationService::consume(action, Some("c-1"), Some(&issued.token)),
+            Err(AppError::ConfirmationRequired(_))
+        ));
+    }
+
+    #[test]
+    fn test_token_is_bound_to_action_and_target() {
+        let issued =
+            ConfirmationService::issue(DestructiveAction::UninstallSkill, Some("skill-a".into()))
+                .unwrap();
+        let token = Some(issued.token.as_str());
+
+        let wrong_target =
+            ConfirmationService::consume(DestructiveAction::UninstallSkill, Some("skill-b"), token);
+        assert!(matches!(
+            wrong_target,
+            Err(AppError::ConfirmationRequired(_))
+        ));
+        let wrong_action = ConfirmationService::consume(
+            DestructiveAction::RemoveContainer,
+            Some("skill-a"),
+            token,
+        );
+        assert!(wrong_action.is_err());
+
+        // Mismatches don't burn the token
+        assert!(
+            ConfirmationService::consume(DestructiveAction::UninstallSkill, Some("skill-a"), token)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_missing_token_or_target() {
+        assert!(matches!(
+            ConfirmationService::consume(DestructiveAction::RollbackInstallation, None, None),
+            Err(AppError::ConfirmationRequired(_))
+        ));
+        assert!(matches!(
+            ConfirmationService::issue(DestructiveAction::RollbackServer, None),
+            Err(AppError::BadRequest(_))
+        ));
+        assert!(ConfirmationService::issue(DestructiveAction::RollbackServers, None).is_ok());
+    }
+}
@@ -0,0 +1,196 @@
+//! # Gateway Credentials
+//!
+//! Generates random gateway auth credentials and rotates the configured one.
+//! Rotation writes the new credential to both the gateway's
+//! `~/.openclaw/openclaw.json` (`gateway.auth.token`) and the wizard's saved
+//! config (`auth_credential`); the caller restarts the gateway.
+//!
+//! SECURITY: credentials are never logged.
+
+use rand::Rng;
+use rand::distr::Alphanumeric;
+use serde_json::Value;
+use std::path::Path;
+
+use crate::error::AppError;
+use crate::services::config::ConfigWriter;
+
+/// Length of generated credentials
+pub const DEFAULT_CREDENTIAL_LENGTH: usize = 32;
+
+/// Shortest credential the security audit accepts (SEC-007)
+pub const MIN_CREDENTIAL_LENGTH: usize = 16;
+
+/// Longest credential the generator will produce
+pub const MAX_CREDENTIAL_LENGTH: usize = 256;
+
+/// Gateway credential helpers
+pub struct CredentialService;
+
+impl CredentialService {
+    /// Random alphanumeric credential of `length` characters from the
+    /// thread-local CSPRNG
+    pub fn generate(length: usize) -> String {
+        rand::rng()
+            .sample_iter(&Alphanumeric)
+            .take(length)
+            .map(char::from)
+            .collect()
+    }
+
+    /// Validate a requested credential length, defaulting to
+    /// DEFAULT_CREDENTIAL_LENGTH
+    pub fn checked_length(requested: Option<usize>) -> Result<usize, AppError> {
+        let length = requested.unwrap_or(DEFAULT_CREDENTIAL_LENGTH);
+        if !(MIN_CREDENTIAL_LENGTH..=MAX_CREDENTIAL_LENGTH).contains(&length) {
+            return Err(AppError::BadRequest(format!(
+                "Credential length must be between {} and {}",
+                MIN_CREDENTIAL_LENGTH, MAX_CREDENTIAL_LENGTH
+            )));
+        }
+        Ok(length)
+    }
+
+    /// Replace the gateway credential in whichever of the two configs exist.
+    ///
+    /// Fails with NotFound when neither config exists and BadRequest when
+    /// gateway auth is off ("none"), since a credential would have no
+    /// effect. Returns the auth mode.
+    pub fn rotate(
+        gateway_path: &Path,
+        wizard_path: &Path,
+        credential: &str,
+    ) -> Result<String, AppError> {
+        let mut gateway = Self::read_object(gateway_path)?;
+        let mut wizard = Self::read_object(wizard_path)?;
+        if gateway.is_none() && wizard.is_none() {
+            return Err(AppError::NotFound(
+                "No gateway config found. Complete the setup wizard first.".into(),
+            ));
+        }
+
+        let auth_mode = gateway
+            .as_ref()
+            .and_then(|g| g["gateway"]["auth"]["mode"].as_str())
+            .or_else(|| wizard.as_ref().and_then(|w| w["auth_mode"].as_str()))
+            .unwrap_or("none")
+            .to_string();
+        if auth_mode == "none" {
+            return Err(AppError::BadRequest(
+                "Gateway auth is off; enable token auth before rotating the credential".into(),
+            ));
+        }
+
+        // Gateway first: it's the config the running service reads
+        if let Some(gateway) = gateway.as_mut() {
+            if !gateway["gateway"].is_object() {
+                gateway["gateway"] = serde_json::json!({});
+            }
+            if !gateway["gateway"]["auth"].is_object() {
+                gateway["gateway"]["auth"] = serde_json::json!({ "mode": auth_mode });
+            }
+            gateway["gateway"]["auth"]["token"] = Value::String(credential.to_string());
+            ConfigWriter::write_json(gateway_path, gateway).map_err(|e| {
+                AppError::InternalError(format!("Failed to write gateway config: {}", e))
+            })?;
+        }
+        if let Some(wizard) = wizard.as_mut() {
+            wizard["auth_credential"] = Value::String(credential.to_string());
+            ConfigWriter::write_json(wizard_path, wizard).map_err(|e| {
+                AppError::InternalError(format!("Failed to write wizard config: {}", e))
+            })?;
+        }
+
+        Ok(auth_mode)
+    }
+
+    /// Read a JSON object config, or None when the file doesn't exist
+    fn read_object(path: &Path) -> Result<Option<Value>, AppError> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let value: Value = ConfigWriter::read_json(path)
+            .map_err(|e| AppError::InternalError(format!("{:#}", e)))?;
+        if !value.is_object() {
+            return Err(AppError::InternalError(format!(
+                "Config at {} is not a JSON object",
+                path.display()
+            )));
+        }
+        Ok(Some(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_generate_is_random_alphanumeric() {
+        let a = CredentialService::generate(DEFAULT_CREDENTIAL_LENGTH);
+        let b = CredentialService::generate(DEFAULT_CREDENTIAL_LENGTH);
+        assert_eq!(a.len(), DEFAULT_CREDENTIAL_LENGTH);
+        assert!(a.chars().all(|c| c.is_ascii_alphanumeric()));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_checked_length_bounds() {
+        assert_eq!(
+            CredentialService::checked_length(None).unwrap(),
+            DEFAULT_CREDENTIAL_LENGTH
+        );
+        assert_eq!(CredentialService::checked_length(Some(16)).unwrap(), 16);
+        assert!(CredentialService::checked_length(Some(15)).is_err());
+        assert!(CredentialService::checked_length(Some(MAX_CREDENTIAL_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_rotate_updates_both_configs() {
+        let dir = tempfile::tempdir().unwrap();
+        let gateway_path = dir.path().join("gateway.json");
+        let wizard_path = dir.path().join("wizard.json");
+        std::fs::write(
+            &gateway_path,
+            json!({"gateway": {"port": 18789, "auth": {"mode": "token", "token": "old"}}})
+                .to_string(),
+        )
+        .unwrap();
+        std::fs::write(
+            &wizard_path,
+            json!({"provider": "anthropic", "auth_mode": "token", "auth_credential": "old"})
+                .to_string(),
+        )
+        .unwrap();
+
+        let mode = CredentialService::rotate(&gateway_path, &wizard_path, "new-secret").unwrap();
+        assert_eq!(mode, "token");
+
+        let gateway: Value = ConfigWriter::read_json(&gateway_path).unwrap();
+        assert_eq!(gateway["gateway"]["auth"]["token"], "new-secret");
+        assert_eq!(gateway["gateway"]["port"], 18789);
+        let wizard: Value = ConfigWriter::read_json(&wizard_path).unwrap();
+        assert_eq!(wizard["auth_credential"], "new-secret");
+        assert_eq!(wizard["provider"], "anthropic");
+    }
+
+    #[test]
+    fn test_rotate_requires_auth_and_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let gateway_path = dir.path().join("gateway.json");
+        let wizard_path = dir.path().join("wizard.json");
+        assert!(matches!(
+            CredentialService::rotate(&gateway_path, &wizard_path, "x"),
+            Err(AppError::NotFound(_))
+        ));
+
+        std::fs::write(&wizard_path, json!({"auth_mode": "none"}).to_string()).unwrap();
+        assert!(matches!(
+            CredentialService::rotate(&gateway_path, &wizard_path, "x"),
+            Err(AppError::BadRequest(_))
+        ));
+        let wizard: Value = ConfigWriter::read_json(&wizard_path).unwrap();
+        assert!(wizard.get("auth_credential").is_none());
+    }
+}
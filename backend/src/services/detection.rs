@@ -1,12 +1,33 @@
 //! # OpenClaw Detection Service
 //!
 //! Detects existing OpenClaw installations and configurations.
+//!
+//! Config lookup follows the gateway's precedence: `OPENCLAW_CONFIG_PATH`,
+//! then `$OPENCLAW_STATE_DIR/openclaw.json`, then `~/.openclaw/openclaw.json`.
+//! The wizard's own saved settings and `./openclaw.json` are reported too,
+//! but the gateway never loads them.
 
-use std::path::PathBuf;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 use crate::models::OpenClawDetection;
+use crate::models::types::ConfigLocation;
 use crate::services::{command::SafeCommand, config::ConfigWriter, platform::Platform};
 
+/// npm registry endpoint for the latest published OpenClaw version
+const NPM_LATEST_URL: &str = "https://registry.npmjs.org/openclaw/latest";
+
+/// How long a registry lookup is reused before asking npm again
+const LATEST_VERSION_TTL: Duration = Duration::from_secs(600);
+
+/// Timeout for the registry lookup, so detection never hangs offline
+const REGISTRY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Cached latest version and when it was fetched
+static LATEST_VERSION_CACHE: Mutex<Option<(Instant, String)>> = Mutex::new(None);
+
 /// OpenClaw detection utilities
 pub struct DetectionService;
 
@@ -40,34 +61,250 @@ impl DetectionService {
             _ => (false, None, None),
         };
 
-        // Check common config locations
-        let config_locations = vec![
-            Platform::home_dir()
-                .ok()
-                .map(|h| h.join(".openclaw").join("openclaw.json")),
-            Some(PathBuf::from("./openclaw.json")),
-        ];
+        let config_locations = Self::config_locations();
+        let active = config_locations.iter().find(|l| l.active);
 
-        let (config_found, config_path, existing_config) = config_locations
-            .into_iter()
-            .flatten()
-            .find_map(|path| {
-                if path.exists() {
-                    let config_value = ConfigWriter::read_json::<serde_json::Value>(&path).ok();
-                    Some((true, Some(path.display().to_string()), config_value))
-                } else {
-                    None
-                }
-            })
-            .unwrap_or((false, None, None));
+        // Prefer the config the gateway loads, else the first one found
+        let found = active
+            .filter(|l| l.exists)
+            .or_else(|| config_locations.iter().find(|l| l.exists));
+        let config_path = found.map(|l| l.path.clone());
+        let existing_config =
+            found.and_then(|l| ConfigWriter::read_json::<Value>(l.path.as_ref()).ok());
+
+        let active_config = active
+            .filter(|l| l.exists)
+            .and_then(|l| ConfigWriter::read_json::<Value>(l.path.as_ref()).ok());
+        let wizard_config = config_locations
+            .iter()
+            .find(|l| l.source == "wizard" && l.exists)
+            .and_then(|l| ConfigWriter::read_json::<Value>(l.path.as_ref()).ok());
+        let config_divergence = Self::config_divergence(
+            &config_locations,
+            wizard_config.as_ref(),
+            active_config.as_ref(),
+        );
 
         OpenClawDetection {
             installed,
             version,
             install_path,
-            config_found,
+            config_found: config_path.is_some(),
             config_path,
             existing_config,
+            latest_version: None,
+            update_available: false,
+            active_config_path: active.map(|l| l.path.clone()),
+            config_locations,
+            config_divergence,
+        }
+    }
+
+    /// Known config locations for this environment, in gateway precedence
+    pub fn config_locations() -> Vec<ConfigLocation> {
+        let env_path = std::env::var_os("OPENCLAW_CONFIG_PATH").map(PathBuf::from);
+        let state_dir = std::env::var_os("OPENCLAW_STATE_DIR").map(PathBuf::from);
+        let home = Platform::home_dir().ok();
+        let wizard = Platform::wizard_config_path().ok();
+
+        Self::locations_from(
+            env_path.as_deref(),
+            state_dir.as_deref(),
+            home.as_deref(),
+            wizard.as_deref(),
+        )
+        .into_iter()
+        .map(|(path, source, active)| ConfigLocation {
+            exists: path.is_file(),
+            path: path.display().to_string(),
+            source: source.to_string(),
+            active,
+        })
+        .collect()
+    }
+
+    /// Candidate paths with their source and whether the gateway loads it.
+    /// The first gateway location that is configured wins, existing or not.
+    fn locations_from(
+        env_path: Option<&Path>,
+        state_dir: Option<&Path>,
+        home: Option<&Path>,
+        wizard: Option<&Path>,
+    ) -> Vec<(PathBuf, &'static str, bool)> {
+        let gateway = [
+            (env_path.map(Path::to_path_buf), "env"),
+            (state_dir.map(|d| d.join("openclaw.json")), "state-dir"),
+            (
+                home.map(|h| h.join(".openclaw").join("openclaw.json")),
+                "default",
+            ),
+        ];
+        let others = [
+            (wizard.map(Path::to_path_buf), "wizard"),
+            (Some(PathBuf::from("./openclaw.json")), "working-dir"),
+        ];
+
+        let mut locations = Vec::new();
+        let mut active_taken = false;
+        for (path, source) in gateway {
+            let Some(path) = path.filter(|p| !p.as_os_str().is_empty()) else {
+                continue;
+            };
+            locations.push((path, source, !active_taken));
+            active_taken = true;
+        }
+        for (path, source) in others {
+            if let Some(path) = path {
+                locations.push((path, source, false));
+            }
+        }
+        locations
+    }
+
+    /// Ways the configs on disk disagree with the one the gateway loads
+    ///
+    /// Flags gateway configs shadowed by a higher-precedence location, and
+    /// wizard settings (port, bind, auth) that differ from the loaded config.
+    /// Secret values are never included.
+    pub fn config_divergence(
+        locations: &[ConfigLocation],
+        wizard: Option<&Value>,
+        active: Option<&Value>,
+    ) -> Vec<String> {
+        let mut divergence = Vec::new();
+
+        if let Some(active_location) = locations.iter().find(|l| l.active) {
+            for shadowed in locations.iter().filter(|l| {
+                l.exists && !l.active && matches!(l.source.as_str(), "state-dir" | "default")
+            }) {
+                divergence.push(format!(
+                    "{} exists but the gateway loads {}",
+                    shadowed.path, active_location.path
+                ));
+            }
+        }
+
+        if let (Some(wizard), Some(active)) = (wizard, active) {
+            let gateway = &active["gateway"];
+            let wizard_bind = wizard["gateway_bind"].as_str().map(Self::gateway_bind);
+            let checks = [
+                (
+                    "gateway port",
+                    wizard["gateway_port"].as_u64().map(|p| p.to_string()),
+                    gateway["port"].as_u64().map(|p| p.to_string()),
+                    false,
+                ),
+                (
+                    "gateway bind",
+                    wizard_bind.map(str::to_string),
+                    gateway["bind"].as_str().map(str::to_string),
+                    false,
+                ),
+                (
+                    "auth mode",
+                    wizard["auth_mode"].as_str().map(str::to_string),
+                    gateway["auth"]["mode"].as_str().map(str::to_string),
+                    false,
+                ),
+                (
+                    "auth token",
+                    wizard["auth_credential"].as_str().map(str::to_string),
+                    gateway["auth"]["token"].as_str().map(str::to_string),
+                    true,
+                ),
+            ];
+            for (name, wizard_value, gateway_value, secret) in checks {
+                let (Some(w), Some(g)) = (wizard_value, gateway_value) else {
+                    continue;
+                };
+                if w == g {
+                    continue;
+                }
+                divergence.push(if secret {
+                    format!("Wizard {} differs from the gateway config", name)
+                } else {
+                    format!("Wizard {} is {} but the gateway config has {}", name, w, g)
+                });
+            }
+        }
+
+        divergence
+    }
+
+    /// Wizard bind address as written to the gateway config
+    fn gateway_bind(bind: &str) -> &str {
+        match bind {
+            "127.0.0.1" | "localhost" | "loopback" => "loopback",
+            "0.0.0.0" | "all" => "all",
+            other => other,
+        }
+    }
+
+    /// Fill in `latest_version` and `update_available` from the npm registry
+    ///
+    /// Registry lookups are cached for 10 minutes. Failures leave the fields
+    /// unset, since being offline shouldn't break detection.
+    pub async fn check_for_update(detection: &mut OpenClawDetection) {
+        let Some(latest) = Self::latest_version().await else {
+            return;
+        };
+
+        detection.update_available = detection
+            .version
+            .as_deref()
+            .is_some_and(|installed| Self::is_newer(&latest, installed));
+        detection.latest_version = Some(latest);
+    }
+
+    /// Latest published OpenClaw version, from cache when fresh
+    async fn latest_version() -> Option<String> {
+        if let Ok(cache) = LATEST_VERSION_CACHE.lock()
+            && let Some((fetched_at, version)) = cache.as_ref()
+            && fetched_at.elapsed() < LATEST_VERSION_TTL
+        {
+            return Some(version.clone());
+        }
+
+        let client = reqwest::Client::builder()
+            .timeout(REGISTRY_TIMEOUT)
+            .build()
+            .ok()?;
+        let body: serde_json::Value = client
+            .get(NPM_LATEST_URL)
+            .send()
+            .await
+            .ok()?
+            .json()
+            .await
+            .ok()?;
+        let version = body["version"].as_str()?.to_string();
+
+        if let Ok(mut cache) = LATEST_VERSION_CACHE.lock() {
+            *cache = Some((Instant::now(), version.clone()));
+        }
+        Some(version)
+    }
+
+    /// Parse the numeric parts of a version string such as "openclaw 1.4.2-beta.1"
+    fn parse_version(version: &str) -> Option<Vec<u64>> {
+        let token = version
+            .split_whitespace()
+            .map(|t| t.trim_start_matches('v'))
+            .find(|t| t.starts_with(|c: char| c.is_ascii_digit()))?;
+        let core = token.split(['-', '+']).next()?;
+        core.split('.').map(|part| part.parse().ok()).collect()
+    }
+
+    /// True if `latest` is a strictly higher version than `installed`
+    fn is_newer(latest: &str, installed: &str) -> bool {
+        match (Self::parse_version(latest), Self::parse_version(installed)) {
+            (Some(mut latest), Some(mut installed)) => {
+                let len = latest.len().max(installed.len());
+                latest.resize(len, 0);
+                installed.resize(len, 0);
+                latest > installed
+            }
+            _ => false,
         }
     }
 }
@@ -76,6 +313,113 @@ impl DetectionService {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_version_formats() {
+        assert_eq!(
+            DetectionService::parse_version("1.4.2"),
+            Some(vec![1, 4, 2])
+        );
+        assert_eq!(
+            DetectionService::parse_version("openclaw v2026.2.9-beta.1"),
+            Some(vec![2026, 2, 9])
+        );
+        assert_eq!(DetectionService::parse_version("unknown"), None);
+    }
+
+    #[test]
+    fn test_is_newer() {
+        assert!(DetectionService::is_newer("1.5.0", "openclaw 1.4.9"));
+        assert!(DetectionService::is_newer("1.4.1", "1.4"));
+        assert!(!DetectionService::is_newer("1.4.0", "1.4"));
+        assert!(!DetectionService::is_newer("1.3.0", "1.4.0"));
+        assert!(!DetectionService::is_newer("garbage", "1.4.0"));
+    }
+
+    fn location(source: &str, exists: bool, active: bool) -> ConfigLocation {
+        ConfigLocation {
+            path: format!("/{}/openclaw.json", source),
+            source: source.to_string(),
+            exists,
+            active,
+        }
+    }
+
+    #[test]
+    fn test_locations_follow_gateway_precedence() {
+        let home = Path::new("/home/me");
+        let wizard = Path::new("/home/me/.config/openclaw-wizard/openclaw.json");
+
+        let defaults = DetectionService::locations_from(None, None, Some(home), Some(wizard));
+        let sources: Vec<_> = defaults.iter().map(|(_, s, a)| (*s, *a)).collect();
+        assert_eq!(
+            sources,
+            vec![("default", true), ("wizard", false), ("working-dir", false)]
+        );
+
+        let overridden = DetectionService::locations_from(
+            Some(Path::new("/etc/openclaw.json")),
+            Some(Path::new("/srv/openclaw")),
+            Some(home),
+            Some(wizard),
+        );
+        assert_eq!(
+            overridden[0],
+            (PathBuf::from("/etc/openclaw.json"), "env", true)
+        );
+        assert_eq!(
+            overridden[1],
+            (
+                PathBuf::from("/srv/openclaw/openclaw.json"),
+                "state-dir",
+                false
+            )
+        );
+        assert!(!overridden[2].2);
+    }
+
+    #[test]
+    fn test_config_divergence() {
+        let locations = vec![
+            location("env", true, true),
+            location("default", true, false),
+            location("wizard", true, false),
+        ];
+        let wizard = serde_json::json!({
+            "gateway_port": 18789,
+            "gateway_bind": "127.0.0.1",
+            "auth_mode": "token",
+            "auth_credential": "secret-a",
+        });
+        let gateway = serde_json::json!({
+            "gateway": { "port": 3000, "bind": "loopback", "auth": { "mode": "token", "token": "secret-b" } },
+        });
+
+        let divergence =
+            DetectionService::config_divergence(&locations, Some(&wizard), Some(&gateway));
+        assert_eq!(divergence.len(), 3);
+        assert!(
+            divergence[0].contains("/default/openclaw.json exists but the gateway loads /env/")
+        );
+        assert!(divergence[1].contains("port is 18789 but the gateway config has 3000"));
+        assert_eq!(
+            divergence[2],
+            "Wizard auth token differs from the gateway config"
+        );
+        assert!(!divergence.iter().any(|d| d.contains("secret")));
+
+        let consistent = serde_json::json!({
+            "gateway": { "port": 18789, "bind": "loopback", "auth": { "mode": "token", "token": "secret-a" } },
+        });
+        let only_default = vec![
+            location("default", true, true),
+            location("wizard", true, false),
+        ];
+        assert!(
+            DetectionService::config_divergence(&only_default, Some(&wizard), Some(&consistent))
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_detect_openclaw_runs() {
         // Just verify the method runs without panicking
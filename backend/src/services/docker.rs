@@ -6,59 +6,183 @@
 
 use bollard::Docker;
 use bollard::container::LogOutput;
-use bollard::models::{ContainerCreateBody, HostConfig, PortBinding};
+use bollard::models::{ContainerCreateBody, EventMessage, HostConfig, PortBinding};
 use bollard::query_parameters::{
-    CreateContainerOptions, ListContainersOptions, LogsOptions, RemoveContainerOptions,
-    StopContainerOptions,
+    CreateContainerOptions, EventsOptionsBuilder, ListContainersOptions, LogsOptions,
+    RemoveContainerOptions, StopContainerOptions,
 };
 use futures::StreamExt;
+use regex::Regex;
 use std::collections::HashMap;
+use std::sync::OnceLock;
 use tracing::{error, info, warn};
 
 use crate::error::AppError;
 use crate::models::types::{
-    ContainerInfo, ContainerLogsResponse, ContainerStatus, DockerCreateRequest,
-    DockerCreateResponse, DockerStatusResponse,
+    ContainerInfo, ContainerListQuery, ContainerLogsResponse, ContainerStatus, DockerCreateRequest,
+    DockerCreateResponse, DockerPruneResponse, DockerStatusResponse,
 };
+use crate::services::config::ConfigWriter;
+use crate::services::offline::OfflineMode;
+use crate::services::platform::Platform;
 
-/// Maximum number of OpenClaw-managed containers allowed simultaneously.
+/// Default maximum number of OpenClaw-managed containers allowed simultaneously.
 const MAX_CONTAINERS: usize = 5;
 
+/// Environment variable that overrides `MAX_CONTAINERS`.
+const MAX_CONTAINERS_ENV: &str = "OPENCLAW_MAX_CONTAINERS";
+
 /// Default Docker image for sandbox containers.
 const DEFAULT_IMAGE: &str = "node:20-alpine";
 
 /// Label used to identify containers managed by OpenClaw Wizard.
 const OPENCLAW_LABEL: &str = "openclaw-wizard";
 
+/// Label recording the app port a sandbox container exposes.
+const PORT_LABEL: &str = "openclaw-wizard.port";
+
+/// App port inside sandbox containers when the request doesn't set one.
+const DEFAULT_CONTAINER_PORT: u16 = 3000;
+
+/// Optional allowlist of image globs, relative to the user's home directory.
+const IMAGE_ALLOWLIST_FILE: &str = ".openclaw/docker-image-allowlist.json";
+
+/// Images allowed when no allowlist file exists: the built-in node alpine family.
+const DEFAULT_IMAGE_ALLOWLIST: &[&str] = &["node:*-alpine"];
+
+/// Writable tmpfs mounts for read-only sandboxes: scratch space and the
+/// `node` user's home (npm cache, `~/.openclaw`). Both are size-capped.
+const READONLY_TMPFS: &[(&str, &str)] = &[
+    ("/tmp", "rw,nosuid,nodev,size=64m"),
+    ("/home/node", "rw,nosuid,nodev,size=128m,uid=1000,gid=1000"),
+];
+
+/// Trade-off reported when a read-only sandbox is created
+const READONLY_NOTE: &str = "Root filesystem is read-only. Only /tmp (64 MB) and /home/node \
+     (128 MB) are writable, and both are cleared when the container stops. Installing \
+     packages globally or writing elsewhere in the image will fail.";
+
+/// How long to wait for the registry when checking an image's platforms.
+const ARCH_CHECK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// How long to wait for the daemon when negotiating the API version.
+const NEGOTIATE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
 pub struct DockerService {
     client: Option<Docker>,
-}
-
-impl Default for DockerService {
-    fn default() -> Self {
-        Self::new()
-    }
+    /// Endpoint the client was built for, e.g. "unix:///run/user/1000/docker.sock"
+    endpoint: Option<String>,
+    /// API version agreed with the daemon, e.g. "1.43"
+    api_version: Option<String>,
 }
 
 impl DockerService {
     /// Create a new DockerService. Attempts to connect to the local Docker daemon.
     /// If Docker is not available, the service is created with client = None
     /// and all operations will return appropriate "not available" responses.
-    pub fn new() -> Self {
-        match Docker::connect_with_socket_defaults() {
+    ///
+    /// Honors `DOCKER_HOST`, then probes the rootful socket and the usual
+    /// rootless socket locations before falling back to bollard's defaults.
+    ///
+    /// The client is downgraded to the daemon's API version when the engine
+    /// is older than bollard, so requests aren't rejected or misparsed.
+    pub async fn new() -> Self {
+        let endpoint = Self::detect_endpoint();
+        let result = match &endpoint {
+            Some(host) => Docker::connect_with_host(host),
+            None => Docker::connect_with_socket_defaults(),
+        };
+
+        match result {
             Ok(client) => {
-                info!("Docker client connected successfully");
+                let (client, api_version) = Self::negotiate(client).await;
+                info!(
+                    "Docker client connected successfully ({}, API {})",
+                    endpoint.as_deref().unwrap_or("default socket"),
+                    api_version.as_deref().unwrap_or("not negotiated")
+                );
                 Self {
                     client: Some(client),
+                    endpoint,
+                    api_version,
                 }
             }
             Err(e) => {
                 warn!("Docker not available: {}. Container features disabled.", e);
-                Self { client: None }
+                Self {
+                    client: None,
+                    endpoint: None,
+                    api_version: None,
+                }
+            }
+        }
+    }
+
+    /// Agree on an API version with the daemon, keeping bollard's default
+    /// client when the daemon doesn't answer in time
+    async fn negotiate(client: Docker) -> (Docker, Option<String>) {
+        match tokio::time::timeout(NEGOTIATE_TIMEOUT, client.clone().negotiate_version()).await {
+            Ok(Ok(negotiated)) => {
+                let version = negotiated.client_version().to_string();
+                (negotiated, Some(version))
+            }
+            Ok(Err(e)) => {
+                warn!("Docker API version negotiation failed: {}", e);
+                (client, None)
+            }
+            Err(_) => {
+                warn!("Docker API version negotiation timed out");
+                (client, None)
             }
         }
     }
 
+    /// Pick the first usable Docker endpoint from the environment.
+    fn detect_endpoint() -> Option<String> {
+        let docker_host = std::env::var("DOCKER_HOST").ok();
+        let runtime_dir = std::env::var("XDG_RUNTIME_DIR").ok();
+        let home = Platform::home_dir()
+            .ok()
+            .map(|h| h.to_string_lossy().into_owned());
+
+        Self::endpoint_candidates(
+            docker_host.as_deref(),
+            runtime_dir.as_deref(),
+            home.as_deref(),
+        )
+        .into_iter()
+        .find(|host| match host.strip_prefix("unix://") {
+            Some(path) => std::path::Path::new(path).exists(),
+            None => true,
+        })
+    }
+
+    /// Candidate endpoints in probe order: `DOCKER_HOST`, the rootful socket,
+    /// the rootless socket under `XDG_RUNTIME_DIR`, then Docker Desktop's per-user socket.
+    fn endpoint_candidates(
+        docker_host: Option<&str>,
+        runtime_dir: Option<&str>,
+        home: Option<&str>,
+    ) -> Vec<String> {
+        let mut candidates = Vec::new();
+        if let Some(host) = docker_host.filter(|h| !h.is_empty()) {
+            candidates.push(host.to_string());
+        }
+        if cfg!(unix) {
+            candidates.push("unix:///var/run/docker.sock".to_string());
+            if let Some(dir) = runtime_dir.filter(|d| !d.is_empty()) {
+                candidates.push(format!("unix://{}/docker.sock", dir.trim_end_matches('/')));
+            }
+            if let Some(home) = home.filter(|h| !h.is_empty()) {
+                candidates.push(format!(
+                    "unix://{}/.docker/run/docker.sock",
+                    home.trim_end_matches('/')
+                ));
+            }
+        }
+        candidates
+    }
+
     /// Get a reference to the Docker client, returning an error if Docker is not available.
     fn require_client(&self) -> Result<&Docker, AppError> {
         self.client.as_ref().ok_or_else(|| {
@@ -78,9 +202,13 @@ impl DockerService {
                     available: false,
                     version: None,
                     containers: vec![],
+                    socket: None,
                     error: Some(
                         "Docker is not installed or the daemon is not running.".to_string(),
                     ),
+                    current_count: 0,
+                    max_containers: Self::max_containers() as u32,
+                    api_version: self.api_version.clone(),
                 });
             }
         };
@@ -93,7 +221,11 @@ impl DockerService {
                     available: false,
                     version: None,
                     containers: vec![],
+                    socket: self.endpoint.clone(),
                     error: Some(format!("Docker daemon unreachable: {}", e)),
+                    current_count: 0,
+                    max_containers: Self::max_containers() as u32,
+                    api_version: self.api_version.clone(),
                 });
             }
         }
@@ -104,17 +236,68 @@ impl DockerService {
             Err(_) => None,
         };
 
-        // List our managed containers
-        let containers = self.list_containers().await.unwrap_or_default();
+        // List our managed containers. The daemon answered the ping, so a
+        // failure here (typically socket permissions) is reported alongside
+        // `available: true` instead of showing an empty container list.
+        let (containers, error) = match self.list_containers().await {
+            Ok(containers) => (containers, None),
+            Err(e) => {
+                warn!("Docker is reachable but listing containers failed: {}", e);
+                (vec![], Some(Self::list_error_message(&e.to_string())))
+            }
+        };
 
         Ok(DockerStatusResponse {
             available: true,
             version,
+            current_count: containers.len() as u32,
             containers,
-            error: None,
+            socket: self.endpoint.clone(),
+            error,
+            max_containers: Self::max_containers() as u32,
+            api_version: self.api_version.clone(),
         })
     }
 
+    /// Container limit, from `OPENCLAW_MAX_CONTAINERS` or the default
+    pub fn max_containers() -> usize {
+        Self::max_containers_from(std::env::var(MAX_CONTAINERS_ENV).ok().as_deref())
+    }
+
+    /// Parse a container limit override, falling back to the default on
+    /// anything that isn't a positive integer
+    fn max_containers_from(value: Option<&str>) -> usize {
+        match value.map(str::trim).filter(|v| !v.is_empty()) {
+            None => MAX_CONTAINERS,
+            Some(raw) => match raw.parse::<usize>() {
+                Ok(max) if max > 0 => max,
+                _ => {
+                    warn!(
+                        "Ignoring {}={:?}: expected a positive integer",
+                        MAX_CONTAINERS_ENV, raw
+                    );
+                    MAX_CONTAINERS
+                }
+            },
+        }
+    }
+
+    /// Describe a container listing failure, hinting at socket permissions
+    fn list_error_message(err: &str) -> String {
+        if err.to_lowercase().contains("permission denied") {
+            format!(
+                "Docker is running but containers could not be listed: {}. \
+                 Add your user to the 'docker' group or use a rootless Docker socket.",
+                err
+            )
+        } else {
+            format!(
+                "Docker is running but containers could not be listed: {}",
+                err
+            )
+        }
+    }
+
     /// List all containers managed by OpenClaw Wizard (filtered by label).
     pub async fn list_containers(&self) -> Result<Vec<ContainerInfo>, AppError> {
         let client = self.require_client()?;
@@ -151,10 +334,11 @@ impl DockerService {
                     _ => ContainerStatus::Stopped,
                 };
 
-                // Extract the mapped host port for container port 3000
+                // Extract the mapped host port for the container's app port
+                let container_port = Self::labeled_container_port(c.labels.as_ref());
                 let port = c.ports.as_ref().and_then(|ports| {
                     ports.iter().find_map(|p| {
-                        if p.private_port == 3000 {
+                        if p.private_port == container_port {
                             p.public_port
                         } else {
                             None
@@ -183,6 +367,7 @@ impl DockerService {
                     status,
                     created_at,
                     port,
+                    container_port,
                 }
             })
             .collect();
@@ -190,6 +375,50 @@ impl DockerService {
         Ok(result)
     }
 
+    /// List managed containers filtered and sorted per `query`.
+    pub async fn list_containers_with(
+        &self,
+        query: &ContainerListQuery,
+    ) -> Result<Vec<ContainerInfo>, AppError> {
+        let sort = query.sort.as_deref().unwrap_or("running");
+        if !matches!(sort, "running" | "newest" | "oldest") {
+            return Err(AppError::BadRequest(format!(
+                "Unknown sort '{}': use running, newest or oldest",
+                sort
+            )));
+        }
+
+        let containers = self.list_containers().await?;
+        Ok(Self::apply_list_query(containers, query))
+    }
+
+    /// Filter by status / `all` and sort by creation time.
+    fn apply_list_query(
+        mut containers: Vec<ContainerInfo>,
+        query: &ContainerListQuery,
+    ) -> Vec<ContainerInfo> {
+        if let Some(status) = &query.status {
+            containers.retain(|c| &c.status == status);
+        }
+        if !query.all.unwrap_or(true) {
+            containers.retain(|c| c.status != ContainerStatus::Exited);
+        }
+
+        // created_at is RFC 3339 in UTC, so string order is chronological
+        match query.sort.as_deref().unwrap_or("running") {
+            "oldest" => containers.sort_by(|a, b| a.created_at.cmp(&b.created_at)),
+            "newest" => containers.sort_by(|a, b| b.created_at.cmp(&a.created_at)),
+            _ => containers.sort_by(|a, b| {
+                let a_running = a.status == ContainerStatus::Running;
+                let b_running = b.status == ContainerStatus::Running;
+                b_running
+                    .cmp(&a_running)
+                    .then_with(|| b.created_at.cmp(&a.created_at))
+            }),
+        }
+        containers
+    }
+
     /// Create a new sandbox container with strict security limits.
     ///
     /// Security enforcement:
@@ -206,13 +435,14 @@ impl DockerService {
     ) -> Result<DockerCreateResponse, AppError> {
         let client = self.require_client()?;
 
-        // Enforce container limit
-        let existing = self.list_containers().await?;
-        if existing.len() >= MAX_CONTAINERS {
-            return Err(AppError::ContainerLimitExceeded(format!(
-                "Maximum of {} containers allowed. Remove existing containers first.",
-                MAX_CONTAINERS
-            )));
+        Self::validate_container_name(&request.name)?;
+
+        let container_port = request.container_port.unwrap_or(DEFAULT_CONTAINER_PORT);
+        let readonly_note = request.readonly.then(|| READONLY_NOTE.to_string());
+        if container_port == 0 {
+            return Err(AppError::BadRequest(
+                "Container port must be between 1 and 65535".to_string(),
+            ));
         }
 
         let image = request
@@ -221,10 +451,44 @@ impl DockerService {
             .unwrap_or(DEFAULT_IMAGE)
             .to_string();
 
-        // Build port bindings: container 3000 -> random host port on 127.0.0.1
+        // Enforce image allowlist
+        let allowlist = Self::load_image_allowlist();
+        if !Self::image_allowed(&image, &allowlist) {
+            return Err(AppError::BadRequest(format!(
+                "Image '{}' is not allowed. Allowed patterns: {}. Edit ~/{} to permit other images.",
+                image,
+                allowlist.join(", "),
+                IMAGE_ALLOWLIST_FILE
+            )));
+        }
+
+        // Warn (but don't refuse) when the image would run under emulation
+        let arch_warning = self.arch_warning(client, &image).await;
+        if let Some(warning) = &arch_warning {
+            warn!("{}", warning);
+        }
+
+        let pruned = if request.prune_exited {
+            self.prune_exited().await?.pruned
+        } else {
+            0
+        };
+
+        // Enforce container limit
+        let existing = self.list_containers().await?;
+        let max = Self::max_containers();
+        if existing.len() >= max {
+            return Err(AppError::ContainerLimitExceeded {
+                current: existing.len(),
+                max,
+            });
+        }
+
+        // Build port bindings: container app port -> random host port on 127.0.0.1
+        let port_key = format!("{}/tcp", container_port);
         let mut port_bindings = HashMap::new();
         port_bindings.insert(
-            "3000/tcp".to_string(),
+            port_key.clone(),
             Some(vec![PortBinding {
                 host_ip: Some("127.0.0.1".to_string()),
                 host_port: Some("0".to_string()), // random port
@@ -240,7 +504,10 @@ impl DockerService {
             security_opt: Some(vec!["no-new-privileges:true".to_string()]),
             cap_drop: Some(vec!["ALL".to_string()]),
             cap_add: Some(vec!["NET_BIND_SERVICE".to_string()]),
-            readonly_rootfs: Some(false), // OpenClaw needs to write to filesystem
+            // OpenClaw needs to write to the filesystem unless hardened mode
+            // gives it tmpfs for the writable paths
+            readonly_rootfs: Some(request.readonly),
+            tmpfs: Self::sandbox_tmpfs(request.readonly),
             port_bindings: Some(port_bindings),
             // CRITICAL: No binds, no Docker socket mounting
             ..Default::default()
@@ -249,13 +516,14 @@ impl DockerService {
         // Labels to identify our containers
         let mut labels = HashMap::new();
         labels.insert(OPENCLAW_LABEL.to_string(), "true".to_string());
+        labels.insert(PORT_LABEL.to_string(), container_port.to_string());
 
         let config = ContainerCreateBody {
             image: Some(image.clone()),
             user: Some("node".to_string()), // Non-root user (exists in node:20-alpine)
             host_config: Some(host_config),
             labels: Some(labels),
-            exposed_ports: Some(vec!["3000/tcp".to_string()]),
+            exposed_ports: Some(vec![port_key.clone()]),
             env: Some(vec!["OPENCLAW_SANDBOX=true".to_string()]),
             ..Default::default()
         };
@@ -268,13 +536,26 @@ impl DockerService {
         // Create the container
         let create_response = match client.create_container(Some(options), config).await {
             Ok(resp) => resp,
+            Err(bollard::errors::Error::DockerResponseServerError {
+                status_code: 409, ..
+            }) => {
+                return Err(AppError::ContainerNameConflict(format!(
+                    "A container named '{}' already exists. Choose a different name, \
+                     or remove the existing container (DELETE /api/docker/{}) and try again.",
+                    request.name, request.name
+                )));
+            }
             Err(e) => {
                 error!("Failed to create container: {}", e);
                 return Ok(DockerCreateResponse {
                     success: false,
                     container_id: None,
                     port: None,
+                    container_port,
+                    pruned,
                     error: Some(format!("Failed to create container: {}", e)),
+                    arch_warning,
+                    readonly_note,
                 });
             }
         };
@@ -298,7 +579,11 @@ impl DockerService {
                 success: false,
                 container_id: None,
                 port: None,
+                container_port,
+                pruned,
                 error: Some(format!("Container created but failed to start: {}", e)),
+                arch_warning,
+                readonly_note,
             });
         }
 
@@ -307,7 +592,7 @@ impl DockerService {
             Ok(inspect) => inspect
                 .network_settings
                 .and_then(|ns| ns.ports)
-                .and_then(|ports| ports.get("3000/tcp").cloned())
+                .and_then(|ports| ports.get(&port_key).cloned())
                 .flatten()
                 .and_then(|bindings| bindings.first().cloned())
                 .and_then(|binding| binding.host_port)
@@ -319,15 +604,188 @@ impl DockerService {
         };
 
         info!(
-            "Container {} created and started (port: {:?})",
-            container_id, mapped_port
+            "Container {} created and started (port: {:?} -> {})",
+            container_id, mapped_port, container_port
         );
 
         Ok(DockerCreateResponse {
             success: true,
             container_id: Some(container_id),
             port: mapped_port,
+            container_port,
+            pruned,
             error: None,
+            arch_warning,
+            readonly_note,
+        })
+    }
+
+    /// tmpfs mounts for a sandbox; none unless the root filesystem is read-only
+    fn sandbox_tmpfs(readonly: bool) -> Option<HashMap<String, String>> {
+        readonly.then(|| {
+            READONLY_TMPFS
+                .iter()
+                .map(|(path, options)| (path.to_string(), options.to_string()))
+                .collect()
+        })
+    }
+
+    /// Check the image's registry manifest for a variant matching the Docker
+    /// host's architecture. Returns None when it matches or can't be checked
+    /// (offline, private registry, daemon without distribution API).
+    async fn arch_warning(&self, client: &Docker, image: &str) -> Option<String> {
+        // The check asks the image's registry
+        if OfflineMode::is_enabled() {
+            return None;
+        }
+        let arch = match client.version().await.ok().and_then(|v| v.arch) {
+            Some(arch) => arch,
+            None => Self::docker_arch(std::env::consts::ARCH).to_string(),
+        };
+
+        match tokio::time::timeout(
+            ARCH_CHECK_TIMEOUT,
+            client.inspect_registry_image(image, None),
+        )
+        .await
+        {
+            Ok(Ok(inspect)) => {
+                let platforms: Vec<(String, String)> = inspect
+                    .platforms
+                    .into_iter()
+                    .map(|p| (p.os.unwrap_or_default(), p.architecture.unwrap_or_default()))
+                    .collect();
+                Self::platform_warning(image, &arch, &platforms)
+            }
+            Ok(Err(e)) => {
+                info!("Could not inspect platforms for image {}: {}", image, e);
+                None
+            }
+            Err(_) => {
+                info!("Timed out inspecting platforms for image {}", image);
+                None
+            }
+        }
+    }
+
+    /// Map a Rust target arch to Docker's platform naming.
+    fn docker_arch(rust_arch: &str) -> &str {
+        match rust_arch {
+            "x86_64" => "amd64",
+            "aarch64" => "arm64",
+            "arm" => "arm",
+            "x86" => "386",
+            other => other,
+        }
+    }
+
+    /// Warning text when none of the image's `(os, arch)` platforms is linux/`arch`.
+    fn platform_warning(image: &str, arch: &str, platforms: &[(String, String)]) -> Option<String> {
+        // An empty list means the registry didn't report platforms
+        if platforms.is_empty()
+            || platforms
+                .iter()
+                .any(|(os, a)| a == arch && (os.is_empty() || os == "linux"))
+        {
+            return None;
+        }
+
+        let available: Vec<String> = platforms
+            .iter()
+            .filter(|(_, a)| !a.is_empty() && a != "unknown")
+            .map(|(os, a)| format!("{}/{}", os, a))
+            .collect();
+        Some(format!(
+            "Image '{}' has no linux/{} variant (available: {}). It will run under \
+             emulation, which is slow and may crash; pick a multi-arch image.",
+            image,
+            arch,
+            available.join(", ")
+        ))
+    }
+
+    /// App port recorded in a container's labels (containers created before
+    /// the label existed used 3000).
+    fn labeled_container_port(labels: Option<&HashMap<String, String>>) -> u16 {
+        labels
+            .and_then(|labels| labels.get(PORT_LABEL))
+            .and_then(|p| p.parse::<u16>().ok())
+            .filter(|p| *p != 0)
+            .unwrap_or(DEFAULT_CONTAINER_PORT)
+    }
+
+    /// Validate a container name against Docker's naming rule.
+    fn validate_container_name(name: &str) -> Result<(), AppError> {
+        static NAME_REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = NAME_REGEX.get_or_init(|| {
+            Regex::new(r"^[a-zA-Z0-9][a-zA-Z0-9_.-]+$").expect("Invalid container name regex")
+        });
+
+        if !regex.is_match(name) {
+            return Err(AppError::BadRequest(format!(
+                "Invalid container name '{}'. Names must be at least 2 characters, start with a \
+                 letter or digit, and contain only letters, digits, '_', '.' or '-'.",
+                name
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Load image glob patterns from the allowlist file, falling back to the
+    /// built-in defaults when the file is missing or unreadable.
+    fn load_image_allowlist() -> Vec<String> {
+        let defaults = || {
+            DEFAULT_IMAGE_ALLOWLIST
+                .iter()
+                .map(|p| p.to_string())
+                .collect()
+        };
+
+        let path = match Platform::home_dir() {
+            Ok(home) => home.join(IMAGE_ALLOWLIST_FILE),
+            Err(_) => return defaults(),
+        };
+        if !path.exists() {
+            return defaults();
+        }
+
+        match ConfigWriter::read_json::<Vec<String>>(&path) {
+            Ok(patterns) => patterns,
+            Err(e) => {
+                warn!("Ignoring invalid image allowlist {}: {}", path.display(), e);
+                defaults()
+            }
+        }
+    }
+
+    /// Check an image reference against allowlist globs (`*` matches any run of characters).
+    fn image_allowed(image: &str, patterns: &[String]) -> bool {
+        patterns.iter().any(|p| glob_match(p, image))
+    }
+
+    /// Remove managed containers that are not running (Exited or Created state).
+    ///
+    /// Removal failures are logged and skipped so one stuck container does not
+    /// block pruning the rest.
+    pub async fn prune_exited(&self) -> Result<DockerPruneResponse, AppError> {
+        let containers = self.list_containers().await?;
+
+        let mut container_ids = Vec::new();
+        for container in containers
+            .iter()
+            .filter(|c| matches!(c.status, ContainerStatus::Exited | ContainerStatus::Created))
+        {
+            match self.remove_container(&container.id).await {
+                Ok(()) => container_ids.push(container.id.clone()),
+                Err(e) => warn!("Failed to prune container {}: {}", container.id, e),
+            }
+        }
+
+        info!("Pruned {} stale sandbox containers", container_ids.len());
+        Ok(DockerPruneResponse {
+            pruned: container_ids.len() as u32,
+            container_ids,
         })
     }
 
@@ -405,36 +863,8 @@ impl DockerService {
 
         while let Some(result) = stream.next().await {
             match result {
-                Ok(output) => {
-                    let line = match output {
-                        LogOutput::StdOut { message } => {
-                            String::from_utf8_lossy(&message).to_string()
-                        }
-                        LogOutput::StdErr { message } => {
-                            String::from_utf8_lossy(&message).to_string()
-                        }
-                        LogOutput::Console { message } => {
-                            String::from_utf8_lossy(&message).to_string()
-                        }
-                        LogOutput::StdIn { message } => {
-                            String::from_utf8_lossy(&message).to_string()
-                        }
-                    };
-                    // Trim trailing newline
-                    logs.push(line.trim_end_matches('\n').to_string());
-                }
-                Err(e) => {
-                    if e.to_string().contains("No such container") {
-                        return Err(AppError::ContainerNotFound(format!(
-                            "Container {} not found",
-                            container_id
-                        )));
-                    }
-                    return Err(AppError::DockerOperationFailed(format!(
-                        "Failed to read logs from container {}: {}",
-                        container_id, e
-                    )));
-                }
+                Ok(output) => logs.push(decode_log_output(output)),
+                Err(e) => return Err(logs_error(container_id, e)),
             }
         }
 
@@ -443,34 +873,372 @@ impl DockerService {
             logs,
         })
     }
+
+    /// Follow a container's logs, starting with the last `tail` lines.
+    ///
+    /// The stream ends when the container stops; dropping it closes the
+    /// underlying Docker connection.
+    pub fn follow_container_logs<'a>(
+        &'a self,
+        container_id: &'a str,
+        tail: u32,
+    ) -> Result<impl futures::Stream<Item = Result<String, AppError>> + 'a, AppError> {
+        let client = self.require_client()?;
+
+        let options = LogsOptions {
+            follow: true,
+            stdout: true,
+            stderr: true,
+            tail: tail.to_string(),
+            ..Default::default()
+        };
+
+        Ok(client.logs(container_id, Some(options)).map(move |result| {
+            result
+                .map(decode_log_output)
+                .map_err(|e| logs_error(container_id, e))
+        }))
+    }
+
+    /// Follow daemon events for managed containers
+    ///
+    /// Only the actions that change a container's state are requested.
+    pub fn container_events(
+        &self,
+    ) -> Result<impl futures::Stream<Item = Result<EventMessage, AppError>> + '_, AppError> {
+        let client = self.require_client()?;
+
+        let mut filters = HashMap::new();
+        filters.insert("type", vec!["container".to_string()]);
+        filters.insert("label", vec![format!("{}=true", OPENCLAW_LABEL)]);
+        filters.insert(
+            "event",
+            [
+                "create", "start", "die", "oom", "pause", "unpause", "destroy",
+            ]
+            .iter()
+            .map(|e| e.to_string())
+            .collect(),
+        );
+        let options = EventsOptionsBuilder::default().filters(&filters).build();
+
+        Ok(client.events(Some(options)).map(|result| {
+            result.map_err(|e| {
+                AppError::DockerOperationFailed(format!("Docker event stream failed: {}", e))
+            })
+        }))
+    }
+}
+
+/// Decode a bollard log frame into a text line (trailing newline trimmed).
+fn decode_log_output(output: LogOutput) -> String {
+    let line = match output {
+        LogOutput::StdOut { message } => String::from_utf8_lossy(&message).to_string(),
+        LogOutput::StdErr { message } => String::from_utf8_lossy(&message).to_string(),
+        LogOutput::Console { message } => String::from_utf8_lossy(&message).to_string(),
+        LogOutput::StdIn { message } => String::from_utf8_lossy(&message).to_string(),
+    };
+    line.trim_end_matches('\n').to_string()
+}
+
+/// Map a bollard log stream error to an AppError.
+fn logs_error(container_id: &str, e: bollard::errors::Error) -> AppError {
+    if e.to_string().contains("No such container") {
+        AppError::ContainerNotFound(format!("Container {} not found", container_id))
+    } else {
+        AppError::DockerOperationFailed(format!(
+            "Failed to read logs from container {}: {}",
+            container_id, e
+        ))
+    }
+}
+
+/// Minimal glob matcher supporting only `*` wildcards.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or("");
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No wildcard: exact match
+        return rest.is_empty();
+    };
+
+    for part in middle {
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_new_creates_service() {
+    #[tokio::test]
+    async fn test_new_creates_service() {
         // DockerService::new() should never panic, even without Docker
-        let service = DockerService::new();
+        let service = DockerService::new().await;
         // Client may or may not be Some depending on the environment
         assert!(service.client.is_some() || service.client.is_none());
     }
 
     #[test]
     fn test_require_client_when_none() {
-        let service = DockerService { client: None };
+        let service = DockerService {
+            client: None,
+            endpoint: None,
+            api_version: None,
+        };
         let result = service.require_client();
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_endpoint_candidates_order() {
+        let candidates = DockerService::endpoint_candidates(
+            Some("tcp://10.0.0.5:2375"),
+            Some("/run/user/1000"),
+            Some("/home/dev"),
+        );
+        assert_eq!(candidates[0], "tcp://10.0.0.5:2375");
+        if cfg!(unix) {
+            assert_eq!(candidates[1], "unix:///var/run/docker.sock");
+            assert_eq!(candidates[2], "unix:///run/user/1000/docker.sock");
+            assert_eq!(candidates[3], "unix:///home/dev/.docker/run/docker.sock");
+        }
+    }
+
+    #[test]
+    fn test_endpoint_candidates_ignores_empty_env() {
+        let candidates = DockerService::endpoint_candidates(Some(""), Some(""), None);
+        assert!(
+            !candidates
+                .iter()
+                .any(|c| c.is_empty() || c == "unix:///docker.sock")
+        );
+    }
+
+    #[test]
+    fn test_validate_container_name() {
+        assert!(DockerService::validate_container_name("openclaw-sandbox").is_ok());
+        assert!(DockerService::validate_container_name("Sandbox_1.test").is_ok());
+        assert!(DockerService::validate_container_name("a1").is_ok());
+    }
+
+    #[test]
+    fn test_validate_container_name_rejects_invalid() {
+        for name in [
+            "",
+            "a",
+            "my sandbox",
+            "-leading",
+            ".hidden",
+            "name/slash",
+            "emoji😀",
+        ] {
+            assert!(
+                DockerService::validate_container_name(name).is_err(),
+                "'{}' should be rejected",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_log_output_trims_newline() {
+        let output = LogOutput::StdOut {
+            message: "server listening\n".into(),
+        };
+        assert_eq!(decode_log_output(output), "server listening");
+
+        let output = LogOutput::StdErr {
+            message: "warn: low memory".into(),
+        };
+        assert_eq!(decode_log_output(output), "warn: low memory");
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("node:*-alpine", "node:20-alpine"));
+        assert!(glob_match("node:*-alpine", "node:22.1-alpine"));
+        assert!(!glob_match("node:*-alpine", "node:20"));
+        assert!(!glob_match("node:*-alpine", "evil/node:20-alpine"));
+        assert!(glob_match(
+            "ghcr.io/openclaw/*",
+            "ghcr.io/openclaw/sandbox:1"
+        ));
+        assert!(glob_match("redis:7", "redis:7"));
+        assert!(!glob_match("redis:7", "redis:7-alpine"));
+        assert!(glob_match("*", "anything"));
+    }
+
+    #[test]
+    fn test_default_allowlist_permits_default_image() {
+        let defaults: Vec<String> = DEFAULT_IMAGE_ALLOWLIST
+            .iter()
+            .map(|p| p.to_string())
+            .collect();
+        assert!(DockerService::image_allowed(DEFAULT_IMAGE, &defaults));
+        assert!(!DockerService::image_allowed("ubuntu:latest", &defaults));
+    }
+
+    #[test]
+    fn test_list_error_message_hints_at_permissions() {
+        let msg = DockerService::list_error_message(
+            "Failed to list containers: Permission denied (os error 13)",
+        );
+        assert!(msg.contains("could not be listed"));
+        assert!(msg.contains("docker' group"));
+
+        let msg = DockerService::list_error_message("Failed to list containers: timeout");
+        assert!(msg.contains("timeout"));
+        assert!(!msg.contains("docker' group"));
+    }
+
     #[test]
     fn test_max_containers_constant() {
         assert_eq!(MAX_CONTAINERS, 5);
     }
 
+    #[test]
+    fn test_sandbox_tmpfs_only_for_readonly() {
+        assert!(DockerService::sandbox_tmpfs(false).is_none());
+        let tmpfs = DockerService::sandbox_tmpfs(true).unwrap();
+        assert!(tmpfs["/tmp"].contains("size="));
+        assert!(tmpfs["/home/node"].contains("uid=1000"));
+    }
+
+    #[test]
+    fn test_max_containers_override() {
+        assert_eq!(DockerService::max_containers_from(None), MAX_CONTAINERS);
+        assert_eq!(DockerService::max_containers_from(Some("12")), 12);
+        assert_eq!(DockerService::max_containers_from(Some(" 8 ")), 8);
+        assert_eq!(
+            DockerService::max_containers_from(Some("0")),
+            MAX_CONTAINERS
+        );
+        assert_eq!(
+            DockerService::max_containers_from(Some("lots")),
+            MAX_CONTAINERS
+        );
+        assert_eq!(DockerService::max_containers_from(Some("")), MAX_CONTAINERS);
+    }
+
     #[test]
     fn test_default_image_constant() {
         assert_eq!(DEFAULT_IMAGE, "node:20-alpine");
     }
+
+    fn container(id: &str, status: ContainerStatus, created_at: &str) -> ContainerInfo {
+        ContainerInfo {
+            id: id.to_string(),
+            name: id.to_string(),
+            image: "node:20-alpine".to_string(),
+            status,
+            created_at: created_at.to_string(),
+            port: None,
+            container_port: DEFAULT_CONTAINER_PORT,
+        }
+    }
+
+    fn ids(containers: &[ContainerInfo]) -> Vec<&str> {
+        containers.iter().map(|c| c.id.as_str()).collect()
+    }
+
+    fn sample_containers() -> Vec<ContainerInfo> {
+        vec![
+            container(
+                "old-exited",
+                ContainerStatus::Exited,
+                "2026-01-01T00:00:00+00:00",
+            ),
+            container(
+                "old-running",
+                ContainerStatus::Running,
+                "2026-01-02T00:00:00+00:00",
+            ),
+            container(
+                "new-exited",
+                ContainerStatus::Exited,
+                "2026-01-03T00:00:00+00:00",
+            ),
+            container(
+                "new-running",
+                ContainerStatus::Running,
+                "2026-01-04T00:00:00+00:00",
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_list_query_defaults_to_running_first() {
+        let sorted = DockerService::apply_list_query(sample_containers(), &Default::default());
+        assert_eq!(
+            ids(&sorted),
+            vec!["new-running", "old-running", "new-exited", "old-exited"]
+        );
+    }
+
+    #[test]
+    fn test_list_query_filters_and_sorts() {
+        let query = ContainerListQuery {
+            all: Some(false),
+            sort: Some("oldest".into()),
+            ..Default::default()
+        };
+        let sorted = DockerService::apply_list_query(sample_containers(), &query);
+        assert_eq!(ids(&sorted), vec!["old-running", "new-running"]);
+
+        let query = ContainerListQuery {
+            status: Some(ContainerStatus::Exited),
+            sort: Some("newest".into()),
+            ..Default::default()
+        };
+        let sorted = DockerService::apply_list_query(sample_containers(), &query);
+        assert_eq!(ids(&sorted), vec!["new-exited", "old-exited"]);
+    }
+
+    #[test]
+    fn test_docker_arch_names() {
+        assert_eq!(DockerService::docker_arch("x86_64"), "amd64");
+        assert_eq!(DockerService::docker_arch("aarch64"), "arm64");
+        assert_eq!(DockerService::docker_arch("riscv64"), "riscv64");
+    }
+
+    #[test]
+    fn test_platform_warning() {
+        let multi = vec![
+            ("linux".to_string(), "amd64".to_string()),
+            ("linux".to_string(), "arm64".to_string()),
+            ("unknown".to_string(), "unknown".to_string()),
+        ];
+        assert_eq!(
+            DockerService::platform_warning("node:20-alpine", "arm64", &multi),
+            None
+        );
+        assert_eq!(DockerService::platform_warning("x", "arm64", &[]), None);
+
+        let amd64_only = vec![("linux".to_string(), "amd64".to_string())];
+        let warning = DockerService::platform_warning("legacy:1", "arm64", &amd64_only).unwrap();
+        assert!(warning.contains("no linux/arm64 variant"));
+        assert!(warning.contains("available: linux/amd64"));
+    }
+
+    #[test]
+    fn test_labeled_container_port() {
+        assert_eq!(DockerService::labeled_container_port(None), 3000);
+
+        let mut labels = HashMap::new();
+        labels.insert(PORT_LABEL.to_string(), "18789".to_string());
+        assert_eq!(DockerService::labeled_container_port(Some(&labels)), 18789);
+
+        labels.insert(PORT_LABEL.to_string(), "not-a-port".to_string());
+        assert_eq!(DockerService::labeled_container_port(Some(&labels)), 3000);
+    }
 }
@@ -0,0 +1,193 @@
+//! # Docker Events
+//!
+//! Background watcher on the Docker daemon's event stream. When a managed
+//! sandbox starts, dies, is OOM-killed or removed — including outside the
+//! wizard (`docker stop`, the OOM killer) — the change is recorded and
+//! broadcast to `/ws/docker/events` subscribers, so the UI doesn't go stale
+//! until the next list.
+//!
+//! The watcher reconnects when the daemon isn't running or the stream ends.
+
+use bollard::models::EventMessage;
+use futures::StreamExt;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tracing::{info, warn};
+
+use crate::models::types::{ContainerEvent, ContainerStatus};
+use crate::services::docker::DockerService;
+
+/// Delay before reconnecting to the daemon
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Events buffered per subscriber before it starts missing them
+const EVENT_CHANNEL_CAPACITY: usize = 64;
+
+static EVENTS: LazyLock<broadcast::Sender<ContainerEvent>> =
+    LazyLock::new(|| broadcast::channel(EVENT_CHANNEL_CAPACITY).0);
+
+/// Last event seen per container id
+static LAST_EVENTS: LazyLock<Mutex<HashMap<String, ContainerEvent>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+pub struct DockerEvents;
+
+impl DockerEvents {
+    /// Start the background watcher. Call once at startup.
+    pub fn spawn_watcher() {
+        tokio::spawn(async {
+            loop {
+                // When Docker isn't installed or running, check again later
+                let service = DockerService::new().await;
+                if let Ok(stream) = service.container_events() {
+                    info!("Watching Docker events for sandbox containers");
+                    futures::pin_mut!(stream);
+                    while let Some(item) = stream.next().await {
+                        match item {
+                            Ok(message) => {
+                                if let Some(event) = Self::from_message(&message) {
+                                    Self::record(event);
+                                }
+                            }
+                            Err(e) => {
+                                warn!("{}", e);
+                                break;
+                            }
+                        }
+                    }
+                }
+                tokio::time::sleep(RECONNECT_INTERVAL).await;
+            }
+        });
+    }
+
+    /// Receive events as they happen
+    pub fn subscribe() -> broadcast::Receiver<ContainerEvent> {
+        EVENTS.subscribe()
+    }
+
+    /// Latest event for each container the watcher has seen
+    pub fn snapshot() -> Vec<ContainerEvent> {
+        let mut events: Vec<ContainerEvent> = LAST_EVENTS
+            .lock()
+            .map(|last| last.values().cloned().collect())
+            .unwrap_or_default();
+        events.sort_by(|a, b| a.time.cmp(&b.time));
+        events
+    }
+
+    /// Remember the container's new state and notify subscribers
+    fn record(event: ContainerEvent) {
+        if let Ok(mut last) = LAST_EVENTS.lock() {
+            if event.status.is_some() {
+                last.insert(event.container_id.clone(), event.clone());
+            } else {
+                last.remove(&event.container_id);
+            }
+        }
+        // No subscribers is not an error
+        let _ = EVENTS.send(event);
+    }
+
+    /// Convert a daemon event; None for events without a container id or
+    /// that don't change its state
+    fn from_message(message: &EventMessage) -> Option<ContainerEvent> {
+        let actor = message.actor.as_ref()?;
+        let container_id = actor.id.clone()?;
+        let action = message.action.clone()?;
+        let attribute = |key: &str| actor.attributes.as_ref().and_then(|a| a.get(key)).cloned();
+        let exit_code = attribute("exitCode").and_then(|code| code.parse::<i32>().ok());
+
+        let status = match action.as_str() {
+            "create" => Some(ContainerStatus::Created),
+            "start" | "unpause" => Some(ContainerStatus::Running),
+            "pause" => Some(ContainerStatus::Stopped),
+            "die" => Some(ContainerStatus::Exited),
+            "oom" => Some(ContainerStatus::Error),
+            "destroy" => None,
+            _ => return None,
+        };
+
+        Some(ContainerEvent {
+            container_id,
+            name: attribute("name"),
+            action,
+            status,
+            exit_code,
+            time: message
+                .time
+                .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+                .map(|t| t.to_rfc3339()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::models::EventActor;
+
+    fn message(action: &str, attributes: &[(&str, &str)]) -> EventMessage {
+        EventMessage {
+            action: Some(action.to_string()),
+            actor: Some(EventActor {
+                id: Some("abc123".to_string()),
+                attributes: Some(
+                    attributes
+                        .iter()
+                        .map(|(k, v)| (k.to_string(), v.to_string()))
+                        .collect(),
+                ),
+            }),
+            time: Some(1_771_200_000),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_from_message_maps_state_changes() {
+        let died = DockerEvents::from_message(&message(
+            "die",
+            &[("name", "openclaw-sandbox"), ("exitCode", "137")],
+        ))
+        .unwrap();
+        assert_eq!(died.container_id, "abc123");
+        assert_eq!(died.name.as_deref(), Some("openclaw-sandbox"));
+        assert_eq!(died.status, Some(ContainerStatus::Exited));
+        assert_eq!(died.exit_code, Some(137));
+        assert!(died.time.unwrap().starts_with("2026-02-16"));
+
+        let started = DockerEvents::from_message(&message("start", &[])).unwrap();
+        assert_eq!(started.status, Some(ContainerStatus::Running));
+
+        let removed = DockerEvents::from_message(&message("destroy", &[])).unwrap();
+        assert_eq!(removed.status, None);
+    }
+
+    #[test]
+    fn test_from_message_ignores_other_events() {
+        assert!(DockerEvents::from_message(&message("exec_start", &[])).is_none());
+        assert!(DockerEvents::from_message(&EventMessage::default()).is_none());
+    }
+
+    #[test]
+    fn test_record_updates_snapshot_and_notifies() {
+        let mut rx = DockerEvents::subscribe();
+        let mut event = DockerEvents::from_message(&message("start", &[])).unwrap();
+        event.container_id = "record-test".to_string();
+
+        DockerEvents::record(event.clone());
+        assert!(DockerEvents::snapshot().contains(&event));
+        assert_eq!(rx.try_recv().unwrap(), event);
+
+        event.status = None;
+        DockerEvents::record(event);
+        assert!(
+            !DockerEvents::snapshot()
+                .iter()
+                .any(|e| e.container_id == "record-test")
+        );
+    }
+}
@@ -5,7 +5,7 @@
 //! missing command gracefully for older OpenClaw versions.
 
 use crate::models::types::{DiagnosticCheck, DoctorReport};
-use crate::services::command::SafeCommand;
+use crate::services::command::{SafeCommand, strip_ansi};
 use anyhow::Result;
 use chrono::Utc;
 
@@ -65,7 +65,7 @@ impl DoctorService {
         let mut section_lines: Vec<String> = Vec::new();
 
         // Strip ANSI escape codes for clean parsing
-        let clean = Self::strip_ansi(text);
+        let clean = strip_ansi(text);
 
         for line in clean.lines() {
             let trimmed = line.trim();
@@ -142,26 +142,6 @@ impl DoctorService {
         }
     }
 
-    /// Strip ANSI escape sequences from text
-    fn strip_ansi(text: &str) -> String {
-        let mut result = String::with_capacity(text.len());
-        let mut chars = text.chars().peekable();
-        while let Some(ch) = chars.next() {
-            if ch == '\x1b' {
-                // Skip until we hit a letter (end of escape sequence)
-                while let Some(&next) = chars.peek() {
-                    chars.next();
-                    if next.is_ascii_alphabetic() {
-                        break;
-                    }
-                }
-            } else {
-                result.push(ch);
-            }
-        }
-        result
-    }
-
     /// Extract section name from a header line like "◇  Section Name ───╮"
     fn extract_section_name(line: &str) -> Option<String> {
         // Look for ◇ followed by section name, or just a line ending with ─╮
@@ -538,13 +518,6 @@ Agents: main (default)
         );
     }
 
-    #[test]
-    fn test_strip_ansi() {
-        let text = "\x1b[32mPASS\x1b[0m: Node installed";
-        let clean = DoctorService::strip_ansi(text);
-        assert_eq!(clean, "PASS: Node installed");
-    }
-
     #[test]
     fn test_extract_section_name() {
         assert_eq!(
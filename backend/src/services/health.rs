@@ -1,40 +1,372 @@
 //! # Health Monitoring Service
 //!
-//! Monitors OpenClaw gateway health by executing `openclaw health --json`
-//! and parsing the output. Returns structured health data including channel status.
+//! Monitors OpenClaw gateway health by asking the gateway itself: an
+//! unauthenticated `GET /health` on the configured host and port. When that
+//! endpoint can't be reached or doesn't answer with health JSON, it falls back
+//! to `openclaw health --json` and then to inference from the config and
+//! whether the port is open. Returns structured health data including
+//! channel status; `source` records which way the snapshot was produced.
+//! Also provides the readiness check (valid config + reachable gateway port)
+//! used by `/api/health/ready`.
+//!
+//! A failed probe is classified as "unauthorized" when the gateway is up but
+//! rejected the probe's credentials (an auth error in the CLI output, or the
+//! port is listening with token/password auth on), otherwise "unreachable".
+//!
+//! Both checks also look up the port the running gateway process actually
+//! bound. When the config was edited without a restart the two differ, and
+//! the snapshot carries a `port_mismatch` hint instead of a bare failure.
 
-use crate::models::types::{ChannelHealth, HealthSnapshot};
+use crate::models::types::{ChannelHealth, HealthSnapshot, ReadinessCheck, ReadinessReport};
 use crate::services::command::SafeCommand;
 use crate::services::config::ConfigWriter;
-use std::path::PathBuf;
+use crate::services::platform::{DEFAULT_GATEWAY_PORT, Platform};
+use crate::services::processes::ProcessService;
+use std::path::Path;
+use std::time::Duration;
+
+/// How long the readiness probe waits for the gateway port
+const GATEWAY_CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Path of the gateway's own health endpoint, served without auth
+const GATEWAY_HEALTH_PATH: &str = "/health";
+
+/// How long the gateway's health endpoint gets to answer
+const GATEWAY_HEALTH_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// How long the health probe waits for the gateway port after a failed check
+const HEALTH_CONNECT_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// HTTP statuses that mean the gateway rejected the probe's credentials
+const AUTH_FAILURE_STATUSES: &[&str] = &["401", "403"];
+
+/// Lowercase fragments of CLI output that indicate rejected credentials
+const AUTH_FAILURE_MARKERS: &[&str] = &[
+    "unauthorized",
+    "forbidden",
+    "invalid token",
+    "token mismatch",
+    "authentication failed",
+    "auth failed",
+];
 
 pub struct HealthService;
 
 impl HealthService {
     /// Get current health snapshot of the OpenClaw gateway
     ///
-    /// Executes `openclaw health --json` and parses the output.
-    /// If the command fails or daemon is not running, returns an unreachable snapshot
-    /// with channels populated from the saved config file as fallback.
+    /// Asks the gateway's `/health` endpoint first. If it doesn't answer,
+    /// executes `openclaw health --json` and parses the output; if that fails
+    /// too, returns an unreachable (or unauthorized) snapshot with channels
+    /// populated from the saved config file as fallback.
     /// This method NEVER errors - it always returns a valid HealthSnapshot.
-    pub fn get_health() -> HealthSnapshot {
-        // Try to execute health check
-        let output = match SafeCommand::run("openclaw", &["health", "--json"]) {
-            Ok(out) => out,
-            Err(_) => {
-                return Self::unreachable_with_config_channels();
+    pub async fn get_health() -> HealthSnapshot {
+        let config = Platform::openclaw_config_path()
+            .ok()
+            .and_then(|path| Self::read_config(&path).ok());
+        let port = config
+            .as_ref()
+            .map(Self::gateway_port)
+            .unwrap_or(DEFAULT_GATEWAY_PORT);
+        let host = Self::gateway_host(config.as_ref());
+
+        let mut snapshot = match Self::probe_gateway_endpoint(&host, port).await {
+            Some(mut snapshot) => {
+                snapshot.auth_mode = config
+                    .as_ref()
+                    .map(Self::auth_mode)
+                    .unwrap_or_else(|| "none".to_string());
+                snapshot
+            }
+            None => tokio::task::spawn_blocking(move || Self::probe(config.as_ref(), port))
+                .await
+                .unwrap_or_else(|_| Self::unreachable_with_config_channels()),
+        };
+
+        let listening = tokio::task::spawn_blocking(Self::gateway_listening_ports)
+            .await
+            .unwrap_or_default();
+        let (listening_port, mismatch) = Self::check_listening_port(port, &listening);
+        if let Some(hint) = &mismatch
+            && !snapshot.gateway_reachable
+        {
+            snapshot.gateway_detail = Some(hint.clone());
+        }
+        snapshot.listening_port = listening_port;
+        snapshot.port_mismatch = mismatch;
+        snapshot
+    }
+
+    /// Ask the gateway's own health endpoint, without credentials
+    ///
+    /// None when the gateway doesn't answer or the reply isn't health JSON
+    /// (e.g. the control UI's HTML fallback on a gateway without the endpoint).
+    async fn probe_gateway_endpoint(host: &str, port: u16) -> Option<HealthSnapshot> {
+        let url = format!(
+            "http://{}{}",
+            Self::host_port(host, port),
+            GATEWAY_HEALTH_PATH
+        );
+        let client = reqwest::Client::builder()
+            .timeout(GATEWAY_HEALTH_TIMEOUT)
+            .build()
+            .ok()?;
+        let response = client
+            .get(&url)
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .ok()?;
+        if !response.status().is_success() {
+            return None;
+        }
+        let json: serde_json::Value = response.json().await.ok()?;
+        json.get("ok")?.as_bool()?;
+
+        let mut snapshot = Self::parse_health_json(json);
+        snapshot.source = Some("gateway".to_string());
+        Some(snapshot)
+    }
+
+    /// Host to reach the gateway on, from `gateway.bind`
+    ///
+    /// Loopback and wildcard binds are reached on 127.0.0.1; an explicit
+    /// address is used as-is.
+    fn gateway_host(config: Option<&serde_json::Value>) -> String {
+        let bind = config
+            .and_then(|c| c["gateway"]["bind"].as_str())
+            .unwrap_or("loopback");
+        match bind.parse::<std::net::IpAddr>() {
+            Ok(addr) if !addr.is_unspecified() => addr.to_string(),
+            _ => "127.0.0.1".to_string(),
+        }
+    }
+
+    /// `host:port`, bracketing IPv6 hosts
+    fn host_port(host: &str, port: u16) -> String {
+        if host.contains(':') {
+            format!("[{}]:{}", host, port)
+        } else {
+            format!("{}:{}", host, port)
+        }
+    }
+
+    /// Probe the gateway with `openclaw health --json`, classifying failures
+    fn probe(config: Option<&serde_json::Value>, port: u16) -> HealthSnapshot {
+        let auth_mode = config
+            .map(Self::auth_mode)
+            .unwrap_or_else(|| "none".to_string());
+
+        let failure_output = match SafeCommand::run("openclaw", &["health", "--json"]) {
+            Ok(output) if output.exit_code == 0 => {
+                match serde_json::from_str::<serde_json::Value>(&output.stdout) {
+                    Ok(json) => {
+                        let mut snapshot = Self::parse_health_json(json);
+                        snapshot.source = Some("cli".to_string());
+                        snapshot.auth_mode = auth_mode.clone();
+                        if snapshot.gateway_reachable {
+                            return snapshot;
+                        }
+                        let (status, detail) = Self::classify_failure(
+                            &output.stdout,
+                            Self::port_listening(port),
+                            &auth_mode,
+                        );
+                        snapshot.gateway_status = status.to_string();
+                        snapshot.gateway_detail = detail;
+                        return snapshot;
+                    }
+                    Err(_) => output.stdout,
+                }
             }
+            Ok(output) => format!("{}\n{}", output.stdout, output.stderr),
+            Err(e) => e.to_string(),
         };
 
-        // Check if command succeeded
-        if output.exit_code != 0 {
-            return Self::unreachable_with_config_channels();
+        let (status, detail) =
+            Self::classify_failure(&failure_output, Self::port_listening(port), &auth_mode);
+        let mut snapshot = Self::unreachable_with_config_channels();
+        snapshot.gateway_status = status.to_string();
+        snapshot.gateway_detail = detail;
+        snapshot.auth_mode = auth_mode;
+        snapshot.source = Some("inferred".to_string());
+        snapshot
+    }
+
+    /// Classify a failed health probe from the CLI output, whether the
+    /// gateway port accepts connections, and the configured auth mode
+    fn classify_failure(
+        output: &str,
+        port_listening: bool,
+        auth_mode: &str,
+    ) -> (&'static str, Option<String>) {
+        let lower = output.to_lowercase();
+        // Status codes must be whole words so ports like 4010 don't match
+        let auth_status = lower
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|word| AUTH_FAILURE_STATUSES.contains(&word));
+        if auth_status || AUTH_FAILURE_MARKERS.iter().any(|m| lower.contains(m)) {
+            return (
+                "unauthorized",
+                Some(format!(
+                    "Gateway rejected the health probe's credentials (auth mode: {}). \
+                     Check gateway.auth in openclaw.json.",
+                    auth_mode
+                )),
+            );
         }
+        if port_listening && matches!(auth_mode, "token" | "password") {
+            return (
+                "unauthorized",
+                Some(format!(
+                    "Gateway is listening with {} auth but the health probe failed. \
+                     Check that the configured {} matches the running gateway.",
+                    auth_mode, auth_mode
+                )),
+            );
+        }
+        if port_listening {
+            return (
+                "unreachable",
+                Some("Gateway port is open but the gateway isn't responding".to_string()),
+            );
+        }
+        ("unreachable", None)
+    }
 
-        // Try to parse JSON output
-        match serde_json::from_str::<serde_json::Value>(&output.stdout) {
-            Ok(json) => Self::parse_health_json(json),
-            Err(_) => Self::unreachable_with_config_channels(),
+    /// Ports the running gateway processes listen on, sorted
+    fn gateway_listening_ports() -> Vec<u16> {
+        let mut ports: Vec<u16> = ProcessService::gateway_pids()
+            .into_iter()
+            .flat_map(ProcessService::listening_ports)
+            .collect();
+        ports.sort_unstable();
+        ports.dedup();
+        ports
+    }
+
+    /// The port the running gateway listens on and, when it isn't the
+    /// configured one, a restart hint
+    ///
+    /// `listening` is empty when no gateway runs or its sockets can't be read,
+    /// in which case nothing is reported.
+    fn check_listening_port(configured: u16, listening: &[u16]) -> (Option<u16>, Option<String>) {
+        match listening.first() {
+            None => (None, None),
+            Some(_) if listening.contains(&configured) => (Some(configured), None),
+            Some(&actual) => (
+                Some(actual),
+                Some(format!(
+                    "The config sets gateway port {} but the running gateway is listening \
+                     on {}. Restart the gateway to apply the port change.",
+                    configured, actual
+                )),
+            ),
+        }
+    }
+
+    /// Gateway auth mode from config ("none" when unset)
+    fn auth_mode(config: &serde_json::Value) -> String {
+        config["gateway"]["auth"]["mode"]
+            .as_str()
+            .unwrap_or("none")
+            .to_string()
+    }
+
+    /// The gateway port from openclaw.json
+    pub(crate) fn configured_gateway_port() -> u16 {
+        Platform::openclaw_config_path()
+            .ok()
+            .and_then(|path| Self::read_config(&path).ok())
+            .as_ref()
+            .map(Self::gateway_port)
+            .unwrap_or(DEFAULT_GATEWAY_PORT)
+    }
+
+    /// Whether something accepts TCP connections on the local gateway port
+    pub(crate) fn port_listening(port: u16) -> bool {
+        let addr = std::net::SocketAddr::from(([127, 0, 0, 1], port));
+        std::net::TcpStream::connect_timeout(&addr, HEALTH_CONNECT_TIMEOUT).is_ok()
+    }
+
+    /// Check whether OpenClaw is ready to serve: config parses and the
+    /// gateway accepts TCP connections on its configured port
+    pub async fn readiness() -> ReadinessReport {
+        let config = Platform::openclaw_config_path()
+            .map_err(|e| e.to_string())
+            .and_then(|path| Self::read_config(&path));
+
+        let config_check = match &config {
+            Ok(_) => ReadinessCheck {
+                name: "config".into(),
+                ok: true,
+                message: "openclaw.json is valid".into(),
+            },
+            Err(e) => ReadinessCheck {
+                name: "config".into(),
+                ok: false,
+                message: e.clone(),
+            },
+        };
+
+        let port = config
+            .as_ref()
+            .ok()
+            .map(Self::gateway_port)
+            .unwrap_or(DEFAULT_GATEWAY_PORT);
+        let mut gateway_check = Self::check_gateway_port(port).await;
+        if !gateway_check.ok {
+            let listening = tokio::task::spawn_blocking(Self::gateway_listening_ports)
+                .await
+                .unwrap_or_default();
+            if let (_, Some(hint)) = Self::check_listening_port(port, &listening) {
+                gateway_check.message = format!("{}. {}", gateway_check.message, hint);
+            }
+        }
+
+        let checks = vec![config_check, gateway_check];
+        ReadinessReport {
+            ready: checks.iter().all(|c| c.ok),
+            checks,
+        }
+    }
+
+    /// Read and parse openclaw.json, requiring a JSON object
+    fn read_config(path: &Path) -> Result<serde_json::Value, String> {
+        if !path.exists() {
+            return Err(format!("Config not found at {}", path.display()));
+        }
+        let config: serde_json::Value = ConfigWriter::read_json(path)
+            .map_err(|e| format!("Invalid config at {}: {:#}", path.display(), e))?;
+        if !config.is_object() {
+            return Err(format!("Config at {} is not a JSON object", path.display()));
+        }
+        Ok(config)
+    }
+
+    /// Gateway port from config, falling back to the default
+    fn gateway_port(config: &serde_json::Value) -> u16 {
+        config["gateway"]["port"]
+            .as_u64()
+            .and_then(|p| u16::try_from(p).ok())
+            .unwrap_or(DEFAULT_GATEWAY_PORT)
+    }
+
+    async fn check_gateway_port(port: u16) -> ReadinessCheck {
+        let addr = format!("127.0.0.1:{}", port);
+        let connect = tokio::net::TcpStream::connect(&addr);
+        let (ok, message) = match tokio::time::timeout(GATEWAY_CONNECT_TIMEOUT, connect).await {
+            Ok(Ok(_)) => (true, format!("Gateway listening on {}", addr)),
+            Ok(Err(e)) => (false, format!("Gateway not reachable on {}: {}", addr, e)),
+            Err(_) => (
+                false,
+                format!("Timed out connecting to gateway on {}", addr),
+            ),
+        };
+        ReadinessCheck {
+            name: "gateway".into(),
+            ok,
+            message,
         }
     }
 
@@ -44,6 +376,12 @@ impl HealthService {
         HealthSnapshot {
             gateway_reachable: false,
             gateway_mode: "unreachable".to_string(),
+            gateway_status: "unreachable".to_string(),
+            auth_mode: "none".to_string(),
+            gateway_detail: None,
+            listening_port: None,
+            port_mismatch: None,
+            source: None,
             channels,
             session_count: 0,
             probe_duration_ms: 0,
@@ -52,8 +390,10 @@ impl HealthService {
 
     /// Read configured channels from openclaw.json as offline fallback
     fn channels_from_config() -> Vec<ChannelHealth> {
-        let home = std::env::var("HOME").unwrap_or_default();
-        let path = PathBuf::from(&home).join(".openclaw/openclaw.json");
+        let path = match Platform::openclaw_config_path() {
+            Ok(p) => p,
+            Err(_) => return vec![],
+        };
 
         let config = match ConfigWriter::read_json::<serde_json::Value>(&path) {
             Ok(c) => c,
@@ -99,11 +439,12 @@ impl HealthService {
         // "ok" field indicates gateway is reachable
         let gateway_reachable = json.get("ok").and_then(|v| v.as_bool()).unwrap_or(false);
 
-        // No explicit mode field; derive from reachability
-        let gateway_mode = if gateway_reachable {
-            "connected".to_string()
-        } else {
-            "unreachable".to_string()
+        // The gateway endpoint reports its mode; the CLI output doesn't,
+        // so derive it from reachability there
+        let gateway_mode = match json.get("mode").and_then(|v| v.as_str()) {
+            Some(mode) if gateway_reachable => mode.to_string(),
+            _ if gateway_reachable => "connected".to_string(),
+            _ => "unreachable".to_string(),
         };
 
         // Session count is nested under sessions.count
@@ -160,6 +501,17 @@ impl HealthService {
         HealthSnapshot {
             gateway_reachable,
             gateway_mode,
+            gateway_status: if gateway_reachable {
+                "healthy"
+            } else {
+                "unreachable"
+            }
+            .to_string(),
+            auth_mode: "none".to_string(),
+            gateway_detail: None,
+            listening_port: None,
+            port_mismatch: None,
+            source: None,
             channels,
             session_count,
             probe_duration_ms,
@@ -196,6 +548,12 @@ impl HealthService {
         HealthSnapshot {
             gateway_reachable: false,
             gateway_mode: "unreachable".to_string(),
+            gateway_status: "unreachable".to_string(),
+            auth_mode: "none".to_string(),
+            gateway_detail: None,
+            listening_port: None,
+            port_mismatch: None,
+            source: None,
             channels: vec![],
             session_count: 0,
             probe_duration_ms: 0,
@@ -208,9 +566,34 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_get_health_never_panics() {
+    fn test_read_config_validates_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("openclaw.json");
+        assert!(HealthService::read_config(&path).is_err());
+
+        std::fs::write(&path, "{not json").unwrap();
+        assert!(HealthService::read_config(&path).is_err());
+
+        std::fs::write(&path, r#"{"gateway": {"port": 4100}}"#).unwrap();
+        let config = HealthService::read_config(&path).unwrap();
+        assert_eq!(HealthService::gateway_port(&config), 4100);
+        assert_eq!(HealthService::gateway_port(&serde_json::json!({})), 18789);
+    }
+
+    #[tokio::test]
+    async fn test_check_gateway_port() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        assert!(HealthService::check_gateway_port(port).await.ok);
+
+        drop(listener);
+        assert!(!HealthService::check_gateway_port(port).await.ok);
+    }
+
+    #[tokio::test]
+    async fn test_get_health_never_panics() {
         // Should always return a valid HealthSnapshot, even if daemon not running
-        let health = HealthService::get_health();
+        let health = HealthService::get_health().await;
 
         // Should have valid fields
         assert!(!health.gateway_mode.is_empty());
@@ -238,6 +621,7 @@ mod tests {
 
         assert!(health.gateway_reachable);
         assert_eq!(health.gateway_mode, "connected");
+        assert_eq!(health.gateway_status, "healthy");
         assert_eq!(health.session_count, 3);
         assert_eq!(health.probe_duration_ms, 145);
         assert_eq!(health.channels.len(), 1);
@@ -262,6 +646,124 @@ mod tests {
         assert_eq!(health.channels.len(), 0);
     }
 
+    #[test]
+    fn test_classify_failure() {
+        let (status, detail) =
+            HealthService::classify_failure("Error: 401 Unauthorized", false, "token");
+        assert_eq!(status, "unauthorized");
+        assert!(detail.unwrap().contains("auth mode: token"));
+
+        // Listening gateway with auth on, no explicit auth error
+        let (status, _) = HealthService::classify_failure("gateway closed", true, "password");
+        assert_eq!(status, "unauthorized");
+
+        let (status, detail) = HealthService::classify_failure("timeout", true, "none");
+        assert_eq!(status, "unreachable");
+        assert!(detail.is_some());
+
+        let (status, detail) =
+            HealthService::classify_failure("ECONNREFUSED 127.0.0.1:4010", false, "token");
+        assert_eq!(status, "unreachable");
+        assert!(detail.is_none());
+    }
+
+    /// Serve one canned HTTP response on a local port
+    async fn serve_once(content_type: &'static str, body: &'static str) -> u16 {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = [0u8; 1024];
+            let _ = stream.read(&mut request).await;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                content_type,
+                body.len(),
+                body
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+        });
+        port
+    }
+
+    #[tokio::test]
+    async fn test_probe_gateway_endpoint() {
+        let port = serve_once(
+            "application/json",
+            r#"{"ok":true,"mode":"local","sessions":{"count":3},"channels":{"telegram":{"status":"connected"}}}"#,
+        )
+        .await;
+        let snapshot = HealthService::probe_gateway_endpoint("127.0.0.1", port)
+            .await
+            .unwrap();
+        assert!(snapshot.gateway_reachable);
+        assert_eq!(snapshot.gateway_mode, "local");
+        assert_eq!(snapshot.session_count, 3);
+        assert_eq!(snapshot.channels[0].platform, "telegram");
+        assert_eq!(snapshot.source.as_deref(), Some("gateway"));
+
+        // A control UI page instead of health JSON means "no endpoint"
+        let port = serve_once("text/html", "<html></html>").await;
+        assert!(
+            HealthService::probe_gateway_endpoint("127.0.0.1", port)
+                .await
+                .is_none()
+        );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let closed = listener.local_addr().unwrap().port();
+        drop(listener);
+        assert!(
+            HealthService::probe_gateway_endpoint("127.0.0.1", closed)
+                .await
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_gateway_host_from_bind() {
+        let host = |bind: &str| {
+            HealthService::gateway_host(Some(&serde_json::json!({ "gateway": { "bind": bind } })))
+        };
+        assert_eq!(host("loopback"), "127.0.0.1");
+        assert_eq!(host("0.0.0.0"), "127.0.0.1");
+        assert_eq!(host("192.168.1.20"), "192.168.1.20");
+        assert_eq!(HealthService::gateway_host(None), "127.0.0.1");
+        assert_eq!(HealthService::host_port("::1", 18789), "[::1]:18789");
+        assert_eq!(
+            HealthService::host_port("127.0.0.1", 18789),
+            "127.0.0.1:18789"
+        );
+    }
+
+    #[test]
+    fn test_check_listening_port() {
+        assert_eq!(
+            HealthService::check_listening_port(18789, &[]),
+            (None, None)
+        );
+        assert_eq!(
+            HealthService::check_listening_port(18789, &[18789, 18791]),
+            (Some(18789), None)
+        );
+
+        let (port, hint) = HealthService::check_listening_port(4100, &[18789, 18791]);
+        assert_eq!(port, Some(18789));
+        let hint = hint.unwrap();
+        assert!(hint.contains("port 4100"));
+        assert!(hint.contains("listening on 18789"));
+        assert!(hint.contains("Restart the gateway"));
+    }
+
+    #[test]
+    fn test_auth_mode_from_config() {
+        let config = serde_json::json!({"gateway": {"auth": {"mode": "token"}}});
+        assert_eq!(HealthService::auth_mode(&config), "token");
+        assert_eq!(HealthService::auth_mode(&serde_json::json!({})), "none");
+    }
+
     #[test]
     fn test_unreachable_snapshot() {
         let snapshot = HealthService::unreachable_snapshot("Test error");
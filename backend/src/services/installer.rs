@@ -1,16 +1,216 @@
 //! # Installation Service
 //!
 //! Handles Node.js and OpenClaw installation with streaming progress updates.
+//! Running installs are tracked by id so they can be cancelled; each child
+//! process leads its own process group, and cancelling sends the group
+//! SIGTERM (sudo relays it to the root command) and waits for the child to
+//! exit before reporting the cancel. Long, quiet npm installs send a heartbeat every
+//! HEARTBEAT_INTERVAL so the UI doesn't look frozen.
 
 use anyhow::{Context, Result};
+use regex::Regex;
+use std::collections::HashMap;
 use std::process::Stdio;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
 use tokio::io::{AsyncBufReadExt, BufReader};
-use tokio::process::Command as TokioCommand;
-use tokio::sync::mpsc;
+use tokio::process::{Child, Command as TokioCommand};
+use tokio::sync::{Mutex as AsyncMutex, mpsc};
+use tokio::task::{JoinHandle, JoinSet};
+use tracing::{Instrument, error, warn};
+
+use crate::error::AppError;
+use crate::models::types::NodeSource;
+use crate::models::types::{InstallPlan, InstallPlanStage};
+use crate::models::{InstallProgress, InstallRequest, OnboardPreview, WizardConfig};
+use crate::services::command::{NO_COLOR_ENV, SafeCommand, describe_status, exit_signal};
+use crate::services::node::{NodeDetector, NodeInstallation};
+use crate::services::offline::OfflineMode;
+use crate::services::provider_mapping::{ProviderAuth, ProviderInput};
+use crate::services::redact::redact_secrets;
+use crate::services::service_manager::ServiceManager;
+use crate::services::wizard_config::WizardConfigStore;
+
+/// Send a "still working" update after this long without new output
+pub(crate) const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Severity of one line of npm output: "error", "warning" or "info"
+///
+/// npm prints deprecation notices and `npm WARN` lines on most installs;
+/// those are warnings, not failures.
+pub(crate) fn npm_line_level(line: &str) -> &'static str {
+    let lower = line.to_ascii_lowercase();
+    if lower.starts_with("npm err")
+        || lower.starts_with("npm error")
+        || lower.contains("gyp err!")
+        || line.contains("EACCES")
+        || line.contains("EPERM")
+    {
+        "error"
+    } else if lower.starts_with("npm warn") || lower.contains("deprecated") {
+        "warning"
+    } else {
+        "info"
+    }
+}
+
+/// Heartbeat text for a quiet stage, e.g. "Still installing OpenClaw... (1m 05s elapsed)"
+pub(crate) fn heartbeat_message(action: &str, elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs >= 60 {
+        format!(
+            "Still {}... ({}m {:02}s elapsed)",
+            action,
+            secs / 60,
+            secs % 60
+        )
+    } else {
+        format!("Still {}... ({}s elapsed)", action, secs)
+    }
+}
+
+/// Buffered progress messages between the install stages and the stream
+const PLAN_CHANNEL_CAPACITY: usize = 100;
+
+/// Tracks when each progress stage started, to report how long it took
+#[derive(Debug, Default)]
+pub(crate) struct StageTimer {
+    started: HashMap<String, Instant>,
+}
+
+impl StageTimer {
+    /// Record an update for `stage`; returns the stage's elapsed time in
+    /// milliseconds when `status` ends it ("completed" or "failed")
+    pub(crate) fn observe(&mut self, stage: &str, status: &str) -> Option<u32> {
+        self.observe_at(stage, status, Instant::now())
+    }
+
+    fn observe_at(&mut self, stage: &str, status: &str, now: Instant) -> Option<u32> {
+        let started = *self.started.entry(stage.to_string()).or_insert(now);
+        matches!(status, "completed" | "failed")
+            .then(|| u32::try_from(now.duration_since(started).as_millis()).unwrap_or(u32::MAX))
+    }
+}
+
+/// Child processes started by one install. The install task holds a
+/// child's lock while waiting on it, so cancel can take it once the task
+/// is aborted.
+type InstallChildren = Arc<Mutex<Vec<Arc<AsyncMutex<Child>>>>>;
+
+tokio::task_local! {
+    /// Children of the install running on the current task
+    static INSTALL_CHILDREN: InstallChildren;
+}
+
+/// How long a cancelled child gets to exit after SIGTERM before SIGKILL
+const CANCEL_GRACE: Duration = Duration::from_secs(10);
+
+/// A running install: its task, its child processes and the channel its
+/// progress goes to
+struct RunningInstall {
+    task: JoinHandle<()>,
+    children: InstallChildren,
+    tx: mpsc::Sender<InstallProgress>,
+}
+
+/// Running installs keyed by install id
+static RUNNING_INSTALLS: LazyLock<Mutex<HashMap<String, RunningInstall>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Spawn `command` as the leader of a new process group and register it
+/// with the current install, so cancelling reaches everything it starts
+fn spawn_in_group(command: &mut TokioCommand) -> std::io::Result<Arc<AsyncMutex<Child>>> {
+    #[cfg(unix)]
+    command.process_group(0);
+    let child = Arc::new(AsyncMutex::new(command.spawn()?));
+    let _ = INSTALL_CHILDREN.try_with(|children| {
+        children
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(child.clone())
+    });
+    Ok(child)
+}
+
+/// Take a spawned child's stdout and stderr pipes
+async fn take_output(
+    child: &AsyncMutex<Child>,
+) -> (
+    Option<tokio::process::ChildStdout>,
+    Option<tokio::process::ChildStderr>,
+) {
+    let mut child = child.lock().await;
+    (child.stdout.take(), child.stderr.take())
+}
+
+/// Send SIGTERM to `child`'s process group and wait for the child to exit,
+/// escalating to SIGKILL after CANCEL_GRACE
+async fn terminate_process_group(child: &mut Child) {
+    // No id once the child has been waited on
+    let Some(pgid) = child.id() else {
+        return;
+    };
+    for signal in ["TERM", "KILL"] {
+        #[cfg(unix)]
+        let _ = TokioCommand::new("kill")
+            .args([
+                format!("-{}", signal),
+                "--".to_string(),
+                format!("-{}", pgid),
+            ])
+            .status()
+            .await;
+        #[cfg(not(unix))]
+        let _ = child.start_kill();
+        if tokio::time::timeout(CANCEL_GRACE, child.wait())
+            .await
+            .is_ok()
+        {
+            return;
+        }
+    }
+    warn!("Cancelled install child {} did not exit", pgid);
+}
 
-use crate::models::InstallProgress;
-use crate::services::command::SafeCommand;
-use crate::services::platform::Platform;
+/// npm package installed by the openclaw-install stage
+const OPENCLAW_PACKAGE: &str = "openclaw";
+
+/// A pinnable OpenClaw version: an exact semver or an npm dist-tag. Also
+/// keeps the spec safe to embed in the remote install's shell command.
+static OPENCLAW_VERSION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^(\d+\.\d+\.\d+(-[0-9A-Za-z.-]+)?(\+[0-9A-Za-z.-]+)?|[A-Za-z][A-Za-z0-9._-]{0,63})$",
+    )
+    .unwrap()
+});
+
+/// Which gateway service steps the verify stage runs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GatewaySetup {
+    /// Run `openclaw gateway install`
+    pub install_service: bool,
+    /// Run `openclaw gateway start` and check its health
+    pub start: bool,
+}
+
+impl Default for GatewaySetup {
+    fn default() -> Self {
+        Self {
+            install_service: true,
+            start: true,
+        }
+    }
+}
+
+impl From<&InstallRequest> for GatewaySetup {
+    fn from(request: &InstallRequest) -> Self {
+        Self {
+            install_service: request.install_gateway_service.unwrap_or(true),
+            start: request.start_gateway.unwrap_or(true),
+        }
+    }
+}
 
 /// Installation service
 pub struct InstallerService;
@@ -18,26 +218,222 @@ pub struct InstallerService;
 impl InstallerService {
     const MIN_NODE_MAJOR: u32 = 22;
 
+    /// Spawn an install task and register it under `install_id` for cancellation
+    ///
+    /// A failed install sends a final "failed" progress message. The entry is
+    /// removed when the task finishes or is cancelled. Fails without starting
+    /// anything if an install with the same id is already running.
+    pub fn spawn_install(
+        install_id: String,
+        install_node: bool,
+        install_openclaw: bool,
+        openclaw_version: Option<String>,
+        gateway: GatewaySetup,
+        tx: mpsc::Sender<InstallProgress>,
+    ) -> Result<(), AppError> {
+        // Hold the lock while spawning so the task can't unregister before it's registered
+        let mut running = RUNNING_INSTALLS.lock().unwrap_or_else(|e| e.into_inner());
+        if running.contains_key(&install_id) {
+            return Err(AppError::BadRequest(format!(
+                "Install '{}' is already running",
+                install_id
+            )));
+        }
+
+        let children = InstallChildren::default();
+        let task_tx = tx.clone();
+        let task_id = install_id.clone();
+        let task = tokio::spawn(
+            INSTALL_CHILDREN.scope(
+                children.clone(),
+                async move {
+                    if let Err(e) = Self::run_install(
+                        install_node,
+                        install_openclaw,
+                        openclaw_version.as_deref(),
+                        gateway,
+                        task_tx.clone(),
+                    )
+                    .await
+                    {
+                        error!("Installation failed: {}", e);
+                        let _ = task_tx
+                            .send(InstallProgress {
+                                stage: "error".into(),
+                                status: "failed".into(),
+                                message: "Installation failed".into(),
+                                error: Some(e.to_string()),
+                                ..Default::default()
+                            })
+                            .await;
+                    }
+                    Self::unregister_install(&task_id);
+                }
+                .in_current_span(),
+            ),
+        );
+
+        running.insert(install_id, RunningInstall { task, children, tx });
+        Ok(())
+    }
+
+    /// Cancel the running install `install_id`
+    ///
+    /// Aborts the task (and its output reader tasks), then terminates each
+    /// child's process group and waits for the child to exit, so "cancelled"
+    /// is only reported once nothing is left running. Returns false if no
+    /// install with that id was running.
+    pub async fn cancel_install(install_id: &str) -> bool {
+        let Some(install) = RUNNING_INSTALLS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(install_id)
+        else {
+            return false;
+        };
+
+        install.task.abort();
+        let _ = install.task.await;
+
+        let children: Vec<_> = install
+            .children
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .drain(..)
+            .collect();
+        for child in children {
+            terminate_process_group(&mut *child.lock().await).await;
+        }
+
+        let _ = install
+            .tx
+            .send(InstallProgress {
+                stage: "install".into(),
+                status: "cancelled".into(),
+                message: "Installation cancelled".into(),
+                ..Default::default()
+            })
+            .await;
+        true
+    }
+
+    /// Remove a finished install from the registry
+    fn unregister_install(install_id: &str) {
+        RUNNING_INSTALLS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(install_id);
+    }
+
+    /// Generate an id for an install the client didn't name
+    pub fn generate_install_id() -> String {
+        format!("install-{}", uuid::Uuid::new_v4())
+    }
+
+    /// npm spec for the OpenClaw package: `openclaw` (latest) or
+    /// `openclaw@<version>` for an exact version or dist-tag
+    pub fn openclaw_package_spec(version: Option<&str>) -> Result<String> {
+        match version.map(str::trim).filter(|v| !v.is_empty()) {
+            None => Ok(OPENCLAW_PACKAGE.to_string()),
+            Some(version) if OPENCLAW_VERSION.is_match(version) => {
+                Ok(format!("{}@{}", OPENCLAW_PACKAGE, version))
+            }
+            Some(version) => anyhow::bail!(
+                "Invalid OpenClaw version '{}': use an exact version like 2026.2.15 or a dist-tag like latest",
+                version
+            ),
+        }
+    }
+
+    /// Stages `run_install` runs for the given flags, in order
+    pub fn install_plan(
+        install_node: bool,
+        install_openclaw: bool,
+        gateway: GatewaySetup,
+    ) -> InstallPlan {
+        let verify_label = if gateway.start {
+            "Verify and start the gateway"
+        } else {
+            "Verify the installation"
+        };
+        let stages = [
+            (install_node, "node-install", "Install Node.js"),
+            (install_openclaw, "openclaw-install", "Install OpenClaw"),
+            (true, "verify", verify_label),
+        ];
+        InstallPlan {
+            stages: stages
+                .into_iter()
+                .filter(|(runs, _, _)| *runs)
+                .map(|(_, id, label)| InstallPlanStage {
+                    id: id.to_string(),
+                    label: label.to_string(),
+                })
+                .collect(),
+        }
+    }
+
     /// Run installation with streaming progress
+    ///
+    /// Progress for the stages of `install_plan` carries the stage's index,
+    /// and completed/failed updates carry how long the stage took.
+    ///
+    /// `openclaw_version` pins the OpenClaw package (latest when None); an
+    /// invalid version fails before anything is installed.
     pub async fn run_install(
         install_node: bool,
         install_openclaw: bool,
+        openclaw_version: Option<&str>,
+        gateway: GatewaySetup,
         tx: mpsc::Sender<InstallProgress>,
     ) -> Result<()> {
-        if install_node {
-            Self::install_node(&tx).await?;
-        }
-        if install_openclaw {
-            Self::install_openclaw(&tx).await?;
+        let package_spec = Self::openclaw_package_spec(openclaw_version)?;
+        let plan = Self::install_plan(install_node, install_openclaw, gateway);
+        let (stage_tx, mut stage_rx) = mpsc::channel::<InstallProgress>(PLAN_CHANNEL_CAPACITY);
+        let forwarder = tokio::spawn(
+            async move {
+                let mut timer = StageTimer::default();
+                while let Some(mut progress) = stage_rx.recv().await {
+                    progress.stage_index = Self::stage_index(&plan, &progress.stage);
+                    progress.elapsed_ms = timer.observe(&progress.stage, &progress.status);
+                    if tx.send(progress).await.is_err() {
+                        break;
+                    }
+                }
+            }
+            .in_current_span(),
+        );
+
+        let result = async {
+            if install_node {
+                Self::install_node(&stage_tx).await?;
+            }
+            if install_openclaw {
+                Self::install_openclaw(&package_spec, &stage_tx).await?;
+            }
+            Self::verify_installation(gateway, &stage_tx).await
         }
-        Self::verify_installation(&tx).await?;
-        Ok(())
+        .await;
+
+        // Deliver everything sent so far before the caller reports the outcome
+        drop(stage_tx);
+        let _ = forwarder.await;
+        result
+    }
+
+    /// Index of `stage` in `plan`
+    fn stage_index(plan: &InstallPlan, stage: &str) -> Option<u8> {
+        plan.stages
+            .iter()
+            .position(|s| s.id == stage)
+            .and_then(|i| u8::try_from(i).ok())
     }
 
     /// Install Node.js
     async fn install_node(tx: &mpsc::Sender<InstallProgress>) -> Result<()> {
-        if let Ok(Some(version)) = SafeCommand::check_node_version()
-            && Self::parse_node_major(&version).is_some_and(|major| major >= Self::MIN_NODE_MAJOR)
+        let existing = NodeDetector::detect();
+        if let Some(version) = &existing.version
+            && Self::parse_node_major(version).is_some_and(|major| major >= Self::MIN_NODE_MAJOR)
         {
             let _ = tx
                 .send(InstallProgress {
@@ -51,6 +447,43 @@ impl InstallerService {
             return Ok(());
         }
 
+        // An old Node.js owned by a version manager stays first on PATH (or
+        // gets shadowed) if we layer the system package over it
+        let managed = existing
+            .source
+            .filter(|source| existing.version.is_some() && NodeDetector::is_managed(*source));
+        if let Some(source) = managed {
+            let _ = tx
+                .send(InstallProgress {
+                    stage: "node-install".into(),
+                    status: "running".into(),
+                    message: Self::shadow_warning(&existing, source),
+                    output_line: existing
+                        .path
+                        .as_ref()
+                        .map(|p| format!("Found {}", p.display())),
+                    ..Default::default()
+                })
+                .await;
+        }
+
+        // Node.js comes from nodejs.org or NodeSource; there is no local cache
+        if OfflineMode::is_enabled() {
+            let _ = tx
+                .send(InstallProgress {
+                    stage: "node-install".into(),
+                    status: "failed".into(),
+                    message: "Node.js is missing and can't be downloaded in offline mode".into(),
+                    error: Some(format!(
+                        "Install Node.js {}+ from a local package, then retry",
+                        Self::MIN_NODE_MAJOR
+                    )),
+                    ..Default::default()
+                })
+                .await;
+            anyhow::bail!("Node.js download is disabled in offline mode");
+        }
+
         // Send starting message
         let _ = tx
             .send(InstallProgress {
@@ -95,6 +528,25 @@ impl InstallerService {
             .await;
 
         match SafeCommand::check_node_version() {
+            Ok(Some(version))
+                if Self::parse_node_major(&version)
+                    .is_some_and(|major| major < Self::MIN_NODE_MAJOR) =>
+            {
+                // The new install is shadowed by the old one on PATH
+                let hint = managed
+                    .map(NodeDetector::upgrade_hint)
+                    .unwrap_or("check your PATH for an older node");
+                let _ = tx
+                    .send(InstallProgress {
+                        stage: "node-install".into(),
+                        status: "failed".into(),
+                        message: format!("node still resolves to {} after installation", version),
+                        error: Some(format!("Upgrade the existing Node.js instead: {}", hint)),
+                        ..Default::default()
+                    })
+                    .await;
+                anyhow::bail!("Node.js {} still active after installation", version);
+            }
             Ok(Some(version)) => {
                 let _ = tx
                     .send(InstallProgress {
@@ -192,20 +644,22 @@ impl InstallerService {
             })
             .await;
 
-        let mut child = TokioCommand::new("sudo")
-            .args(["installer", "-pkg", &pkg_path, "-target", "/"])
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-            .context("Failed to start installer")?;
+        let child = spawn_in_group(
+            TokioCommand::new("sudo")
+                .args(["installer", "-pkg", &pkg_path, "-target", "/"])
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped()),
+        )
+        .context("Failed to start installer")?;
 
         // Stream both stdout and stderr concurrently
-        let stdout = child.stdout.take();
-        let stderr = child.stderr.take();
+        let (stdout, stderr) = take_output(&child).await;
         let tx_out = tx.clone();
         let tx_err = tx.clone();
 
-        let stdout_task = tokio::spawn(async move {
+        // Readers live in a JoinSet so cancelling the install aborts them too
+        let mut readers = JoinSet::new();
+        readers.spawn(async move {
             if let Some(stdout) = stdout {
                 let mut reader = BufReader::new(stdout).lines();
                 while let Some(line) = reader.next_line().await.unwrap_or(None) {
@@ -226,7 +680,7 @@ impl InstallerService {
             }
         });
 
-        let stderr_task = tokio::spawn(async move {
+        readers.spawn(async move {
             if let Some(stderr) = stderr {
                 let mut reader = BufReader::new(stderr).lines();
                 while let Some(line) = reader.next_line().await.unwrap_or(None) {
@@ -247,8 +701,8 @@ impl InstallerService {
             }
         });
 
-        let _ = tokio::join!(stdout_task, stderr_task);
-        let status = child.wait().await?;
+        while readers.join_next().await.is_some() {}
+        let status = child.lock().await.wait().await?;
 
         if !status.success() {
             let _ = tx
@@ -256,11 +710,11 @@ impl InstallerService {
                     stage: "node-install".into(),
                     status: "failed".into(),
                     message: "Node.js installation failed".into(),
-                    error: Some("Installer exited with non-zero status".into()),
+                    error: Some(format!("Installer {}", describe_status(&status))),
                     ..Default::default()
                 })
                 .await;
-            anyhow::bail!("Installer failed with exit code: {:?}", status.code());
+            anyhow::bail!("Installer failed: {}", describe_status(&status));
         }
 
         let _ = tokio::fs::remove_file(&pkg_path).await;
@@ -300,15 +754,16 @@ impl InstallerService {
             .context("Failed to write setup script")?;
 
         // Run setup script
-        let mut setup_child = TokioCommand::new("sudo")
-            .args(["-E", "bash", script_path])
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-            .context("Failed to run setup script")?;
+        let setup_child = spawn_in_group(
+            TokioCommand::new("sudo")
+                .args(["-E", "bash", script_path])
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped()),
+        )
+        .context("Failed to run setup script")?;
 
         // Wait for setup to complete
-        let status = setup_child.wait().await?;
+        let status = setup_child.lock().await.wait().await?;
         if !status.success() {
             let _ = tx
                 .send(InstallProgress {
@@ -336,15 +791,16 @@ impl InstallerService {
             .await;
 
         // Install nodejs
-        let mut apt_child = TokioCommand::new("sudo")
-            .args(["apt-get", "install", "-y", "nodejs"])
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-            .context("Failed to run apt-get install")?;
+        let apt_child = spawn_in_group(
+            TokioCommand::new("sudo")
+                .args(["apt-get", "install", "-y", "nodejs"])
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped()),
+        )
+        .context("Failed to run apt-get install")?;
 
         // Stream output
-        if let Some(stdout) = apt_child.stdout.take() {
+        if let (Some(stdout), _) = take_output(&apt_child).await {
             let mut reader = BufReader::new(stdout).lines();
             while let Some(line) = reader.next_line().await? {
                 let _ = tx
@@ -360,25 +816,31 @@ impl InstallerService {
             }
         }
 
-        let status = apt_child.wait().await?;
+        let status = apt_child.lock().await.wait().await?;
         if !status.success() {
             let _ = tx
                 .send(InstallProgress {
                     stage: "node-install".into(),
                     status: "failed".into(),
                     message: "apt-get install failed".into(),
-                    error: Some("Installation process failed".into()),
+                    error: Some(format!("apt-get {}", describe_status(&status))),
                     ..Default::default()
                 })
                 .await;
-            anyhow::bail!("apt-get install nodejs failed");
+            anyhow::bail!(
+                "apt-get install nodejs failed: {}",
+                describe_status(&status)
+            );
         }
 
         Ok(())
     }
 
     /// Install OpenClaw via npm
-    async fn install_openclaw(tx: &mpsc::Sender<InstallProgress>) -> Result<()> {
+    async fn install_openclaw(
+        package_spec: &str,
+        tx: &mpsc::Sender<InstallProgress>,
+    ) -> Result<()> {
         // Detect platform info for display
         let os = std::env::consts::OS;
         let arch = std::env::consts::ARCH;
@@ -388,39 +850,51 @@ impl InstallerService {
                 stage: "openclaw-install".into(),
                 status: "running".into(),
                 message: format!("Installing OpenClaw on {} ({})...", os, arch),
-                output_line: Some("$ npm install -g openclaw".to_string()),
+                output_line: Some(format!("$ npm install -g {}", package_spec)),
                 progress_pct: Some(10),
                 ..Default::default()
             })
             .await;
 
         // Run npm install -g openclaw with verbose output
-        let mut child = TokioCommand::new("npm")
-            .args(["install", "-g", "openclaw", "--loglevel", "notice"])
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-            .context("Failed to start npm install")?;
+        let child = spawn_in_group(
+            TokioCommand::new("npm")
+                .args(["install", "-g", package_spec, "--loglevel", "notice"])
+                .args(OfflineMode::npm_install_args())
+                .envs(NO_COLOR_ENV)
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped()),
+        )
+        .context("Failed to start npm install")?;
 
         // Stream stdout and stderr concurrently (npm writes progress to stderr)
-        let stdout = child.stdout.take();
-        let stderr = child.stderr.take();
+        let (stdout, stderr) = take_output(&child).await;
 
         let tx_out = tx.clone();
         let tx_err = tx.clone();
 
-        let stdout_task = tokio::spawn(async move {
+        // Seconds since `started` at which the last output line arrived
+        let started = Instant::now();
+        let last_output = Arc::new(AtomicU64::new(0));
+        let last_out = last_output.clone();
+        let last_err = last_output.clone();
+
+        // Readers live in a JoinSet so cancelling the install aborts them too
+        let mut readers = JoinSet::new();
+        readers.spawn(async move {
             if let Some(stdout) = stdout {
                 let mut reader = BufReader::new(stdout).lines();
                 while let Some(line) = reader.next_line().await.unwrap_or(None) {
                     if line.trim().is_empty() {
                         continue;
                     }
+                    last_out.store(started.elapsed().as_secs(), Ordering::Relaxed);
                     let _ = tx_out
                         .send(InstallProgress {
                             stage: "openclaw-install".into(),
                             status: "running".into(),
                             message: "Installing packages...".into(),
+                            line_level: Some(npm_line_level(&line).into()),
                             output_line: Some(line),
                             progress_pct: Some(50),
                             ..Default::default()
@@ -430,26 +904,24 @@ impl InstallerService {
             }
         });
 
-        let stderr_task = tokio::spawn(async move {
+        readers.spawn(async move {
             if let Some(stderr) = stderr {
                 let mut reader = BufReader::new(stderr).lines();
                 while let Some(line) = reader.next_line().await.unwrap_or(None) {
                     if line.trim().is_empty() {
                         continue;
                     }
+                    last_err.store(started.elapsed().as_secs(), Ordering::Relaxed);
 
                     // Derive a human-readable message from npm output
-                    let message = if line.contains("added") || line.contains("changed") {
-                        "Finalizing packages...".to_string()
-                    } else if line.contains("WARN") {
-                        "Installing (warnings are normal)...".to_string()
-                    } else if line.contains("gyp ERR")
-                        || line.contains("sharp")
-                        || line.contains("EACCES")
-                    {
-                        "Warning: build issue detected".to_string()
-                    } else {
-                        "Installing dependencies...".to_string()
+                    let level = npm_line_level(&line);
+                    let message = match level {
+                        "error" => "Build or permission problem detected".to_string(),
+                        "warning" => "Installing (warnings are normal)...".to_string(),
+                        _ if line.contains("added") || line.contains("changed") => {
+                            "Finalizing packages...".to_string()
+                        }
+                        _ => "Installing dependencies...".to_string(),
                     };
 
                     let _ = tx_err
@@ -457,6 +929,7 @@ impl InstallerService {
                             stage: "openclaw-install".into(),
                             status: "running".into(),
                             message,
+                            line_level: Some(level.into()),
                             output_line: Some(line),
                             progress_pct: Some(50),
                             ..Default::default()
@@ -466,20 +939,62 @@ impl InstallerService {
             }
         });
 
-        // Wait for both streams to complete
-        let _ = tokio::join!(stdout_task, stderr_task);
+        // Wait for both streams to complete, sending a heartbeat whenever
+        // npm has been silent (e.g. during a native build)
+        let mut heartbeat = tokio::time::interval(HEARTBEAT_INTERVAL);
+        heartbeat.tick().await;
+        loop {
+            tokio::select! {
+                joined = readers.join_next() => {
+                    if joined.is_none() {
+                        break;
+                    }
+                }
+                _ = heartbeat.tick() => {
+                    let elapsed = started.elapsed();
+                    let quiet_for = elapsed
+                        .as_secs()
+                        .saturating_sub(last_output.load(Ordering::Relaxed));
+                    if quiet_for >= HEARTBEAT_INTERVAL.as_secs() {
+                        let _ = tx
+                            .send(InstallProgress {
+                                stage: "openclaw-install".into(),
+                                status: "running".into(),
+                                message: heartbeat_message("installing OpenClaw", elapsed),
+                                progress_pct: Some(50),
+                                ..Default::default()
+                            })
+                            .await;
+                    }
+                }
+            }
+        }
 
-        let status = child.wait().await?;
+        let status = child.lock().await.wait().await?;
 
         if !status.success() {
-            let _ = tx.send(InstallProgress {
-                stage: "openclaw-install".into(),
-                status: "failed".into(),
-                message: "OpenClaw installation failed".into(),
-                error: Some("npm install exited with error. Common causes: sharp/libvips build issues, permission problems".into()),
-                ..Default::default()
-            }).await;
-            anyhow::bail!("npm install openclaw failed");
+            let reason = describe_status(&status);
+            let error = if exit_signal(&status) == Some(9) {
+                format!(
+                    "npm was {}. Add swap or use a machine with more RAM, then retry",
+                    reason
+                )
+            } else {
+                format!(
+                    "npm install ended with {}. Common causes: sharp/libvips build issues, permission problems",
+                    reason
+                )
+            };
+            let _ = tx
+                .send(InstallProgress {
+                    stage: "openclaw-install".into(),
+                    status: "failed".into(),
+                    message: "OpenClaw installation failed".into(),
+                    error: Some(error),
+                    ..Default::default()
+                })
+                .await;
+            anyhow::bail!("npm install openclaw failed: {}", reason);
         }
 
         let _ = tx
@@ -495,8 +1010,12 @@ impl InstallerService {
         Ok(())
     }
 
-    /// Verify installation and start the gateway service
-    async fn verify_installation(tx: &mpsc::Sender<InstallProgress>) -> Result<()> {
+    /// Verify installation, then install and start the gateway service as
+    /// `gateway` allows
+    async fn verify_installation(
+        gateway: GatewaySetup,
+        tx: &mpsc::Sender<InstallProgress>,
+    ) -> Result<()> {
         let _ = tx
             .send(InstallProgress {
                 stage: "verify".into(),
@@ -648,67 +1167,111 @@ impl InstallerService {
             }
         }
 
-        // Install and start the gateway service
-        let _ = tx
-            .send(InstallProgress {
-                stage: "verify".into(),
-                status: "running".into(),
-                message: "Installing gateway service...".into(),
-                progress_pct: Some(75),
-                ..Default::default()
-            })
-            .await;
+        // Install the gateway service unless the user manages it themselves
+        // or one is already registered (a second would fight over the port)
+        let existing_unit = if gateway.install_service {
+            ServiceManager::existing_gateway_unit()
+        } else {
+            None
+        };
+        if let Some(unit) = &existing_unit {
+            let _ = tx
+                .send(InstallProgress {
+                    stage: "verify".into(),
+                    status: "running".into(),
+                    message: format!(
+                        "⚠ Gateway service already registered with {}: {} is {} (not reinstalling)",
+                        unit.manager, unit.name, unit.state
+                    ),
+                    progress_pct: Some(80),
+                    ..Default::default()
+                })
+                .await;
+        } else if gateway.install_service {
+            let _ = tx
+                .send(InstallProgress {
+                    stage: "verify".into(),
+                    status: "running".into(),
+                    message: "Installing gateway service...".into(),
+                    progress_pct: Some(75),
+                    ..Default::default()
+                })
+                .await;
 
-        match SafeCommand::run("openclaw", &["gateway", "install"]) {
-            Ok(output) if output.exit_code == 0 => {
-                let _ = tx
-                    .send(InstallProgress {
-                        stage: "verify".into(),
-                        status: "running".into(),
-                        message: "✓ Gateway service installed".into(),
-                        progress_pct: Some(80),
-                        ..Default::default()
-                    })
-                    .await;
-            }
-            Ok(output)
-                if output.stderr.contains("already") || output.stdout.contains("already") =>
-            {
-                // Already installed - that's fine
-                let _ = tx
-                    .send(InstallProgress {
-                        stage: "verify".into(),
-                        status: "running".into(),
-                        message: "✓ Gateway service already installed".into(),
-                        progress_pct: Some(80),
-                        ..Default::default()
-                    })
-                    .await;
-            }
-            Ok(output) => {
-                let _ = tx
-                    .send(InstallProgress {
-                        stage: "verify".into(),
-                        status: "failed".into(),
-                        message: "Gateway service installation failed".into(),
-                        error: Some(format!("Install error: {}", output.stderr.trim())),
-                        ..Default::default()
-                    })
-                    .await;
-                anyhow::bail!("Gateway service install failed");
-            }
-            Err(e) => {
-                let _ = tx
-                    .send(InstallProgress {
-                        stage: "verify".into(),
-                        status: "failed".into(),
-                        message: "Could not install gateway service".into(),
-                        error: Some(format!("Command error: {}", e)),
-                        ..Default::default()
-                    })
-                    .await;
-                anyhow::bail!("Could not run gateway install command");
+            match SafeCommand::run("openclaw", &["gateway", "install"]) {
+                Ok(output) if output.exit_code == 0 => {
+                    let _ = tx
+                        .send(InstallProgress {
+                            stage: "verify".into(),
+                            status: "running".into(),
+                            message: "✓ Gateway service installed".into(),
+                            progress_pct: Some(80),
+                            ..Default::default()
+                        })
+                        .await;
+                }
+                Ok(output)
+                    if output.stderr.contains("already") || output.stdout.contains("already") =>
+                {
+                    // Already installed - that's fine
+                    let _ = tx
+                        .send(InstallProgress {
+                            stage: "verify".into(),
+                            status: "running".into(),
+                            message: "✓ Gateway service already installed".into(),
+                            progress_pct: Some(80),
+                            ..Default::default()
+                        })
+                        .await;
+                }
+                Ok(output) => {
+                    let _ = tx
+                        .send(InstallProgress {
+                            stage: "verify".into(),
+                            status: "failed".into(),
+                            message: "Gateway service installation failed".into(),
+                            error: Some(format!("Install error: {}", output.stderr.trim())),
+                            ..Default::default()
+                        })
+                        .await;
+                    anyhow::bail!("Gateway service install failed");
+                }
+                Err(e) => {
+                    let _ = tx
+                        .send(InstallProgress {
+                            stage: "verify".into(),
+                            status: "failed".into(),
+                            message: "Could not install gateway service".into(),
+                            error: Some(format!("Command error: {}", e)),
+                            ..Default::default()
+                        })
+                        .await;
+                    anyhow::bail!("Could not run gateway install command");
+                }
             }
+        } else {
+            let _ = tx
+                .send(InstallProgress {
+                    stage: "verify".into(),
+                    status: "running".into(),
+                    message: "Skipping gateway service install (managed outside the wizard)".into(),
+                    progress_pct: Some(80),
+                    ..Default::default()
+                })
+                .await;
+        }
+
+        if !gateway.start {
+            let _ = tx
+                .send(InstallProgress {
+                    stage: "verify".into(),
+                    status: "completed".into(),
+                    message: "Setup complete. Start the gateway with your service manager.".into(),
+                    progress_pct: Some(100),
+                    ..Default::default()
+                })
+                .await;
+            return Ok(());
         }
 
         // Start the gateway
@@ -839,189 +1402,8 @@ impl InstallerService {
     /// Deploy the wizard's saved config to OpenClaw using `openclaw onboard`.
     /// This registers the AI provider auth, sets gateway config, and creates workspace.
     async fn deploy_config(tx: &mpsc::Sender<InstallProgress>) -> Result<()> {
-        let wizard_config_dir =
-            Platform::config_dir().context("Failed to determine wizard config directory")?;
-        let wizard_config_path = wizard_config_dir.join("openclaw.json");
-
-        if !wizard_config_path.exists() {
-            anyhow::bail!("No wizard config found at {}", wizard_config_path.display());
-        }
-
-        // Read wizard config
-        let config_bytes = tokio::fs::read(&wizard_config_path)
-            .await
-            .context("Failed to read wizard config")?;
-        let config: serde_json::Value =
-            serde_json::from_slice(&config_bytes).context("Failed to parse wizard config")?;
-
-        // Build openclaw onboard command with all wizard settings
-        let mut args: Vec<String> = vec![
-            "onboard".into(),
-            "--non-interactive".into(),
-            "--accept-risk".into(),
-            "--mode".into(),
-            "local".into(),
-            "--no-install-daemon".into(),
-            "--skip-health".into(),
-            "--skip-ui".into(),
-        ];
-
-        // AI provider auth
-        let api_key = config.get("api_key").and_then(|v| v.as_str()).unwrap_or("");
-        let auth_type = config
-            .get("auth_type")
-            .and_then(|v| v.as_str())
-            .unwrap_or("api-key");
-        let provider = config
-            .get("provider")
-            .and_then(|v| v.as_str())
-            .unwrap_or("anthropic");
-
-        match provider {
-            // Skip: no auth configured
-            "skip" => {
-                args.extend(["--auth-choice".into(), "skip".into()]);
-            }
-            // OAuth providers: pass auth-choice only, no key
-            "chutes" => {
-                args.extend(["--auth-choice".into(), "chutes".into()]);
-            }
-            "github-copilot" => {
-                args.extend(["--auth-choice".into(), "github-copilot".into()]);
-            }
-            "qwen" => {
-                args.extend(["--auth-choice".into(), "qwen-portal".into()]);
-            }
-            // Custom provider: base URL + model ID + optional key
-            "custom" => {
-                args.extend(["--auth-choice".into(), "custom-api-key".into()]);
-                if let Some(base_url) = config.get("base_url").and_then(|v| v.as_str())
-                    && !base_url.is_empty()
-                {
-                    args.extend(["--custom-base-url".into(), base_url.into()]);
-                }
-                if let Some(model_id) = config.get("model_id").and_then(|v| v.as_str())
-                    && !model_id.is_empty()
-                {
-                    args.extend(["--custom-model-id".into(), model_id.into()]);
-                }
-                if let Some(compat) = config.get("compatibility").and_then(|v| v.as_str())
-                    && !compat.is_empty()
-                {
-                    args.extend(["--custom-compatibility".into(), compat.into()]);
-                }
-                if !api_key.is_empty() {
-                    args.extend(["--custom-api-key".into(), api_key.into()]);
-                }
-            }
-            // Cloudflare: needs account ID, gateway ID, and key
-            "cloudflare" => {
-                args.extend([
-                    "--auth-choice".into(),
-                    "cloudflare-ai-gateway-api-key".into(),
-                ]);
-                if !api_key.is_empty() {
-                    args.extend(["--cloudflare-ai-gateway-api-key".into(), api_key.into()]);
-                }
-                if let Some(account_id) = config.get("account_id").and_then(|v| v.as_str())
-                    && !account_id.is_empty()
-                {
-                    args.extend([
-                        "--cloudflare-ai-gateway-account-id".into(),
-                        account_id.into(),
-                    ]);
-                }
-                if let Some(gateway_id) = config.get("gateway_id").and_then(|v| v.as_str())
-                    && !gateway_id.is_empty()
-                {
-                    args.extend([
-                        "--cloudflare-ai-gateway-gateway-id".into(),
-                        gateway_id.into(),
-                    ]);
-                }
-            }
-            // vLLM: self-hosted, base URL only
-            "vllm" => {
-                args.extend(["--auth-choice".into(), "vllm".into()]);
-                // vLLM base URL is typically passed as custom-base-url
-                if let Some(base_url) = config.get("base_url").and_then(|v| v.as_str())
-                    && !base_url.is_empty()
-                {
-                    args.extend(["--custom-base-url".into(), base_url.into()]);
-                }
-            }
-            // Provider-specific auth-choice mappings where the CLI flag name differs from provider ID
-            "google" => {
-                if !api_key.is_empty() {
-                    args.extend(["--auth-choice".into(), "gemini-api-key".into()]);
-                    args.extend(["--gemini-api-key".into(), api_key.into()]);
-                }
-            }
-            "vercel-ai-gateway" => {
-                if !api_key.is_empty() {
-                    args.extend(["--auth-choice".into(), "ai-gateway-api-key".into()]);
-                    args.extend(["--ai-gateway-api-key".into(), api_key.into()]);
-                }
-            }
-            "opencode-zen" => {
-                if !api_key.is_empty() {
-                    args.extend(["--auth-choice".into(), "opencode-zen".into()]);
-                    args.extend(["--opencode-zen-api-key".into(), api_key.into()]);
-                }
-            }
-            "minimax" => {
-                if !api_key.is_empty() {
-                    args.extend(["--auth-choice".into(), "minimax-api-key-cn".into()]);
-                    args.extend(["--minimax-api-key".into(), api_key.into()]);
-                }
-            }
-            // Standard API key providers: --auth-choice {provider}-api-key --{provider}-api-key <key>
-            // Works for: anthropic, openai, xai, openrouter, moonshot, zai, qianfan, xiaomi,
-            //            synthetic, together, huggingface, venice, litellm
-            _ => {
-                if !api_key.is_empty() {
-                    if auth_type == "setup-token" {
-                        // Setup tokens use --auth-choice token with --token-provider
-                        args.extend(["--auth-choice".into(), "token".into()]);
-                        args.extend(["--token".into(), api_key.into()]);
-                        args.extend(["--token-provider".into(), provider.into()]);
-                    } else {
-                        let key_flag = format!("--{}-api-key", provider);
-                        args.extend(["--auth-choice".into(), format!("{}-api-key", provider)]);
-                        args.extend([key_flag, api_key.into()]);
-                    }
-                }
-            }
-        }
-
-        // Gateway settings
-        let port = config
-            .get("gateway_port")
-            .and_then(|v| v.as_u64())
-            .unwrap_or(18789);
-        args.extend(["--gateway-port".into(), port.to_string()]);
-
-        let bind = config
-            .get("gateway_bind")
-            .and_then(|v| v.as_str())
-            .unwrap_or("127.0.0.1");
-        let bind_mode = match bind {
-            "127.0.0.1" | "localhost" => "loopback",
-            "0.0.0.0" => "lan",
-            other => other,
-        };
-        args.extend(["--gateway-bind".into(), bind_mode.into()]);
-
-        args.extend(["--gateway-auth".into(), "token".into()]);
-        if let Some(credential) = config.get("auth_credential").and_then(|v| v.as_str())
-            && !credential.is_empty()
-        {
-            args.extend(["--gateway-token".into(), credential.into()]);
-        }
-
-        // Skip channels in onboard; we'll set them separately
-        args.push("--skip-channels".into());
-        args.push("--skip-skills".into());
+        let config = Self::read_wizard_config().await?;
+        let args = Self::build_onboard_args(&config);
 
         let _ = tx
             .send(InstallProgress {
@@ -1054,9 +1436,10 @@ impl InstallerService {
                         status: "running".into(),
                         message: format!(
                             "Onboard warning: {}",
-                            output.stderr.lines().last().unwrap_or("").trim()
+                            redact_secrets(output.stderr.lines().last().unwrap_or("").trim())
                         ),
-                        output_line: Some(output.stdout.trim().to_string()),
+                        // onboard can echo the keys it was given
+                        output_line: Some(redact_secrets(output.stdout.trim())),
                         progress_pct: Some(64),
                         ..Default::default()
                     })
@@ -1068,21 +1451,18 @@ impl InstallerService {
         }
 
         // Deploy channel configurations separately via config set
-        if let Some(channels) = config.get("channels").and_then(|v| v.as_array()) {
+        if let Some(channels) = &config.channels {
             for channel in channels {
-                let platform = match channel.get("platform").and_then(|v| v.as_str()) {
-                    Some(p) => p,
-                    None => continue,
-                };
+                let platform = channel.platform.as_str();
 
-                if let Some(token) = channel.get("bot_token").and_then(|v| v.as_str())
+                if let Some(token) = channel.bot_token.as_deref()
                     && !token.is_empty()
                 {
                     let path = format!("channels.{}.botToken", platform);
                     let _ = SafeCommand::run("openclaw", &["config", "set", &path, token]);
                 }
 
-                if let Some(token) = channel.get("app_token").and_then(|v| v.as_str())
+                if let Some(token) = channel.app_token.as_deref()
                     && !token.is_empty()
                 {
                     let path = format!("channels.{}.appToken", platform);
@@ -1114,6 +1494,125 @@ impl InstallerService {
         Ok(())
     }
 
+    /// Load the wizard's saved config with `${ENV_VAR}` placeholders resolved
+    async fn read_wizard_config() -> Result<WizardConfig> {
+        let wizard_config_path = WizardConfigStore::path()?;
+
+        if !wizard_config_path.exists() {
+            return Err(AppError::NotFound(format!(
+                "No wizard config found at {}",
+                wizard_config_path.display()
+            ))
+            .into());
+        }
+
+        tokio::task::spawn_blocking(move || WizardConfigStore::load_from(&wizard_config_path))
+            .await
+            .context("Wizard config task panicked")?
+    }
+
+    /// Build the `openclaw onboard` argv from the wizard config (unmasked)
+    fn build_onboard_args(config: &WizardConfig) -> Vec<String> {
+        let mut args: Vec<String> = vec![
+            "onboard".into(),
+            "--non-interactive".into(),
+            "--accept-risk".into(),
+            "--mode".into(),
+            "local".into(),
+            "--no-install-daemon".into(),
+            "--skip-health".into(),
+            "--skip-ui".into(),
+        ];
+
+        // AI provider auth
+        args.extend(ProviderAuth::resolve(&ProviderInput::from(config)).onboard_args());
+
+        // Gateway settings
+        args.extend(["--gateway-port".into(), config.gateway_port.to_string()]);
+
+        let bind_mode = match config.gateway_bind.as_str() {
+            "127.0.0.1" | "localhost" => "loopback",
+            "0.0.0.0" => "lan",
+            other => other,
+        };
+        args.extend(["--gateway-bind".into(), bind_mode.into()]);
+
+        args.extend(["--gateway-auth".into(), "token".into()]);
+        if let Some(credential) = config.auth_credential.as_deref()
+            && !credential.is_empty()
+        {
+            args.extend(["--gateway-token".into(), credential.into()]);
+        }
+
+        // Skip channels in onboard; we'll set them separately
+        args.push("--skip-channels".into());
+        args.push("--skip-skills".into());
+
+        args
+    }
+
+    /// Build a preview of the onboard command from the saved config without running it.
+    /// Secret values are masked so the result is safe to display.
+    pub async fn onboard_preview() -> Result<OnboardPreview> {
+        let config = Self::read_wizard_config().await?;
+        let args = Self::mask_onboard_args(&Self::build_onboard_args(&config));
+
+        let command = std::iter::once("openclaw".to_string())
+            .chain(args.iter().map(|a| Self::shell_quote(a)))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        Ok(OnboardPreview {
+            program: "openclaw".to_string(),
+            args,
+            command,
+        })
+    }
+
+    /// Replace the value following any secret-bearing flag with a mask
+    fn mask_onboard_args(args: &[String]) -> Vec<String> {
+        let mut masked = Vec::with_capacity(args.len());
+        let mut mask_next = false;
+        for arg in args {
+            if mask_next {
+                masked.push("********".to_string());
+                mask_next = false;
+                continue;
+            }
+            mask_next = Self::is_secret_flag(arg);
+            masked.push(arg.clone());
+        }
+        masked
+    }
+
+    fn is_secret_flag(flag: &str) -> bool {
+        flag.starts_with("--")
+            && (flag.ends_with("-api-key") || flag.ends_with("-token") || flag == "--token")
+    }
+
+    /// Quote an argument for display if it contains shell-special characters
+    fn shell_quote(arg: &str) -> String {
+        if !arg.is_empty()
+            && arg
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "-_./:=@*".contains(c))
+        {
+            arg.to_string()
+        } else {
+            format!("'{}'", arg.replace('\'', "'\\''"))
+        }
+    }
+
+    /// Explain that a pkg install would sit alongside a manager-owned Node.js
+    fn shadow_warning(existing: &NodeInstallation, source: NodeSource) -> String {
+        format!(
+            "Found Node.js {} installed via {:?}; a system install won't replace it and may be shadowed on PATH. Prefer: {}",
+            existing.version.as_deref().unwrap_or("(unknown version)"),
+            source,
+            NodeDetector::upgrade_hint(source)
+        )
+    }
+
     fn parse_node_major(version_str: &str) -> Option<u32> {
         version_str
             .trim()
@@ -1126,7 +1625,195 @@ impl InstallerService {
 
 #[cfg(test)]
 mod tests {
-    use super::InstallerService;
+    use super::{
+        AppError, GatewaySetup, INSTALL_CHILDREN, InstallChildren, InstallProgress, InstallRequest,
+        InstallerService, RUNNING_INSTALLS, RunningInstall, StageTimer, TokioCommand, exit_signal,
+        heartbeat_message, npm_line_level, spawn_in_group,
+    };
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+
+    #[test]
+    fn test_generated_install_ids_are_unique() {
+        let ids: std::collections::HashSet<String> = (0..100)
+            .map(|_| InstallerService::generate_install_id())
+            .collect();
+        assert_eq!(ids.len(), 100);
+        assert!(ids.iter().all(|id| id.starts_with("install-")));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_install_aborts_task_and_reports() {
+        let (tx, mut rx) = mpsc::channel::<InstallProgress>(4);
+        let (started_tx, started_rx) = tokio::sync::oneshot::channel();
+        let children = InstallChildren::default();
+        let task = tokio::spawn(INSTALL_CHILDREN.scope(children.clone(), async move {
+            let child = spawn_in_group(TokioCommand::new("sleep").arg("60")).unwrap();
+            let _ = started_tx.send(child.clone());
+            let _ = child.lock().await.wait().await;
+        }));
+        let child = started_rx.await.unwrap();
+        RUNNING_INSTALLS.lock().unwrap().insert(
+            "test-cancel".to_string(),
+            RunningInstall { task, children, tx },
+        );
+
+        assert!(InstallerService::cancel_install("test-cancel").await);
+        // The child was terminated and reaped before the cancel was reported
+        let status = child.lock().await.try_wait().unwrap().unwrap();
+        assert_eq!(exit_signal(&status), Some(15));
+
+        let progress = rx.recv().await.unwrap();
+        assert_eq!(progress.status, "cancelled");
+        // Registry entry (and its sender) are gone, so the stream ends
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_install_unknown_id() {
+        assert!(!InstallerService::cancel_install("no-such-install").await);
+    }
+
+    #[tokio::test]
+    async fn test_spawn_install_rejects_running_id() {
+        let (tx, _rx) = mpsc::channel::<InstallProgress>(4);
+        let task = tokio::spawn(tokio::time::sleep(Duration::from_secs(60)));
+        RUNNING_INSTALLS.lock().unwrap().insert(
+            "test-duplicate".to_string(),
+            RunningInstall {
+                task,
+                children: InstallChildren::default(),
+                tx: tx.clone(),
+            },
+        );
+
+        let result = InstallerService::spawn_install(
+            "test-duplicate".to_string(),
+            false,
+            false,
+            None,
+            GatewaySetup::default(),
+            tx,
+        );
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+        assert!(InstallerService::cancel_install("test-duplicate").await);
+    }
+
+    #[test]
+    fn install_plan_lists_requested_stages() {
+        let ids = |plan: crate::models::types::InstallPlan| -> Vec<String> {
+            plan.stages.into_iter().map(|s| s.id).collect()
+        };
+        let gateway = GatewaySetup::default();
+        assert_eq!(
+            ids(InstallerService::install_plan(true, true, gateway)),
+            ["node-install", "openclaw-install", "verify"]
+        );
+        assert_eq!(
+            ids(InstallerService::install_plan(false, false, gateway)),
+            ["verify"]
+        );
+
+        let plan = InstallerService::install_plan(false, true, gateway);
+        assert_eq!(InstallerService::stage_index(&plan, "verify"), Some(1));
+        assert_eq!(InstallerService::stage_index(&plan, "node-install"), None);
+    }
+
+    #[test]
+    fn gateway_setup_defaults_to_install_and_start() {
+        let request: InstallRequest = serde_json::from_value(serde_json::json!({
+            "install_node": false,
+            "install_openclaw": true,
+        }))
+        .unwrap();
+        assert_eq!(GatewaySetup::from(&request), GatewaySetup::default());
+
+        let request: InstallRequest = serde_json::from_value(serde_json::json!({
+            "install_node": false,
+            "install_openclaw": true,
+            "install_gateway_service": false,
+            "start_gateway": false,
+        }))
+        .unwrap();
+        let gateway = GatewaySetup::from(&request);
+        assert!(!gateway.install_service && !gateway.start);
+
+        let plan = InstallerService::install_plan(false, true, gateway);
+        assert_eq!(plan.stages[1].label, "Verify the installation");
+    }
+
+    #[test]
+    fn openclaw_package_spec_pins_versions_and_tags() {
+        let spec = InstallerService::openclaw_package_spec;
+        assert_eq!(spec(None).unwrap(), "openclaw");
+        assert_eq!(spec(Some(" ")).unwrap(), "openclaw");
+        assert_eq!(spec(Some("2026.2.15")).unwrap(), "openclaw@2026.2.15");
+        assert_eq!(spec(Some("1.0.0-beta.2")).unwrap(), "openclaw@1.0.0-beta.2");
+        assert_eq!(spec(Some("next")).unwrap(), "openclaw@next");
+
+        for bad in [
+            "^1.0.0",
+            "1.0",
+            "latest; rm -rf ~",
+            "$(id)",
+            "1.0.0 && true",
+        ] {
+            assert!(spec(Some(bad)).is_err(), "{} should be rejected", bad);
+        }
+    }
+
+    #[test]
+    fn stage_timer_reports_elapsed_on_completion() {
+        let start = std::time::Instant::now();
+        let mut timer = StageTimer::default();
+        assert_eq!(timer.observe_at("node-install", "running", start), None);
+        assert_eq!(
+            timer.observe_at("node-install", "running", start + Duration::from_secs(1)),
+            None
+        );
+        assert_eq!(
+            timer.observe_at(
+                "node-install",
+                "completed",
+                start + Duration::from_millis(4200)
+            ),
+            Some(4200)
+        );
+        // A stage first seen in a terminal state took no measurable time
+        let later = start + Duration::from_secs(5);
+        assert_eq!(timer.observe_at("verify", "failed", later), Some(0));
+    }
+
+    #[test]
+    fn heartbeat_message_formats_elapsed_time() {
+        assert_eq!(
+            heartbeat_message("installing OpenClaw", Duration::from_secs(15)),
+            "Still installing OpenClaw... (15s elapsed)"
+        );
+        assert_eq!(
+            heartbeat_message("installing OpenClaw", Duration::from_secs(65)),
+            "Still installing OpenClaw... (1m 05s elapsed)"
+        );
+    }
+
+    #[test]
+    fn npm_line_level_separates_warnings_from_errors() {
+        let cases = [
+            (
+                "npm WARN deprecated inflight@1.0.6: This module leaks memory",
+                "warning",
+            ),
+            ("npm warn deprecated glob@7.2.3", "warning"),
+            ("npm ERR! code EACCES", "error"),
+            ("npm error code 1", "error"),
+            ("gyp ERR! build error", "error"),
+            ("added 312 packages in 41s", "info"),
+            ("sharp: Downloading libvips", "info"),
+        ];
+        for (line, level) in cases {
+            assert_eq!(npm_line_level(line), level, "{}", line);
+        }
+    }
 
     #[test]
     fn parse_node_major_handles_common_versions() {
@@ -1140,4 +1827,90 @@ mod tests {
         assert_eq!(InstallerService::parse_node_major(""), None);
         assert_eq!(InstallerService::parse_node_major("not-a-version"), None);
     }
+
+    #[test]
+    fn build_onboard_args_includes_provider_and_gateway_flags() {
+        let config: crate::models::WizardConfig = serde_json::from_value(serde_json::json!({
+            "provider": "anthropic",
+            "auth_type": "api-key",
+            "api_key": "sk-ant-secret",
+            "gateway_port": 18789,
+            "gateway_bind": "127.0.0.1",
+            "auth_mode": "token",
+            "auth_credential": "gateway-secret",
+        }))
+        .unwrap();
+        let args = InstallerService::build_onboard_args(&config);
+
+        assert_eq!(args[0], "onboard");
+        assert!(
+            args.windows(2)
+                .any(|w| w == ["--anthropic-api-key", "sk-ant-secret"])
+        );
+        assert!(args.windows(2).any(|w| w == ["--gateway-bind", "loopback"]));
+        assert!(
+            args.windows(2)
+                .any(|w| w == ["--gateway-token", "gateway-secret"])
+        );
+    }
+
+    #[test]
+    fn build_onboard_args_uses_resolved_placeholders() {
+        let raw = serde_json::json!({
+            "provider": "anthropic", "api_key": "sk-ant-secret", "auth_type": "api-key",
+            "gateway_port": 18789, "gateway_bind": "127.0.0.1", "auth_mode": "token",
+            "auth_credential": "${OPENCLAW_AUTH_CREDENTIAL}",
+        });
+        let resolved = crate::services::config_template::ConfigTemplate::resolve_with(&raw, |_| {
+            Some("gw-secret".to_string())
+        })
+        .unwrap();
+        let (migrated, _) =
+            crate::services::wizard_config::WizardConfigStore::migrate(resolved).unwrap();
+        let config: crate::models::WizardConfig = serde_json::from_value(migrated).unwrap();
+
+        let args = InstallerService::build_onboard_args(&config);
+        assert!(
+            args.windows(2)
+                .any(|w| w[0] == "--gateway-token" && w[1] == "gw-secret")
+        );
+        assert!(!args.iter().any(|a| a.contains("${")));
+    }
+
+    #[test]
+    fn mask_onboard_args_hides_secret_values() {
+        let args: Vec<String> = [
+            "onboard",
+            "--auth-choice",
+            "token",
+            "--token",
+            "sk-ant-oat01-secret",
+            "--token-provider",
+            "anthropic",
+            "--gateway-token",
+            "gw-secret",
+            "--gateway-port",
+            "18789",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        let masked = InstallerService::mask_onboard_args(&args);
+
+        assert!(!masked.iter().any(|a| a.contains("secret")));
+        assert!(
+            masked
+                .windows(2)
+                .any(|w| w == ["--token-provider", "anthropic"])
+        );
+        assert!(masked.windows(2).any(|w| w == ["--gateway-port", "18789"]));
+        assert_eq!(masked.len(), args.len());
+    }
+
+    #[test]
+    fn shell_quote_wraps_special_characters() {
+        assert_eq!(InstallerService::shell_quote("--mode"), "--mode");
+        assert_eq!(InstallerService::shell_quote("a b"), "'a b'");
+        assert_eq!(InstallerService::shell_quote(""), "''");
+    }
 }
@@ -4,39 +4,97 @@
 //! log streaming via bounded channels (capacity 1000) for WebSocket delivery.
 
 use crate::models::types::{LogLine, LogsResponse};
+use crate::services::command::{SafeCommand, strip_ansi};
+use crate::services::platform::Platform;
+use crate::services::service_manager::ServiceManager;
 use anyhow::{Context, Result};
-use std::path::PathBuf;
-use tokio::sync::mpsc;
+use flate2::read::GzDecoder;
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use tokio::sync::{mpsc, oneshot};
 
 const LOG_CHANNEL_CAPACITY: usize = 1000;
 
+/// Lines of lead-up context kept before an error cluster
+const ERROR_WINDOW_CONTEXT: usize = 5;
+
+/// Maximum lines returned for an error window
+const ERROR_WINDOW_MAX_LINES: usize = 50;
+
+/// Upper bound on lines returned by `get_recent_logs`
+pub const MAX_RECENT_LINES: usize = 10_000;
+
+/// Assumed average line length when sizing the first read from the end
+const ESTIMATED_LINE_BYTES: u64 = 256;
+
+/// Most bytes read from the end of a log; unusually long lines yield fewer lines
+const MAX_TAIL_BYTES: u64 = 32 * 1024 * 1024;
+
+/// Services whose logs can be read. The name ends up in file paths and in
+/// `openclaw <service> logs`, so nothing outside this list is accepted.
+pub const LOG_SERVICES: &[&str] = &["gateway", "daemon"];
+
+/// A running `tail -F` of a log file
+///
+/// Lines arrive on `lines`. Dropping the `LogTail` signals the tail task,
+/// which kills the `tail` child and exits, so a closed stream never leaves
+/// a `tail` process behind. The task also ends when `tail` exits.
+pub struct LogTail {
+    pub lines: mpsc::Receiver<String>,
+    /// Dropped with the `LogTail`, which wakes the task up to stop
+    _stop: oneshot::Sender<()>,
+}
+
 pub struct LogService;
 
 impl LogService {
     /// Get recent log lines from a service log file
     ///
-    /// Reads the last N lines, parses metadata, applies level and search filters.
+    /// Reads the last N lines (at most `MAX_RECENT_LINES`), parses metadata,
+    /// applies level and search filters. With `include_rotated`, lines from
+    /// rotated siblings (`gateway.log.1`, `gateway.log.2.gz`, ...) fill the
+    /// window when the current file is shorter than N lines.
     pub fn get_recent_logs(
         service: &str,
         lines: usize,
         level: Option<&str>,
         search: Option<&str>,
+        include_rotated: bool,
     ) -> Result<LogsResponse> {
+        Self::validate_service(service)?;
+        let lines = lines.min(MAX_RECENT_LINES);
         let log_path = Self::find_log_path(service);
+        let mut rotated_paths = None;
+        let mut journald_unit = None;
 
-        let raw_lines = if let Some(path) = log_path {
-            let content = std::fs::read_to_string(&path)
+        let mut parsed: Vec<LogLine> = if let Some(ref path) = log_path {
+            let mut raw = Self::read_last_lines(path, lines, MAX_TAIL_BYTES)
                 .with_context(|| format!("Failed to read log file: {}", path.display()))?;
-            let all_lines: Vec<&str> = content.lines().collect();
-            let start = all_lines.len().saturating_sub(lines);
-            all_lines[start..].iter().map(|s| s.to_string()).collect()
+            if include_rotated {
+                let (older, used) = Self::read_rotated_lines(path, lines - raw.len())?;
+                raw.splice(0..0, older);
+                rotated_paths = Some(
+                    used.iter()
+                        .map(|p| p.to_string_lossy().to_string())
+                        .collect(),
+                );
+            }
+            raw.iter().map(|l| Self::parse_log_line(l)).collect()
         } else {
-            // Try running openclaw gateway logs as fallback
-            Self::try_command_fallback(service, lines)?
+            // Try running openclaw gateway logs as fallback, then journald
+            let raw = Self::try_command_fallback(service, lines)?;
+            if raw.is_empty()
+                && let Some(unit) = ServiceManager::journald_unit(service)
+            {
+                journald_unit = Some(unit.to_string());
+                Self::read_journald(unit, lines)
+            } else {
+                raw.iter().map(|l| Self::parse_log_line(l)).collect()
+            }
         };
 
-        // Parse and filter lines
-        let mut parsed: Vec<LogLine> = raw_lines.iter().map(|l| Self::parse_log_line(l)).collect();
+        let level_counts = Self::count_levels(&parsed);
 
         // Apply level filter
         if let Some(min_level) = level {
@@ -61,25 +119,134 @@ impl LogService {
             service: service.to_string(),
             lines: parsed,
             total,
+            log_path: log_path.map(|p| p.to_string_lossy().to_string()),
+            rotated_paths,
+            journald_unit,
+            level_counts,
         })
     }
 
+    /// Reject service names outside `LOG_SERVICES`
+    pub fn validate_service(service: &str) -> Result<()> {
+        if LOG_SERVICES.contains(&service) {
+            Ok(())
+        } else {
+            anyhow::bail!(
+                "Unknown service {:?}; expected one of: {}",
+                service,
+                LOG_SERVICES.join(", ")
+            )
+        }
+    }
+
+    /// Count parsed lines per detected level; lines without a level are not counted
+    fn count_levels(lines: &[LogLine]) -> HashMap<String, u32> {
+        let mut counts = HashMap::new();
+        for level in lines.iter().filter_map(|l| l.level.as_ref()) {
+            *counts.entry(level.clone()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Select the most recent error cluster from parsed log lines
+    ///
+    /// Starts at the last error-level line, extends forward over unleveled
+    /// continuation lines (stack frames), backward over neighbouring error and
+    /// continuation lines, then adds a few lines of lead-up context. Returns
+    /// None when there are no error-level lines.
+    pub fn error_window(lines: &[LogLine]) -> Option<&[LogLine]> {
+        let is_error = |line: &LogLine| line.level.as_deref() == Some("error");
+        let last_error = lines.iter().rposition(is_error)?;
+
+        let mut end = last_error + 1;
+        while end < lines.len() && lines[end].level.is_none() {
+            end += 1;
+        }
+
+        let mut start = last_error;
+        while start > 0 && (lines[start - 1].level.is_none() || is_error(&lines[start - 1])) {
+            start -= 1;
+        }
+        start = start.saturating_sub(ERROR_WINDOW_CONTEXT);
+
+        // Oversized clusters lose lead-up first, then trailing frames: the
+        // final error line itself always stays in the window
+        start = start
+            .max(end.saturating_sub(ERROR_WINDOW_MAX_LINES))
+            .min(last_error);
+        end = end.min(start + ERROR_WINDOW_MAX_LINES);
+        Some(&lines[start..end])
+    }
+
     /// Find the log file path for a service
     ///
-    /// Checks common locations in order:
-    /// 1. ~/.openclaw/logs/{service}.log
-    /// 2. /var/log/openclaw/{service}.log
-    /// 3. ~/Library/Logs/openclaw/{service}.log
+    /// Checks locations in order:
+    /// 1. $OPENCLAW_LOG_DIR/{service}.log
+    /// 2. {log_dir}/{service}.log from the wizard config
+    /// 3. ~/.openclaw/logs/{service}.log
+    /// 4. /var/log/openclaw/{service}.log
+    /// 5. ~/Library/Logs/openclaw/{service}.log
     pub fn find_log_path(service: &str) -> Option<PathBuf> {
-        let home = std::env::var("HOME").ok()?;
+        Self::validate_service(service).ok()?;
+        let env_dir = std::env::var("OPENCLAW_LOG_DIR").ok();
+        let config_dir = Self::configured_log_dir();
+        let home = Platform::home_dir()
+            .ok()
+            .map(|h| h.to_string_lossy().into_owned());
 
-        let candidates = vec![
-            PathBuf::from(format!("{}/.openclaw/logs/{}.log", home, service)),
-            PathBuf::from(format!("/var/log/openclaw/{}.log", service)),
-            PathBuf::from(format!("{}/Library/Logs/openclaw/{}.log", home, service)),
-        ];
+        Self::candidate_log_paths(
+            service,
+            env_dir.as_deref(),
+            config_dir.as_deref(),
+            home.as_deref(),
+        )
+        .into_iter()
+        .find(|p| p.exists())
+    }
+
+    /// Build the ordered list of candidate log paths for a service
+    fn candidate_log_paths(
+        service: &str,
+        env_dir: Option<&str>,
+        config_dir: Option<&str>,
+        home: Option<&str>,
+    ) -> Vec<PathBuf> {
+        let file_name = format!("{}.log", service);
+        let mut candidates: Vec<PathBuf> = [env_dir, config_dir]
+            .into_iter()
+            .flatten()
+            .filter(|dir| !dir.is_empty())
+            .map(|dir| PathBuf::from(dir).join(&file_name))
+            .collect();
 
-        candidates.into_iter().find(|p| p.exists())
+        if let Some(home) = home {
+            candidates.push(PathBuf::from(format!(
+                "{}/.openclaw/logs/{}",
+                home, file_name
+            )));
+        }
+        candidates.push(PathBuf::from(format!("/var/log/openclaw/{}", file_name)));
+        if let Some(home) = home {
+            candidates.push(PathBuf::from(format!(
+                "{}/Library/Logs/openclaw/{}",
+                home, file_name
+            )));
+        }
+
+        candidates
+    }
+
+    /// Read the `log_dir` setting from the wizard's saved config
+    fn configured_log_dir() -> Option<String> {
+        use crate::services::config::ConfigWriter;
+        use crate::services::platform::Platform;
+
+        let path = Platform::wizard_config_path().ok()?;
+        let config: serde_json::Value = ConfigWriter::read_json(&path).ok()?;
+        config
+            .get("log_dir")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
     }
 
     /// Parse a single log line into a LogLine struct
@@ -89,34 +256,42 @@ impl LogService {
     /// - Common: `2026-02-16 12:00:00`
     /// - Level patterns: `[ERROR]`, `[WARN]`, `[INFO]`, `[DEBUG]`, `level=error`
     pub fn parse_log_line(line: &str) -> LogLine {
-        let timestamp = Self::extract_timestamp(line);
-        let level = Self::extract_level(line);
+        // Colored output would hide `[ERROR]`-style markers from the parsers
+        let line = strip_ansi(line);
+        let timestamp = Self::extract_timestamp(&line);
+        let level = Self::extract_level(&line);
 
         LogLine {
-            content: line.to_string(),
+            content: line,
             timestamp,
             level,
         }
     }
 
-    /// Start tailing a log file, streaming lines via bounded channel
+    /// Start tailing a service's log file, streaming lines via bounded channel
     ///
-    /// Returns the receiver and task handle. Caller must abort the task on disconnect.
-    /// Channel capacity is 1000 to prevent memory leaks.
-    pub async fn tail_log_file(
-        service: &str,
-    ) -> Result<(mpsc::Receiver<String>, tokio::task::JoinHandle<()>)> {
+    /// Channel capacity is 1000 to prevent memory leaks. Follows the file by
+    /// name, so the stream survives logrotate renaming or recreating the file.
+    /// Drop the returned `LogTail` to stop; see its docs for the lifecycle.
+    pub async fn tail_log_file(service: &str) -> Result<LogTail> {
         let log_path = Self::find_log_path(service)
             .ok_or_else(|| anyhow::anyhow!("No log file found for service: {}", service))?;
+        Ok(Self::tail_path(&log_path))
+    }
 
+    /// Spawn the task running `tail -F` on `path`
+    fn tail_path(path: &Path) -> LogTail {
         let (tx, rx) = mpsc::channel::<String>(LOG_CHANNEL_CAPACITY);
-        let path_str = log_path.to_string_lossy().to_string();
+        let (stop_tx, mut stop_rx) = oneshot::channel::<()>();
+        let path_str = path.to_string_lossy().to_string();
 
-        let handle = tokio::spawn(async move {
+        tokio::spawn(async move {
+            // kill_on_drop also covers the task being aborted at shutdown
             let child = tokio::process::Command::new("tail")
-                .args(["-f", "-n", "0", &path_str])
+                .args(Self::tail_args(&path_str))
                 .stdout(std::process::Stdio::piped())
                 .stderr(std::process::Stdio::null())
+                .kill_on_drop(true)
                 .spawn();
 
             if let Ok(mut child) = child {
@@ -125,9 +300,18 @@ impl LogService {
                     let reader = BufReader::new(stdout);
                     let mut lines = reader.lines();
 
-                    while let Ok(Some(line)) = lines.next_line().await {
-                        if tx.send(line).await.is_err() {
-                            break; // Channel closed, client disconnected
+                    loop {
+                        tokio::select! {
+                            // Resolves when the LogTail (and its sender) is dropped
+                            _ = &mut stop_rx => break,
+                            line = lines.next_line() => match line {
+                                Ok(Some(line)) => {
+                                    if tx.send(line).await.is_err() {
+                                        break; // Channel closed, client disconnected
+                                    }
+                                }
+                                _ => break, // tail exited
+                            },
                         }
                     }
                 }
@@ -135,13 +319,156 @@ impl LogService {
             }
         });
 
-        Ok((rx, handle))
+        LogTail {
+            lines: rx,
+            _stop: stop_tx,
+        }
+    }
+
+    /// Arguments for `tail`: -F follows by name and retries when the file is
+    /// rotated away, unlike -f which stays attached to the old inode.
+    fn tail_args(path: &str) -> [&str; 4] {
+        ["-F", "-n", "0", path]
+    }
+
+    /// Last `lines` lines of a file, reading backwards from the end
+    ///
+    /// Starts with a window sized for `lines` average-length lines and
+    /// doubles it until it holds enough lines, reaches the start of the file
+    /// or hits `max_bytes`. Invalid UTF-8 is replaced.
+    fn read_last_lines(path: &Path, lines: usize, max_bytes: u64) -> Result<Vec<String>> {
+        if lines == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut file = std::fs::File::open(path)?;
+        let len = file.metadata()?.len();
+        let mut window = (lines as u64 + 1)
+            .saturating_mul(ESTIMATED_LINE_BYTES)
+            .min(max_bytes);
+
+        loop {
+            let start = len.saturating_sub(window);
+            let mut buf = Vec::with_capacity((len - start) as usize);
+            file.seek(SeekFrom::Start(start))?;
+            (&mut file).take(len - start).read_to_end(&mut buf)?;
+
+            let text = String::from_utf8_lossy(&buf);
+            let mut found: Vec<&str> = text.lines().collect();
+            // The first line is partial unless the window starts at a line boundary
+            let at_boundary = start == 0 || {
+                file.seek(SeekFrom::Start(start - 1))?;
+                let mut prev = [0u8; 1];
+                file.read_exact(&mut prev)?;
+                prev[0] == b'\n'
+            };
+            if !at_boundary && !found.is_empty() {
+                found.remove(0);
+            }
+
+            if found.len() >= lines || start == 0 || window >= max_bytes {
+                let skip = found.len().saturating_sub(lines);
+                return Ok(found[skip..].iter().map(|s| s.to_string()).collect());
+            }
+            window = window.saturating_mul(2).min(max_bytes);
+        }
+    }
+
+    /// Rotated siblings of a log file, oldest first
+    ///
+    /// Matches numbered rotation (`{name}.1`, `{name}.2.gz`; higher is older)
+    /// and logrotate `dateext` suffixes (`{name}-20260101[.gz]`). Dated files
+    /// are taken to be older than numbered ones.
+    pub fn rotated_log_paths(log_path: &Path) -> Vec<PathBuf> {
+        let (Some(dir), Some(file_name)) = (
+            log_path.parent(),
+            log_path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string()),
+        ) else {
+            return Vec::new();
+        };
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return Vec::new();
+        };
+
+        let mut dated = Vec::new();
+        let mut numbered = Vec::new();
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let Some(suffix) = name.strip_prefix(&file_name) else {
+                continue;
+            };
+            let suffix = suffix.strip_suffix(".gz").unwrap_or(suffix);
+            if let Some(index) = suffix.strip_prefix('.').and_then(|n| n.parse::<u32>().ok()) {
+                numbered.push((index, entry.path()));
+            } else if suffix
+                .strip_prefix('-')
+                .is_some_and(|d| !d.is_empty() && d.chars().all(|c| c.is_ascii_digit()))
+            {
+                dated.push((name, entry.path()));
+            }
+        }
+
+        dated.sort();
+        numbered.sort_by_key(|(index, _)| std::cmp::Reverse(*index));
+        dated
+            .into_iter()
+            .map(|(_, p)| p)
+            .chain(numbered.into_iter().map(|(_, p)| p))
+            .collect()
+    }
+
+    /// Up to `lines` of the most recent rotated lines, oldest first, and the
+    /// rotated files they came from
+    fn read_rotated_lines(log_path: &Path, lines: usize) -> Result<(Vec<String>, Vec<PathBuf>)> {
+        let mut collected: Vec<Vec<String>> = Vec::new();
+        let mut used = Vec::new();
+        let mut remaining = lines;
+
+        // Newest rotation first, until the window is full
+        for path in Self::rotated_log_paths(log_path).into_iter().rev() {
+            if remaining == 0 {
+                break;
+            }
+            let chunk = if path.extension().is_some_and(|ext| ext == "gz") {
+                Self::read_last_gz_lines(&path, remaining)
+            } else {
+                Self::read_last_lines(&path, remaining, MAX_TAIL_BYTES)
+            }
+            .with_context(|| format!("Failed to read rotated log: {}", path.display()))?;
+            remaining -= chunk.len();
+            collected.push(chunk);
+            used.push(path);
+        }
+
+        collected.reverse();
+        used.reverse();
+        Ok((collected.concat(), used))
+    }
+
+    /// Last `lines` lines of a gzipped log, decompressed as a stream so only
+    /// the kept lines are held in memory
+    fn read_last_gz_lines(path: &Path, lines: usize) -> Result<Vec<String>> {
+        let reader = BufReader::new(GzDecoder::new(std::fs::File::open(path)?));
+        let mut tail = VecDeque::with_capacity(lines.min(1024));
+        for line in reader.split(b'\n') {
+            let line = line?;
+            if tail.len() == lines {
+                tail.pop_front();
+            }
+            tail.push_back(
+                String::from_utf8_lossy(&line)
+                    .trim_end_matches('\r')
+                    .to_string(),
+            );
+        }
+        Ok(tail.into())
     }
 
     /// Try to get logs via openclaw command as fallback
     fn try_command_fallback(service: &str, lines: usize) -> Result<Vec<String>> {
-        use crate::services::command::SafeCommand;
-
+        Self::validate_service(service)?;
         let output = SafeCommand::run("openclaw", &[service, "logs", "--tail", &lines.to_string()]);
 
         match output {
@@ -155,6 +482,78 @@ impl LogService {
         }
     }
 
+    /// Last `lines` journal entries of a systemd user unit
+    ///
+    /// Runs `journalctl --user -u <unit> -o json`; an unavailable journal
+    /// yields no lines.
+    fn read_journald(unit: &str, lines: usize) -> Vec<LogLine> {
+        let lines = lines.to_string();
+        let output = SafeCommand::run(
+            "journalctl",
+            &[
+                "--user",
+                "-u",
+                unit,
+                "-n",
+                &lines,
+                "-o",
+                "json",
+                "--no-pager",
+            ],
+        );
+        match output {
+            Ok(out) if out.exit_code == 0 => out
+                .stdout
+                .lines()
+                .filter_map(Self::parse_journal_entry)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Parse one `journalctl -o json` entry
+    ///
+    /// The level comes from the message when it carries one, otherwise from
+    /// the syslog `PRIORITY`. Non-UTF-8 messages arrive as byte arrays.
+    /// ANSI color codes are stripped, as for file logs.
+    fn parse_journal_entry(entry: &str) -> Option<LogLine> {
+        let entry: serde_json::Value = serde_json::from_str(entry).ok()?;
+        let content = match &entry["MESSAGE"] {
+            serde_json::Value::String(message) => strip_ansi(message),
+            serde_json::Value::Array(bytes) => {
+                let bytes: Vec<u8> = bytes
+                    .iter()
+                    .filter_map(|b| b.as_u64().and_then(|b| u8::try_from(b).ok()))
+                    .collect();
+                strip_ansi(&String::from_utf8_lossy(&bytes))
+            }
+            _ => return None,
+        };
+
+        let timestamp = entry["__REALTIME_TIMESTAMP"]
+            .as_str()
+            .and_then(|micros| micros.parse::<i64>().ok())
+            .and_then(chrono::DateTime::from_timestamp_micros)
+            .map(|ts| ts.to_rfc3339_opts(chrono::SecondsFormat::Millis, true));
+
+        let priority_level = entry["PRIORITY"]
+            .as_str()
+            .and_then(|p| p.parse::<u8>().ok())
+            .map(|priority| match priority {
+                0..=3 => "error",
+                4 => "warn",
+                5 | 6 => "info",
+                _ => "debug",
+            });
+        let level = Self::extract_level(&content).or_else(|| priority_level.map(str::to_string));
+
+        Some(LogLine {
+            content,
+            timestamp,
+            level,
+        })
+    }
+
     /// Extract timestamp from a log line
     fn extract_timestamp(line: &str) -> Option<String> {
         // ISO 8601: 2026-02-16T12:00:00.000Z or 2026-02-16T12:00:00+00:00
@@ -246,6 +645,164 @@ impl LogService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::io::Write;
+    use std::time::Duration;
+
+    /// PIDs of `tail` processes following `path`
+    fn tail_pids(path: &Path) -> Vec<u32> {
+        use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+        let mut sys = System::new();
+        sys.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            ProcessRefreshKind::new().with_cmd(UpdateKind::Always),
+        );
+        let path = path.to_string_lossy();
+        sys.processes()
+            .iter()
+            .filter(|(_, p)| p.cmd().iter().any(|arg| arg.to_string_lossy() == path))
+            .map(|(pid, _)| pid.as_u32())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_dropping_log_tail_kills_tail_process() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("gateway.log");
+        std::fs::write(&path, "").unwrap();
+
+        let mut tail = LogService::tail_path(&path);
+        // Keep appending until tail is attached and reports a line
+        let line = loop {
+            let mut file = std::fs::OpenOptions::new()
+                .append(true)
+                .open(&path)
+                .unwrap();
+            writeln!(file, "hello").unwrap();
+            match tokio::time::timeout(Duration::from_millis(200), tail.lines.recv()).await {
+                Ok(line) => break line,
+                Err(_) => continue,
+            }
+        };
+        assert_eq!(line.as_deref(), Some("hello"));
+        assert!(!tail_pids(&path).is_empty());
+
+        drop(tail);
+        for _ in 0..50 {
+            if tail_pids(&path).is_empty() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        panic!("tail process still running after the LogTail was dropped");
+    }
+
+    fn lines(raw: &[&str]) -> Vec<LogLine> {
+        raw.iter().map(|l| LogService::parse_log_line(l)).collect()
+    }
+
+    #[test]
+    fn test_error_window_selects_last_cluster_with_stack_trace() {
+        let parsed = lines(&[
+            "[ERROR] old failure",
+            "[INFO] recovered",
+            "[INFO] one",
+            "[INFO] two",
+            "[INFO] three",
+            "[INFO] four",
+            "[INFO] five",
+            "[INFO] six",
+            "[ERROR] Unhandled rejection",
+            "    at connect (net.js:10)",
+            "    at main (index.js:3)",
+            "[INFO] retrying",
+        ]);
+
+        let window = LogService::error_window(&parsed).unwrap();
+        assert_eq!(window.len(), 8);
+        assert_eq!(window[0].content, "[INFO] two");
+        assert_eq!(window[5].content, "[ERROR] Unhandled rejection");
+        assert_eq!(window[7].content, "    at main (index.js:3)");
+    }
+
+    #[test]
+    fn test_error_window_caps_length_and_keeps_error() {
+        let mut raw = vec!["[INFO] starting", "[ERROR] first"];
+        raw.extend(std::iter::repeat_n("    at frame", 80));
+        let parsed = lines(&raw);
+
+        let window = LogService::error_window(&parsed).unwrap();
+        assert_eq!(window.len(), ERROR_WINDOW_MAX_LINES);
+        assert_eq!(window[0].content, "[ERROR] first");
+        assert_eq!(window.last().unwrap().content, "    at frame");
+
+        // A long lead-up is trimmed from the front instead
+        let mut raw: Vec<&str> = std::iter::repeat_n("[ERROR] earlier", 80).collect();
+        raw.extend(["[ERROR] last", "    at frame"]);
+        let parsed = lines(&raw);
+
+        let window = LogService::error_window(&parsed).unwrap();
+        assert_eq!(window.len(), ERROR_WINDOW_MAX_LINES);
+        assert_eq!(window[window.len() - 2].content, "[ERROR] last");
+    }
+
+    #[test]
+    fn test_error_window_none_without_errors() {
+        let parsed = lines(&["[INFO] ok", "[WARN] slow"]);
+        assert!(LogService::error_window(&parsed).is_none());
+    }
+
+    #[test]
+    fn test_count_levels() {
+        let lines: Vec<LogLine> = [
+            "[ERROR] a",
+            "[ERROR] b",
+            "[WARN] c",
+            "[INFO] d",
+            "continuation line",
+        ]
+        .iter()
+        .map(|l| LogService::parse_log_line(l))
+        .collect();
+
+        let counts = LogService::count_levels(&lines);
+        assert_eq!(counts.get("error"), Some(&2));
+        assert_eq!(counts.get("warn"), Some(&1));
+        assert_eq!(counts.get("info"), Some(&1));
+        assert_eq!(counts.get("debug"), None);
+        assert_eq!(counts.values().sum::<u32>(), 4);
+    }
+
+    #[test]
+    fn test_tail_args_follow_by_name() {
+        let args = LogService::tail_args("/tmp/gateway.log");
+        assert_eq!(args[0], "-F");
+        assert_eq!(args[3], "/tmp/gateway.log");
+    }
+
+    #[test]
+    fn test_candidate_log_paths_prefers_env_and_config_dirs() {
+        let paths = LogService::candidate_log_paths(
+            "gateway",
+            Some("/srv/logs"),
+            Some("/opt/openclaw/logs"),
+            Some("/home/dev"),
+        );
+        assert_eq!(paths[0], PathBuf::from("/srv/logs/gateway.log"));
+        assert_eq!(paths[1], PathBuf::from("/opt/openclaw/logs/gateway.log"));
+        assert_eq!(
+            paths[2],
+            PathBuf::from("/home/dev/.openclaw/logs/gateway.log")
+        );
+        assert_eq!(paths.len(), 5);
+    }
+
+    #[test]
+    fn test_candidate_log_paths_without_home() {
+        let paths = LogService::candidate_log_paths("daemon", Some(""), None, None);
+        assert_eq!(paths, vec![PathBuf::from("/var/log/openclaw/daemon.log")]);
+    }
 
     #[test]
     fn test_parse_log_line_with_iso_timestamp_and_level() {
@@ -302,6 +859,64 @@ mod tests {
         assert_eq!(parsed.level, Some("debug".to_string()));
     }
 
+    #[test]
+    fn test_parse_log_line_strips_ansi_colors() {
+        let line = "\x1b[2m2026-02-16T10:00:00Z\x1b[0m \x1b[31m[ERROR]\x1b[0m boom";
+        let parsed = LogService::parse_log_line(line);
+
+        assert_eq!(parsed.content, "2026-02-16T10:00:00Z [ERROR] boom");
+        assert_eq!(parsed.level, Some("error".to_string()));
+        assert_eq!(parsed.timestamp, Some("2026-02-16T10:00:00Z".to_string()));
+    }
+
+    #[test]
+    fn test_parse_journal_entry() {
+        let entry = r#"{"MESSAGE":"Gateway listening on 18789","PRIORITY":"6","__REALTIME_TIMESTAMP":"1771243200000000"}"#;
+        let line = LogService::parse_journal_entry(entry).unwrap();
+        assert_eq!(line.content, "Gateway listening on 18789");
+        assert_eq!(line.level.as_deref(), Some("info"));
+        assert_eq!(line.timestamp.as_deref(), Some("2026-02-16T12:00:00.000Z"));
+
+        let entry = r#"{"MESSAGE":[91,69,82,82,79,82,93,32,98,111,111,109],"PRIORITY":"6"}"#;
+        let line = LogService::parse_journal_entry(entry).unwrap();
+        assert_eq!(line.content, "[ERROR] boom");
+        assert_eq!(line.level.as_deref(), Some("error"));
+
+        // Colored output is stripped before the level is read
+        let entry = r#"{"MESSAGE":"\u001b[31m[ERROR]\u001b[0m boom","PRIORITY":"6"}"#;
+        let line = LogService::parse_journal_entry(entry).unwrap();
+        assert_eq!(line.content, "[ERROR] boom");
+        assert_eq!(line.level.as_deref(), Some("error"));
+
+        let entry = r#"{"MESSAGE":"crashed","PRIORITY":"3"}"#;
+        let line = LogService::parse_journal_entry(entry).unwrap();
+        assert_eq!(line.level.as_deref(), Some("error"));
+        assert!(line.timestamp.is_none());
+
+        assert!(LogService::parse_journal_entry("not json").is_none());
+        assert!(LogService::parse_journal_entry(r#"{"PRIORITY":"6"}"#).is_none());
+    }
+
+    #[test]
+    fn test_validate_service_rejects_traversal_and_arguments() {
+        assert!(LogService::validate_service("gateway").is_ok());
+        assert!(LogService::validate_service("daemon").is_ok());
+        for bad in [
+            "../etc/passwd",
+            "../../gateway",
+            "gateway/../../x",
+            "gateway logs",
+            "gateway --help",
+            "--version",
+            "",
+            "Gateway",
+        ] {
+            assert!(LogService::validate_service(bad).is_err(), "{:?}", bad);
+            assert!(LogService::find_log_path(bad).is_none());
+            assert!(LogService::get_recent_logs(bad, 10, None, None, false).is_err());
+        }
+    }
+
     #[test]
     fn test_level_severity_ordering() {
         assert!(LogService::level_severity("error") > LogService::level_severity("warn"));
@@ -321,11 +936,78 @@ mod tests {
 
     #[test]
     fn test_get_recent_logs_empty_when_no_file() {
-        let result = LogService::get_recent_logs("nonexistent_service", 100, None, None);
+        // A known service with no log file still succeeds
+        let result = LogService::get_recent_logs("daemon", 100, None, None, false);
         assert!(result.is_ok());
         // Should return empty or whatever the fallback provides
     }
 
+    #[test]
+    fn test_read_last_lines_reads_only_the_tail() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("gateway.log");
+        let content: String = (0..5000).map(|i| format!("line {}\n", i)).collect();
+        std::fs::write(&path, content).unwrap();
+
+        let tail = LogService::read_last_lines(&path, 3, MAX_TAIL_BYTES).unwrap();
+        assert_eq!(tail, vec!["line 4997", "line 4998", "line 4999"]);
+
+        let all = LogService::read_last_lines(&path, 10_000, MAX_TAIL_BYTES).unwrap();
+        assert_eq!(all.len(), 5000);
+        assert_eq!(all[0], "line 0");
+        assert!(
+            LogService::read_last_lines(&path, 0, MAX_TAIL_BYTES)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_read_last_lines_handles_long_lines_and_byte_cap() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("gateway.log");
+        let long = "x".repeat(4000);
+        std::fs::write(&path, format!("first\n{}\n{}\nlast", long, long)).unwrap();
+
+        // The window grows past the estimated line length
+        let tail = LogService::read_last_lines(&path, 2, MAX_TAIL_BYTES).unwrap();
+        assert_eq!(tail, vec![long.as_str(), "last"]);
+
+        // A cap smaller than the lines returns only the complete lines inside it
+        let capped = LogService::read_last_lines(&path, 3, 100).unwrap();
+        assert_eq!(capped, vec!["last"]);
+    }
+
+    #[test]
+    fn test_rotated_logs_are_ordered_and_decoded() {
+        use flate2::{Compression, write::GzEncoder};
+        use std::io::Write;
+
+        let dir = tempfile::tempdir().unwrap();
+        let current = dir.path().join("gateway.log");
+        std::fs::write(&current, "current 1\ncurrent 2\n").unwrap();
+        std::fs::write(dir.path().join("gateway.log.1"), "rotated 1\n").unwrap();
+        let mut gz = GzEncoder::new(Vec::new(), Compression::default());
+        gz.write_all(b"old 1\nold 2\nold 3\n").unwrap();
+        std::fs::write(dir.path().join("gateway.log.2.gz"), gz.finish().unwrap()).unwrap();
+        std::fs::write(dir.path().join("daemon.log.1"), "other service\n").unwrap();
+
+        let rotated = LogService::rotated_log_paths(&current);
+        let names: Vec<_> = rotated
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(names, vec!["gateway.log.2.gz", "gateway.log.1"]);
+
+        let (lines, used) = LogService::read_rotated_lines(&current, 3).unwrap();
+        assert_eq!(lines, vec!["old 2", "old 3", "rotated 1"]);
+        assert_eq!(used, rotated);
+
+        let (lines, used) = LogService::read_rotated_lines(&current, 1).unwrap();
+        assert_eq!(lines, vec!["rotated 1"]);
+        assert_eq!(used.len(), 1);
+    }
+
     #[test]
     fn test_level_filter() {
         // Simulate filtering
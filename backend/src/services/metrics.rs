@@ -0,0 +1,140 @@
+//! # Metrics History
+//!
+//! Samples system and gateway CPU/memory on a fixed interval and keeps the
+//! last few minutes in a bounded in-memory ring buffer for dashboard charts.
+
+use crate::models::types::{MetricsHistoryResponse, MetricsSample};
+use crate::services::service_manager::ServiceManager;
+use std::collections::VecDeque;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use sysinfo::{ProcessesToUpdate, System};
+
+/// Time between samples
+pub const SAMPLE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Samples kept in the ring buffer (5 minutes at the default interval)
+pub const MAX_SAMPLES: usize = 60;
+
+/// Sampled metrics, oldest first
+static HISTORY: LazyLock<Mutex<VecDeque<MetricsSample>>> =
+    LazyLock::new(|| Mutex::new(VecDeque::with_capacity(MAX_SAMPLES)));
+
+pub struct MetricsService;
+
+impl MetricsService {
+    /// Start the background sampler. Call once at startup.
+    pub fn spawn_sampler() {
+        tokio::spawn(async {
+            let mut sys = System::new_all();
+            let mut interval = tokio::time::interval(SAMPLE_INTERVAL);
+            loop {
+                interval.tick().await;
+                // Refreshing all processes is blocking work
+                let result = tokio::task::spawn_blocking(move || {
+                    let sample = Self::sample(&mut sys);
+                    (sys, sample)
+                })
+                .await;
+                match result {
+                    Ok((returned, sample)) => {
+                        sys = returned;
+                        Self::record(sample);
+                    }
+                    Err(e) => {
+                        tracing::warn!("Metrics sampler stopped: {}", e);
+                        return;
+                    }
+                }
+            }
+        });
+    }
+
+    /// Current history snapshot
+    pub fn history() -> MetricsHistoryResponse {
+        let samples = HISTORY
+            .lock()
+            .map(|h| h.iter().cloned().collect())
+            .unwrap_or_default();
+        MetricsHistoryResponse {
+            interval_seconds: SAMPLE_INTERVAL.as_secs(),
+            samples,
+        }
+    }
+
+    /// Append a sample, dropping the oldest once the buffer is full
+    fn record(sample: MetricsSample) {
+        if let Ok(mut history) = HISTORY.lock() {
+            Self::push_bounded(&mut history, sample);
+        }
+    }
+
+    fn push_bounded(history: &mut VecDeque<MetricsSample>, sample: MetricsSample) {
+        while history.len() >= MAX_SAMPLES {
+            history.pop_front();
+        }
+        history.push_back(sample);
+    }
+
+    /// Take one sample. CPU usage is relative to the previous refresh of `sys`.
+    fn sample(sys: &mut System) -> MetricsSample {
+        sys.refresh_cpu_usage();
+        sys.refresh_memory();
+        sys.refresh_processes(ProcessesToUpdate::All, true);
+
+        let gateway = ServiceManager::find_gateway_process(sys);
+
+        MetricsSample {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            system_cpu_percent: sys.global_cpu_usage(),
+            system_memory_used_mb: sys.used_memory() / 1024 / 1024,
+            system_memory_total_mb: sys.total_memory() / 1024 / 1024,
+            gateway_running: gateway.running,
+            gateway_cpu_percent: gateway.cpu_percent,
+            gateway_memory_mb: gateway.memory_mb,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_at(timestamp: u64) -> MetricsSample {
+        MetricsSample {
+            timestamp,
+            system_cpu_percent: 1.0,
+            system_memory_used_mb: 512,
+            system_memory_total_mb: 1024,
+            gateway_running: false,
+            gateway_cpu_percent: None,
+            gateway_memory_mb: None,
+        }
+    }
+
+    #[test]
+    fn test_push_bounded_drops_oldest() {
+        let mut history = VecDeque::new();
+        for t in 0..(MAX_SAMPLES as u64 + 10) {
+            MetricsService::push_bounded(&mut history, sample_at(t));
+        }
+        assert_eq!(history.len(), MAX_SAMPLES);
+        assert_eq!(history.front().unwrap().timestamp, 10);
+        assert_eq!(history.back().unwrap().timestamp, MAX_SAMPLES as u64 + 9);
+    }
+
+    #[test]
+    fn test_sample_reports_system_memory() {
+        let mut sys = System::new_all();
+        let sample = MetricsService::sample(&mut sys);
+        assert!(sample.system_memory_total_mb > 0);
+        assert!(sample.system_memory_used_mb <= sample.system_memory_total_mb);
+        // A running gateway may have unreadable metrics, a stopped one has none
+        if !sample.gateway_running {
+            assert!(sample.gateway_memory_mb.is_none());
+        }
+    }
+}
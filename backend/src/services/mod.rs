@@ -4,20 +4,37 @@ pub mod config;
 pub mod daemon;
 pub mod detection;
 pub mod docker;
+pub mod docker_events;
 pub mod doctor;
 pub mod health;
 pub mod installer;
 pub mod log_analyzer;
 pub mod log_service;
+pub mod metrics;
+pub mod node;
+pub mod offline;
 pub mod platform;
+pub mod processes;
+pub mod provider_mapping;
+pub mod reconcile;
+pub mod redact;
 pub mod remote;
 pub mod service_manager;
+pub mod setup;
 pub mod skills;
 pub mod ssh;
+pub mod ssh_config;
+pub mod support_bundle;
 
 pub use docker::DockerService;
 pub use skills::SkillsService;
+pub mod channel_allowlist;
 pub mod config_analyzer;
+pub mod config_schema;
+pub mod config_set;
+pub mod config_template;
+pub mod confirmation;
+pub mod credentials;
 pub mod multi_server;
 pub mod rollback;
 pub mod security_auditor;
@@ -25,3 +42,4 @@ pub use rollback::RollbackService;
 pub mod uninstaller;
 pub mod upgrader;
 pub mod whatsapp;
+pub mod wizard_config;
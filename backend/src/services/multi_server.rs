@@ -2,27 +2,52 @@
 //!
 //! Manages multi-server deployment: server CRUD, parallel deployment via
 //! tokio::JoinSet, per-server progress tracking via mpsc channels, and
-//! saga-pattern rollback for partial failures.
+//! saga-pattern rollback (per server or for a whole batch) for partial failures.
 //!
-//! Server targets persisted to ~/.openclaw/servers.json.
+//! Server targets persisted to ~/.openclaw/servers.json, deployment runs
+//! recorded in ~/.openclaw/deploy-history.json. Every read-modify-write of
+//! servers.json goes through `update_servers`, which holds a process-wide
+//! lock so overlapping requests can't lose each other's changes.
+//!
+//! Servers can also be imported in bulk from the Host entries in
+//! `~/.ssh/config`.
 
-use crate::models::types::{MultiServerProgress, ServerDeployResult, ServerTarget, WizardConfig};
+use crate::models::types::{
+    DeploymentRecord, MultiServerProgress, ServerDeployResult, ServerTarget, ServerTestResult,
+    SshConfigImportResponse, WizardConfig,
+};
 use crate::services::config::ConfigWriter;
+use crate::services::platform::Platform;
 use crate::services::remote::RemoteService;
-use crate::services::ssh::SshService;
+use crate::services::ssh::{DEFAULT_SSH_PORT, SshService};
+use crate::services::ssh_config::{SshConfig, SshHostEntry};
 use anyhow::{Context, Result};
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::sync::mpsc;
 use tokio::task::JoinSet;
-use tracing::{info, warn};
+use tracing::{Instrument, info, warn};
 
 /// Maximum number of parallel server deployments
 const MAX_PARALLEL_DEPLOYMENTS: usize = 5;
 
+/// Maximum number of concurrent connection tests
+const MAX_PARALLEL_TESTS: usize = 10;
+
 /// Server list persistence file
 const SERVERS_FILE: &str = "servers.json";
 
+/// Serializes every write to servers.json within this process
+static SERVERS_LOCK: Mutex<()> = Mutex::new(());
+
+/// Deployment history persistence file
+const DEPLOY_HISTORY_FILE: &str = "deploy-history.json";
+
+/// Maximum number of deployment runs kept in the history
+const MAX_DEPLOY_HISTORY: usize = 100;
+
 /// Multi-server deployment orchestrator
 pub struct MultiServerOrchestrator;
 
@@ -31,17 +56,53 @@ impl MultiServerOrchestrator {
     ///
     /// Returns empty vec if file doesn't exist.
     pub fn load_servers() -> Result<Vec<ServerTarget>> {
-        let path = Self::servers_file_path();
+        Self::load_servers_at(&Self::servers_file_path()?)
+    }
+
+    /// Atomically read, modify and save the server list
+    ///
+    /// The list is only written back if `f` succeeds.
+    pub fn update_servers<R>(f: impl FnOnce(&mut Vec<ServerTarget>) -> Result<R>) -> Result<R> {
+        Self::update_servers_at(&Self::servers_file_path()?, f)
+    }
+
+    fn update_servers_at<R>(
+        path: &Path,
+        f: impl FnOnce(&mut Vec<ServerTarget>) -> Result<R>,
+    ) -> Result<R> {
+        let _guard = Self::lock_servers();
+        let mut servers = Self::load_servers_at(path)?;
+        let result = f(&mut servers)?;
+        Self::write_servers_at(path, &servers)?;
+        Ok(result)
+    }
+
+    fn load_servers_at(path: &Path) -> Result<Vec<ServerTarget>> {
         if !path.exists() {
             return Ok(Vec::new());
         }
-        ConfigWriter::read_json(&path).context("Failed to read servers.json")
+        ConfigWriter::read_json(path).context("Failed to read servers.json")
     }
 
-    /// Save server list to ~/.openclaw/servers.json
-    pub fn save_servers(servers: &[ServerTarget]) -> Result<()> {
-        let path = Self::servers_file_path();
-        ConfigWriter::write_json(&path, &servers.to_vec()).context("Failed to write servers.json")
+    fn write_servers_at(path: &Path, servers: &[ServerTarget]) -> Result<()> {
+        ConfigWriter::write_json(path, &servers.to_vec()).context("Failed to write servers.json")
+    }
+
+    /// A panic while holding the lock leaves the file intact, so recover the guard
+    fn lock_servers() -> std::sync::MutexGuard<'static, ()> {
+        SERVERS_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Set the status of the given servers in one locked write
+    fn set_statuses(statuses: &[(String, String)]) -> Result<()> {
+        Self::update_servers(|servers| {
+            for (id, status) in statuses {
+                if let Some(server) = servers.iter_mut().find(|s| &s.id == id) {
+                    server.status = status.clone();
+                }
+            }
+            Ok(())
+        })
     }
 
     /// Add a new server target
@@ -55,25 +116,105 @@ impl MultiServerOrchestrator {
             server.status = "pending".to_string();
         }
 
-        let mut servers = Self::load_servers()?;
-        servers.push(server.clone());
-        Self::save_servers(&servers)?;
+        Self::update_servers(|servers| {
+            servers.push(server.clone());
+            Ok(())
+        })?;
 
         info!("Added server: {} ({})", server.name, server.id);
         Ok(server)
     }
 
-    /// Remove a server by ID
-    pub fn remove_server(id: &str) -> Result<()> {
-        let mut servers = Self::load_servers()?;
-        let initial_len = servers.len();
-        servers.retain(|s| s.id != id);
+    /// Import the Host entries of `~/.ssh/config` as server targets
+    ///
+    /// Entries whose host is already in the list are skipped, as are
+    /// entries without a usable user or host name.
+    pub fn import_ssh_config() -> Result<SshConfigImportResponse> {
+        let home = Platform::home_dir()?;
+        let path = home.join(".ssh").join("config");
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+
+        let entries = SshConfig::parse(&content, Some(&home));
+        let default_user = std::env::var("USER")
+            .or_else(|_| std::env::var("USERNAME"))
+            .ok();
+        let response = Self::update_servers(|servers| {
+            Ok(Self::merge_ssh_entries(
+                servers,
+                entries,
+                default_user.as_deref(),
+            ))
+        })?;
+
+        info!(
+            "Imported {} servers from {} ({} skipped)",
+            response.imported.len(),
+            path.display(),
+            response.skipped.len()
+        );
+        Ok(response)
+    }
+
+    /// Append SSH config entries to `servers`, skipping duplicates by host
+    ///
+    /// Hosts on a non-default port are added by alias so ssh applies the
+    /// Port (and the rest of the Host block) from the config when connecting.
+    fn merge_ssh_entries(
+        servers: &mut Vec<ServerTarget>,
+        entries: Vec<SshHostEntry>,
+        default_user: Option<&str>,
+    ) -> SshConfigImportResponse {
+        let mut imported = Vec::new();
+        let mut skipped = Vec::new();
+
+        for entry in entries {
+            let Some(username) = entry.user.as_deref().or(default_user) else {
+                skipped.push(format!("{}: no User set", entry.alias));
+                continue;
+            };
+            let host = if entry.port == DEFAULT_SSH_PORT {
+                entry.hostname.clone()
+            } else {
+                entry.alias.clone()
+            };
+            if let Err(e) = SshService::validate_ssh_request(&host, username, None) {
+                skipped.push(format!("{}: {}", entry.alias, e));
+                continue;
+            }
+
+            let known = [&host, &entry.hostname, &entry.alias];
+            if servers.iter().any(|s| known.contains(&&s.host)) {
+                skipped.push(format!("{}: already added", entry.alias));
+                continue;
+            }
 
-        if servers.len() == initial_len {
-            anyhow::bail!("Server not found: {}", id);
+            let server = ServerTarget {
+                id: Self::generate_server_id(),
+                name: entry.alias,
+                host,
+                username: username.to_string(),
+                key_path: entry.identity_file.unwrap_or_default(),
+                status: "pending".to_string(),
+            };
+            servers.push(server.clone());
+            imported.push(server);
         }
 
-        Self::save_servers(&servers)?;
+        SshConfigImportResponse { imported, skipped }
+    }
+
+    /// Remove a server by ID
+    pub fn remove_server(id: &str) -> Result<()> {
+        Self::update_servers(|servers| {
+            let initial_len = servers.len();
+            servers.retain(|s| s.id != id);
+
+            if servers.len() == initial_len {
+                anyhow::bail!("Server not found: {}", id);
+            }
+            Ok(())
+        })?;
         info!("Removed server: {}", id);
         Ok(())
     }
@@ -81,48 +222,92 @@ impl MultiServerOrchestrator {
     /// Test connection to a server
     ///
     /// Uses SshService::check_connection and updates the server's status.
-    pub async fn test_server(id: &str) -> Result<crate::models::types::ServerTestResult> {
-        let mut servers = Self::load_servers()?;
-        let server = servers
-            .iter_mut()
+    pub async fn test_server(id: &str) -> Result<ServerTestResult> {
+        let server = Self::load_servers()?
+            .into_iter()
             .find(|s| s.id == id)
             .ok_or_else(|| anyhow::anyhow!("Server not found: {}", id))?;
 
-        let ssh = SshService::new();
-        match ssh.check_connection(&server.host, &server.username).await {
-            Ok(true) => {
-                server.status = "connected".to_string();
-                let result = crate::models::types::ServerTestResult {
-                    server_id: id.to_string(),
-                    success: true,
-                    message: format!("Connected to {}@{}", server.username, server.host),
-                };
-                Self::save_servers(&servers)?;
-                Ok(result)
-            }
-            Ok(false) => {
-                server.status = "failed".to_string();
-                let result = crate::models::types::ServerTestResult {
-                    server_id: id.to_string(),
-                    success: false,
-                    message: "SSH authentication failed. Check your SSH key and username."
-                        .to_string(),
+        // The connection check runs without holding the lock
+        let result = Self::check_target(&server).await;
+        Self::set_statuses(&[(server.id, Self::status_for(&result))])?;
+        Ok(result)
+    }
+
+    /// Test connections to many servers concurrently
+    ///
+    /// Tests every server when `server_ids` is None, otherwise only the listed
+    /// ones. At most MAX_PARALLEL_TESTS connections are open at once. Each
+    /// tested server's status is updated and saved in a single write.
+    pub async fn test_all_servers(
+        server_ids: Option<Vec<String>>,
+    ) -> Result<Vec<ServerTestResult>> {
+        let mut pending: Vec<ServerTarget> = Self::load_servers()?
+            .into_iter()
+            .filter(|s| server_ids.as_ref().is_none_or(|ids| ids.contains(&s.id)))
+            .collect();
+
+        let mut join_set = JoinSet::new();
+        let mut results = Vec::with_capacity(pending.len());
+
+        loop {
+            while join_set.len() < MAX_PARALLEL_TESTS {
+                let Some(target) = pending.pop() else {
+                    break;
                 };
-                Self::save_servers(&servers)?;
-                Ok(result)
+                join_set.spawn(async move { Self::check_target(&target).await }.in_current_span());
             }
-            Err(e) => {
-                server.status = "failed".to_string();
-                let msg = format!("Connection failed: {}", e);
-                let result = crate::models::types::ServerTestResult {
-                    server_id: id.to_string(),
-                    success: false,
-                    message: msg,
-                };
-                Self::save_servers(&servers)?;
-                Ok(result)
+
+            match join_set.join_next().await {
+                Some(Ok(result)) => results.push(result),
+                Some(Err(e)) => warn!("Connection test task panicked: {}", e),
+                None => break,
             }
         }
+
+        let statuses: Vec<(String, String)> = results
+            .iter()
+            .map(|r| (r.server_id.clone(), Self::status_for(r)))
+            .collect();
+        Self::set_statuses(&statuses)?;
+
+        info!(
+            "Tested {} servers: {} reachable",
+            results.len(),
+            results.iter().filter(|r| r.success).count()
+        );
+        Ok(results)
+    }
+
+    /// Run a single SSH connection check against a server
+    async fn check_target(server: &ServerTarget) -> ServerTestResult {
+        let ssh = SshService::new();
+        let (success, message) = match ssh.check_connection(&server.host, &server.username).await {
+            Ok(true) => (
+                true,
+                format!("Connected to {}@{}", server.username, server.host),
+            ),
+            Ok(false) => (
+                false,
+                "SSH authentication failed. Check your SSH key and username.".to_string(),
+            ),
+            Err(e) => (false, format!("Connection failed: {}", e)),
+        };
+
+        ServerTestResult {
+            server_id: server.id.clone(),
+            success,
+            message,
+        }
+    }
+
+    /// Server status recorded after a connection test
+    fn status_for(result: &ServerTestResult) -> String {
+        if result.success {
+            "connected".to_string()
+        } else {
+            "failed".to_string()
+        }
     }
 
     /// Deploy OpenClaw to multiple servers in parallel
@@ -134,6 +319,7 @@ impl MultiServerOrchestrator {
         config: WizardConfig,
         progress_tx: mpsc::Sender<MultiServerProgress>,
     ) -> Vec<ServerDeployResult> {
+        let started_at = Self::now_secs();
         let servers = match Self::load_servers() {
             Ok(s) => s,
             Err(e) => {
@@ -156,9 +342,10 @@ impl MultiServerOrchestrator {
             let config_clone = config.clone();
             let agg_tx = progress_tx.clone();
 
-            join_set.spawn(async move {
-                Self::deploy_single_server(target, config_clone, agg_tx).await
-            });
+            join_set.spawn(
+                async move { Self::deploy_single_server(target, config_clone, agg_tx).await }
+                    .in_current_span(),
+            );
         }
 
         // Collect all results
@@ -173,22 +360,55 @@ impl MultiServerOrchestrator {
         }
 
         // Update server statuses
-        if let Ok(mut all_servers) = Self::load_servers() {
-            for result in &results {
-                if let Some(server) = all_servers.iter_mut().find(|s| s.id == result.server_id) {
-                    server.status = if result.success {
-                        "deployed".to_string()
-                    } else {
-                        "failed".to_string()
-                    };
-                }
-            }
-            let _ = Self::save_servers(&all_servers);
+        let statuses: Vec<(String, String)> = results
+            .iter()
+            .map(|r| {
+                let status = if r.success { "deployed" } else { "failed" };
+                (r.server_id.clone(), status.to_string())
+            })
+            .collect();
+        if let Err(e) = Self::set_statuses(&statuses) {
+            warn!("Failed to update server statuses: {}", e);
+        }
+
+        let record = DeploymentRecord {
+            started_at,
+            finished_at: Self::now_secs(),
+            server_ids,
+            results: results.clone(),
+        };
+        if let Err(e) = Self::record_deployment(record) {
+            warn!("Failed to record deployment history: {}", e);
         }
 
         results
     }
 
+    /// Load past deployment runs from ~/.openclaw/deploy-history.json
+    ///
+    /// Returns empty vec if file doesn't exist.
+    pub fn load_deploy_history() -> Result<Vec<DeploymentRecord>> {
+        let path = Self::deploy_history_path()?;
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        ConfigWriter::read_json(&path).context("Failed to read deploy-history.json")
+    }
+
+    /// Append a deployment run, keeping the newest MAX_DEPLOY_HISTORY runs
+    fn record_deployment(record: DeploymentRecord) -> Result<()> {
+        let mut history = Self::load_deploy_history()?;
+        Self::append_bounded(&mut history, record);
+        ConfigWriter::write_json(&Self::deploy_history_path()?, &history)
+            .context("Failed to write deploy-history.json")
+    }
+
+    fn append_bounded(history: &mut Vec<DeploymentRecord>, record: DeploymentRecord) {
+        history.push(record);
+        let excess = history.len().saturating_sub(MAX_DEPLOY_HISTORY);
+        history.drain(..excess);
+    }
+
     /// Deploy to a single server with progress reporting
     async fn deploy_single_server(
         target: ServerTarget,
@@ -209,29 +429,32 @@ impl MultiServerOrchestrator {
         let fwd_name = server_name.clone();
         let fwd_tx = agg_tx.clone();
 
-        let forward_handle = tokio::spawn(async move {
-            let mut completed_stages = Vec::new();
-            while let Some(progress) = per_server_rx.recv().await {
-                if progress.status == "completed" {
-                    completed_stages.push(progress.stage.clone());
-                }
-
-                let multi_progress = MultiServerProgress {
-                    server_id: fwd_id.clone(),
-                    server_name: fwd_name.clone(),
-                    stage: progress.stage,
-                    status: progress.status,
-                    message: progress.message,
-                    error: progress.error,
-                    timestamp: progress.timestamp,
-                };
+        let forward_handle = tokio::spawn(
+            async move {
+                let mut completed_stages = Vec::new();
+                while let Some(progress) = per_server_rx.recv().await {
+                    if progress.status == "completed" {
+                        completed_stages.push(progress.stage.clone());
+                    }
+
+                    let multi_progress = MultiServerProgress {
+                        server_id: fwd_id.clone(),
+                        server_name: fwd_name.clone(),
+                        stage: progress.stage,
+                        status: progress.status,
+                        message: progress.message,
+                        error: progress.error,
+                        timestamp: progress.timestamp,
+                    };
 
-                if fwd_tx.send(multi_progress).await.is_err() {
-                    break;
+                    if fwd_tx.send(multi_progress).await.is_err() {
+                        break;
+                    }
                 }
+                completed_stages
             }
-            completed_stages
-        });
+            .in_current_span(),
+        );
 
         // Run the actual deployment
         let deploy_result = remote
@@ -324,11 +547,7 @@ npm uninstall -g @anthropic/openclaw 2>/dev/null || true"#;
         }
 
         // Update server status to pending
-        let mut all_servers = Self::load_servers()?;
-        if let Some(s) = all_servers.iter_mut().find(|s| s.id == id) {
-            s.status = "pending".to_string();
-        }
-        Self::save_servers(&all_servers)?;
+        Self::set_statuses(&[(id.to_string(), "pending".to_string())])?;
 
         Ok(ServerDeployResult {
             server_id: id.to_string(),
@@ -339,20 +558,77 @@ npm uninstall -g @anthropic/openclaw 2>/dev/null || true"#;
         })
     }
 
+    /// Roll back a batch of servers concurrently
+    ///
+    /// Runs `rollback_server` for each id, up to MAX_PARALLEL_DEPLOYMENTS at a
+    /// time. Servers that can't be rolled back (e.g. unknown ids) are reported
+    /// as failed results rather than aborting the batch.
+    pub async fn rollback_batch(server_ids: Vec<String>) -> Vec<ServerDeployResult> {
+        // Drop repeated ids anywhere in the list, keeping request order
+        let mut seen = HashSet::new();
+        let mut pending = server_ids;
+        pending.retain(|id| seen.insert(id.clone()));
+        pending.reverse();
+
+        let mut join_set = JoinSet::new();
+        let mut results = Vec::with_capacity(pending.len());
+
+        loop {
+            while join_set.len() < MAX_PARALLEL_DEPLOYMENTS {
+                let Some(id) = pending.pop() else {
+                    break;
+                };
+                join_set.spawn(
+                    async move {
+                        Self::rollback_server(&id)
+                            .await
+                            .unwrap_or_else(|e| ServerDeployResult {
+                                server_id: id.clone(),
+                                server_name: String::new(),
+                                success: false,
+                                error: Some(e.to_string()),
+                                completed_stages: vec![],
+                            })
+                    }
+                    .in_current_span(),
+                );
+            }
+
+            match join_set.join_next().await {
+                Some(Ok(result)) => results.push(result),
+                Some(Err(e)) => warn!("Rollback task panicked: {}", e),
+                None => break,
+            }
+        }
+
+        info!(
+            "Batch rollback complete: {}/{} successful",
+            results.iter().filter(|r| r.success).count(),
+            results.len()
+        );
+        results
+    }
+
     /// Get the path to ~/.openclaw/servers.json
-    fn servers_file_path() -> PathBuf {
-        let home = std::env::var("HOME").unwrap_or_else(|_| "/tmp".to_string());
-        PathBuf::from(home).join(".openclaw").join(SERVERS_FILE)
+    fn servers_file_path() -> Result<PathBuf> {
+        Ok(Platform::openclaw_dir()?.join(SERVERS_FILE))
     }
 
-    /// Generate a unique server ID using timestamp + random suffix
-    fn generate_server_id() -> String {
-        let timestamp = SystemTime::now()
+    /// Get the path to ~/.openclaw/deploy-history.json
+    fn deploy_history_path() -> Result<PathBuf> {
+        Ok(Platform::openclaw_dir()?.join(DEPLOY_HISTORY_FILE))
+    }
+
+    fn now_secs() -> u64 {
+        SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap_or_default()
-            .as_millis();
-        let random: u32 = (timestamp as u32).wrapping_mul(2654435761); // Knuth multiplicative hash
-        format!("srv-{:x}-{:x}", timestamp, random)
+            .as_secs()
+    }
+
+    /// Generate a unique server ID from a random UUID, e.g. `srv-3f2c...`
+    fn generate_server_id() -> String {
+        format!("srv-{}", uuid::Uuid::new_v4())
     }
 }
 
@@ -360,19 +636,69 @@ npm uninstall -g @anthropic/openclaw 2>/dev/null || true"#;
 mod tests {
     use super::*;
 
+    fn ssh_entry(alias: &str, hostname: &str, port: u16, user: Option<&str>) -> SshHostEntry {
+        SshHostEntry {
+            alias: alias.to_string(),
+            hostname: hostname.to_string(),
+            user: user.map(str::to_string),
+            port,
+            identity_file: Some("/home/me/.ssh/id_ed25519".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_merge_ssh_entries_skips_duplicates() {
+        let mut servers = vec![ServerTarget {
+            id: "srv-1".into(),
+            name: "existing".into(),
+            host: "203.0.113.10".into(),
+            username: "root".into(),
+            key_path: String::new(),
+            status: "connected".into(),
+        }];
+        let entries = vec![
+            ssh_entry("web1", "203.0.113.10", 22, Some("deploy")),
+            ssh_entry("db", "db.example.com", 2222, Some("ops")),
+            ssh_entry("cache", "cache.example.com", 22, None),
+            ssh_entry("app", "app.example.com", 22, None),
+        ];
+
+        let response = MultiServerOrchestrator::merge_ssh_entries(&mut servers, entries, None);
+        let hosts: Vec<&str> = response.imported.iter().map(|s| s.host.as_str()).collect();
+        // Non-default port keeps the alias so ssh picks up Port from the config
+        assert_eq!(hosts, ["db"]);
+        assert_eq!(response.imported[0].username, "ops");
+        assert_eq!(response.imported[0].key_path, "/home/me/.ssh/id_ed25519");
+        assert_eq!(response.skipped.len(), 3);
+        assert!(response.skipped[0].contains("already added"));
+        assert_eq!(servers.len(), 2);
+
+        // A default user fills in hosts without User
+        let again = vec![ssh_entry("app", "app.example.com", 22, None)];
+        let response = MultiServerOrchestrator::merge_ssh_entries(&mut servers, again, Some("me"));
+        assert_eq!(response.imported[0].username, "me");
+        assert_eq!(response.imported[0].host, "app.example.com");
+    }
+
     #[test]
     fn test_generate_server_id_unique() {
         let id1 = MultiServerOrchestrator::generate_server_id();
-        // Small delay to get different timestamp
-        std::thread::sleep(std::time::Duration::from_millis(2));
         let id2 = MultiServerOrchestrator::generate_server_id();
         assert_ne!(id1, id2, "Two generated IDs should be different");
         assert!(id1.starts_with("srv-"), "ID should start with srv-");
     }
 
+    #[test]
+    fn test_generate_server_id_no_collisions_in_tight_loop() {
+        let ids: HashSet<String> = (0..10_000)
+            .map(|_| MultiServerOrchestrator::generate_server_id())
+            .collect();
+        assert_eq!(ids.len(), 10_000);
+    }
+
     #[test]
     fn test_servers_file_path() {
-        let path = MultiServerOrchestrator::servers_file_path();
+        let path = MultiServerOrchestrator::servers_file_path().unwrap();
         let path_str = path.to_string_lossy();
         assert!(
             path_str.ends_with("servers.json"),
@@ -389,11 +715,119 @@ mod tests {
         // When servers.json doesn't exist, should return empty vec
         // This test works because we don't have a servers.json in the test env
         // (or if we do, it would be valid JSON)
-        let path = MultiServerOrchestrator::servers_file_path();
+        let path = MultiServerOrchestrator::servers_file_path().unwrap();
         if !path.exists() {
             let result = MultiServerOrchestrator::load_servers();
             assert!(result.is_ok());
             assert!(result.unwrap().is_empty());
         }
     }
+
+    #[test]
+    fn test_status_for_result() {
+        let mut result = ServerTestResult {
+            server_id: "srv-1".into(),
+            success: true,
+            message: String::new(),
+        };
+        assert_eq!(MultiServerOrchestrator::status_for(&result), "connected");
+        result.success = false;
+        assert_eq!(MultiServerOrchestrator::status_for(&result), "failed");
+    }
+
+    #[test]
+    fn test_deploy_history_path() {
+        let path = MultiServerOrchestrator::deploy_history_path().unwrap();
+        assert!(path.ends_with(".openclaw/deploy-history.json"));
+    }
+
+    #[test]
+    fn test_append_bounded_keeps_newest() {
+        let mut history = Vec::new();
+        for i in 0..(MAX_DEPLOY_HISTORY as u64 + 3) {
+            let record = DeploymentRecord {
+                started_at: i,
+                finished_at: i + 1,
+                server_ids: vec!["srv-1".into()],
+                results: vec![],
+            };
+            MultiServerOrchestrator::append_bounded(&mut history, record);
+        }
+        assert_eq!(history.len(), MAX_DEPLOY_HISTORY);
+        assert_eq!(history[0].started_at, 3);
+    }
+
+    #[tokio::test]
+    async fn test_rollback_batch_reports_unknown_servers() {
+        let results = MultiServerOrchestrator::rollback_batch(vec![
+            "srv-does-not-exist-a".into(),
+            "srv-does-not-exist-b".into(),
+        ])
+        .await;
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| !r.success));
+        assert!(results.iter().all(|r| {
+            r.error
+                .as_deref()
+                .unwrap_or("")
+                .contains("Server not found")
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_rollback_batch_skips_repeated_ids() {
+        let results = MultiServerOrchestrator::rollback_batch(vec![
+            "srv-does-not-exist-a".into(),
+            "srv-does-not-exist-b".into(),
+            "srv-does-not-exist-a".into(),
+        ])
+        .await;
+        let mut ids: Vec<&str> = results.iter().map(|r| r.server_id.as_str()).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, ["srv-does-not-exist-a", "srv-does-not-exist-b"]);
+    }
+
+    #[test]
+    fn test_concurrent_updates_do_not_lose_writes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SERVERS_FILE);
+
+        let handles: Vec<_> = (0..16)
+            .map(|i| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    MultiServerOrchestrator::update_servers_at(&path, |servers| {
+                        servers.push(ServerTarget {
+                            id: format!("srv-{}", i),
+                            name: format!("server {}", i),
+                            host: "example.com".into(),
+                            username: "root".into(),
+                            key_path: "~/.ssh/id_ed25519".into(),
+                            status: "pending".into(),
+                        });
+                        Ok(())
+                    })
+                    .unwrap();
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let servers = MultiServerOrchestrator::load_servers_at(&path).unwrap();
+        assert_eq!(servers.len(), 16);
+    }
+
+    #[test]
+    fn test_update_servers_skips_write_on_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SERVERS_FILE);
+
+        let result = MultiServerOrchestrator::update_servers_at(&path, |_| -> Result<()> {
+            anyhow::bail!("Server not found: srv-x")
+        });
+        assert!(result.is_err());
+        assert!(!path.exists());
+    }
 }
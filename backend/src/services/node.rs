@@ -0,0 +1,162 @@
+//! # Node.js Detection
+//!
+//! Finds the `node` binary on PATH and works out which installer put it
+//! there (Homebrew, nvm, a distro package, ...) from its resolved path.
+//! The installer uses this to avoid silently layering the official package
+//! over a Node.js that a version manager owns.
+
+use std::path::{Path, PathBuf};
+
+use crate::models::types::NodeSource;
+use crate::services::command::SafeCommand;
+use crate::services::platform::Platform;
+
+/// Where the active Node.js comes from
+#[derive(Debug, Clone)]
+pub struct NodeInstallation {
+    pub version: Option<String>,
+    /// Resolved (symlink-free) path of the `node` binary
+    pub path: Option<PathBuf>,
+    pub source: Option<NodeSource>,
+}
+
+/// Node.js detection helpers
+pub struct NodeDetector;
+
+impl NodeDetector {
+    /// Detect the Node.js that `node` resolves to on PATH
+    pub fn detect() -> NodeInstallation {
+        let home = Platform::home_dir().ok();
+        let path = Self::find_on_path();
+        NodeInstallation {
+            version: SafeCommand::check_node_version().ok().flatten(),
+            source: path
+                .as_deref()
+                .map(|p| Self::source_for(p, home.as_deref())),
+            path,
+        }
+    }
+
+    /// First `node` executable on PATH, with symlinks resolved
+    fn find_on_path() -> Option<PathBuf> {
+        let binary = if cfg!(windows) { "node.exe" } else { "node" };
+        let path_var = std::env::var_os("PATH")?;
+        std::env::split_paths(&path_var)
+            .map(|dir| dir.join(binary))
+            .find(|candidate| candidate.is_file())
+            .map(|found| std::fs::canonicalize(&found).unwrap_or(found))
+    }
+
+    /// Classify a resolved node path by the directory layout each installer uses
+    pub fn source_for(path: &Path, home: Option<&Path>) -> NodeSource {
+        let p = path.to_string_lossy().replace('\\', "/");
+        let under_home = |dir: &str| {
+            home.is_some_and(|h| path.starts_with(h.join(dir))) || p.contains(&format!("/{}/", dir))
+        };
+
+        if p.starts_with("/opt/homebrew/")
+            || p.contains("/Cellar/")
+            || p.starts_with("/home/linuxbrew/.linuxbrew/")
+        {
+            NodeSource::Homebrew
+        } else if under_home(".nvm") || p.contains("/nvm/versions/") {
+            NodeSource::Nvm
+        } else if under_home(".volta") {
+            NodeSource::Volta
+        } else if p.contains("/fnm/") || under_home(".fnm") {
+            NodeSource::Fnm
+        } else if under_home(".asdf") || p.contains("/asdf/installs/") {
+            NodeSource::Asdf
+        } else if p.starts_with("/usr/bin/") || p.starts_with("/bin/") {
+            NodeSource::SystemPackage
+        } else if p.starts_with("/usr/local/") || p.contains("/nodejs/") {
+            NodeSource::Official
+        } else {
+            NodeSource::Unknown
+        }
+    }
+
+    /// Whether a version manager owns this Node.js, so a package install
+    /// would shadow (or be shadowed by) it instead of upgrading it
+    pub fn is_managed(source: NodeSource) -> bool {
+        matches!(
+            source,
+            NodeSource::Homebrew
+                | NodeSource::Nvm
+                | NodeSource::Volta
+                | NodeSource::Fnm
+                | NodeSource::Asdf
+        )
+    }
+
+    /// Command that upgrades Node.js through its own manager
+    pub fn upgrade_hint(source: NodeSource) -> &'static str {
+        match source {
+            NodeSource::Homebrew => "brew upgrade node",
+            NodeSource::Nvm => "nvm install 22 && nvm alias default 22",
+            NodeSource::Volta => "volta install node@22",
+            NodeSource::Fnm => "fnm install 22 && fnm default 22",
+            NodeSource::Asdf => "asdf install nodejs latest:22",
+            NodeSource::SystemPackage => {
+                "upgrade the nodejs package (e.g. from the NodeSource repository)"
+            }
+            NodeSource::Official | NodeSource::Unknown => {
+                "install Node.js 22+ from https://nodejs.org/"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_for_known_layouts() {
+        let home = Path::new("/home/me");
+        let cases = [
+            (
+                "/opt/homebrew/Cellar/node/21.1.0/bin/node",
+                NodeSource::Homebrew,
+            ),
+            (
+                "/usr/local/Cellar/node/20.0.0/bin/node",
+                NodeSource::Homebrew,
+            ),
+            (
+                "/home/me/.nvm/versions/node/v18.19.0/bin/node",
+                NodeSource::Nvm,
+            ),
+            (
+                "/home/me/.volta/tools/image/node/20.1.0/bin/node",
+                NodeSource::Volta,
+            ),
+            (
+                "/home/me/.local/share/fnm/node-versions/v20.0.0/installation/bin/node",
+                NodeSource::Fnm,
+            ),
+            (
+                "/home/me/.asdf/installs/nodejs/20.0.0/bin/node",
+                NodeSource::Asdf,
+            ),
+            ("/usr/bin/node", NodeSource::SystemPackage),
+            ("/usr/local/bin/node", NodeSource::Official),
+            ("/srv/tools/node", NodeSource::Unknown),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(
+                NodeDetector::source_for(Path::new(path), Some(home)),
+                expected,
+                "{}",
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn test_managed_sources_have_manager_hints() {
+        assert!(NodeDetector::is_managed(NodeSource::Nvm));
+        assert!(!NodeDetector::is_managed(NodeSource::SystemPackage));
+        assert!(NodeDetector::upgrade_hint(NodeSource::Homebrew).starts_with("brew"));
+    }
+}
@@ -0,0 +1,92 @@
+//! # Offline Mode
+//!
+//! `--offline` (or `OPENCLAW_WIZARD_OFFLINE=1`) turns off every outbound
+//! call the wizard makes on its own: npm registry searches and version
+//! checks, VirusTotal scans, AI analysis and credential validation against
+//! provider APIs. Features that need the network fail with
+//! `AppError::Offline`, AI analysis falls back to the heuristic analyzer,
+//! and npm installs run with `--offline` so only the local cache is used.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::error::AppError;
+
+/// Environment variable that enables offline mode
+pub const OFFLINE_ENV: &str = "OPENCLAW_WIZARD_OFFLINE";
+
+/// Set once at startup
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// Process-wide offline switch
+pub struct OfflineMode;
+
+impl OfflineMode {
+    /// Turn offline mode on for the rest of the process
+    pub fn enable() {
+        OFFLINE.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether outbound network calls are disabled
+    pub fn is_enabled() -> bool {
+        OFFLINE.load(Ordering::Relaxed)
+    }
+
+    /// Whether `OPENCLAW_WIZARD_OFFLINE` asks for offline mode
+    pub fn env_requested() -> bool {
+        Self::env_requested_from(std::env::var(OFFLINE_ENV).ok().as_deref())
+    }
+
+    fn env_requested_from(value: Option<&str>) -> bool {
+        value.is_some_and(|v| {
+            !matches!(
+                v.trim().to_ascii_lowercase().as_str(),
+                "" | "0" | "false" | "no"
+            )
+        })
+    }
+
+    /// Fail with `AppError::Offline` if `action` would need the network
+    pub fn ensure_online(action: &str) -> Result<(), AppError> {
+        if Self::is_enabled() {
+            Err(Self::error(action))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn error(action: &str) -> AppError {
+        AppError::Offline(format!(
+            "{} needs network access, but the wizard is running in offline mode",
+            action
+        ))
+    }
+
+    /// Extra npm arguments that restrict installs to the local cache
+    pub fn npm_install_args() -> &'static [&'static str] {
+        if Self::is_enabled() {
+            &["--offline"]
+        } else {
+            &[]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_requested_from() {
+        assert!(OfflineMode::env_requested_from(Some("1")));
+        assert!(OfflineMode::env_requested_from(Some("true")));
+        assert!(!OfflineMode::env_requested_from(Some("0")));
+        assert!(!OfflineMode::env_requested_from(Some(" ")));
+        assert!(!OfflineMode::env_requested_from(None));
+    }
+
+    #[test]
+    fn test_error_names_the_action() {
+        let err = OfflineMode::error("Skill search").to_string();
+        assert!(err.contains("Skill search needs network access"));
+    }
+}
@@ -6,10 +6,72 @@
 use anyhow::{Context, Result};
 use std::env;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use crate::models::types::{DockerStatusResponse, SystemIdentity, WizardVersion};
 use crate::models::{RequirementCheck, SystemInfo, SystemRequirements};
 use crate::services::command::SafeCommand;
+use crate::services::node::NodeDetector;
+
+/// Port the OpenClaw gateway listens on when its config doesn't set one
+pub const DEFAULT_GATEWAY_PORT: u16 = 18789;
+
+/// External tool an optional feature needs, checked on request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeatureTool {
+    /// Docker sandbox
+    Docker,
+    /// Skill repositories
+    Git,
+}
+
+impl FeatureTool {
+    /// Parse a comma-separated feature list: `docker` (or `sandbox`) and
+    /// `git` (or `skills`). Duplicates are dropped.
+    pub fn parse_list(list: &str) -> std::result::Result<Vec<Self>, String> {
+        let mut tools = Vec::new();
+        for feature in list.split(',').map(str::trim).filter(|f| !f.is_empty()) {
+            let tool = match feature.to_ascii_lowercase().as_str() {
+                "docker" | "sandbox" => Self::Docker,
+                "git" | "skills" => Self::Git,
+                other => {
+                    return Err(format!(
+                        "Unknown feature '{}'; expected docker, sandbox, git or skills",
+                        other
+                    ));
+                }
+            };
+            if !tools.contains(&tool) {
+                tools.push(tool);
+            }
+        }
+        Ok(tools)
+    }
+
+    /// How to install the tool on `os` (as returned by `Platform::os`)
+    pub fn install_hint(self, os: &str) -> &'static str {
+        match (self, os) {
+            (Self::Docker, "macos") => {
+                "Install Docker Desktop from https://docs.docker.com/desktop/ (or `brew install --cask docker`) and start it"
+            }
+            (Self::Docker, "windows") => {
+                "Install Docker Desktop with the WSL 2 backend from https://docs.docker.com/desktop/ and start it"
+            }
+            (Self::Docker, _) => {
+                "Install Docker Engine (https://docs.docker.com/engine/install/) and start it with `sudo systemctl start docker`"
+            }
+            (Self::Git, "macos") => {
+                "Install Git with `xcode-select --install` or `brew install git`"
+            }
+            (Self::Git, "windows") => {
+                "Install Git for Windows from https://git-scm.com/download/win (or `winget install Git.Git`)"
+            }
+            (Self::Git, _) => {
+                "Install Git with your package manager, e.g. `sudo apt install git` or `sudo dnf install git`"
+            }
+        }
+    }
+}
 
 /// Platform detection and path utilities
 pub struct Platform;
@@ -34,7 +96,6 @@ impl Platform {
     /// Checks /proc/version for "microsoft" string on Linux systems.
     /// Always returns false on non-Linux systems.
     #[cfg(target_os = "linux")]
-    #[allow(dead_code)]
     pub fn is_wsl() -> bool {
         if let Ok(version) = fs::read_to_string("/proc/version") {
             version.to_lowercase().contains("microsoft")
@@ -44,19 +105,108 @@ impl Platform {
     }
 
     #[cfg(not(target_os = "linux"))]
-    #[allow(dead_code)]
     pub fn is_wsl() -> bool {
         false
     }
 
+    /// Why the browser shouldn't be opened automatically, if it shouldn't
+    ///
+    /// Set `OPENCLAW_WIZARD_NO_OPEN=1` to opt out explicitly. SSH sessions and
+    /// Linux without a display (servers, containers) are treated as headless.
+    pub fn browser_skip_reason() -> Option<&'static str> {
+        Self::browser_skip_reason_from(|name| env::var(name).ok(), Self::os(), Self::is_wsl())
+    }
+
+    fn browser_skip_reason_from(
+        lookup: impl Fn(&str) -> Option<String>,
+        os: &str,
+        wsl: bool,
+    ) -> Option<&'static str> {
+        let set = |name: &str| lookup(name).is_some_and(|v| !v.trim().is_empty());
+
+        let opted_out = lookup("OPENCLAW_WIZARD_NO_OPEN").is_some_and(|v| {
+            !matches!(
+                v.trim().to_ascii_lowercase().as_str(),
+                "" | "0" | "false" | "no"
+            )
+        });
+        if opted_out {
+            Some("OPENCLAW_WIZARD_NO_OPEN is set")
+        } else if set("SSH_CONNECTION") {
+            Some("running over SSH")
+        } else if os == "linux" && !wsl && !set("DISPLAY") && !set("WAYLAND_DISPLAY") {
+            // WSL opens the Windows browser without a Linux display
+            Some("no graphical display")
+        } else {
+            None
+        }
+    }
+
     /// Returns the user's home directory
     ///
-    /// Tries HOME env var (Unix), then USERPROFILE (Windows).
+    /// Tries HOME env var (Unix), then USERPROFILE (Windows); empty values
+    /// count as unset. This is the only place the home directory is read.
+    /// There is no fallback: callers that can work without one (log and
+    /// Docker socket discovery) use `.ok()` and skip home-relative paths.
     pub fn home_dir() -> Result<PathBuf> {
-        env::var("HOME")
-            .or_else(|_| env::var("USERPROFILE"))
-            .context("Failed to determine home directory (HOME or USERPROFILE not set)")
+        Self::home_dir_from(env::var_os("HOME"), env::var_os("USERPROFILE"))
+    }
+
+    pub(crate) fn home_dir_from(
+        home: Option<std::ffi::OsString>,
+        userprofile: Option<std::ffi::OsString>,
+    ) -> Result<PathBuf> {
+        home.filter(|h| !h.is_empty())
+            .or_else(|| userprofile.filter(|p| !p.is_empty()))
             .map(PathBuf::from)
+            .context(
+                "Failed to determine home directory: HOME (or USERPROFILE) is not set. \
+                 Set HOME for the service (e.g. systemd units with ProtectHome=), or \
+                 OPENCLAW_CONFIG_PATH / OPENCLAW_STATE_DIR to locate the gateway config",
+            )
+    }
+
+    /// Returns OpenClaw's own directory (`~/.openclaw`)
+    ///
+    /// Resolved from `home_dir`, so it is `%USERPROFILE%\.openclaw` on Windows.
+    /// This is where the gateway reads openclaw.json and writes its logs;
+    /// the wizard's own settings live in `config_dir`.
+    pub fn openclaw_dir() -> Result<PathBuf> {
+        Ok(Self::home_dir()?.join(".openclaw"))
+    }
+
+    /// Returns the path of the config file the gateway loads
+    ///
+    /// Follows the gateway's precedence: `OPENCLAW_CONFIG_PATH`, then
+    /// `$OPENCLAW_STATE_DIR/openclaw.json`, then `~/.openclaw/openclaw.json`.
+    /// Everything that reads or writes the gateway config goes through here.
+    pub fn openclaw_config_path() -> Result<PathBuf> {
+        let env_path = env::var_os("OPENCLAW_CONFIG_PATH").map(PathBuf::from);
+        let state_dir = env::var_os("OPENCLAW_STATE_DIR").map(PathBuf::from);
+        if let Some(path) = Self::configured_gateway_config(env_path, state_dir) {
+            return Ok(path);
+        }
+        Ok(Self::openclaw_dir()?.join("openclaw.json"))
+    }
+
+    /// Gateway config path set through the environment, ignoring empty values
+    fn configured_gateway_config(
+        env_path: Option<PathBuf>,
+        state_dir: Option<PathBuf>,
+    ) -> Option<PathBuf> {
+        env_path.filter(|p| !p.as_os_str().is_empty()).or_else(|| {
+            state_dir
+                .filter(|d| !d.as_os_str().is_empty())
+                .map(|d| d.join("openclaw.json"))
+        })
+    }
+
+    /// Returns the path of the wizard's saved settings (`config_dir/openclaw.json`)
+    ///
+    /// This is the wizard's own format, not something the gateway reads;
+    /// `ConfigReconciler` deploys it to `openclaw_config_path`.
+    pub fn wizard_config_path() -> Result<PathBuf> {
+        Ok(Self::config_dir()?.join("openclaw.json"))
     }
 
     /// Returns the application config directory
@@ -142,16 +292,110 @@ impl Platform {
 
     /// Returns comprehensive system information
     ///
-    /// Combines OS, arch, Node.js version detection, and OpenClaw detection.
+    /// Combines OS, arch, Node.js version and source detection, and OpenClaw detection.
     pub fn system_info() -> SystemInfo {
+        let node = NodeDetector::detect();
         SystemInfo {
             os: Self::os().to_string(),
             arch: Self::arch().to_string(),
-            node_version: SafeCommand::check_node_version().ok().flatten(),
+            node_version: node.version,
+            node_path: node.path.map(|p| p.display().to_string()),
+            node_source: node.source,
             openclaw_installed: false, // TODO: Implement OpenClaw binary detection
         }
     }
 
+    /// Version and build info of this wizard binary, embedded by build.rs
+    pub fn wizard_version() -> WizardVersion {
+        let build_timestamp = env!("WIZARD_BUILD_TIMESTAMP")
+            .parse::<i64>()
+            .ok()
+            .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+            .map(|t| t.to_rfc3339())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        WizardVersion {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            git_commit: env!("WIZARD_GIT_COMMIT").to_string(),
+            build_timestamp,
+        }
+    }
+
+    /// Returns the effective user and the directories the wizard reads
+    ///
+    /// Helps explain "wrong config" issues, e.g. when launched via sudo and
+    /// HOME points at /root instead of the invoking user's home.
+    pub fn identity() -> SystemIdentity {
+        let (uid, username) = Self::effective_user();
+        let home = Self::home_dir().ok();
+        let config_dir = Self::config_dir().ok();
+        let openclaw_dir = Self::openclaw_dir().ok();
+
+        SystemIdentity {
+            uid,
+            username,
+            sudo_user: env::var("SUDO_USER").ok().filter(|u| !u.is_empty()),
+            home: home.map(|p| p.display().to_string()),
+            config_dir_writable: config_dir.as_deref().is_some_and(Self::is_dir_writable),
+            config_dir: config_dir.map(|p| p.display().to_string()),
+            openclaw_dir_writable: openclaw_dir.as_deref().is_some_and(Self::is_dir_writable),
+            openclaw_dir: openclaw_dir.map(|p| p.display().to_string()),
+        }
+    }
+
+    /// Effective uid and user name of this process
+    fn effective_user() -> (Option<u32>, Option<String>) {
+        use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind, Users};
+
+        let Ok(pid) = sysinfo::get_current_pid() else {
+            return (None, env::var("USER").ok());
+        };
+        let mut sys = System::new();
+        sys.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[pid]),
+            false,
+            ProcessRefreshKind::new().with_user(UpdateKind::Always),
+        );
+        let Some(user_id) = sys
+            .process(pid)
+            .and_then(|p| p.effective_user_id().or(p.user_id()))
+            .cloned()
+        else {
+            return (None, env::var("USER").ok());
+        };
+
+        let users = Users::new_with_refreshed_list();
+        let username = users
+            .get_user_by_id(&user_id)
+            .map(|u| u.name().to_string())
+            .or_else(|| env::var("USER").ok());
+
+        #[cfg(unix)]
+        let uid = Some(*user_id);
+        #[cfg(not(unix))]
+        let uid = None;
+
+        (uid, username)
+    }
+
+    /// Whether a file can be created in `dir` (which must already exist)
+    fn is_dir_writable(dir: &Path) -> bool {
+        if !dir.is_dir() {
+            return false;
+        }
+        let probe = dir.join(format!(
+            ".openclaw-wizard-write-test-{}",
+            std::process::id()
+        ));
+        match fs::File::create(&probe) {
+            Ok(_) => {
+                let _ = fs::remove_file(&probe);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
     /// Returns detailed system requirements check results
     ///
     /// Checks OS, Node.js version, npm, and disk space.
@@ -168,7 +412,8 @@ impl Platform {
         });
 
         // Node.js check: passes if version >= 22.0.0
-        let node_version_opt = SafeCommand::check_node_version().ok().flatten();
+        let node = NodeDetector::detect();
+        let node_version_opt = node.version.clone();
         let node_installed = node_version_opt.is_some();
         let node_passed = if let Some(ref version) = node_version_opt {
             // Parse version string (e.g., "v22.1.0" -> 22.1.0)
@@ -192,10 +437,15 @@ impl Platform {
                 .clone()
                 .unwrap_or_else(|| "Not installed".to_string()),
             passed: node_passed,
-            help_text: if !node_passed {
-                Some("Install Node.js from https://nodejs.org/".to_string())
-            } else {
-                None
+            help_text: match (node_passed, node.source) {
+                (true, _) => None,
+                // Too old: upgrade through whatever installed it
+                (false, Some(source)) if node_installed => Some(format!(
+                    "Node.js from {:?} is too old; upgrade with: {}",
+                    source,
+                    NodeDetector::upgrade_hint(source)
+                )),
+                (false, _) => Some("Install Node.js from https://nodejs.org/".to_string()),
             },
         });
 
@@ -271,6 +521,63 @@ impl Platform {
             node_version: node_version_opt,
         }
     }
+
+    /// Add feature checks to a requirements result, updating `all_passed`
+    pub fn with_feature_checks(
+        mut requirements: SystemRequirements,
+        extra: Vec<RequirementCheck>,
+    ) -> SystemRequirements {
+        requirements.checks.extend(extra);
+        requirements.all_passed = requirements.checks.iter().all(|check| check.passed);
+        requirements
+    }
+
+    /// Docker check from a `DockerService::check_available` result
+    pub fn docker_requirement(status: &DockerStatusResponse) -> RequirementCheck {
+        let hint = FeatureTool::Docker.install_hint(Self::os());
+        RequirementCheck {
+            name: "Docker".to_string(),
+            required: "Running Docker daemon".to_string(),
+            actual: match (&status.version, status.available) {
+                (Some(version), true) => version.clone(),
+                (None, true) => "Available".to_string(),
+                (_, false) => "Not available".to_string(),
+            },
+            passed: status.available,
+            help_text: (!status.available).then(|| match &status.error {
+                Some(error) => format!("{} {}", error, hint),
+                None => hint.to_string(),
+            }),
+        }
+    }
+
+    /// Git presence and version, from `git --version`
+    pub fn git_requirement() -> RequirementCheck {
+        let output = SafeCommand::run("git", &["--version"])
+            .ok()
+            .filter(|out| out.exit_code == 0)
+            .map(|out| out.stdout);
+        Self::git_requirement_from(output.as_deref(), Self::os())
+    }
+
+    fn git_requirement_from(version_output: Option<&str>, os: &str) -> RequirementCheck {
+        // "git version 2.43.0" or "git version 2.39.3 (Apple Git-146)"
+        let version = version_output.map(|out| {
+            out.trim()
+                .trim_start_matches("git version")
+                .trim()
+                .to_string()
+        });
+        RequirementCheck {
+            name: "Git".to_string(),
+            required: "Any version".to_string(),
+            passed: version.is_some(),
+            help_text: version
+                .is_none()
+                .then(|| FeatureTool::Git.install_hint(os).to_string()),
+            actual: version.unwrap_or_else(|| "Not installed".to_string()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -284,6 +591,14 @@ mod tests {
         println!("Detected OS: {}", os);
     }
 
+    #[test]
+    fn test_wizard_version_reports_crate_version() {
+        let build = Platform::wizard_version();
+        assert_eq!(build.version, env!("CARGO_PKG_VERSION"));
+        assert!(!build.git_commit.is_empty());
+        assert!(chrono::DateTime::parse_from_rfc3339(&build.build_timestamp).is_ok());
+    }
+
     #[test]
     fn test_arch_not_empty() {
         let arch = Platform::arch();
@@ -298,6 +613,194 @@ mod tests {
         println!("Home directory: {}", home.display());
     }
 
+    #[test]
+    fn test_is_dir_writable() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(Platform::is_dir_writable(dir.path()));
+        assert!(!Platform::is_dir_writable(&dir.path().join("missing")));
+        // The probe file is cleaned up
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_identity_resolves_home_and_config_dir() {
+        let identity = Platform::identity();
+        assert!(identity.home.is_some());
+        assert!(identity.config_dir.is_some());
+        assert!(identity.config_dir_writable);
+        #[cfg(unix)]
+        assert!(identity.uid.is_some());
+    }
+
+    #[test]
+    fn test_openclaw_paths_under_home() {
+        let home = Platform::home_dir().unwrap();
+        assert_eq!(Platform::openclaw_dir().unwrap(), home.join(".openclaw"));
+        assert_eq!(
+            Platform::openclaw_config_path().unwrap(),
+            home.join(".openclaw").join("openclaw.json")
+        );
+    }
+
+    #[test]
+    fn test_home_dir_from_unset_or_empty_home() {
+        let err = Platform::home_dir_from(None, None).unwrap_err();
+        assert!(err.to_string().contains("HOME"));
+        assert!(Platform::home_dir_from(Some("".into()), Some("".into())).is_err());
+
+        assert_eq!(
+            Platform::home_dir_from(Some("".into()), Some("C:\\Users\\me".into())).unwrap(),
+            PathBuf::from("C:\\Users\\me")
+        );
+        assert_eq!(
+            Platform::home_dir_from(Some("/home/me".into()), None).unwrap(),
+            PathBuf::from("/home/me")
+        );
+    }
+
+    #[test]
+    fn test_configured_gateway_config_without_home() {
+        // An explicit gateway config path doesn't need a home directory
+        assert_eq!(
+            Platform::configured_gateway_config(None, Some(PathBuf::from("/srv/openclaw"))),
+            Some(PathBuf::from("/srv/openclaw/openclaw.json"))
+        );
+    }
+
+    #[test]
+    fn test_browser_skip_reason() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(k, _)| *k == name)
+                    .map(|(_, v)| v.to_string())
+            }
+        };
+        let desktop: &[(&str, &str)] = &[("DISPLAY", ":0")];
+
+        assert_eq!(
+            Platform::browser_skip_reason_from(env(desktop), "linux", false),
+            None
+        );
+        assert_eq!(
+            Platform::browser_skip_reason_from(env(&[]), "macos", false),
+            None
+        );
+        assert_eq!(
+            Platform::browser_skip_reason_from(env(&[]), "linux", true),
+            None
+        );
+        assert_eq!(
+            Platform::browser_skip_reason_from(env(&[]), "linux", false),
+            Some("no graphical display")
+        );
+        assert_eq!(
+            Platform::browser_skip_reason_from(
+                env(&[
+                    ("DISPLAY", ":0"),
+                    ("SSH_CONNECTION", "10.0.0.2 5000 10.0.0.1 22")
+                ]),
+                "linux",
+                false
+            ),
+            Some("running over SSH")
+        );
+        assert_eq!(
+            Platform::browser_skip_reason_from(
+                env(&[("OPENCLAW_WIZARD_NO_OPEN", "1")]),
+                "macos",
+                false
+            ),
+            Some("OPENCLAW_WIZARD_NO_OPEN is set")
+        );
+        assert_eq!(
+            Platform::browser_skip_reason_from(
+                env(&[("OPENCLAW_WIZARD_NO_OPEN", "0")]),
+                "macos",
+                false
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_configured_gateway_config_precedence() {
+        let env_path = Some(PathBuf::from("/etc/openclaw/custom.json"));
+        let state_dir = Some(PathBuf::from("/srv/openclaw"));
+        assert_eq!(
+            Platform::configured_gateway_config(env_path.clone(), state_dir.clone()),
+            env_path
+        );
+        assert_eq!(
+            Platform::configured_gateway_config(Some(PathBuf::new()), state_dir),
+            Some(PathBuf::from("/srv/openclaw/openclaw.json"))
+        );
+        assert_eq!(
+            Platform::configured_gateway_config(None, Some(PathBuf::new())),
+            None
+        );
+    }
+
+    #[test]
+    fn test_feature_tool_parse_list() {
+        assert_eq!(
+            FeatureTool::parse_list("docker, skills,git").unwrap(),
+            vec![FeatureTool::Docker, FeatureTool::Git]
+        );
+        assert_eq!(
+            FeatureTool::parse_list("Sandbox").unwrap(),
+            vec![FeatureTool::Docker]
+        );
+        assert!(FeatureTool::parse_list("").unwrap().is_empty());
+        assert!(FeatureTool::parse_list("docker,kubernetes").is_err());
+    }
+
+    #[test]
+    fn test_git_requirement_from_version_output() {
+        let check =
+            Platform::git_requirement_from(Some("git version 2.39.3 (Apple Git-146)\n"), "macos");
+        assert!(check.passed);
+        assert_eq!(check.actual, "2.39.3 (Apple Git-146)");
+        assert!(check.help_text.is_none());
+
+        let missing = Platform::git_requirement_from(None, "windows");
+        assert!(!missing.passed);
+        assert!(missing.help_text.unwrap().contains("Git for Windows"));
+    }
+
+    #[test]
+    fn test_docker_requirement_and_all_passed() {
+        let unavailable = DockerStatusResponse {
+            available: false,
+            version: None,
+            containers: vec![],
+            socket: None,
+            error: Some("Docker daemon unreachable.".to_string()),
+            current_count: 0,
+            max_containers: 5,
+            api_version: None,
+        };
+        let check = Platform::docker_requirement(&unavailable);
+        assert!(!check.passed);
+        assert!(
+            check
+                .help_text
+                .as_deref()
+                .unwrap()
+                .starts_with("Docker daemon unreachable.")
+        );
+
+        let base = SystemRequirements {
+            checks: vec![],
+            all_passed: true,
+            node_installed: true,
+            node_version: Some("v22.1.0".to_string()),
+        };
+        let combined = Platform::with_feature_checks(base, vec![check]);
+        assert_eq!(combined.checks.len(), 1);
+        assert!(!combined.all_passed);
+    }
+
     #[test]
     fn test_config_dir_creates() {
         let config_dir = Platform::config_dir().unwrap();
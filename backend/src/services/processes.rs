@@ -0,0 +1,433 @@
+//! # Stray Process Cleanup
+//!
+//! Failed installs and logins (e.g. an abandoned WhatsApp QR login) can leave
+//! `openclaw` processes running. `ProcessService` lists them and kills one on
+//! request, refusing any PID that isn't an openclaw process so the endpoint
+//! can't be used to kill arbitrary processes. Processes are identified by
+//! their executable (see `openclaw_role`), not by words in their arguments.
+//!
+//! It also reports which TCP ports a process is listening on, so health
+//! checks can tell when the running gateway bound a different port than the
+//! config now says, and which users own the listeners on a port (readable
+//! even when the owning process isn't).
+
+#[cfg(target_os = "linux")]
+use std::collections::HashSet;
+use sysinfo::{Pid, Process, ProcessRefreshKind, ProcessesToUpdate, Signal, System, UpdateKind};
+
+use crate::error::AppError;
+use crate::models::types::OpenClawProcess;
+#[cfg(not(target_os = "linux"))]
+use crate::services::command::SafeCommand;
+
+/// Lists and kills openclaw processes
+pub struct ProcessService;
+
+impl ProcessService {
+    /// Every openclaw process visible to this user, largest first
+    pub fn list() -> Vec<OpenClawProcess> {
+        let sys = Self::snapshot(ProcessesToUpdate::All);
+        let own_pid = sysinfo::get_current_pid().ok();
+
+        let mut processes: Vec<OpenClawProcess> = sys
+            .processes()
+            .iter()
+            .filter(|(pid, _)| Some(**pid) != own_pid)
+            .filter_map(|(pid, process)| Self::describe(*pid, process))
+            .collect();
+        processes.sort_by(|a, b| b.memory_mb.cmp(&a.memory_mb).then(a.pid.cmp(&b.pid)));
+        processes
+    }
+
+    /// Kill `pid` if it is an openclaw process, returning what was killed
+    ///
+    /// Sends SIGTERM where supported and falls back to SIGKILL.
+    pub fn kill(pid: u32) -> Result<OpenClawProcess, AppError> {
+        let target = Pid::from_u32(pid);
+        if sysinfo::get_current_pid().ok() == Some(target) {
+            return Err(AppError::BadRequest(
+                "Refusing to kill the wizard itself".to_string(),
+            ));
+        }
+
+        let sys = Self::snapshot(ProcessesToUpdate::Some(&[target]));
+        let process = sys
+            .process(target)
+            .ok_or_else(|| AppError::NotFound(format!("No process with PID {}", pid)))?;
+        let described = Self::describe(target, process).ok_or_else(|| {
+            AppError::BadRequest(format!("PID {} is not an openclaw process", pid))
+        })?;
+
+        let killed = process
+            .kill_with(Signal::Term)
+            .unwrap_or_else(|| process.kill());
+        if !killed {
+            return Err(AppError::InternalError(format!(
+                "Failed to kill process {} (it may belong to another user)",
+                pid
+            )));
+        }
+
+        Ok(described)
+    }
+
+    /// PIDs of running openclaw gateway processes
+    pub fn gateway_pids() -> Vec<u32> {
+        Self::list()
+            .into_iter()
+            .filter(|process| process.gateway)
+            .map(|process| process.pid)
+            .collect()
+    }
+
+    /// TCP ports `pid` is listening on, sorted and deduplicated
+    ///
+    /// Empty when the process is gone or its sockets can't be inspected
+    /// (e.g. it belongs to another user).
+    pub fn listening_ports(pid: u32) -> Vec<u16> {
+        let mut ports = Self::find_listening_ports(pid);
+        ports.sort_unstable();
+        ports.dedup();
+        ports
+    }
+
+    /// Match the process's socket inodes against the kernel's TCP tables
+    #[cfg(target_os = "linux")]
+    fn find_listening_ports(pid: u32) -> Vec<u16> {
+        let Ok(fds) = std::fs::read_dir(format!("/proc/{}/fd", pid)) else {
+            return Vec::new();
+        };
+        let inodes: HashSet<u64> = fds
+            .flatten()
+            .filter_map(|fd| std::fs::read_link(fd.path()).ok())
+            .filter_map(|target| socket_inode(&target.to_string_lossy()))
+            .collect();
+        if inodes.is_empty() {
+            return Vec::new();
+        }
+
+        listening_sockets()
+            .filter(|socket| inodes.contains(&socket.inode))
+            .map(|socket| socket.port)
+            .collect()
+    }
+
+    /// Uids owning a TCP listener on `port`, sorted and deduplicated
+    ///
+    /// Read from the kernel's TCP tables without opening any process's fds,
+    /// so it works for another user's listener. Always empty off Linux.
+    pub fn listening_socket_uids(port: u16) -> Vec<u32> {
+        #[cfg(target_os = "linux")]
+        let mut uids: Vec<u32> = listening_sockets()
+            .filter(|socket| socket.port == port)
+            .map(|socket| socket.uid)
+            .collect();
+        #[cfg(not(target_os = "linux"))]
+        let mut uids: Vec<u32> = {
+            let _ = port;
+            Vec::new()
+        };
+        uids.sort_unstable();
+        uids.dedup();
+        uids
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn find_listening_ports(pid: u32) -> Vec<u16> {
+        let pid = pid.to_string();
+        let args = ["-nP", "-a", "-p", &pid, "-iTCP", "-sTCP:LISTEN", "-Fn"];
+        match SafeCommand::run("lsof", &args) {
+            Ok(output) => parse_lsof_ports(&output.stdout),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    fn snapshot(processes: ProcessesToUpdate<'_>) -> System {
+        let mut sys = System::new();
+        sys.refresh_processes_specifics(
+            processes,
+            true,
+            ProcessRefreshKind::new()
+                .with_memory()
+                .with_cmd(UpdateKind::OnlyIfNotSet),
+        );
+        sys
+    }
+
+    /// The listing entry for `process`, or None if it isn't openclaw's
+    fn describe(pid: Pid, process: &Process) -> Option<OpenClawProcess> {
+        let name = process.name().to_string_lossy().to_string();
+        let args: Vec<String> = process
+            .cmd()
+            .iter()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+        let role = openclaw_role(&name, &args)?;
+
+        Some(OpenClawProcess {
+            pid: pid.as_u32(),
+            gateway: role == ProcessRole::Gateway,
+            name,
+            cmdline: args.join(" "),
+            memory_mb: u32::try_from(process.memory() / 1024 / 1024).unwrap_or(u32::MAX),
+        })
+    }
+}
+
+/// What an openclaw process is running
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProcessRole {
+    /// `openclaw gateway ...`, or a gateway that renamed itself `openclaw-gateway`
+    Gateway,
+    /// Any other `openclaw` subcommand (logins, onboarding, ...)
+    Other,
+}
+
+/// Identify an openclaw process by its executable, None for anything else
+///
+/// Only the `openclaw` binary itself, or `node` running a script from the
+/// `openclaw` package (`node_modules/openclaw/...`) or its `openclaw` bin
+/// link, counts. The word merely appearing in arguments (an editor on
+/// `~/.openclaw/openclaw.json`, `tail -F` on its logs, the wizard) doesn't.
+fn openclaw_role(name: &str, args: &[String]) -> Option<ProcessRole> {
+    let argv0 = args.first().map(String::as_str).unwrap_or(name);
+    let program = executable_name(argv0);
+    let comm = executable_name(name);
+    // `comm` is truncated to 15 characters on Linux
+    if program.starts_with("openclaw-gateway") || comm.starts_with("openclaw-gatewa") {
+        return Some(ProcessRole::Gateway);
+    }
+
+    let rest = if program == "openclaw" || (args.is_empty() && comm == "openclaw") {
+        args.get(1..).unwrap_or_default()
+    } else if matches!(program.as_str(), "node" | "nodejs") {
+        let script = args.iter().skip(1).position(|arg| !arg.starts_with('-'))? + 1;
+        if !is_openclaw_script(&args[script]) {
+            return None;
+        }
+        &args[script + 1..]
+    } else {
+        return None;
+    };
+
+    let subcommand = rest.iter().find(|arg| !arg.starts_with('-'));
+    Some(match subcommand.map(String::as_str) {
+        Some("gateway") => ProcessRole::Gateway,
+        _ => ProcessRole::Other,
+    })
+}
+
+/// Lowercased file name of a program path without its extension
+fn executable_name(path: &str) -> String {
+    let file = path.rsplit(['/', '\\']).next().unwrap_or(path);
+    let file = file.to_lowercase();
+    [".exe", ".cmd", ".mjs", ".js"]
+        .iter()
+        .find_map(|ext| file.strip_suffix(ext))
+        .map(str::to_string)
+        .unwrap_or(file)
+}
+
+/// Whether a node script belongs to the openclaw package
+fn is_openclaw_script(script: &str) -> bool {
+    let normalized = script.replace('\\', "/").to_lowercase();
+    normalized.contains("node_modules/openclaw/") || executable_name(script) == "openclaw"
+}
+
+/// Inode of a `/proc/<pid>/fd` link target like `socket:[12345]`
+#[cfg(target_os = "linux")]
+fn socket_inode(target: &str) -> Option<u64> {
+    target
+        .strip_prefix("socket:[")?
+        .strip_suffix(']')?
+        .parse()
+        .ok()
+}
+
+/// A LISTEN socket from the kernel's TCP tables
+#[cfg(target_os = "linux")]
+#[derive(Debug, PartialEq)]
+struct ListeningSocket {
+    inode: u64,
+    port: u16,
+    uid: u32,
+}
+
+/// LISTEN sockets in `/proc/net/tcp` and `/proc/net/tcp6`
+#[cfg(target_os = "linux")]
+fn listening_sockets() -> impl Iterator<Item = ListeningSocket> {
+    ["/proc/net/tcp", "/proc/net/tcp6"]
+        .iter()
+        .filter_map(|table| std::fs::read_to_string(table).ok())
+        .flat_map(|table| parse_listening_sockets(&table))
+}
+
+/// Every LISTEN socket in a `/proc/net/tcp{,6}` table
+#[cfg(target_os = "linux")]
+fn parse_listening_sockets(table: &str) -> Vec<ListeningSocket> {
+    /// TCP_LISTEN in the kernel's `st` column
+    const LISTEN: &str = "0A";
+
+    table
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.get(3) != Some(&LISTEN) {
+                return None;
+            }
+            let port = fields.get(1)?.rsplit(':').next()?;
+            let port = u16::from_str_radix(port, 16).ok()?;
+            let uid = fields.get(7)?.parse().ok()?;
+            let inode = fields.get(9)?.parse().ok()?;
+            Some(ListeningSocket { inode, port, uid })
+        })
+        .collect()
+}
+
+/// Ports from `lsof -Fn` name lines like `n*:18789` or `n[::1]:18789`
+#[cfg_attr(target_os = "linux", allow(dead_code))]
+fn parse_lsof_ports(output: &str) -> Vec<u16> {
+    output
+        .lines()
+        .filter_map(|line| line.strip_prefix('n'))
+        .filter_map(|name| name.rsplit(':').next()?.parse().ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn role(name: &str, cmdline: &str) -> Option<ProcessRole> {
+        let args: Vec<String> = cmdline.split_whitespace().map(str::to_string).collect();
+        openclaw_role(name, &args)
+    }
+
+    #[test]
+    fn test_openclaw_role_matches_executable() {
+        assert_eq!(
+            role("openclaw", "openclaw channels login"),
+            Some(ProcessRole::Other)
+        );
+        assert_eq!(
+            role("openclaw", "/usr/local/bin/openclaw gateway --port 18789"),
+            Some(ProcessRole::Gateway)
+        );
+        assert_eq!(
+            role(
+                "node",
+                "/usr/bin/node /usr/lib/node_modules/openclaw/dist/index.js gateway"
+            ),
+            Some(ProcessRole::Gateway)
+        );
+        assert_eq!(
+            role("node", "node --no-warnings /usr/local/bin/openclaw onboard"),
+            Some(ProcessRole::Other)
+        );
+        assert_eq!(
+            role("openclaw-gatewa", "openclaw-gateway"),
+            Some(ProcessRole::Gateway)
+        );
+        assert_eq!(
+            role(
+                "node.exe",
+                r"C:\node\node.exe C:\npm\node_modules\openclaw\openclaw.mjs"
+            ),
+            Some(ProcessRole::Other)
+        );
+        assert_eq!(role("openclaw", ""), Some(ProcessRole::Other));
+    }
+
+    #[test]
+    fn test_openclaw_role_ignores_mentions_in_arguments() {
+        assert_eq!(role("node", "node server.js"), None);
+        assert_eq!(role("openclaw-wizard", ""), None);
+        assert_eq!(
+            role(
+                "node",
+                "node /home/me/openclaw-wizard/frontend/node_modules/.bin/vite"
+            ),
+            None
+        );
+        assert_eq!(role("vim", "vim /home/me/.openclaw/openclaw.json"), None);
+        assert_eq!(role("tail", "tail -F /tmp/openclaw/gateway.log"), None);
+        assert_eq!(
+            role(
+                "docker",
+                "docker run --name openclaw-sandbox openclaw gateway"
+            ),
+            None
+        );
+        assert_eq!(role("bash", "bash -c openclaw gateway"), None);
+        assert_eq!(role("node", "node /srv/app/gateway.js openclaw"), None);
+    }
+
+    #[test]
+    fn test_kill_refuses_non_openclaw_process() {
+        let mut child = std::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .unwrap();
+        let result = ProcessService::kill(child.id());
+        let _ = child.kill();
+        let _ = child.wait();
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_kill_refuses_own_pid() {
+        assert!(matches!(
+            ProcessService::kill(std::process::id()),
+            Err(AppError::BadRequest(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_lsof_ports() {
+        let output = "p4242\nf23\nn*:18789\nf24\nn[::1]:18791\n";
+        assert_eq!(parse_lsof_ports(output), vec![18789, 18791]);
+        assert!(parse_lsof_ports("").is_empty());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_parse_listening_sockets() {
+        let table = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:4965 00000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 5501 1 0000000000000000 100 0 0 10 0
+   1: 0100007F:4965 0100007F:D431 01 00000000:00000000 00:00000000 00000000  1000        0 5502 1 0000000000000000 20 4 30 10 -1";
+        assert_eq!(
+            parse_listening_sockets(table),
+            vec![ListeningSocket {
+                inode: 5501,
+                port: 18789,
+                uid: 1000
+            }]
+        );
+        assert_eq!(socket_inode("socket:[5501]"), Some(5501));
+        assert_eq!(socket_inode("pipe:[5501]"), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_listening_ports_finds_own_listener() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        assert!(ProcessService::listening_ports(std::process::id()).contains(&port));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_listening_socket_uids_reports_owner() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let uid = std::fs::metadata("/proc/self").map(|m| {
+            use std::os::unix::fs::MetadataExt;
+            m.uid()
+        });
+        assert_eq!(
+            ProcessService::listening_socket_uids(port),
+            vec![uid.unwrap()]
+        );
+    }
+}
@@ -0,0 +1,367 @@
+//! # Provider Mapping
+//!
+//! Single translation of the wizard's provider settings into OpenClaw auth
+//! settings. The local installer renders the result as `openclaw onboard`
+//! flags; the remote installer renders it as the `ai` block of openclaw.json.
+
+use crate::models::WizardConfig;
+
+/// Provider-related fields of a wizard config, borrowed from either a
+/// `WizardConfig` or the raw saved JSON.
+#[derive(Debug, Clone, Default)]
+pub struct ProviderInput<'a> {
+    pub provider: &'a str,
+    pub auth_type: &'a str,
+    pub api_key: &'a str,
+    pub base_url: Option<&'a str>,
+    pub model_id: Option<&'a str>,
+    pub compatibility: Option<&'a str>,
+    pub account_id: Option<&'a str>,
+    pub gateway_id: Option<&'a str>,
+}
+
+impl<'a> From<&'a WizardConfig> for ProviderInput<'a> {
+    fn from(config: &'a WizardConfig) -> Self {
+        Self {
+            provider: &config.provider,
+            auth_type: &config.auth_type,
+            api_key: &config.api_key,
+            base_url: config.base_url.as_deref(),
+            model_id: config.model_id.as_deref(),
+            compatibility: config.compatibility.as_deref(),
+            account_id: config.account_id.as_deref(),
+            gateway_id: config.gateway_id.as_deref(),
+        }
+    }
+}
+
+/// One provider setting, with its onboard flag and openclaw.json key
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderFlag {
+    pub flag: String,
+    /// Key in the `ai` config block, or None if the setting is onboard-only
+    pub config_key: Option<&'static str>,
+    pub value: String,
+}
+
+/// Resolved provider auth: the onboard `--auth-choice` plus its settings
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProviderAuth {
+    pub provider: String,
+    pub auth_type: String,
+    pub auth_choice: Option<String>,
+    pub flags: Vec<ProviderFlag>,
+}
+
+impl ProviderAuth {
+    /// Map wizard provider settings to OpenClaw auth settings
+    pub fn resolve(input: &ProviderInput) -> Self {
+        let mut auth = Self {
+            provider: input.provider.to_string(),
+            auth_type: input.auth_type.to_string(),
+            ..Default::default()
+        };
+        let api_key = input.api_key;
+
+        match input.provider {
+            // Skip: no auth configured
+            "skip" => auth.auth_choice = Some("skip".into()),
+            // OAuth providers: auth-choice only, no key
+            "chutes" | "github-copilot" => auth.auth_choice = Some(input.provider.into()),
+            "qwen" => auth.auth_choice = Some("qwen-portal".into()),
+            // Custom provider: base URL + model ID + optional key
+            "custom" => {
+                auth.auth_choice = Some("custom-api-key".into());
+                auth.push("--custom-base-url", Some("baseUrl"), input.base_url);
+                auth.push("--custom-model-id", Some("modelId"), input.model_id);
+                auth.push(
+                    "--custom-compatibility",
+                    Some("compatibility"),
+                    input.compatibility,
+                );
+                auth.push("--custom-api-key", Some("apiKey"), Some(api_key));
+            }
+            // Cloudflare: needs account ID, gateway ID, and key
+            "cloudflare" => {
+                auth.auth_choice = Some("cloudflare-ai-gateway-api-key".into());
+                auth.push(
+                    "--cloudflare-ai-gateway-api-key",
+                    Some("apiKey"),
+                    Some(api_key),
+                );
+                auth.push(
+                    "--cloudflare-ai-gateway-account-id",
+                    Some("accountId"),
+                    input.account_id,
+                );
+                auth.push(
+                    "--cloudflare-ai-gateway-gateway-id",
+                    Some("gatewayId"),
+                    input.gateway_id,
+                );
+            }
+            // vLLM: self-hosted, base URL only
+            "vllm" => {
+                auth.auth_choice = Some("vllm".into());
+                auth.push("--custom-base-url", Some("baseUrl"), input.base_url);
+            }
+            // Every remaining provider needs a key to be configured at all
+            _ if api_key.is_empty() => {}
+            // Provider-specific auth-choice mappings where the CLI flag name differs from provider ID
+            "google" => auth.keyed("gemini-api-key", "--gemini-api-key", api_key),
+            "vercel-ai-gateway" => {
+                auth.keyed("ai-gateway-api-key", "--ai-gateway-api-key", api_key)
+            }
+            "opencode-zen" => auth.keyed("opencode-zen", "--opencode-zen-api-key", api_key),
+            "minimax" => auth.keyed("minimax-api-key-cn", "--minimax-api-key", api_key),
+            // Setup tokens use --auth-choice token with --token-provider
+            provider if input.auth_type == "setup-token" => {
+                auth.keyed("token", "--token", api_key);
+                auth.push("--token-provider", None, Some(provider));
+            }
+            // Standard API key providers: --auth-choice {provider}-api-key --{provider}-api-key <key>
+            // Works for: anthropic, openai, xai, openrouter, moonshot, zai, qianfan, xiaomi,
+            //            synthetic, together, huggingface, venice, litellm
+            provider => auth.keyed(
+                &format!("{}-api-key", provider),
+                &format!("--{}-api-key", provider),
+                api_key,
+            ),
+        }
+
+        auth
+    }
+
+    /// Render as `openclaw onboard` arguments
+    pub fn onboard_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(ref choice) = self.auth_choice {
+            args.extend(["--auth-choice".to_string(), choice.clone()]);
+        }
+        for f in &self.flags {
+            args.extend([f.flag.clone(), f.value.clone()]);
+        }
+        args
+    }
+
+    /// Render as the `ai` block of an openclaw.json config
+    pub fn config_fragment(&self) -> serde_json::Value {
+        let mut ai = serde_json::json!({
+            "provider": self.provider,
+            "authType": self.auth_type,
+        });
+        if let Some(ref choice) = self.auth_choice {
+            ai["authChoice"] = choice.clone().into();
+        }
+        for f in &self.flags {
+            if let Some(key) = f.config_key {
+                ai[key] = f.value.clone().into();
+            }
+        }
+        ai
+    }
+
+    fn keyed(&mut self, choice: &str, key_flag: &str, api_key: &str) {
+        self.auth_choice = Some(choice.to_string());
+        self.push(key_flag, Some("apiKey"), Some(api_key));
+    }
+
+    /// Add a setting, skipping missing or empty values
+    fn push(&mut self, flag: &str, config_key: Option<&'static str>, value: Option<&str>) {
+        if let Some(value) = value.filter(|v| !v.is_empty()) {
+            self.flags.push(ProviderFlag {
+                flag: flag.to_string(),
+                config_key,
+                value: value.to_string(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input<'a>(provider: &'a str, auth_type: &'a str, api_key: &'a str) -> ProviderInput<'a> {
+        ProviderInput {
+            provider,
+            auth_type,
+            api_key,
+            ..Default::default()
+        }
+    }
+
+    fn args(auth: &ProviderAuth) -> Vec<String> {
+        auth.onboard_args()
+    }
+
+    #[test]
+    fn test_standard_api_key_provider() {
+        for provider in ["anthropic", "openai", "openrouter", "xai"] {
+            let auth = ProviderAuth::resolve(&input(provider, "api-key", "key-123"));
+            assert_eq!(
+                args(&auth),
+                vec![
+                    "--auth-choice".to_string(),
+                    format!("{}-api-key", provider),
+                    format!("--{}-api-key", provider),
+                    "key-123".to_string(),
+                ]
+            );
+            assert_eq!(auth.config_fragment()["apiKey"], "key-123");
+        }
+    }
+
+    #[test]
+    fn test_setup_token() {
+        let auth = ProviderAuth::resolve(&input("anthropic", "setup-token", "sk-ant-oat01-x"));
+        assert_eq!(
+            args(&auth),
+            [
+                "--auth-choice",
+                "token",
+                "--token",
+                "sk-ant-oat01-x",
+                "--token-provider",
+                "anthropic"
+            ]
+        );
+        let fragment = auth.config_fragment();
+        assert_eq!(fragment["authChoice"], "token");
+        assert_eq!(fragment["apiKey"], "sk-ant-oat01-x");
+        assert!(fragment.get("tokenProvider").is_none());
+    }
+
+    #[test]
+    fn test_custom_provider() {
+        let auth = ProviderAuth::resolve(&ProviderInput {
+            base_url: Some("https://llm.example.com/v1"),
+            model_id: Some("my-model"),
+            compatibility: Some("openai"),
+            ..input("custom", "api-key", "key-123")
+        });
+        assert_eq!(
+            args(&auth),
+            [
+                "--auth-choice",
+                "custom-api-key",
+                "--custom-base-url",
+                "https://llm.example.com/v1",
+                "--custom-model-id",
+                "my-model",
+                "--custom-compatibility",
+                "openai",
+                "--custom-api-key",
+                "key-123"
+            ]
+        );
+        let fragment = auth.config_fragment();
+        assert_eq!(fragment["baseUrl"], "https://llm.example.com/v1");
+        assert_eq!(fragment["modelId"], "my-model");
+        assert_eq!(fragment["compatibility"], "openai");
+    }
+
+    #[test]
+    fn test_custom_provider_without_key() {
+        let auth = ProviderAuth::resolve(&ProviderInput {
+            base_url: Some("http://localhost:8000"),
+            model_id: Some(""),
+            ..input("custom", "api-key", "")
+        });
+        assert_eq!(
+            args(&auth),
+            [
+                "--auth-choice",
+                "custom-api-key",
+                "--custom-base-url",
+                "http://localhost:8000"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cloudflare() {
+        let auth = ProviderAuth::resolve(&ProviderInput {
+            account_id: Some("acct"),
+            gateway_id: Some("gw"),
+            ..input("cloudflare", "api-key", "cf-key")
+        });
+        assert_eq!(
+            args(&auth),
+            [
+                "--auth-choice",
+                "cloudflare-ai-gateway-api-key",
+                "--cloudflare-ai-gateway-api-key",
+                "cf-key",
+                "--cloudflare-ai-gateway-account-id",
+                "acct",
+                "--cloudflare-ai-gateway-gateway-id",
+                "gw"
+            ]
+        );
+        let fragment = auth.config_fragment();
+        assert_eq!(fragment["accountId"], "acct");
+        assert_eq!(fragment["gatewayId"], "gw");
+    }
+
+    #[test]
+    fn test_renamed_key_providers() {
+        let cases = [
+            ("google", "gemini-api-key", "--gemini-api-key"),
+            ("minimax", "minimax-api-key-cn", "--minimax-api-key"),
+            (
+                "vercel-ai-gateway",
+                "ai-gateway-api-key",
+                "--ai-gateway-api-key",
+            ),
+            ("opencode-zen", "opencode-zen", "--opencode-zen-api-key"),
+        ];
+        for (provider, choice, flag) in cases {
+            let auth = ProviderAuth::resolve(&input(provider, "api-key", "k"));
+            assert_eq!(args(&auth), ["--auth-choice", choice, flag, "k"]);
+            assert_eq!(auth.config_fragment()["authChoice"], choice);
+        }
+    }
+
+    #[test]
+    fn test_vllm() {
+        let auth = ProviderAuth::resolve(&ProviderInput {
+            base_url: Some("http://gpu-box:8000/v1"),
+            ..input("vllm", "api-key", "ignored")
+        });
+        assert_eq!(
+            args(&auth),
+            [
+                "--auth-choice",
+                "vllm",
+                "--custom-base-url",
+                "http://gpu-box:8000/v1"
+            ]
+        );
+        assert!(auth.config_fragment().get("apiKey").is_none());
+    }
+
+    #[test]
+    fn test_skip_and_oauth_providers_carry_no_key() {
+        let cases = [
+            ("skip", "skip"),
+            ("chutes", "chutes"),
+            ("github-copilot", "github-copilot"),
+            ("qwen", "qwen-portal"),
+        ];
+        for (provider, choice) in cases {
+            let auth = ProviderAuth::resolve(&input(provider, "oauth", "leftover-key"));
+            assert_eq!(args(&auth), ["--auth-choice", choice]);
+            assert!(auth.config_fragment().get("apiKey").is_none());
+        }
+    }
+
+    #[test]
+    fn test_key_provider_without_key_is_unconfigured() {
+        for provider in ["anthropic", "google", "minimax"] {
+            let auth = ProviderAuth::resolve(&input(provider, "api-key", ""));
+            assert!(args(&auth).is_empty());
+            assert!(auth.config_fragment().get("authChoice").is_none());
+        }
+    }
+}
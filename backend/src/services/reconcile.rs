@@ -0,0 +1,260 @@
+//! # Config Reconciliation
+//!
+//! The wizard keeps its settings in `Platform::wizard_config_path()`, while
+//! the gateway loads `Platform::openclaw_config_path()`. Saving in the wizard
+//! writes both, but the gateway config can drift afterwards (hand edits,
+//! `openclaw configure`, an older wizard that wrote elsewhere).
+//!
+//! `ConfigReconciler` reports how the two differ and can redeploy the wizard
+//! settings to the gateway config. Deploying merges into the existing file so
+//! sections the wizard doesn't manage (`meta`, `agents`, `commands`, ...) are
+//! kept. Deploying settings that turn gateway auth off or bind beyond
+//! loopback needs the caller's confirmation, as `ConfigSetter` does.
+
+use anyhow::{Context, Result};
+use serde_json::{Map, Value, json};
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use crate::error::AppError;
+use crate::models::WizardConfig;
+use crate::models::types::ConfigReconcileReport;
+use crate::services::config::ConfigWriter;
+use crate::services::config_set::ConfigSetter;
+use crate::services::detection::DetectionService;
+use crate::services::platform::Platform;
+use crate::services::wizard_config::WizardConfigStore;
+
+/// Compares and syncs the wizard config with the gateway config
+pub struct ConfigReconciler;
+
+impl ConfigReconciler {
+    /// Compare the wizard's saved settings with the gateway config
+    pub fn status() -> Result<ConfigReconcileReport> {
+        let wizard_path = Platform::wizard_config_path()?;
+        let gateway_path = Platform::openclaw_config_path()?;
+        let wizard = ConfigWriter::read_json::<Value>(&wizard_path).ok();
+        let gateway = ConfigWriter::read_json::<Value>(&gateway_path).ok();
+
+        let mut divergence = DetectionService::config_divergence(
+            &DetectionService::config_locations(),
+            wizard.as_ref(),
+            gateway.as_ref(),
+        );
+        let in_sync = Self::in_sync(wizard.as_ref(), gateway.as_ref());
+        if wizard.is_some() && gateway.is_none() {
+            divergence.push(format!(
+                "Wizard settings have not been deployed to {}",
+                gateway_path.display()
+            ));
+        }
+
+        Ok(ConfigReconcileReport {
+            wizard_exists: wizard_path.is_file(),
+            wizard_path: wizard_path.display().to_string(),
+            gateway_exists: gateway_path.is_file(),
+            gateway_path: gateway_path.display().to_string(),
+            divergence,
+            in_sync,
+            synced: None,
+        })
+    }
+
+    /// Deploy the wizard's saved settings to the gateway config if they differ
+    ///
+    /// `confirm_weakening` is called before writing settings that weaken
+    /// gateway access (see `weakens_access`); its error aborts the sync.
+    pub fn sync(
+        confirm_weakening: impl FnOnce() -> Result<(), AppError>,
+    ) -> Result<ConfigReconcileReport> {
+        let before = Self::status()?;
+        if before.in_sync {
+            return Ok(ConfigReconcileReport {
+                synced: Some(false),
+                ..before
+            });
+        }
+
+        let config = WizardConfigStore::load()?;
+        if Self::weakens_access(&config) {
+            confirm_weakening()?;
+        }
+        let path = Self::deploy(&config)?;
+        info!("Synced wizard config to {}", path.display());
+
+        Ok(ConfigReconcileReport {
+            synced: Some(true),
+            ..Self::status()?
+        })
+    }
+
+    /// Whether deploying `config` turns gateway auth off or binds the
+    /// gateway beyond loopback
+    pub fn weakens_access(config: &WizardConfig) -> bool {
+        let gateway = &Self::gateway_config(config)["gateway"];
+        ConfigSetter::weakens_access("gateway.auth.mode", &gateway["auth"]["mode"])
+            || !ConfigSetter::is_loopback_bind(gateway["bind"].as_str().unwrap_or_default())
+    }
+
+    /// Write the wizard settings into the gateway config, returning its path
+    pub fn deploy(config: &WizardConfig) -> Result<PathBuf> {
+        let path = Platform::openclaw_config_path()?;
+        Self::deploy_to(config, &path)?;
+        Ok(path)
+    }
+
+    /// Merge the wizard settings into the gateway config at `path`
+    pub fn deploy_to(config: &WizardConfig, path: &Path) -> Result<()> {
+        // Keep meta/commands/agents sections written by the gateway itself
+        let existing: Value = ConfigWriter::read_json(path).unwrap_or_else(|_| json!({}));
+        let merged = Self::merge(existing, Self::gateway_config(config));
+        ConfigWriter::write_json(path, &merged)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Wizard settings in the gateway's config format
+    ///
+    /// `gateway.bind` must be "loopback" not "127.0.0.1", and `gateway.auth`
+    /// uses "token" not "credential".
+    pub fn gateway_config(config: &WizardConfig) -> Value {
+        let bind_value = match config.gateway_bind.as_str() {
+            "127.0.0.1" | "localhost" | "loopback" => "loopback",
+            "0.0.0.0" | "all" => "all",
+            other => other,
+        };
+
+        let mut gateway_config = json!({
+            "gateway": {
+                "mode": "local",
+                "port": config.gateway_port,
+                "bind": bind_value,
+                "auth": {
+                    "mode": config.auth_mode,
+                    "token": config.auth_credential,
+                }
+            }
+        });
+
+        if let Some(channels) = &config.channels {
+            let mut channels_obj = Map::new();
+            for channel in channels {
+                let mut channel_config = Map::new();
+                channel_config.insert("enabled".to_string(), json!(channel.enabled));
+                channel_config.insert("dmPolicy".to_string(), json!(channel.dm_policy));
+                channel_config.insert("allowFrom".to_string(), json!(channel.allowed_users));
+
+                if let Some(ref token) = channel.bot_token {
+                    channel_config.insert("botToken".to_string(), json!(token));
+                }
+                if let Some(ref token) = channel.app_token {
+                    channel_config.insert("appToken".to_string(), json!(token));
+                }
+
+                channels_obj.insert(channel.platform.clone(), Value::Object(channel_config));
+            }
+            gateway_config
+                .as_object_mut()
+                .unwrap()
+                .insert("channels".to_string(), Value::Object(channels_obj));
+        }
+
+        gateway_config
+    }
+
+    /// Replace the top-level sections of `existing` that `update` provides
+    fn merge(mut existing: Value, update: Value) -> Value {
+        if let (Some(existing_obj), Value::Object(new_obj)) = (existing.as_object_mut(), &update) {
+            for (key, value) in new_obj {
+                existing_obj.insert(key.clone(), value.clone());
+            }
+            existing
+        } else {
+            update
+        }
+    }
+
+    /// Whether the gateway config carries the wizard's port, bind and auth
+    fn in_sync(wizard: Option<&Value>, gateway: Option<&Value>) -> bool {
+        match (wizard, gateway) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(_), Some(_)) => {
+                DetectionService::config_divergence(&[], wizard, gateway).is_empty()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wizard_config() -> WizardConfig {
+        serde_json::from_value(json!({
+            "provider": "anthropic",
+            "api_key": "sk-ant",
+            "auth_type": "api-key",
+            "gateway_port": 18789,
+            "gateway_bind": "127.0.0.1",
+            "auth_mode": "token",
+            "auth_credential": "t0k",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_gateway_config_uses_gateway_format() {
+        let gateway = ConfigReconciler::gateway_config(&wizard_config());
+        assert_eq!(gateway["gateway"]["bind"], "loopback");
+        assert_eq!(gateway["gateway"]["port"], 18789);
+        assert_eq!(gateway["gateway"]["auth"]["token"], "t0k");
+        assert!(gateway.get("channels").is_none());
+    }
+
+    #[test]
+    fn test_weakens_access() {
+        let mut config = wizard_config();
+        assert!(!ConfigReconciler::weakens_access(&config));
+
+        config.auth_mode = "none".to_string();
+        assert!(ConfigReconciler::weakens_access(&config));
+
+        config.auth_mode = "token".to_string();
+        config.gateway_bind = "0.0.0.0".to_string();
+        assert!(ConfigReconciler::weakens_access(&config));
+        config.gateway_bind = "lan".to_string();
+        assert!(ConfigReconciler::weakens_access(&config));
+        config.gateway_bind = "localhost".to_string();
+        assert!(!ConfigReconciler::weakens_access(&config));
+    }
+
+    #[test]
+    fn test_deploy_keeps_unmanaged_sections() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("openclaw.json");
+        std::fs::write(
+            &path,
+            r#"{"meta":{"version":"1"},"gateway":{"port":3000,"bind":"all"}}"#,
+        )
+        .unwrap();
+
+        ConfigReconciler::deploy_to(&wizard_config(), &path).unwrap();
+        let deployed: Value = ConfigWriter::read_json(&path).unwrap();
+        assert_eq!(deployed["meta"]["version"], "1");
+        assert_eq!(deployed["gateway"]["port"], 18789);
+        assert_eq!(deployed["gateway"]["bind"], "loopback");
+    }
+
+    #[test]
+    fn test_in_sync_compares_deployed_settings() {
+        let config = wizard_config();
+        let wizard = serde_json::to_value(&config).unwrap();
+        let gateway = ConfigReconciler::gateway_config(&config);
+        assert!(ConfigReconciler::in_sync(Some(&wizard), Some(&gateway)));
+        assert!(ConfigReconciler::in_sync(None, None));
+        assert!(!ConfigReconciler::in_sync(Some(&wizard), None));
+
+        let stale = json!({ "gateway": { "port": 3000, "bind": "loopback" } });
+        assert!(!ConfigReconciler::in_sync(Some(&wizard), Some(&stale)));
+    }
+}
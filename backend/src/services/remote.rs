@@ -11,15 +11,22 @@
 //!
 //! SECURITY:
 //! - SSH key paths retrieved from platform keychain (never passed over WebSocket)
-//! - Config JSON is shell-escaped before remote write
+//! - Config JSON is sent over the SSH session's stdin (never on the command line)
+//!   and written with mode 0600
 //! - All operations use KnownHosts::Strict (inherited from SshService)
 
 use anyhow::{Context, Result};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 use tokio::sync::mpsc;
-use tracing::{info, warn};
+use tracing::{Instrument, info, warn};
 
 use crate::models::{RemoteSetupProgress, WizardConfig};
+use crate::services::installer::{
+    HEARTBEAT_INTERVAL, InstallerService, StageTimer, heartbeat_message,
+};
+use crate::services::platform::Platform;
+use crate::services::provider_mapping::{ProviderAuth, ProviderInput};
+use crate::services::redact::redact_secrets;
 use crate::services::ssh::SshService;
 
 /// NVM install script URL
@@ -52,31 +59,86 @@ impl RemoteService {
         user: &str,
         config: WizardConfig,
         progress_tx: mpsc::Sender<RemoteSetupProgress>,
+    ) -> Result<()> {
+        self.install_openclaw_remote_from(
+            host,
+            user,
+            RemoteConfigSource::Wizard(Box::new(config)),
+            None,
+            progress_tx,
+        )
+        .await
+    }
+
+    /// Same as `install_openclaw_remote`, with the config taken from `source`
+    /// and OpenClaw pinned to `openclaw_version` (latest when None)
+    pub async fn install_openclaw_remote_from(
+        &self,
+        host: &str,
+        user: &str,
+        source: RemoteConfigSource,
+        openclaw_version: Option<&str>,
+        progress_tx: mpsc::Sender<RemoteSetupProgress>,
+    ) -> Result<()> {
+        // Validated before it goes into the remote shell command
+        let package_spec = InstallerService::openclaw_package_spec(openclaw_version)?;
+
+        // Time each stage on its way to the caller
+        let (stage_tx, mut stage_rx) = mpsc::channel::<RemoteSetupProgress>(100);
+        let forwarder = tokio::spawn(
+            async move {
+                let mut timer = StageTimer::default();
+                while let Some(mut progress) = stage_rx.recv().await {
+                    progress.elapsed_ms = timer.observe(&progress.stage, &progress.status);
+                    if progress_tx.send(progress).await.is_err() {
+                        warn!("Progress receiver dropped, cannot send update");
+                        break;
+                    }
+                }
+            }
+            .in_current_span(),
+        );
+
+        let result = self
+            .run_remote_stages(host, user, &source, &package_spec, &stage_tx)
+            .await;
+        drop(stage_tx);
+        let _ = forwarder.await;
+        result
+    }
+
+    /// The remote install stages, in order
+    async fn run_remote_stages(
+        &self,
+        host: &str,
+        user: &str,
+        source: &RemoteConfigSource,
+        package_spec: &str,
+        progress_tx: &mpsc::Sender<RemoteSetupProgress>,
     ) -> Result<()> {
         // Stage 1: Check connection
-        self.stage_check_connection(host, user, &progress_tx)
-            .await?;
+        self.stage_check_connection(host, user, progress_tx).await?;
 
         // Stage 2: Check/install Node.js
-        self.stage_ensure_node(host, user, &progress_tx).await?;
+        self.stage_ensure_node(host, user, progress_tx).await?;
 
         // Stage 3: Install OpenClaw
-        self.stage_install_openclaw(host, user, &progress_tx)
+        self.stage_install_openclaw(host, user, package_spec, progress_tx)
             .await?;
 
         // Stage 4: Write config
-        self.stage_write_config(host, user, &config, &progress_tx)
+        self.stage_write_config(host, user, source, progress_tx)
             .await?;
 
         // Stage 5: Install daemon
-        self.stage_install_daemon(host, user, &progress_tx).await?;
+        self.stage_install_daemon(host, user, progress_tx).await?;
 
         // Stage 6: Start daemon
-        self.stage_start_daemon(host, user, &progress_tx).await?;
+        self.stage_start_daemon(host, user, progress_tx).await?;
 
         // Stage 7: Send completion
         Self::send_progress(
-            &progress_tx,
+            progress_tx,
             "complete",
             "completed",
             "Remote setup complete!",
@@ -228,10 +290,13 @@ node --version"#
         let host_owned = host.to_string();
         let user_owned = user.to_string();
 
-        let install_handle = tokio::spawn(async move {
-            ssh.stream_remote_command(&host_owned, &user_owned, &nvm_script, line_tx)
-                .await
-        });
+        let install_handle = tokio::spawn(
+            async move {
+                ssh.stream_remote_command(&host_owned, &user_owned, &nvm_script, line_tx)
+                    .await
+            }
+            .in_current_span(),
+        );
 
         // Forward streamed lines as progress
         while let Some(line) = line_rx.recv().await {
@@ -281,36 +346,49 @@ node --version"#
         &self,
         host: &str,
         user: &str,
+        package_spec: &str,
         tx: &mpsc::Sender<RemoteSetupProgress>,
     ) -> Result<()> {
         Self::send_progress(
             tx,
             "openclaw",
             "in_progress",
-            "Installing OpenClaw via npm...",
+            &format!("Installing {} via npm...", package_spec),
             None,
         )
         .await;
 
-        // Source nvm before running npm (nvm installs node in user space)
-        let install_cmd = r#"export NVM_DIR="$HOME/.nvm"
-[ -s "$NVM_DIR/nvm.sh" ] && \. "$NVM_DIR/nvm.sh"
-npm install -g openclaw"#;
+        let install_cmd = Self::install_openclaw_command(package_spec);
 
         let (line_tx, mut line_rx) = mpsc::channel::<String>(100);
 
         let ssh = self.ssh_service.clone();
         let host_owned = host.to_string();
         let user_owned = user.to_string();
-        let cmd_owned = install_cmd.to_string();
+        let cmd_owned = install_cmd;
 
-        let install_handle = tokio::spawn(async move {
-            ssh.stream_remote_command(&host_owned, &user_owned, &cmd_owned, line_tx)
-                .await
-        });
+        let install_handle = tokio::spawn(
+            async move {
+                ssh.stream_remote_command(&host_owned, &user_owned, &cmd_owned, line_tx)
+                    .await
+            }
+            .in_current_span(),
+        );
 
-        while let Some(line) = line_rx.recv().await {
-            Self::send_progress(tx, "openclaw", "in_progress", &line, None).await;
+        // npm can be silent for minutes during native builds; send a
+        // heartbeat whenever no line has arrived for HEARTBEAT_INTERVAL
+        let started = Instant::now();
+        loop {
+            match tokio::time::timeout(HEARTBEAT_INTERVAL, line_rx.recv()).await {
+                Ok(Some(line)) => {
+                    Self::send_progress(tx, "openclaw", "in_progress", &line, None).await;
+                }
+                Ok(None) => break,
+                Err(_) => {
+                    let message = heartbeat_message("installing OpenClaw", started.elapsed());
+                    Self::send_progress(tx, "openclaw", "in_progress", &message, None).await;
+                }
+            }
         }
 
         let result = install_handle
@@ -350,45 +428,54 @@ npm install -g openclaw"#;
         }
     }
 
+    /// Shell command installing `package_spec`, which must come from
+    /// `InstallerService::openclaw_package_spec`
+    fn install_openclaw_command(package_spec: &str) -> String {
+        // Source nvm before running npm (nvm installs node in user space)
+        format!(
+            r#"export NVM_DIR="$HOME/.nvm"
+[ -s "$NVM_DIR/nvm.sh" ] && \. "$NVM_DIR/nvm.sh"
+npm install -g {}"#,
+            package_spec
+        )
+    }
+
     /// Stage 4: Write OpenClaw config remotely
     async fn stage_write_config(
         &self,
         host: &str,
         user: &str,
-        config: &WizardConfig,
+        source: &RemoteConfigSource,
         tx: &mpsc::Sender<RemoteSetupProgress>,
     ) -> Result<()> {
-        Self::send_progress(
-            tx,
-            "config",
-            "in_progress",
-            "Writing OpenClaw configuration...",
-            None,
-        )
-        .await;
+        let message = match source {
+            RemoteConfigSource::Wizard(_) => "Writing OpenClaw configuration...",
+            RemoteConfigSource::Local(_) => "Copying local OpenClaw configuration...",
+        };
+        Self::send_progress(tx, "config", "in_progress", message, None).await;
 
-        // Build OpenClaw config JSON from WizardConfig
-        let openclaw_config = Self::build_openclaw_config(config)?;
+        let openclaw_config = match source {
+            RemoteConfigSource::Wizard(config) => Self::build_openclaw_config(config)?,
+            RemoteConfigSource::Local(config) => config.clone(),
+        };
         let config_json = serde_json::to_string_pretty(&openclaw_config)
             .context("Failed to serialize OpenClaw config")?;
 
-        // Shell-escape the JSON for safe transport via SSH
-        // Use heredoc to avoid single-quote escaping issues
-        let write_cmd = format!(
-            r#"mkdir -p ~/.openclaw && cat > ~/.openclaw/openclaw.json << 'OPENCLAW_CONFIG_EOF'
-{}
-OPENCLAW_CONFIG_EOF"#,
-            config_json
-        );
+        // The config is streamed over stdin so secrets never appear in the
+        // remote command line, and umask keeps the file private to the user
+        let write_cmd = "umask 077 && mkdir -p ~/.openclaw && cat > ~/.openclaw/openclaw.json";
 
         let output = self
             .ssh_service
-            .exec_remote(host, user, &write_cmd)
+            .exec_remote_with_stdin(host, user, write_cmd, config_json.as_bytes())
             .await
             .context("Failed to write config remotely")?;
 
         if output.exit_code != 0 {
-            let msg = format!("Failed to write config: {}", output.stderr.trim());
+            let msg = format!(
+                "Failed to write config: {}",
+                redact_secrets(output.stderr.trim())
+            );
             Self::send_progress(tx, "config", "failed", &msg, Some(&msg)).await;
             anyhow::bail!(msg);
         }
@@ -433,7 +520,10 @@ openclaw install-daemon"#;
             .context("Failed to install daemon")?;
 
         if output.exit_code != 0 {
-            let msg = format!("Daemon installation failed: {}", output.stderr.trim());
+            let msg = format!(
+                "Daemon installation failed: {}",
+                redact_secrets(output.stderr.trim())
+            );
             Self::send_progress(tx, "daemon", "failed", &msg, Some(&msg)).await;
             anyhow::bail!(msg);
         }
@@ -477,7 +567,10 @@ openclaw start"#;
             .context("Failed to start daemon")?;
 
         if output.exit_code != 0 {
-            let msg = format!("Failed to start daemon: {}", output.stderr.trim());
+            let msg = format!(
+                "Failed to start daemon: {}",
+                redact_secrets(output.stderr.trim())
+            );
             Self::send_progress(tx, "daemon", "failed", &msg, Some(&msg)).await;
             anyhow::bail!(msg);
         }
@@ -496,14 +589,31 @@ openclaw start"#;
 
     // ===== Helper Methods =====
 
+    /// Read the local ~/.openclaw/openclaw.json for copying to a remote server
+    ///
+    /// Fails unless the file parses as a JSON object, so a broken local config
+    /// is never sent.
+    pub fn load_local_config() -> Result<serde_json::Value> {
+        let path = Platform::wizard_config_path()?;
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Local config not found at {}", path.display()))?;
+        Self::parse_local_config(&content)
+            .with_context(|| format!("Local config at {} is invalid", path.display()))
+    }
+
+    fn parse_local_config(content: &str) -> Result<serde_json::Value> {
+        let value: serde_json::Value =
+            serde_json::from_str(content).context("Config is not valid JSON")?;
+        if !value.is_object() {
+            anyhow::bail!("Config must be a JSON object");
+        }
+        Ok(value)
+    }
+
     /// Build OpenClaw config JSON from WizardConfig
     fn build_openclaw_config(config: &WizardConfig) -> Result<serde_json::Value> {
         let mut oc_config = serde_json::json!({
-            "ai": {
-                "provider": config.provider,
-                "apiKey": config.api_key,
-                "authType": config.auth_type,
-            },
+            "ai": ProviderAuth::resolve(&ProviderInput::from(config)).config_fragment(),
             "gateway": {
                 "port": config.gateway_port,
                 "bind": config.gateway_bind,
@@ -567,9 +677,10 @@ openclaw start"#;
         let progress = RemoteSetupProgress {
             stage: stage.to_string(),
             status: status.to_string(),
-            message: message.to_string(),
-            error: error.map(|s| s.to_string()),
+            message: redact_secrets(message),
+            error: error.map(redact_secrets),
             timestamp,
+            elapsed_ms: None,
         };
 
         if tx.send(progress).await.is_err() {
@@ -578,6 +689,15 @@ openclaw start"#;
     }
 }
 
+/// Where the remote openclaw.json comes from
+#[derive(Debug, Clone)]
+pub enum RemoteConfigSource {
+    /// Built from the wizard's settings
+    Wizard(Box<WizardConfig>),
+    /// The local openclaw.json, copied verbatim (secrets included)
+    Local(serde_json::Value),
+}
+
 impl Default for RemoteService {
     fn default() -> Self {
         Self::new()
@@ -588,6 +708,13 @@ impl Default for RemoteService {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_install_openclaw_command_uses_spec() {
+        let command = RemoteService::install_openclaw_command("openclaw@2026.2.15");
+        assert!(command.ends_with("npm install -g openclaw@2026.2.15"));
+        assert!(command.contains("nvm.sh"));
+    }
+
     #[test]
     fn test_parse_node_major_valid() {
         assert_eq!(RemoteService::parse_node_major("v22.12.0"), Some(22));
@@ -606,6 +733,7 @@ mod tests {
     #[test]
     fn test_build_openclaw_config_basic() {
         let config = WizardConfig {
+            schema_version: None,
             provider: "openai".into(),
             api_key: "sk-test-123".into(),
             auth_type: "api-key".into(),
@@ -619,6 +747,8 @@ mod tests {
             compatibility: None,
             account_id: None,
             gateway_id: None,
+            log_dir: None,
+            analysis_model: None,
         };
 
         let result = RemoteService::build_openclaw_config(&config).unwrap();
@@ -632,6 +762,7 @@ mod tests {
         use crate::models::types::ChannelConfig;
 
         let config = WizardConfig {
+            schema_version: None,
             provider: "anthropic".into(),
             api_key: "sk-ant-test".into(),
             auth_type: "api-key".into(),
@@ -652,6 +783,8 @@ mod tests {
             compatibility: None,
             account_id: None,
             gateway_id: None,
+            log_dir: None,
+            analysis_model: None,
         };
 
         let result = RemoteService::build_openclaw_config(&config).unwrap();
@@ -659,4 +792,64 @@ mod tests {
         assert!(result["channels"].is_array());
         assert_eq!(result["channels"][0]["platform"], "telegram");
     }
+
+    #[test]
+    fn test_build_openclaw_config_provider_fields() {
+        let mut config = WizardConfig {
+            schema_version: None,
+            provider: "cloudflare".into(),
+            api_key: "cf-key".into(),
+            auth_type: "api-key".into(),
+            gateway_port: 18789,
+            gateway_bind: "127.0.0.1".into(),
+            auth_mode: "token".into(),
+            auth_credential: None,
+            channels: None,
+            base_url: None,
+            model_id: None,
+            compatibility: None,
+            account_id: Some("acct-1".into()),
+            gateway_id: Some("gw-1".into()),
+            log_dir: None,
+            analysis_model: None,
+        };
+
+        let result = RemoteService::build_openclaw_config(&config).unwrap();
+        assert_eq!(result["ai"]["authChoice"], "cloudflare-ai-gateway-api-key");
+        assert_eq!(result["ai"]["accountId"], "acct-1");
+        assert_eq!(result["ai"]["gatewayId"], "gw-1");
+        assert_eq!(result["ai"]["apiKey"], "cf-key");
+
+        config.provider = "custom".into();
+        config.base_url = Some("https://llm.example.com/v1".into());
+        config.model_id = Some("my-model".into());
+        config.compatibility = Some("openai".into());
+        let result = RemoteService::build_openclaw_config(&config).unwrap();
+        assert_eq!(result["ai"]["authChoice"], "custom-api-key");
+        assert_eq!(result["ai"]["baseUrl"], "https://llm.example.com/v1");
+        assert_eq!(result["ai"]["modelId"], "my-model");
+        assert_eq!(result["ai"]["compatibility"], "openai");
+        assert!(result["ai"].get("accountId").is_none());
+
+        config.provider = "vercel-ai-gateway".into();
+        let result = RemoteService::build_openclaw_config(&config).unwrap();
+        assert_eq!(result["ai"]["authChoice"], "ai-gateway-api-key");
+
+        config.provider = "github-copilot".into();
+        let result = RemoteService::build_openclaw_config(&config).unwrap();
+        assert_eq!(result["ai"]["authChoice"], "github-copilot");
+        assert!(result["ai"].get("apiKey").is_none());
+    }
+
+    #[test]
+    fn test_parse_local_config() {
+        let value =
+            RemoteService::parse_local_config(r#"{"gateway": {"port": 3000}, "apiKey": "sk-x"}"#)
+                .unwrap();
+        assert_eq!(value["gateway"]["port"], 3000);
+        assert_eq!(value["apiKey"], "sk-x");
+
+        assert!(RemoteService::parse_local_config("{not json").is_err());
+        assert!(RemoteService::parse_local_config("[1, 2]").is_err());
+    }
 }
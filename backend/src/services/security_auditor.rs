@@ -5,8 +5,9 @@
 
 use crate::models::types::{SecurityAudit, SecurityFinding};
 use crate::services::config::ConfigWriter;
+use crate::services::credentials::MIN_CREDENTIAL_LENGTH;
+use crate::services::platform::Platform;
 use anyhow::Result;
-use std::path::PathBuf;
 
 pub struct SecurityAuditor;
 
@@ -15,9 +16,7 @@ impl SecurityAuditor {
     ///
     /// Returns a SecurityAudit with findings, counts, and overall score.
     pub fn audit() -> Result<SecurityAudit> {
-        let home = std::env::var("HOME")
-            .map_err(|_| anyhow::anyhow!("HOME environment variable not set"))?;
-        let config_path = PathBuf::from(format!("{}/.openclaw/openclaw.json", home));
+        let config_path = Platform::openclaw_config_path()?;
 
         let config: serde_json::Value =
             ConfigWriter::read_json(&config_path).unwrap_or_else(|_| serde_json::json!({}));
@@ -273,10 +272,11 @@ impl SecurityAuditor {
             .get("auth")
             .and_then(|a| a.get("credential"))
             .or_else(|| config.get("gateway").and_then(|g| g.get("auth_credential")))
+            .or_else(|| config.pointer("/gateway/auth/token"))
             .and_then(|c| c.as_str());
 
         if let Some(cred) = credential
-            && cred.len() < 16
+            && cred.len() < MIN_CREDENTIAL_LENGTH
         {
             findings.push(SecurityFinding {
                 id: "SEC-007".to_string(),
@@ -288,7 +288,11 @@ impl SecurityAuditor {
                     cred.len()
                 ),
                 affected_field: "auth.credential".to_string(),
-                fix_suggestion: Some("Use a credential with at least 16 characters".to_string()),
+                fix_suggestion: Some(format!(
+                    "Use a credential with at least {} characters \
+                     (GET /api/wizard/generate-credential creates one)",
+                    MIN_CREDENTIAL_LENGTH
+                )),
             });
         }
     }
@@ -300,11 +304,10 @@ impl SecurityAuditor {
         {
             use std::os::unix::fs::MetadataExt;
 
-            let home = match std::env::var("HOME") {
-                Ok(h) => h,
+            let config_path = match Platform::openclaw_config_path() {
+                Ok(p) => p,
                 Err(_) => return,
             };
-            let config_path = PathBuf::from(format!("{}/.openclaw/openclaw.json", home));
 
             if let Ok(metadata) = std::fs::metadata(&config_path) {
                 let mode = metadata.mode();
@@ -416,6 +419,29 @@ mod tests {
         assert_eq!(sec007.unwrap().severity, "warning");
     }
 
+    #[test]
+    fn test_generated_credential_passes_weak_credential_check() {
+        use crate::services::credentials::{CredentialService, DEFAULT_CREDENTIAL_LENGTH};
+
+        let weak = serde_json::json!({
+            "gateway": { "bind": "127.0.0.1", "auth": { "mode": "token", "token": "short" } }
+        });
+        let result = SecurityAuditor::audit_config(&weak).unwrap();
+        assert!(result.findings.iter().any(|f| f.id == "SEC-007"));
+
+        let strong = serde_json::json!({
+            "gateway": {
+                "bind": "127.0.0.1",
+                "auth": {
+                    "mode": "token",
+                    "token": CredentialService::generate(DEFAULT_CREDENTIAL_LENGTH),
+                }
+            }
+        });
+        let result = SecurityAuditor::audit_config(&strong).unwrap();
+        assert!(!result.findings.iter().any(|f| f.id == "SEC-007"));
+    }
+
     #[test]
     fn test_overall_score_logic() {
         // All secure
@@ -6,9 +6,39 @@
 
 use crate::models::types::{ServiceActionResponse, ServiceProcessStatus, ServicesStatus};
 use crate::services::command::SafeCommand;
+use crate::services::health::HealthService;
+use crate::services::platform::Platform;
+use crate::services::processes::ProcessService;
 use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
 use std::time::{SystemTime, UNIX_EPOCH};
-use sysinfo::{ProcessesToUpdate, System};
+use sysinfo::{Pid, Process, ProcessesToUpdate, System};
+
+/// Gateway last identified by its command line: pid and process name.
+/// Lets a later scan recognise it when the command line can't be read,
+/// while the name check catches the PID being reused by another process.
+static LAST_GATEWAY: LazyLock<Mutex<Option<(u32, String)>>> = LazyLock::new(|| Mutex::new(None));
+
+/// Note for a gateway process whose stats this user can't read
+const UNREADABLE_NOTE: &str =
+    "Gateway process stats aren't readable (it may belong to another user)";
+
+/// Systemd user unit that `openclaw gateway install` creates on Linux
+pub const GATEWAY_SYSTEMD_UNIT: &str = "openclaw-gateway";
+
+/// launchd label that `openclaw gateway install` registers on macOS
+pub const GATEWAY_LAUNCHD_LABEL: &str = "ai.openclaw.gateway";
+
+/// A gateway service that is already registered with the service manager
+#[derive(Debug, Clone, PartialEq)]
+pub struct GatewayUnit {
+    /// "systemd (user)", "systemd (system)" or "launchd"
+    pub manager: &'static str,
+    pub name: &'static str,
+    /// e.g. "active (running)", "running (pid 4242)"
+    pub state: String,
+}
 
 pub struct ServiceManager;
 
@@ -50,6 +80,96 @@ impl ServiceManager {
         }
     }
 
+    /// The systemd user unit of a log service, when it is systemd-managed
+    ///
+    /// Such installs log to journald rather than a file. Only the gateway
+    /// has a unit; the legacy daemon has none.
+    pub fn journald_unit(service: &str) -> Option<&'static str> {
+        let unit = match service {
+            "gateway" => GATEWAY_SYSTEMD_UNIT,
+            _ => return None,
+        };
+        if !cfg!(target_os = "linux") {
+            return None;
+        }
+        let output = SafeCommand::run(
+            "systemctl",
+            &["--user", "show", unit, "--property=LoadState", "--value"],
+        )
+        .ok()?;
+        (output.exit_code == 0 && Self::unit_loaded(&output.stdout)).then_some(unit)
+    }
+
+    /// The gateway service already registered with systemd (user or system
+    /// scope) or launchd, if any
+    pub fn existing_gateway_unit() -> Option<GatewayUnit> {
+        if cfg!(target_os = "linux") {
+            [("systemd (user)", true), ("systemd (system)", false)]
+                .into_iter()
+                .find_map(|(manager, user)| {
+                    let mut args = vec!["show", GATEWAY_SYSTEMD_UNIT];
+                    if user {
+                        args.insert(0, "--user");
+                    }
+                    args.push("--property=LoadState,ActiveState,SubState");
+                    let output = SafeCommand::run("systemctl", &args).ok()?;
+                    let state = Self::systemd_unit_state(&output.stdout)?;
+                    Some(GatewayUnit {
+                        manager,
+                        name: GATEWAY_SYSTEMD_UNIT,
+                        state,
+                    })
+                })
+        } else if cfg!(target_os = "macos") {
+            let output = SafeCommand::run("launchctl", &["list", GATEWAY_LAUNCHD_LABEL]).ok()?;
+            (output.exit_code == 0).then(|| GatewayUnit {
+                manager: "launchd",
+                name: GATEWAY_LAUNCHD_LABEL,
+                state: Self::launchd_job_state(&output.stdout),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// "active (running)" from `systemctl show` properties, None when the
+    /// unit file doesn't exist
+    fn systemd_unit_state(show: &str) -> Option<String> {
+        let property = |name: &str| {
+            show.lines()
+                .find_map(|line| line.strip_prefix(name)?.strip_prefix('='))
+                .map(str::trim)
+        };
+        if !Self::unit_loaded(property("LoadState")?) {
+            return None;
+        }
+        let active = property("ActiveState").unwrap_or("unknown");
+        Some(match property("SubState") {
+            Some(sub) if !sub.is_empty() => format!("{} ({})", active, sub),
+            _ => active.to_string(),
+        })
+    }
+
+    /// State of a job from `launchctl list <label>` output
+    fn launchd_job_state(list: &str) -> String {
+        let value = |key: &str| {
+            list.lines().find_map(|line| {
+                let rest = line.trim().strip_prefix(&format!("\"{}\" = ", key))?;
+                Some(rest.trim_end_matches(';').trim().to_string())
+            })
+        };
+        match (value("PID"), value("LastExitStatus")) {
+            (Some(pid), _) => format!("running (pid {})", pid),
+            (None, Some(status)) => format!("loaded, not running (last exit {})", status),
+            (None, None) => "loaded".to_string(),
+        }
+    }
+
+    /// Whether `systemctl show --property=LoadState --value` reports a unit file
+    fn unit_loaded(load_state: &str) -> bool {
+        load_state.trim() == "loaded"
+    }
+
     /// Install the gateway service (launchd/systemd/schtasks)
     fn install_gateway() -> Result<()> {
         let output = SafeCommand::run("openclaw", &["gateway", "install"])
@@ -165,73 +285,188 @@ impl ServiceManager {
     }
 
     /// Find the gateway process by looking for node processes running openclaw gateway
-    fn find_gateway_process(sys: &System) -> ServiceProcessStatus {
-        let current_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs();
-
+    ///
+    /// When the process can't be inspected (another user's process, or its
+    /// stats are partly unreadable) it's still reported as running with
+    /// `None` metrics and a `note`:
+    /// 1. a node process whose command line names the gateway
+    /// 2. the previously found gateway PID, if it still has the same name
+    /// 3. a node process with an unreadable command line that owns the
+    ///    listening socket on the configured gateway port, matched by its
+    ///    socket fds or, when those aren't readable either, by the
+    ///    listener's uid in the kernel's TCP table
+    pub(crate) fn find_gateway_process(sys: &System) -> ServiceProcessStatus {
+        let mut last = LAST_GATEWAY.lock().unwrap_or_else(|e| e.into_inner());
+
+        let mut opaque_pids = Vec::new();
         for (pid, process) in sys.processes() {
             let name = process.name().to_string_lossy().to_lowercase();
-            let cmd_args: Vec<String> = process
+            if !name.contains("node") {
+                continue;
+            }
+
+            let cmd_str = process
                 .cmd()
                 .iter()
                 .map(|s| s.to_string_lossy().to_lowercase())
-                .collect();
+                .collect::<Vec<_>>()
+                .join(" ");
+            if cmd_str.is_empty() {
+                opaque_pids.push((pid.as_u32(), process.user_id().map(|uid| **uid)));
+                continue;
+            }
+
+            // Skip wizard processes
+            if cmd_str.contains("vite")
+                || cmd_str.contains("openclaw-wizard")
+                || cmd_str.contains("vitest")
+            {
+                continue;
+            }
 
-            if name.contains("node") {
-                let cmd_str = cmd_args.join(" ");
+            // Must contain "gateway" to match the gateway process
+            if cmd_str.contains("gateway") {
+                *last = Some((pid.as_u32(), name));
+                return Self::process_status(*pid, process);
+            }
+        }
 
-                // Skip wizard processes
-                if cmd_str.contains("vite")
-                    || cmd_str.contains("openclaw-wizard")
-                    || cmd_str.contains("vitest")
+        if let Some((pid, name)) = last.clone() {
+            let process = sys.process(Pid::from_u32(pid));
+            let current = process.map(|p| p.name().to_string_lossy().to_lowercase());
+            // A readable command line was already checked above
+            match process {
+                Some(process)
+                    if Self::same_process(&name, current.as_deref())
+                        && opaque_pids.iter().any(|(opaque, _)| *opaque == pid) =>
                 {
-                    continue;
-                }
-
-                // Must contain "gateway" to match the gateway process
-                if cmd_str.contains("gateway") {
-                    let start_time = process.start_time();
-                    let uptime_seconds = if start_time > 0 {
-                        Some(current_time.saturating_sub(start_time))
-                    } else {
-                        None
-                    };
-
-                    return ServiceProcessStatus {
-                        running: true,
-                        pid: Some(pid.as_u32()),
-                        uptime_seconds,
-                        memory_mb: Some(process.memory() / 1024 / 1024),
-                        cpu_percent: Some(process.cpu_usage()),
-                    };
+                    let mut status = Self::process_status(Pid::from_u32(pid), process);
+                    status.note.get_or_insert_with(|| {
+                        "Gateway command line isn't readable; matched its previous PID".to_string()
+                    });
+                    return status;
                 }
+                // Exited, or the PID now belongs to something else
+                _ => *last = None,
             }
         }
+        drop(last);
+
+        // Only a socket owned by one of those processes counts as the gateway
+        let port = HealthService::configured_gateway_port();
+        let owner = Self::port_owner(
+            &opaque_pids,
+            |pid| ProcessService::listening_ports(pid).contains(&port),
+            || ProcessService::listening_socket_uids(port),
+        );
+        Self::fallback_status(owner, || HealthService::port_listening(port))
+    }
+
+    /// The first `(pid, uid)` candidate that owns the gateway's listener
+    ///
+    /// `holds_port` checks a process's own socket fds, which fails for
+    /// another user's process; only then are candidates matched against
+    /// the uids owning a listener on the port.
+    fn port_owner(
+        candidates: &[(u32, Option<u32>)],
+        holds_port: impl Fn(u32) -> bool,
+        listener_uids: impl FnOnce() -> Vec<u32>,
+    ) -> Option<u32> {
+        if let Some((pid, _)) = candidates.iter().find(|(pid, _)| holds_port(*pid)) {
+            return Some(*pid);
+        }
+        if candidates.is_empty() {
+            return None;
+        }
+        let uids = listener_uids();
+        candidates
+            .iter()
+            .find(|(_, uid)| uid.is_some_and(|uid| uids.contains(&uid)))
+            .map(|(pid, _)| *pid)
+    }
+
+    /// Status of an identified gateway process, dropping stats that read as
+    /// empty (sysinfo reports 0 for values it couldn't read)
+    fn process_status(pid: Pid, process: &Process) -> ServiceProcessStatus {
+        let current_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let start_time = process.start_time();
+        let uptime_seconds = (start_time > 0).then(|| current_time.saturating_sub(start_time));
+        let readable = process.memory() > 0;
+
+        ServiceProcessStatus {
+            running: true,
+            pid: Some(pid.as_u32()),
+            uptime_seconds,
+            memory_mb: readable.then(|| process.memory() / 1024 / 1024),
+            cpu_percent: readable.then(|| process.cpu_usage()),
+            note: (!readable || uptime_seconds.is_none()).then(|| UNREADABLE_NOTE.to_string()),
+        }
+    }
+
+    /// Whether the process now at a remembered PID is still the one we saw
+    fn same_process(remembered_name: &str, current_name: Option<&str>) -> bool {
+        current_name.is_some_and(|name| name == remembered_name)
+    }
+
+    /// Status when no process could be matched by its command line
+    ///
+    /// `owner` is the process verified to hold the gateway port's listening
+    /// socket; `port_listening` is only asked when there is none.
+    fn fallback_status(
+        owner: Option<u32>,
+        port_listening: impl FnOnce() -> bool,
+    ) -> ServiceProcessStatus {
+        let (running, pid, note) = match owner {
+            Some(pid) => (
+                true,
+                Some(pid),
+                Some(
+                    "Gateway port is held by a gateway process whose command line isn't readable, so metrics are unavailable"
+                        .to_string(),
+                ),
+            ),
+            None if port_listening() => (
+                false,
+                None,
+                Some(
+                    "Gateway port is in use, but not by a process identified as the gateway"
+                        .to_string(),
+                ),
+            ),
+            None => (false, None, None),
+        };
 
         ServiceProcessStatus {
-            running: false,
-            pid: None,
+            running,
+            pid,
             uptime_seconds: None,
             memory_mb: None,
             cpu_percent: None,
+            note,
         }
     }
 
+    /// Directories searched for recent errors; the system-wide ones are
+    /// still checked when there is no home directory
+    fn error_log_dirs(home: Option<&Path>) -> Vec<PathBuf> {
+        let mut dirs = Vec::new();
+        if let Some(home) = home {
+            dirs.push(home.join(".openclaw").join("logs"));
+        }
+        dirs.push(PathBuf::from("/var/log/openclaw"));
+        if let Some(home) = home {
+            dirs.push(home.join("Library").join("Logs").join("openclaw"));
+        }
+        dirs.push(PathBuf::from("/tmp/openclaw"));
+        dirs
+    }
+
     /// Count ERROR lines in log files from the last 24 hours
     fn count_recent_errors() -> u32 {
-        let home = match std::env::var("HOME") {
-            Ok(h) => h,
-            Err(_) => return 0,
-        };
-
-        let log_paths = vec![
-            format!("{}/.openclaw/logs", home),
-            "/var/log/openclaw".to_string(),
-            format!("{}/Library/Logs/openclaw", home),
-            "/tmp/openclaw".to_string(),
-        ];
+        let log_paths = Self::error_log_dirs(Platform::home_dir().ok().as_deref());
 
         let twenty_four_hours_ago = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -241,8 +476,7 @@ impl ServiceManager {
 
         let mut total_errors: u32 = 0;
 
-        for log_dir in &log_paths {
-            let dir = std::path::Path::new(log_dir);
+        for dir in &log_paths {
             if !dir.exists() {
                 continue;
             }
@@ -292,7 +526,8 @@ mod tests {
         let status = ServiceManager::services_status();
 
         if status.gateway.running {
-            assert!(status.gateway.pid.is_some());
+            // Only an unidentifiable process (explained by a note) lacks a pid
+            assert!(status.gateway.pid.is_some() || status.gateway.note.is_some());
         } else {
             assert!(status.gateway.pid.is_none());
         }
@@ -311,6 +546,109 @@ mod tests {
         assert!(count < u32::MAX);
     }
 
+    #[test]
+    fn test_daemon_has_no_journald_unit() {
+        assert!(ServiceManager::journald_unit("daemon").is_none());
+    }
+
+    #[test]
+    fn test_port_owner_falls_back_to_listener_uid() {
+        let candidates = [(10, Some(1000)), (20, Some(0)), (30, None)];
+        assert_eq!(
+            ServiceManager::port_owner(&candidates, |pid| pid == 30, || unreachable!()),
+            Some(30)
+        );
+        // A root gateway's fds can't be read, but its listener's uid can
+        assert_eq!(
+            ServiceManager::port_owner(&candidates, |_| false, || vec![0]),
+            Some(20)
+        );
+        assert_eq!(
+            ServiceManager::port_owner(&candidates, |_| false, || vec![999]),
+            None
+        );
+        assert_eq!(
+            ServiceManager::port_owner(&[], |_| false, || unreachable!()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_fallback_status_requires_verified_port_owner() {
+        let stopped = ServiceManager::fallback_status(None, || false);
+        assert!(!stopped.running);
+        assert!(stopped.note.is_none());
+
+        let owned = ServiceManager::fallback_status(Some(42), || unreachable!());
+        assert!(owned.running);
+        assert_eq!(owned.pid, Some(42));
+        assert!(owned.memory_mb.is_none() && owned.cpu_percent.is_none());
+        assert!(owned.note.is_some());
+
+        // Something else on the port is not reported as the gateway
+        let foreign = ServiceManager::fallback_status(None, || true);
+        assert!(!foreign.running);
+        assert!(foreign.pid.is_none());
+        assert!(foreign.note.is_some());
+    }
+
+    #[test]
+    fn test_error_log_dirs_without_home() {
+        let dirs = ServiceManager::error_log_dirs(None);
+        assert_eq!(
+            dirs,
+            vec![
+                PathBuf::from("/var/log/openclaw"),
+                PathBuf::from("/tmp/openclaw")
+            ]
+        );
+
+        let dirs = ServiceManager::error_log_dirs(Some(Path::new("/home/me")));
+        assert_eq!(dirs[0], PathBuf::from("/home/me/.openclaw/logs"));
+        assert_eq!(dirs.len(), 4);
+    }
+
+    #[test]
+    fn test_systemd_unit_state() {
+        let running = "LoadState=loaded\nActiveState=active\nSubState=running\n";
+        assert_eq!(
+            ServiceManager::systemd_unit_state(running).as_deref(),
+            Some("active (running)")
+        );
+        let missing = "LoadState=not-found\nActiveState=inactive\nSubState=dead\n";
+        assert_eq!(ServiceManager::systemd_unit_state(missing), None);
+        assert_eq!(ServiceManager::systemd_unit_state(""), None);
+    }
+
+    #[test]
+    fn test_launchd_job_state() {
+        let running = "{\n\t\"LimitLoadToSessionType\" = \"Aqua\";\n\t\"Label\" = \"ai.openclaw.gateway\";\n\t\"PID\" = 4242;\n\t\"LastExitStatus\" = 0;\n};";
+        assert_eq!(
+            ServiceManager::launchd_job_state(running),
+            "running (pid 4242)"
+        );
+
+        let stopped = "{\n\t\"Label\" = \"ai.openclaw.gateway\";\n\t\"LastExitStatus\" = 256;\n};";
+        assert_eq!(
+            ServiceManager::launchd_job_state(stopped),
+            "loaded, not running (last exit 256)"
+        );
+    }
+
+    #[test]
+    fn test_unit_loaded_requires_unit_file() {
+        assert!(ServiceManager::unit_loaded("loaded\n"));
+        assert!(!ServiceManager::unit_loaded("not-found\n"));
+        assert!(!ServiceManager::unit_loaded(""));
+    }
+
+    #[test]
+    fn test_same_process_detects_reused_pid() {
+        assert!(ServiceManager::same_process("node", Some("node")));
+        assert!(!ServiceManager::same_process("node", Some("postgres")));
+        assert!(!ServiceManager::same_process("node", None));
+    }
+
     #[test]
     fn test_find_gateway_process() {
         let mut sys = System::new_all();
@@ -0,0 +1,303 @@
+//! # Full Setup Service
+//!
+//! Runs the whole first-time setup as one flow: install Node.js/OpenClaw,
+//! `openclaw doctor --fix`, start the gateway, then probe its health. Every
+//! step reports through a single `InstallProgress` stream; each stage opens
+//! with a `setup` message naming it ("Step 2/4: ...") and the flow ends with
+//! a `setup` completed or failed message.
+
+use anyhow::{Result, bail};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+use crate::models::InstallProgress;
+use crate::models::types::DiagnosticCheck;
+use crate::services::command::SafeCommand;
+use crate::services::doctor::DoctorService;
+use crate::services::health::HealthService;
+use crate::services::installer::{GatewaySetup, InstallerService};
+use crate::services::service_manager::ServiceManager;
+
+/// Stages of the full setup, in order
+pub const SETUP_STAGES: [&str; 4] = ["install", "doctor", "gateway-start", "health"];
+
+/// How many times the health probe is tried after the gateway starts
+const HEALTH_ATTEMPTS: u32 = 5;
+
+/// Pause between health probes while the gateway comes up
+const HEALTH_RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// Full setup orchestration
+pub struct SetupService;
+
+impl SetupService {
+    /// Run install → doctor → gateway start → health with streaming progress
+    ///
+    /// Install and gateway start failures stop the flow; doctor findings are
+    /// reported but don't. An unhealthy gateway after the retries fails it.
+    /// `gateway` is passed to the install stage's verify step.
+    pub async fn run_full_setup(
+        install_node: bool,
+        install_openclaw: bool,
+        openclaw_version: Option<String>,
+        gateway: GatewaySetup,
+        tx: mpsc::Sender<InstallProgress>,
+    ) -> Result<()> {
+        let result = Self::run_stages(
+            install_node,
+            install_openclaw,
+            openclaw_version.as_deref(),
+            gateway,
+            &tx,
+        )
+        .await;
+
+        let _ = tx
+            .send(match &result {
+                Ok(()) => InstallProgress {
+                    stage: "setup".into(),
+                    status: "completed".into(),
+                    message: "Setup complete. OpenClaw is installed and the gateway is healthy."
+                        .into(),
+                    progress_pct: Some(100),
+                    ..Default::default()
+                },
+                Err(e) => InstallProgress {
+                    stage: "setup".into(),
+                    status: "failed".into(),
+                    message: "Setup failed".into(),
+                    error: Some(e.to_string()),
+                    ..Default::default()
+                },
+            })
+            .await;
+
+        result
+    }
+
+    async fn run_stages(
+        install_node: bool,
+        install_openclaw: bool,
+        openclaw_version: Option<&str>,
+        gateway: GatewaySetup,
+        tx: &mpsc::Sender<InstallProgress>,
+    ) -> Result<()> {
+        let _ = tx.send(Self::stage_started(0)).await;
+        InstallerService::run_install(
+            install_node,
+            install_openclaw,
+            openclaw_version,
+            gateway,
+            tx.clone(),
+        )
+        .await?;
+
+        let _ = tx.send(Self::stage_started(1)).await;
+        Self::run_doctor_fix(tx).await;
+
+        let _ = tx.send(Self::stage_started(2)).await;
+        Self::start_gateway(tx).await?;
+
+        let _ = tx.send(Self::stage_started(3)).await;
+        Self::probe_health(tx).await
+    }
+
+    /// Stage transition message for `SETUP_STAGES[index]`
+    fn stage_started(index: usize) -> InstallProgress {
+        let stage = SETUP_STAGES[index];
+        let description = match stage {
+            "install" => "Installing Node.js and OpenClaw",
+            "doctor" => "Running diagnostics and auto-fix",
+            "gateway-start" => "Starting the gateway",
+            _ => "Checking gateway health",
+        };
+        InstallProgress {
+            stage: "setup".into(),
+            status: "running".into(),
+            message: format!("Step {}/{}: {}", index + 1, SETUP_STAGES.len(), description),
+            output_line: Some(format!("stage: {}", stage)),
+            progress_pct: Some((index * 100 / SETUP_STAGES.len()) as u8),
+            ..Default::default()
+        }
+    }
+
+    /// Run `openclaw doctor --fix` and report each parsed check
+    async fn run_doctor_fix(tx: &mpsc::Sender<InstallProgress>) {
+        let _ = tx
+            .send(InstallProgress {
+                stage: "doctor".into(),
+                status: "running".into(),
+                message: "Running diagnostics and auto-fix...".into(),
+                output_line: Some("$ openclaw doctor --fix".into()),
+                ..Default::default()
+            })
+            .await;
+
+        let output = match SafeCommand::run("openclaw", &["doctor", "--fix"]) {
+            Ok(output) => output,
+            Err(e) => {
+                let _ = tx
+                    .send(InstallProgress {
+                        stage: "doctor".into(),
+                        status: "completed".into(),
+                        message: "Diagnostics unavailable (continuing)".into(),
+                        error: Some(e.to_string()),
+                        ..Default::default()
+                    })
+                    .await;
+                return;
+            }
+        };
+
+        let text = if output.stdout.trim().is_empty() {
+            &output.stderr
+        } else {
+            &output.stdout
+        };
+        let report = DoctorService::parse_text_output(text);
+        for check in &report.checks {
+            let _ = tx.send(Self::check_progress(check)).await;
+        }
+
+        let _ = tx
+            .send(InstallProgress {
+                stage: "doctor".into(),
+                status: "completed".into(),
+                message: match report.overall_status.as_str() {
+                    "pass" => "Diagnostics passed".to_string(),
+                    status => format!("Diagnostics finished with status '{}' (continuing)", status),
+                },
+                ..Default::default()
+            })
+            .await;
+    }
+
+    /// Progress line for one doctor check
+    fn check_progress(check: &DiagnosticCheck) -> InstallProgress {
+        InstallProgress {
+            stage: "doctor".into(),
+            status: "running".into(),
+            message: format!("{}: {}", check.name, check.message),
+            output_line: Some(format!("[{}] {}", check.status.to_uppercase(), check.name)),
+            error: check.fix_suggestion.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Start the gateway, installing its service first if needed
+    async fn start_gateway(tx: &mpsc::Sender<InstallProgress>) -> Result<()> {
+        let _ = tx
+            .send(InstallProgress {
+                stage: "gateway-start".into(),
+                status: "running".into(),
+                message: "Starting gateway...".into(),
+                output_line: Some("$ openclaw gateway start".into()),
+                ..Default::default()
+            })
+            .await;
+
+        let response = ServiceManager::start_gateway()?;
+        let _ = tx
+            .send(InstallProgress {
+                stage: "gateway-start".into(),
+                status: if response.success {
+                    "completed"
+                } else {
+                    "failed"
+                }
+                .into(),
+                message: response.message.clone(),
+                error: (!response.success).then(|| response.message.clone()),
+                ..Default::default()
+            })
+            .await;
+
+        if !response.success {
+            bail!("{}", response.message);
+        }
+        Ok(())
+    }
+
+    /// Probe gateway health, retrying while it comes up
+    async fn probe_health(tx: &mpsc::Sender<InstallProgress>) -> Result<()> {
+        let mut snapshot = HealthService::get_health().await;
+        for attempt in 1..HEALTH_ATTEMPTS {
+            if snapshot.gateway_reachable {
+                break;
+            }
+            let _ = tx
+                .send(InstallProgress {
+                    stage: "health".into(),
+                    status: "running".into(),
+                    message: format!(
+                        "Gateway {} (attempt {}/{}), retrying...",
+                        snapshot.gateway_status, attempt, HEALTH_ATTEMPTS
+                    ),
+                    output_line: snapshot.gateway_detail.clone(),
+                    ..Default::default()
+                })
+                .await;
+            tokio::time::sleep(HEALTH_RETRY_DELAY).await;
+            snapshot = HealthService::get_health().await;
+        }
+
+        if snapshot.gateway_reachable {
+            let _ = tx
+                .send(InstallProgress {
+                    stage: "health".into(),
+                    status: "completed".into(),
+                    message: format!(
+                        "Gateway healthy ({} channel(s), {}ms probe)",
+                        snapshot.channels.len(),
+                        snapshot.probe_duration_ms
+                    ),
+                    ..Default::default()
+                })
+                .await;
+            return Ok(());
+        }
+
+        let message = format!("Gateway is {} after starting", snapshot.gateway_status);
+        let _ = tx
+            .send(InstallProgress {
+                stage: "health".into(),
+                status: "failed".into(),
+                message: message.clone(),
+                error: snapshot.gateway_detail.clone(),
+                ..Default::default()
+            })
+            .await;
+        bail!(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stage_started_numbers_stages() {
+        let first = SetupService::stage_started(0);
+        assert_eq!(first.stage, "setup");
+        assert_eq!(first.progress_pct, Some(0));
+        assert!(first.message.starts_with("Step 1/4"));
+
+        let last = SetupService::stage_started(3);
+        assert_eq!(last.output_line.as_deref(), Some("stage: health"));
+        assert_eq!(last.progress_pct, Some(75));
+    }
+
+    #[test]
+    fn test_check_progress_carries_fix_suggestion() {
+        let check = DiagnosticCheck {
+            name: "Config".to_string(),
+            status: "warn".to_string(),
+            message: "Gateway token missing".to_string(),
+            fix_suggestion: Some("Run openclaw configure".to_string()),
+        };
+        let progress = SetupService::check_progress(&check);
+        assert_eq!(progress.stage, "doctor");
+        assert_eq!(progress.output_line.as_deref(), Some("[WARN] Config"));
+        assert_eq!(progress.error.as_deref(), Some("Run openclaw configure"));
+    }
+}
@@ -1,8 +1,17 @@
 //! # Skills Service
 //!
 //! Manages skill discovery from ClawHub (npm registry), installation/uninstallation
-//! via npm, and VirusTotal security scanning. Skills are npm packages with the
-//! "openclaw-skill" keyword.
+//! via npm, VirusTotal security scanning, and npm dependency audits. Skills are
+//! npm packages with the "openclaw-skill" keyword.
+//!
+//! Installs can report progress: VT scan results and each line of npm output
+//! are sent as `InstallProgress` updates for the `/ws/skills/install` stream.
+//! `recommended` serves a curated starter list: the bundled
+//! `recommended_skills.json`, or the JSON at `OPENCLAW_RECOMMENDED_SKILLS_URL`
+//! when set.
+//!
+//! Batch installs run one skill at a time (VirusTotal's rate limit makes
+//! parallel scans pointless) and tag each update with the skill it belongs to.
 //!
 //! SECURITY:
 //! - VirusTotal scanning runs BEFORE installation when API key is configured
@@ -11,26 +20,75 @@
 //! - Graceful degradation: no VT API key = scanning disabled (not an error)
 
 use serde::Deserialize;
+use std::process::Stdio;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::sync::mpsc;
 use tracing::{error, info, warn};
 
 use crate::error::AppError;
 use crate::models::types::{
-    InstalledSkill, ScanResult, SkillCategory, SkillInstallResponse, SkillMetadata,
-    SkillSearchResponse, ThreatLevel,
+    EngineDetection, InstallProgress, InstalledSkill, RecommendedSkill, RecommendedSkillsResponse,
+    ScanResult, SkillAudit, SkillBatchItem, SkillBatchProgress, SkillBatchSummary,
+    SkillBatchUninstallSummary, SkillCategory, SkillInstallRequest, SkillInstallResponse,
+    SkillMetadata, SkillSearchResponse, SkillUninstallResult, ThreatLevel, VulnerabilitySummary,
 };
+use crate::services::command::{NO_COLOR_ENV, describe_status};
+use crate::services::installer::{HEARTBEAT_INTERVAL, heartbeat_message, npm_line_level};
+use crate::services::offline::OfflineMode;
 
 /// Minimum interval between VirusTotal API requests in milliseconds.
 /// Public API limit is 4 requests/minute = 1 request per 15 seconds.
 const VT_RATE_LIMIT_MS: u64 = 15_000;
 
-/// Wait time after submitting a file to VirusTotal before retrieving the report.
-const VT_SCAN_WAIT_SECS: u64 = 15;
+/// Times the analysis is fetched while VirusTotal is still processing the
+/// file. Polls are spaced by the rate limit, so this waits about 2 minutes.
+const VT_MAX_POLL_ATTEMPTS: u32 = 8;
+
+/// Largest package tarball downloaded for a VirusTotal scan
+const MAX_TARBALL_BYTES: u64 = 100 * 1024 * 1024;
+
+/// Overall time allowed for downloading a package tarball
+const TARBALL_DOWNLOAD_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(120);
+
+/// npm bulk advisory endpoint, used when `npm audit` can't run in a skill directory.
+const NPM_ADVISORY_URL: &str = "https://registry.npmjs.org/-/npm/v1/security/advisories/bulk";
+
+/// Progress stage for the VirusTotal pre-install scan
+const SCAN_STAGE: &str = "skill-scan";
+
+/// Progress stage for the npm install itself
+const INSTALL_STAGE: &str = "skill-install";
+
+/// Progress stage reporting each skill's outcome in a batch install
+const BATCH_STAGE: &str = "skill-batch";
+
+/// Most skills accepted in one batch install
+pub const MAX_BATCH_SKILLS: usize = 50;
+
+/// Curated skills shipped with the wizard
+const BUNDLED_RECOMMENDED: &str = include_str!("recommended_skills.json");
+
+/// Environment variable overriding the recommended list with a remote JSON URL
+const RECOMMENDED_URL_ENV: &str = "OPENCLAW_RECOMMENDED_SKILLS_URL";
+
+/// Time allowed for fetching a remote recommended list
+const RECOMMENDED_FETCH_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Most entries accepted from a recommended list
+const MAX_RECOMMENDED: usize = 100;
 
 /// Global tracker for the last VirusTotal request timestamp (epoch millis).
 static LAST_VT_REQUEST: AtomicU64 = AtomicU64::new(0);
 
+/// One entry of a recommended skills manifest
+#[derive(Debug, Deserialize)]
+struct RecommendedEntry {
+    name: String,
+    rationale: String,
+}
+
 /// npm registry search response
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
@@ -137,6 +195,7 @@ impl SkillsService {
         query: Option<&str>,
         category: Option<&SkillCategory>,
     ) -> Result<SkillSearchResponse, AppError> {
+        OfflineMode::ensure_online("Skill search")?;
         let search_text = match query {
             Some(q) if !q.is_empty() => format!("keywords:openclaw-skill {}", q),
             _ => "keywords:openclaw-skill".to_string(),
@@ -176,6 +235,7 @@ impl SkillsService {
 
     /// Get detailed metadata for a specific skill by name.
     pub async fn get_skill_details(&self, name: &str) -> Result<SkillMetadata, AppError> {
+        OfflineMode::ensure_online("Skill details")?;
         let url = format!("https://registry.npmjs.org/{}", urlencoded(name));
 
         let response = self.http_client.get(&url).send().await.map_err(|e| {
@@ -223,34 +283,180 @@ impl SkillsService {
         })
     }
 
+    /// The curated recommended skills, with current registry metadata
+    ///
+    /// A remote list that can't be fetched or parsed falls back to the
+    /// bundled one. Offline, the bundled list is returned without metadata.
+    /// Only entries from the bundled list are marked verified; a remote
+    /// list is whatever `OPENCLAW_RECOMMENDED_SKILLS_URL` serves.
+    pub async fn recommended(&self) -> RecommendedSkillsResponse {
+        let remote_url = std::env::var(RECOMMENDED_URL_ENV)
+            .ok()
+            .filter(|url| !url.trim().is_empty() && !OfflineMode::is_enabled());
+
+        let (entries, source) = match remote_url {
+            Some(url) => match self.fetch_recommended(&url).await {
+                Ok(entries) => (entries, url),
+                Err(e) => {
+                    warn!("Recommended skills from {} unavailable: {}", url, e);
+                    (Self::bundled_recommended(), "bundled".to_string())
+                }
+            },
+            None => (Self::bundled_recommended(), "bundled".to_string()),
+        };
+
+        let vetted = source == "bundled";
+        let skills = futures::future::join_all(entries.into_iter().map(|entry| async move {
+            let details = if OfflineMode::is_enabled() {
+                None
+            } else {
+                self.get_skill_details(&entry.name)
+                    .await
+                    .map_err(|e| warn!("No registry details for '{}': {}", entry.name, e))
+                    .ok()
+            };
+            recommended_skill(entry, details, vetted)
+        }))
+        .await;
+
+        RecommendedSkillsResponse { skills, source }
+    }
+
+    fn bundled_recommended() -> Vec<RecommendedEntry> {
+        parse_recommended(BUNDLED_RECOMMENDED).expect("bundled recommended_skills.json is valid")
+    }
+
+    async fn fetch_recommended(&self, url: &str) -> Result<Vec<RecommendedEntry>, String> {
+        let response = self
+            .http_client
+            .get(url)
+            .timeout(RECOMMENDED_FETCH_TIMEOUT)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| e.to_string())?;
+        let body = response.text().await.map_err(|e| e.to_string())?;
+        parse_recommended(&body)
+    }
+
     /// Install a skill via npm. If VT API key is configured, scans the package first.
     ///
     /// SECURITY:
-    /// - Malicious packages are BLOCKED (SkillBlocked error)
+    /// - Malicious packages are BLOCKED (SkillBlocked error) unless `force`
+    ///   is set, which installs them with a warning and logs the override
     /// - Suspicious packages include a warning but are allowed
     /// - If VT is unavailable, installation proceeds with a warning
     pub async fn install_skill(
         &self,
         name: &str,
         version: Option<&str>,
+        force: bool,
+    ) -> Result<SkillInstallResponse, AppError> {
+        self.install_skill_with_progress(name, version, force, None)
+            .await
+    }
+
+    /// Install a skill like `install_skill`, reporting the VT scan and each
+    /// line of npm output to `progress` as it happens.
+    ///
+    /// Updates use the "skill-scan" and "skill-install" stages; npm silences
+    /// longer than HEARTBEAT_INTERVAL produce a heartbeat update.
+    pub async fn install_skill_with_progress(
+        &self,
+        name: &str,
+        version: Option<&str>,
+        force: bool,
+        progress: Option<&mpsc::Sender<InstallProgress>>,
     ) -> Result<SkillInstallResponse, AppError> {
+        let mut warning = None;
+
         // Scan BEFORE installing if VT is configured
         let scan_result = if self.vt_api_key.is_some() {
             let ver = version.unwrap_or("latest");
+            report(
+                progress,
+                InstallProgress {
+                    stage: SCAN_STAGE.into(),
+                    status: "running".into(),
+                    message: format!("Scanning {}@{} with VirusTotal...", name, ver),
+                    progress_pct: Some(10),
+                    ..Default::default()
+                },
+            )
+            .await;
+
             match self.scan_skill(name, ver).await {
                 Ok(Some(result)) => {
-                    // BLOCK malicious packages
-                    if matches!(result.threat_level, ThreatLevel::Malicious) {
-                        return Err(AppError::SkillBlocked(format!(
-                            "Skill '{}' blocked: {} malicious detections by VirusTotal",
-                            name, result.malicious_count
-                        )));
-                    }
-                    if matches!(result.threat_level, ThreatLevel::Suspicious) {
+                    // BLOCK malicious packages unless explicitly overridden
+                    let is_malicious = matches!(result.threat_level, ThreatLevel::Malicious);
+                    if is_malicious && force {
+                        let message = format!(
+                            "Installed despite {} malicious detections by VirusTotal{}",
+                            result.malicious_count,
+                            describe_detections(&result.detections)
+                        );
                         warn!(
-                            "Skill '{}' flagged as suspicious by {} scanners — proceeding with installation",
-                            name, result.suspicious_count
+                            "Blocked-skill override: installing '{}' flagged malicious by {} scanners",
+                            name, result.malicious_count
                         );
+                        report(
+                            progress,
+                            InstallProgress {
+                                stage: SCAN_STAGE.into(),
+                                status: "completed".into(),
+                                message: format!("Malicious detections overridden — {}", message),
+                                error: Some(message.clone()),
+                                progress_pct: Some(30),
+                                ..Default::default()
+                            },
+                        )
+                        .await;
+                        warning = Some(message);
+                    } else if is_malicious {
+                        let message = format!(
+                            "Skill '{}' blocked: {} malicious detections by VirusTotal{}",
+                            name,
+                            result.malicious_count,
+                            describe_detections(&result.detections)
+                        );
+                        report(
+                            progress,
+                            InstallProgress {
+                                stage: SCAN_STAGE.into(),
+                                status: "failed".into(),
+                                message: "Skill blocked by VirusTotal".into(),
+                                error: Some(message.clone()),
+                                ..Default::default()
+                            },
+                        )
+                        .await;
+                        return Err(AppError::SkillBlocked(message));
+                    } else {
+                        let message = if matches!(result.threat_level, ThreatLevel::Suspicious) {
+                            warn!(
+                                "Skill '{}' flagged as suspicious by {} scanners — proceeding with installation",
+                                name, result.suspicious_count
+                            );
+                            let message = format!(
+                                "Flagged as suspicious by {} scanners — proceeding",
+                                result.suspicious_count
+                            );
+                            warning = Some(message.clone());
+                            message
+                        } else {
+                            "No threats detected".to_string()
+                        };
+                        report(
+                            progress,
+                            InstallProgress {
+                                stage: SCAN_STAGE.into(),
+                                status: "completed".into(),
+                                message,
+                                progress_pct: Some(30),
+                                ..Default::default()
+                            },
+                        )
+                        .await;
                     }
                     Some(result)
                 }
@@ -261,6 +467,17 @@ impl SkillsService {
                         "VirusTotal scan failed for '{}': {} — proceeding without scan",
                         name, e
                     );
+                    report(
+                        progress,
+                        InstallProgress {
+                            stage: SCAN_STAGE.into(),
+                            status: "skipped".into(),
+                            message: "VirusTotal scan failed — proceeding without scan".into(),
+                            progress_pct: Some(30),
+                            ..Default::default()
+                        },
+                    )
+                    .await;
                     None
                 }
             }
@@ -274,36 +491,67 @@ impl SkillsService {
             None => name.to_string(),
         };
 
-        // Run npm install using tokio::process::Command (async, safe args)
-        let output = tokio::process::Command::new("npm")
-            .args(["install", "-g", &package_spec])
-            .output()
-            .await
-            .map_err(|e| {
-                AppError::SkillInstallFailed(format!("Failed to run npm install: {}", e))
-            })?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            error!("npm install failed for '{}': {}", name, stderr);
+        report(
+            progress,
+            InstallProgress {
+                stage: INSTALL_STAGE.into(),
+                status: "running".into(),
+                message: format!("Installing {}...", package_spec),
+                progress_pct: Some(40),
+                ..Default::default()
+            },
+        )
+        .await;
+
+        let output = run_npm_install(&package_spec, progress).await?;
+
+        if !output.success {
+            error!("npm install failed for '{}': {}", name, output.stderr);
+            let error = format!(
+                "npm install failed ({}): {}",
+                output.exit,
+                output.stderr.trim()
+            );
+            report(
+                progress,
+                InstallProgress {
+                    stage: INSTALL_STAGE.into(),
+                    status: "failed".into(),
+                    message: format!("Failed to install {}", package_spec),
+                    error: Some(error.clone()),
+                    ..Default::default()
+                },
+            )
+            .await;
             return Ok(SkillInstallResponse {
                 success: false,
                 name: name.to_string(),
                 version: version.unwrap_or("latest").to_string(),
-                error: Some(format!("npm install failed: {}", stderr.trim())),
+                error: Some(error),
                 scan_result,
+                warning,
             });
         }
 
         // Parse installed version from npm output
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let installed_version = parse_npm_install_version(&stdout)
+        let installed_version = parse_npm_install_version(&output.stdout)
             .unwrap_or_else(|| version.unwrap_or("latest").to_string());
 
         info!(
             "Skill '{}@{}' installed successfully",
             name, installed_version
         );
+        report(
+            progress,
+            InstallProgress {
+                stage: INSTALL_STAGE.into(),
+                status: "completed".into(),
+                message: format!("Installed {}@{}", name, installed_version),
+                progress_pct: Some(100),
+                ..Default::default()
+            },
+        )
+        .await;
 
         Ok(SkillInstallResponse {
             success: true,
@@ -311,9 +559,114 @@ impl SkillsService {
             version: installed_version,
             error: None,
             scan_result,
+            warning,
         })
     }
 
+    /// Check a batch install request before anything is installed
+    pub fn validate_batch(skills: &[SkillInstallRequest]) -> Result<(), AppError> {
+        if skills.is_empty() {
+            return Err(AppError::BadRequest("No skills to install".to_string()));
+        }
+        if skills.len() > MAX_BATCH_SKILLS {
+            return Err(AppError::BadRequest(format!(
+                "At most {} skills can be installed at once",
+                MAX_BATCH_SKILLS
+            )));
+        }
+        if skills.iter().any(|skill| skill.name.trim().is_empty()) {
+            return Err(AppError::BadRequest("Skill name is required".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Install `skills` one after another, carrying on past failures
+    ///
+    /// Each skill's scan and npm updates are forwarded to `progress` tagged
+    /// with its position, followed by a "skill-batch" update with its outcome.
+    /// If the `progress` receiver goes away, the skill being installed is
+    /// finished and the rest are skipped.
+    pub async fn install_batch(
+        &self,
+        skills: &[SkillInstallRequest],
+        progress: Option<&mpsc::Sender<SkillBatchProgress>>,
+    ) -> Result<SkillBatchSummary, AppError> {
+        Self::validate_batch(skills)?;
+
+        let total = skills.len() as u32;
+        let mut items = Vec::with_capacity(skills.len());
+        for (index, skill) in skills.iter().enumerate() {
+            if progress.is_some_and(|tx| tx.is_closed()) {
+                info!(
+                    "Batch install cancelled, skipping {} remaining skills",
+                    skills.len() - index
+                );
+                break;
+            }
+            let tag = |progress: InstallProgress| SkillBatchProgress {
+                index: index as u32,
+                total,
+                name: skill.name.clone(),
+                progress,
+            };
+
+            // Forward this skill's updates while it installs
+            let (tx, mut rx) = mpsc::channel::<InstallProgress>(100);
+            let forward = async {
+                while let Some(update) = rx.recv().await {
+                    report(progress, tag(update)).await;
+                }
+            };
+            let install = async {
+                let result = self
+                    .install_skill_with_progress(
+                        &skill.name,
+                        skill.version.as_deref(),
+                        skill.force.unwrap_or(false),
+                        Some(&tx),
+                    )
+                    .await;
+                drop(tx);
+                result
+            };
+            let (result, ()) = tokio::join!(install, forward);
+
+            let item = batch_item(skill, result);
+            info!("Batch install of '{}': {}", item.name, item.status);
+            report(progress, tag(batch_outcome(&item))).await;
+            items.push(item);
+        }
+
+        Ok(summarize(items))
+    }
+
+    /// Check the skill names of a batch uninstall before anything is removed
+    pub fn validate_uninstall_batch(names: &[&str]) -> Result<(), AppError> {
+        if names.is_empty() {
+            return Err(AppError::BadRequest("No skills to uninstall".to_string()));
+        }
+        if names.len() > MAX_BATCH_SKILLS {
+            return Err(AppError::BadRequest(format!(
+                "At most {} skills can be uninstalled at once",
+                MAX_BATCH_SKILLS
+            )));
+        }
+        if names.iter().any(|name| name.trim().is_empty()) {
+            return Err(AppError::BadRequest("Skill name is required".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Uninstall `names` one after another, carrying on past failures
+    pub async fn uninstall_batch(&self, names: &[&str]) -> SkillBatchUninstallSummary {
+        let mut items = Vec::with_capacity(names.len());
+        for name in names {
+            let result = self.uninstall_skill(name).await;
+            items.push(uninstall_result(name, result));
+        }
+        summarize_uninstalls(items)
+    }
+
     /// Uninstall a skill via npm.
     pub async fn uninstall_skill(&self, name: &str) -> Result<(), AppError> {
         let output = tokio::process::Command::new("npm")
@@ -378,6 +731,7 @@ impl SkillsService {
                         version,
                         path,
                         size_bytes: None,
+                        vulnerabilities: None,
                     });
                 }
             }
@@ -386,6 +740,85 @@ impl SkillsService {
         Ok(skills)
     }
 
+    /// Audit an installed skill's dependencies for known vulnerabilities.
+    ///
+    /// Runs `npm audit --json` in the skill's directory. Global installs often
+    /// have no lockfile, in which case npm audit refuses to run and the npm
+    /// bulk advisory API is queried for the skill package itself instead.
+    pub async fn audit_skill(&self, name: &str) -> Result<SkillAudit, AppError> {
+        let skill = self
+            .list_installed()
+            .await?
+            .into_iter()
+            .find(|s| s.name == name)
+            .ok_or_else(|| AppError::SkillNotFound(format!("Skill '{}' is not installed", name)))?;
+
+        self.audit_installed(&skill).await
+    }
+
+    /// Fill in the `vulnerabilities` summary for each installed skill.
+    ///
+    /// Audit failures are logged and leave the summary unset.
+    pub async fn attach_audits(&self, skills: &mut [InstalledSkill]) {
+        for skill in skills.iter_mut() {
+            match self.audit_installed(skill).await {
+                Ok(audit) => skill.vulnerabilities = Some(audit.vulnerabilities),
+                Err(e) => warn!("Dependency audit failed for '{}': {}", skill.name, e),
+            }
+        }
+    }
+
+    /// Audit one installed skill, preferring npm audit over the advisory API.
+    async fn audit_installed(&self, skill: &InstalledSkill) -> Result<SkillAudit, AppError> {
+        OfflineMode::ensure_online("Dependency audit")?;
+        let npm_audit = tokio::process::Command::new("npm")
+            .args(["audit", "--json"])
+            .current_dir(&skill.path)
+            .output()
+            .await;
+
+        // npm audit exits non-zero when vulnerabilities are found, so only the JSON matters
+        if let Ok(output) = npm_audit
+            && let Some(vulnerabilities) = parse_npm_audit(&String::from_utf8_lossy(&output.stdout))
+        {
+            return Ok(SkillAudit {
+                name: skill.name.clone(),
+                version: skill.version.clone(),
+                vulnerabilities,
+                source: "npm-audit".to_string(),
+            });
+        }
+
+        let body = serde_json::json!({ &skill.name: [&skill.version] });
+        let response = self
+            .http_client
+            .post(NPM_ADVISORY_URL)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::InternalError(format!("Failed to query npm advisories: {}", e))
+            })?;
+        // An error body would otherwise parse as "no advisories"
+        if !response.status().is_success() {
+            return Err(AppError::InternalError(format!(
+                "npm advisory service returned {}",
+                response.status()
+            )));
+        }
+
+        let advisories: serde_json::Value = response.json().await.map_err(|e| {
+            AppError::InternalError(format!("Failed to parse npm advisories: {}", e))
+        })?;
+
+        Ok(SkillAudit {
+            name: skill.name.clone(),
+            version: skill.version.clone(),
+            vulnerabilities: count_advisories(&advisories),
+            source: "advisory-api".to_string(),
+        })
+    }
+
     /// Scan a skill package with VirusTotal before installation.
     ///
     /// Returns None if VT API key is not configured (scanning disabled).
@@ -403,6 +836,7 @@ impl SkillsService {
             Some(key) => key.clone(),
             None => return Ok(None), // Scanning disabled, not an error
         };
+        OfflineMode::ensure_online("VirusTotal scanning")?;
 
         // Rate limiting: enforce minimum interval between VT requests
         enforce_vt_rate_limit().await;
@@ -415,45 +849,24 @@ impl SkillsService {
             version
         );
 
-        let tarball_bytes = self
-            .http_client
-            .get(&tarball_url)
-            .send()
-            .await
-            .map_err(|e| {
-                AppError::VirusTotalError(format!("Failed to download package tarball: {}", e))
-            })?
-            .bytes()
-            .await
-            .map_err(|e| {
-                AppError::VirusTotalError(format!("Failed to read tarball bytes: {}", e))
-            })?;
-
-        // Write tarball to temp file for VT scanning
-        let temp_dir = std::env::temp_dir();
-        let temp_path = temp_dir.join(format!("{}-{}.tgz", name.replace('/', "_"), version));
-        tokio::fs::write(&temp_path, &tarball_bytes)
-            .await
-            .map_err(|e| AppError::VirusTotalError(format!("Failed to write temp file: {}", e)))?;
+        // Stream tarball to a temp file of its own for VT scanning
+        let temp_file = scan_temp_path(name, version)?;
+        let size = self
+            .download_capped(&tarball_url, &temp_file, MAX_TARBALL_BYTES)
+            .await?;
 
-        // Submit file to VirusTotal API v3
-        let file_bytes = tokio::fs::read(&temp_path)
+        // Submit file to VirusTotal API v3, streamed from disk
+        let file = tokio::fs::File::open(&temp_file)
             .await
             .map_err(|e| AppError::VirusTotalError(format!("Failed to read temp file: {}", e)))?;
-
         let form = reqwest::multipart::Form::new().part(
             "file",
-            reqwest::multipart::Part::bytes(file_bytes).file_name(
-                temp_path
-                    .file_name()
-                    .unwrap_or_default()
-                    .to_string_lossy()
-                    .to_string(),
-            ),
+            reqwest::multipart::Part::stream_with_length(reqwest::Body::from(file), size)
+                .file_name(format!("{}-{}.tgz", name.replace('/', "_"), version)),
         );
 
-        let client = reqwest::Client::new();
-        let scan_response: serde_json::Value = client
+        let scan_response: serde_json::Value = self
+            .http_client
             .post("https://www.virustotal.com/api/v3/files")
             .header("x-apikey", &api_key)
             .multipart(form)
@@ -466,48 +879,176 @@ impl SkillsService {
                 AppError::VirusTotalError(format!("VT scan response parse failed: {}", e))
             })?;
 
-        // Clean up temp file
-        let _ = tokio::fs::remove_file(&temp_path).await;
+        // The tarball isn't needed while polling for the report
+        drop(temp_file);
 
         let resource_id = scan_response["data"]["id"]
             .as_str()
             .ok_or_else(|| AppError::VirusTotalError("No scan ID in VT response".to_string()))?
             .to_string();
 
-        // Wait for VT to process the file
-        info!(
-            "Waiting {}s for VirusTotal to process '{}'...",
-            VT_SCAN_WAIT_SECS, name
-        );
-        tokio::time::sleep(std::time::Duration::from_secs(VT_SCAN_WAIT_SECS)).await;
+        // Poll until VT finishes: a queued analysis has zero stats and
+        // would otherwise parse as Clean
+        let mut status = String::new();
+        for attempt in 1..=VT_MAX_POLL_ATTEMPTS {
+            // The rate limiter spaces the polls
+            enforce_vt_rate_limit().await;
+
+            let report: serde_json::Value = self
+                .http_client
+                .get(format!(
+                    "https://www.virustotal.com/api/v3/analyses/{}",
+                    resource_id
+                ))
+                .header("x-apikey", &api_key)
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::VirusTotalError(format!("VT report retrieval failed: {}", e))
+                })?
+                .json()
+                .await
+                .map_err(|e| AppError::VirusTotalError(format!("VT report parse failed: {}", e)))?;
+
+            status = vt_analysis_status(&report).to_string();
+            if status == "completed" {
+                return Ok(Some(parse_vt_report(&report)));
+            }
+            info!(
+                "VirusTotal analysis of '{}' is {} (attempt {}/{})",
+                name, status, attempt, VT_MAX_POLL_ATTEMPTS
+            );
+        }
 
-        // Rate limit the report request too
-        enforce_vt_rate_limit().await;
+        Err(AppError::VirusTotalError(format!(
+            "VT analysis still {} after {} attempts",
+            status, VT_MAX_POLL_ATTEMPTS
+        )))
+    }
 
-        // Retrieve the scan report
-        let report: serde_json::Value = client
-            .get(format!(
-                "https://www.virustotal.com/api/v3/analyses/{}",
-                resource_id
-            ))
-            .header("x-apikey", &api_key)
+    /// Download `url` into `path`, failing once the body passes `max_bytes`
+    /// or the download takes longer than `TARBALL_DOWNLOAD_TIMEOUT`.
+    ///
+    /// The body is written as it arrives, so an oversized package never has
+    /// to fit in memory. A partial file is removed on failure.
+    async fn download_capped(
+        &self,
+        url: &str,
+        path: &std::path::Path,
+        max_bytes: u64,
+    ) -> Result<u64, AppError> {
+        let download = tokio::time::timeout(
+            TARBALL_DOWNLOAD_TIMEOUT,
+            self.stream_to_file(url, path, max_bytes),
+        )
+        .await
+        .unwrap_or_else(|_| {
+            Err(AppError::VirusTotalError(format!(
+                "Package tarball download timed out after {}s",
+                TARBALL_DOWNLOAD_TIMEOUT.as_secs()
+            )))
+        });
+
+        if download.is_err() {
+            let _ = tokio::fs::remove_file(path).await;
+        }
+        download
+    }
+
+    async fn stream_to_file(
+        &self,
+        url: &str,
+        path: &std::path::Path,
+        max_bytes: u64,
+    ) -> Result<u64, AppError> {
+        use tokio::io::AsyncWriteExt;
+
+        let mut response = self
+            .http_client
+            .get(url)
             .send()
             .await
-            .map_err(|e| AppError::VirusTotalError(format!("VT report retrieval failed: {}", e)))?
-            .json()
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| {
+                AppError::VirusTotalError(format!("Failed to download package tarball: {}", e))
+            })?;
+
+        // Reject up front when the registry reports the size
+        if let Some(length) = response.content_length() {
+            check_tarball_size(length, max_bytes)?;
+        }
+
+        let mut file = tokio::fs::File::create(path)
+            .await
+            .map_err(|e| AppError::VirusTotalError(format!("Failed to write temp file: {}", e)))?;
+        let mut written: u64 = 0;
+        while let Some(chunk) = response.chunk().await.map_err(|e| {
+            AppError::VirusTotalError(format!("Failed to read tarball bytes: {}", e))
+        })? {
+            written += chunk.len() as u64;
+            check_tarball_size(written, max_bytes)?;
+            file.write_all(&chunk).await.map_err(|e| {
+                AppError::VirusTotalError(format!("Failed to write temp file: {}", e))
+            })?;
+        }
+        file.flush()
             .await
-            .map_err(|e| AppError::VirusTotalError(format!("VT report parse failed: {}", e)))?;
+            .map_err(|e| AppError::VirusTotalError(format!("Failed to write temp file: {}", e)))?;
+
+        Ok(written)
+    }
+}
 
-        // Parse scan results from the report JSON
-        let scan_result = parse_vt_report(&report);
+/// A new, uniquely named temp file for one scan's tarball
+///
+/// Concurrent scans of the same package get separate files. The file is
+/// deleted when the returned path is dropped, so every return path of a
+/// scan cleans up.
+fn scan_temp_path(name: &str, version: &str) -> Result<tempfile::TempPath, AppError> {
+    tempfile::Builder::new()
+        .prefix(&format!("{}-{}-", name.replace('/', "_"), version))
+        .suffix(".tgz")
+        .tempfile()
+        .map(tempfile::NamedTempFile::into_temp_path)
+        .map_err(|e| AppError::VirusTotalError(format!("Failed to create temp file: {}", e)))
+}
 
-        Ok(Some(scan_result))
+/// Error once a tarball is larger than the scan allows
+fn check_tarball_size(bytes: u64, max_bytes: u64) -> Result<(), AppError> {
+    if bytes > max_bytes {
+        return Err(AppError::VirusTotalError(format!(
+            "Package tarball exceeds the scan limit of {} bytes",
+            max_bytes
+        )));
     }
+    Ok(())
 }
 
+/// Status of a VT analysis object ("queued", "in-progress", "completed").
+/// File reports have no status and are always complete.
+fn vt_analysis_status(report: &serde_json::Value) -> &str {
+    let attributes = &report["data"]["attributes"];
+    match attributes["status"].as_str() {
+        Some(status) => status,
+        None if attributes["last_analysis_stats"].is_object() => "completed",
+        None => "unknown",
+    }
+}
+
+/// Detections listed in a blocked-install message
+const MAX_LISTED_DETECTIONS: usize = 5;
+
 /// Parse the VirusTotal report JSON into our ScanResult type.
+///
+/// File reports carry `last_analysis_stats`/`last_analysis_results`;
+/// analysis objects carry the same data as `stats`/`results`.
 fn parse_vt_report(report: &serde_json::Value) -> ScanResult {
-    let stats = &report["data"]["attributes"]["last_analysis_stats"];
+    let attributes = &report["data"]["attributes"];
+    let stats = if attributes["last_analysis_stats"].is_object() {
+        &attributes["last_analysis_stats"]
+    } else {
+        &attributes["stats"]
+    };
 
     let malicious = stats["malicious"].as_u64().unwrap_or(0) as u32;
     let suspicious = stats["suspicious"].as_u64().unwrap_or(0) as u32;
@@ -536,9 +1077,61 @@ fn parse_vt_report(report: &serde_json::Value) -> ScanResult {
         total_scanners,
         scan_date,
         permalink,
+        detections: parse_vt_detections(attributes),
     }
 }
 
+/// Malicious or suspicious per-engine verdicts, malicious first then by engine
+fn parse_vt_detections(attributes: &serde_json::Value) -> Vec<EngineDetection> {
+    let results = attributes["last_analysis_results"]
+        .as_object()
+        .or_else(|| attributes["results"].as_object());
+    let Some(results) = results else {
+        return Vec::new();
+    };
+
+    let mut detections: Vec<EngineDetection> = results
+        .iter()
+        .filter_map(|(key, verdict)| {
+            let category = verdict["category"].as_str()?;
+            if category != "malicious" && category != "suspicious" {
+                return None;
+            }
+            Some(EngineDetection {
+                engine: verdict["engine_name"].as_str().unwrap_or(key).to_string(),
+                category: category.to_string(),
+                result: verdict["result"].as_str().map(|s| s.to_string()),
+            })
+        })
+        .collect();
+    detections.sort_by(|a, b| {
+        (a.category != "malicious", &a.engine).cmp(&(b.category != "malicious", &b.engine))
+    });
+    detections
+}
+
+/// " (EngineA: Sig, EngineB, and 3 more)" for a blocked-install message
+fn describe_detections(detections: &[EngineDetection]) -> String {
+    if detections.is_empty() {
+        return String::new();
+    }
+    let mut listed: Vec<String> = detections
+        .iter()
+        .take(MAX_LISTED_DETECTIONS)
+        .map(|d| match &d.result {
+            Some(result) => format!("{}: {}", d.engine, result),
+            None => d.engine.clone(),
+        })
+        .collect();
+    if detections.len() > MAX_LISTED_DETECTIONS {
+        listed.push(format!(
+            "and {} more",
+            detections.len() - MAX_LISTED_DETECTIONS
+        ));
+    }
+    format!(" ({})", listed.join(", "))
+}
+
 /// Enforce VT rate limiting by waiting if the last request was too recent.
 async fn enforce_vt_rate_limit() {
     let now_ms = SystemTime::now()
@@ -669,6 +1262,271 @@ fn urlencoded(input: &str) -> String {
         .replace('/', "%2F")
 }
 
+/// Read the severity counts from `npm audit --json` output.
+///
+/// Returns None when npm reported an error instead of an audit (e.g. ENOLOCK).
+fn parse_npm_audit(stdout: &str) -> Option<VulnerabilitySummary> {
+    let report: serde_json::Value = serde_json::from_str(stdout).ok()?;
+    if report.get("error").is_some() {
+        return None;
+    }
+
+    let counts = report.get("metadata")?.get("vulnerabilities")?;
+    let count = |severity: &str| counts[severity].as_u64().unwrap_or(0) as u32;
+    Some(VulnerabilitySummary {
+        info: count("info"),
+        low: count("low"),
+        moderate: count("moderate"),
+        high: count("high"),
+        critical: count("critical"),
+        total: count("total"),
+    })
+}
+
+/// Count advisories by severity from an npm bulk advisory response.
+///
+/// The response maps each package name to a list of advisories.
+fn count_advisories(advisories: &serde_json::Value) -> VulnerabilitySummary {
+    let mut summary = VulnerabilitySummary::default();
+    let Some(packages) = advisories.as_object() else {
+        return summary;
+    };
+
+    for advisory in packages.values().filter_map(|v| v.as_array()).flatten() {
+        match advisory["severity"].as_str().unwrap_or("") {
+            "info" => summary.info += 1,
+            "low" => summary.low += 1,
+            "moderate" => summary.moderate += 1,
+            "high" => summary.high += 1,
+            "critical" => summary.critical += 1,
+            _ => continue,
+        }
+        summary.total += 1;
+    }
+    summary
+}
+
+/// Send a progress update if the caller asked for them
+async fn report<T>(progress: Option<&mpsc::Sender<T>>, update: T) {
+    if let Some(tx) = progress {
+        let _ = tx.send(update).await;
+    }
+}
+
+/// A recommended entry with its registry details; `vetted` entries (from
+/// the bundled list) are marked verified, along with their details
+fn recommended_skill(
+    entry: RecommendedEntry,
+    details: Option<SkillMetadata>,
+    vetted: bool,
+) -> RecommendedSkill {
+    RecommendedSkill {
+        name: entry.name,
+        rationale: entry.rationale,
+        verified: vetted,
+        details: details.map(|details| SkillMetadata {
+            verified: details.verified || vetted,
+            ..details
+        }),
+    }
+}
+
+/// Parse a recommended skills manifest: a JSON array of `{name, rationale}`
+fn parse_recommended(json: &str) -> Result<Vec<RecommendedEntry>, String> {
+    let entries: Vec<RecommendedEntry> = serde_json::from_str(json)
+        .map_err(|e| format!("Invalid recommended skills list: {}", e))?;
+    if entries.len() > MAX_RECOMMENDED {
+        return Err(format!(
+            "Recommended skills list has more than {} entries",
+            MAX_RECOMMENDED
+        ));
+    }
+    if entries.iter().any(|entry| entry.name.trim().is_empty()) {
+        return Err("Recommended skills list has an entry without a name".to_string());
+    }
+    Ok(entries)
+}
+
+/// Batch uninstall entry for one skill's result
+fn uninstall_result(name: &str, result: Result<(), AppError>) -> SkillUninstallResult {
+    let (status, error) = match result {
+        Ok(()) => ("uninstalled", None),
+        Err(e) => ("failed", Some(e.to_string())),
+    };
+    SkillUninstallResult {
+        name: name.to_string(),
+        status: status.to_string(),
+        error,
+    }
+}
+
+/// Count the outcomes of a batch uninstall
+fn summarize_uninstalls(items: Vec<SkillUninstallResult>) -> SkillBatchUninstallSummary {
+    let uninstalled = items.iter().filter(|i| i.status == "uninstalled").count() as u32;
+    SkillBatchUninstallSummary {
+        failed: items.len() as u32 - uninstalled,
+        uninstalled,
+        items,
+    }
+}
+
+/// Batch entry for one skill's install result
+fn batch_item(
+    request: &SkillInstallRequest,
+    result: Result<SkillInstallResponse, AppError>,
+) -> SkillBatchItem {
+    let (status, error, response) = match result {
+        Ok(response) if response.success => ("installed", None, Some(response)),
+        Ok(response) => ("failed", response.error.clone(), Some(response)),
+        Err(AppError::SkillBlocked(message)) => ("blocked", Some(message), None),
+        Err(e) => ("failed", Some(e.to_string()), None),
+    };
+    SkillBatchItem {
+        name: request.name.clone(),
+        version: request.version.clone(),
+        status: status.to_string(),
+        error,
+        response,
+    }
+}
+
+/// The "skill-batch" update announcing one skill's outcome
+fn batch_outcome(item: &SkillBatchItem) -> InstallProgress {
+    let (status, message) = match item.status.as_str() {
+        "installed" => ("completed", format!("Installed {}", item.name)),
+        "blocked" => ("failed", format!("Blocked {}", item.name)),
+        _ => ("failed", format!("Failed to install {}", item.name)),
+    };
+    InstallProgress {
+        stage: BATCH_STAGE.into(),
+        status: status.into(),
+        message,
+        error: item.error.clone(),
+        ..Default::default()
+    }
+}
+
+/// Count the outcomes of a batch
+fn summarize(items: Vec<SkillBatchItem>) -> SkillBatchSummary {
+    let count = |status: &str| items.iter().filter(|item| item.status == status).count() as u32;
+    SkillBatchSummary {
+        installed: count("installed"),
+        blocked: count("blocked"),
+        failed: count("failed"),
+        items,
+    }
+}
+
+/// Collected output of an `npm install -g` run
+struct NpmOutput {
+    success: bool,
+    /// How npm ended, e.g. "exit code 1" or "killed by SIGKILL (likely out of memory)"
+    exit: String,
+    stdout: String,
+    stderr: String,
+}
+
+/// Run `npm install -g <package_spec>`, forwarding each output line to
+/// `progress` while collecting stdout/stderr for the caller
+async fn run_npm_install(
+    package_spec: &str,
+    progress: Option<&mpsc::Sender<InstallProgress>>,
+) -> Result<NpmOutput, AppError> {
+    let mut child = tokio::process::Command::new("npm")
+        .args(["install", "-g", package_spec])
+        .args(OfflineMode::npm_install_args())
+        .envs(NO_COLOR_ENV)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| AppError::SkillInstallFailed(format!("Failed to run npm install: {}", e)))?;
+
+    // Both streams feed one channel so lines reach the client in order
+    let (line_tx, mut line_rx) = mpsc::channel::<(bool, String)>(100);
+    if let Some(stdout) = child.stdout.take() {
+        tokio::spawn(forward_lines(stdout, false, line_tx.clone()));
+    }
+    if let Some(stderr) = child.stderr.take() {
+        tokio::spawn(forward_lines(stderr, true, line_tx.clone()));
+    }
+    drop(line_tx);
+
+    let started = Instant::now();
+    let action = format!("installing {}", package_spec);
+    let mut output = NpmOutput {
+        success: false,
+        exit: String::new(),
+        stdout: String::new(),
+        stderr: String::new(),
+    };
+    loop {
+        match tokio::time::timeout(HEARTBEAT_INTERVAL, line_rx.recv()).await {
+            Ok(Some((is_stderr, line))) => {
+                let buffer = if is_stderr {
+                    &mut output.stderr
+                } else {
+                    &mut output.stdout
+                };
+                buffer.push_str(&line);
+                buffer.push('\n');
+
+                if !line.trim().is_empty() {
+                    report(
+                        progress,
+                        InstallProgress {
+                            stage: INSTALL_STAGE.into(),
+                            status: "running".into(),
+                            message: format!("Installing {}...", package_spec),
+                            line_level: Some(npm_line_level(&line).into()),
+                            output_line: Some(line),
+                            progress_pct: Some(60),
+                            ..Default::default()
+                        },
+                    )
+                    .await;
+                }
+            }
+            Ok(None) => break,
+            Err(_) => {
+                report(
+                    progress,
+                    InstallProgress {
+                        stage: INSTALL_STAGE.into(),
+                        status: "running".into(),
+                        message: heartbeat_message(&action, started.elapsed()),
+                        progress_pct: Some(60),
+                        ..Default::default()
+                    },
+                )
+                .await;
+            }
+        }
+    }
+
+    let status = child
+        .wait()
+        .await
+        .map_err(|e| AppError::SkillInstallFailed(format!("Failed to run npm install: {}", e)))?;
+    output.success = status.success();
+    output.exit = describe_status(&status);
+    Ok(output)
+}
+
+/// Send each line of `reader` to `tx`, tagged with which stream it came from
+async fn forward_lines(
+    reader: impl AsyncRead + Unpin,
+    is_stderr: bool,
+    tx: mpsc::Sender<(bool, String)>,
+) {
+    let mut lines = BufReader::new(reader).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        if tx.send((is_stderr, line)).await.is_err() {
+            break;
+        }
+    }
+}
+
 /// Parse the installed version from npm install stdout output.
 /// npm output looks like: "added 1 package in 1s" or "+ package@version"
 fn parse_npm_install_version(stdout: &str) -> Option<String> {
@@ -751,6 +1609,48 @@ mod tests {
         assert_eq!(urlencoded("a/b"), "a%2Fb");
     }
 
+    #[test]
+    fn test_parse_npm_audit_counts() {
+        let stdout = r#"{
+            "auditReportVersion": 2,
+            "vulnerabilities": {},
+            "metadata": {
+                "vulnerabilities": {"info": 0, "low": 1, "moderate": 2, "high": 0, "critical": 1, "total": 4}
+            }
+        }"#;
+        let summary = parse_npm_audit(stdout).unwrap();
+        assert_eq!(summary.low, 1);
+        assert_eq!(summary.moderate, 2);
+        assert_eq!(summary.critical, 1);
+        assert_eq!(summary.total, 4);
+    }
+
+    #[test]
+    fn test_parse_npm_audit_error_returns_none() {
+        let stdout = r#"{"error": {"code": "ENOLOCK", "summary": "This command requires an existing lockfile."}}"#;
+        assert!(parse_npm_audit(stdout).is_none());
+        assert!(parse_npm_audit("not json").is_none());
+    }
+
+    #[test]
+    fn test_count_advisories_by_severity() {
+        let advisories = serde_json::json!({
+            "openclaw-skill-demo": [
+                {"id": 1, "severity": "high"},
+                {"id": 2, "severity": "moderate"},
+                {"id": 3, "severity": "high"}
+            ]
+        });
+        let summary = count_advisories(&advisories);
+        assert_eq!(summary.high, 2);
+        assert_eq!(summary.moderate, 1);
+        assert_eq!(summary.total, 3);
+        assert_eq!(
+            count_advisories(&serde_json::json!({})),
+            VulnerabilitySummary::default()
+        );
+    }
+
     #[test]
     fn test_parse_npm_install_version() {
         assert_eq!(
@@ -831,10 +1731,236 @@ mod tests {
         assert!(result.permalink.is_none());
     }
 
+    #[test]
+    fn test_vt_analysis_status() {
+        let queued = serde_json::json!({
+            "data": { "attributes": { "status": "queued", "stats": { "malicious": 0 } } }
+        });
+        assert_eq!(vt_analysis_status(&queued), "queued");
+
+        let done = serde_json::json!({ "data": { "attributes": { "status": "completed" } } });
+        assert_eq!(vt_analysis_status(&done), "completed");
+
+        let file_report = serde_json::json!({
+            "data": { "attributes": { "last_analysis_stats": { "malicious": 0 } } }
+        });
+        assert_eq!(vt_analysis_status(&file_report), "completed");
+        assert_eq!(vt_analysis_status(&serde_json::json!({})), "unknown");
+    }
+
+    #[test]
+    fn test_parse_vt_report_lists_flagging_engines() {
+        let report = serde_json::json!({
+            "data": {
+                "attributes": {
+                    "stats": { "malicious": 1, "suspicious": 1, "harmless": 1, "undetected": 0 },
+                    "results": {
+                        "Zeta": { "engine_name": "Zeta", "category": "suspicious", "result": null },
+                        "Alpha": { "engine_name": "Alpha", "category": "harmless", "result": null },
+                        "Omega": { "engine_name": "Omega", "category": "malicious", "result": "JS.Trojan" }
+                    }
+                }
+            }
+        });
+        let result = parse_vt_report(&report);
+        assert_eq!(result.total_scanners, 3);
+        let engines: Vec<_> = result
+            .detections
+            .iter()
+            .map(|d| d.engine.as_str())
+            .collect();
+        assert_eq!(engines, vec!["Omega", "Zeta"]);
+        assert_eq!(
+            describe_detections(&result.detections),
+            " (Omega: JS.Trojan, Zeta)"
+        );
+        assert_eq!(describe_detections(&[]), "");
+    }
+
     #[test]
     fn test_new_creates_service() {
         let service = SkillsService::new();
         // VT key may or may not be set depending on env
         assert!(service.vt_api_key.is_some() || service.vt_api_key.is_none());
     }
+
+    #[test]
+    fn test_check_tarball_size() {
+        assert!(check_tarball_size(MAX_TARBALL_BYTES, MAX_TARBALL_BYTES).is_ok());
+        assert!(check_tarball_size(MAX_TARBALL_BYTES + 1, MAX_TARBALL_BYTES).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_download_capped_rejects_oversized_body() {
+        let app =
+            axum::Router::new().route("/pkg.tgz", axum::routing::get(|| async { vec![7u8; 4096] }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/pkg.tgz", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pkg.tgz");
+        let service = SkillsService::new();
+
+        assert_eq!(
+            service.download_capped(&url, &path, 8192).await.unwrap(),
+            4096
+        );
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), 4096);
+
+        let err = service
+            .download_capped(&url, &path, 1024)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("scan limit"));
+        assert!(!path.exists(), "partial download should be removed");
+    }
+
+    #[test]
+    fn test_scan_temp_paths_are_unique_and_removed() {
+        let first = scan_temp_path("@scope/pkg", "1.0.0").unwrap();
+        let second = scan_temp_path("@scope/pkg", "1.0.0").unwrap();
+        assert_ne!(first.to_path_buf(), second.to_path_buf());
+        assert!(first.exists());
+
+        let path = first.to_path_buf();
+        drop(first);
+        assert!(!path.exists(), "temp tarball should not outlive the scan");
+    }
+
+    fn skill(name: &str) -> SkillInstallRequest {
+        SkillInstallRequest {
+            name: name.to_string(),
+            version: None,
+            force: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_batch() {
+        assert!(SkillsService::validate_batch(&[skill("a"), skill("b")]).is_ok());
+        assert!(matches!(
+            SkillsService::validate_batch(&[]),
+            Err(AppError::BadRequest(_))
+        ));
+        assert!(matches!(
+            SkillsService::validate_batch(&[skill("a"), skill(" ")]),
+            Err(AppError::BadRequest(_))
+        ));
+        let too_many: Vec<_> = (0..=MAX_BATCH_SKILLS)
+            .map(|i| skill(&format!("skill-{}", i)))
+            .collect();
+        assert!(SkillsService::validate_batch(&too_many).is_err());
+    }
+
+    #[test]
+    fn test_validate_uninstall_batch() {
+        assert!(SkillsService::validate_uninstall_batch(&["a", "b"]).is_ok());
+        assert!(SkillsService::validate_uninstall_batch(&[]).is_err());
+        assert!(SkillsService::validate_uninstall_batch(&["a", ""]).is_err());
+        let names: Vec<String> = (0..=MAX_BATCH_SKILLS)
+            .map(|i| format!("skill-{}", i))
+            .collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        assert!(SkillsService::validate_uninstall_batch(&names).is_err());
+    }
+
+    #[test]
+    fn test_uninstall_results_are_counted() {
+        let summary = summarize_uninstalls(vec![
+            uninstall_result("a", Ok(())),
+            uninstall_result(
+                "b",
+                Err(AppError::SkillInstallFailed("npm uninstall failed".into())),
+            ),
+        ]);
+        assert_eq!(summary.uninstalled, 1);
+        assert_eq!(summary.failed, 1);
+        assert_eq!(summary.items[1].status, "failed");
+        assert!(
+            summary.items[1]
+                .error
+                .as_deref()
+                .unwrap()
+                .contains("npm uninstall")
+        );
+    }
+
+    #[test]
+    fn test_batch_items_are_classified_and_counted() {
+        let installed = SkillInstallResponse {
+            success: true,
+            name: "a".into(),
+            version: "1.0.0".into(),
+            error: None,
+            scan_result: None,
+            warning: None,
+        };
+        let items = vec![
+            batch_item(&skill("a"), Ok(installed)),
+            batch_item(
+                &skill("b"),
+                Err(AppError::SkillBlocked("Skill 'b' blocked".into())),
+            ),
+            batch_item(
+                &skill("c"),
+                Err(AppError::InternalError("npm install failed".into())),
+            ),
+        ];
+        assert_eq!(items[0].status, "installed");
+        assert!(items[0].response.is_some());
+        assert_eq!(items[1].status, "blocked");
+        assert_eq!(items[1].error.as_deref(), Some("Skill 'b' blocked"));
+        assert_eq!(items[2].status, "failed");
+
+        let outcome = batch_outcome(&items[1]);
+        assert_eq!(outcome.stage, BATCH_STAGE);
+        assert_eq!(outcome.status, "failed");
+
+        let summary = summarize(items);
+        assert_eq!(
+            (summary.installed, summary.blocked, summary.failed),
+            (1, 1, 1)
+        );
+        assert_eq!(summary.items.len(), 3);
+    }
+
+    #[test]
+    fn test_bundled_recommended_is_valid() {
+        let entries = SkillsService::bundled_recommended();
+        assert!(!entries.is_empty());
+        let mut names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), entries.len(), "duplicate recommended skill");
+        assert!(entries.iter().all(|e| !e.rationale.trim().is_empty()));
+    }
+
+    #[test]
+    fn test_parse_recommended() {
+        let entries =
+            parse_recommended(r#"[{"name":"@openclaw/skill-web","rationale":"Browse"}]"#).unwrap();
+        assert_eq!(entries[0].name, "@openclaw/skill-web");
+        assert_eq!(entries[0].rationale, "Browse");
+
+        assert!(parse_recommended(r#"{"skills":[]}"#).is_err());
+        assert!(parse_recommended(r#"[{"name":" ","rationale":"x"}]"#).is_err());
+        assert!(parse_recommended(r#"[{"name":"a"}]"#).is_err());
+    }
+
+    #[test]
+    fn test_only_bundled_recommendations_are_verified() {
+        let entry = || {
+            parse_recommended(r#"[{"name":"a","rationale":"b"}]"#)
+                .unwrap()
+                .remove(0)
+        };
+
+        let bundled = recommended_skill(entry(), None, true);
+        assert!(bundled.verified);
+
+        let remote = recommended_skill(entry(), None, false);
+        assert!(!remote.verified);
+        assert!(remote.details.is_none());
+    }
 }
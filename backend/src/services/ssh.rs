@@ -4,21 +4,43 @@
 //!
 //! SECURITY:
 //! - Always uses KnownHosts::Strict for MITM protection
+//! - New hosts are trusted only after the user confirms their key fingerprint
 //! - Credentials stored in platform keychain (never in config files)
 //! - No credentials logged (only sanitized user@host)
 
 use anyhow::{Context, Result};
 use keyring::Entry;
-use openssh::{KnownHosts, Session};
+use openssh::{KnownHosts, Session, Stdio};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::PathBuf;
 use std::sync::OnceLock;
+use tokio::io::AsyncWriteExt;
 use tokio::sync::mpsc;
 use ts_rs::TS;
 
+use crate::models::types::HostKeyInfo;
+use crate::services::command::SafeCommand;
+use crate::services::platform::Platform;
+
 /// Keyring service name for SSH credentials
 const KEYRING_SERVICE: &str = "openclaw-wizard-ssh";
 
+/// Default SSH port
+pub const DEFAULT_SSH_PORT: u16 = 22;
+
+/// Seconds ssh-keyscan waits for a server before giving up
+const KEYSCAN_TIMEOUT_SECS: &str = "5";
+
+/// A host key line as printed by ssh-keyscan, with its fingerprint
+#[derive(Debug, Clone)]
+struct ScannedHostKey {
+    /// known_hosts line, e.g. "example.com ssh-ed25519 AAAA..."
+    line: String,
+    info: HostKeyInfo,
+}
+
 /// Command execution output
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../bindings/")]
@@ -114,6 +136,64 @@ impl SshService {
         Ok(result)
     }
 
+    /// Execute command on remote host with `input` written to its stdin
+    ///
+    /// Used to transfer file contents (e.g. configs containing secrets) over
+    /// the encrypted session without putting them on the remote command line.
+    pub async fn exec_remote_with_stdin(
+        &self,
+        host: &str,
+        user: &str,
+        command: &str,
+        input: &[u8],
+    ) -> Result<CommandOutput> {
+        Self::validate_host(host)?;
+        Self::validate_username(user)?;
+
+        if command.is_empty() {
+            anyhow::bail!("Remote command cannot be empty");
+        }
+
+        let connection_str = format!("{}@{}", user, host);
+
+        tracing::debug!("Executing remote command with stdin on {}", connection_str);
+
+        let session = Session::connect(&connection_str, KnownHosts::Strict)
+            .await
+            .with_context(|| format!("Failed to connect to {}", connection_str))?;
+
+        let mut child = session
+            .command(command)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .await
+            .with_context(|| format!("Failed to execute remote command: {}", command))?;
+
+        if let Some(mut stdin) = child.stdin().take() {
+            stdin
+                .write_all(input)
+                .await
+                .context("Failed to write remote command input")?;
+            stdin
+                .shutdown()
+                .await
+                .context("Failed to close remote command input")?;
+        }
+
+        let output = child
+            .wait_with_output()
+            .await
+            .with_context(|| format!("Failed to execute remote command: {}", command))?;
+
+        Ok(CommandOutput {
+            stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+            exit_code: output.status.code().unwrap_or(-1),
+        })
+    }
+
     /// Stream remote command output line-by-line
     ///
     /// Sends output lines via mpsc channel for real-time progress display.
@@ -224,10 +304,189 @@ impl SshService {
         Ok(())
     }
 
+    // ===== Known Hosts =====
+
+    /// Fetch the host keys a server offers (like `ssh-keyscan`)
+    pub async fn fetch_host_keys(&self, host: &str, port: u16) -> Result<Vec<HostKeyInfo>> {
+        Ok(Self::scan_host_keys(host, port)
+            .await?
+            .into_iter()
+            .map(|k| k.info)
+            .collect())
+    }
+
+    /// Whether ~/.ssh/known_hosts already has an entry for this host
+    pub fn is_known_host(&self, host: &str, port: u16) -> Result<bool> {
+        Self::validate_host(host)?;
+        let path = Self::known_hosts_path()?;
+        if !path.exists() {
+            return Ok(false);
+        }
+        let path_str = path.to_string_lossy();
+        let output = SafeCommand::run(
+            "ssh-keygen",
+            &["-F", &Self::known_hosts_name(host, port), "-f", &path_str],
+        )?;
+        Ok(output.exit_code == 0 && !output.stdout.trim().is_empty())
+    }
+
+    /// Add a server's host key to ~/.ssh/known_hosts after the user approved it
+    ///
+    /// The server is scanned again and only keys matching `fingerprint` are
+    /// written, so a key that changed since it was shown is never trusted.
+    pub async fn trust_host(
+        &self,
+        host: &str,
+        port: u16,
+        fingerprint: &str,
+    ) -> Result<HostKeyInfo> {
+        let scanned = Self::scan_host_keys(host, port).await?;
+        let key = scanned
+            .into_iter()
+            .find(|k| k.info.fingerprint == fingerprint)
+            .with_context(|| {
+                format!(
+                    "Host key for {} no longer matches the approved fingerprint {}",
+                    host, fingerprint
+                )
+            })?;
+
+        let path = Self::known_hosts_path()?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                let _ = std::fs::set_permissions(dir, std::fs::Permissions::from_mode(0o700));
+            }
+        }
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        writeln!(file, "{}", key.line)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        tracing::info!(
+            "Trusted {} host key for {} ({})",
+            key.info.key_type,
+            host,
+            key.info.fingerprint
+        );
+        Ok(key.info)
+    }
+
+    /// Run ssh-keyscan and fingerprint every key it returns
+    async fn scan_host_keys(host: &str, port: u16) -> Result<Vec<ScannedHostKey>> {
+        Self::validate_host(host)?;
+        let port_str = port.to_string();
+
+        let output = tokio::process::Command::new("ssh-keyscan")
+            .args(["-T", KEYSCAN_TIMEOUT_SECS, "-p", &port_str, host])
+            .output()
+            .await
+            .context("Failed to run ssh-keyscan (is OpenSSH installed?)")?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        let lines = Self::parse_keyscan_output(&stdout);
+        if lines.is_empty() {
+            anyhow::bail!("No host keys received from {}:{}", host, port);
+        }
+
+        // ssh-keygen fingerprints known_hosts-format files in order. The
+        // file is created exclusively with a random name and removed on drop.
+        let mut tmp = tempfile::Builder::new()
+            .prefix("openclaw-wizard-keyscan-")
+            .tempfile()
+            .context("Failed to create a file for scanned host keys")?;
+        tmp.write_all((lines.join("\n") + "\n").as_bytes())
+            .context("Failed to write scanned host keys")?;
+        let result = SafeCommand::run(
+            "ssh-keygen",
+            &["-l", "-E", "sha256", "-f", &tmp.path().to_string_lossy()],
+        )?;
+        if result.exit_code != 0 {
+            anyhow::bail!("ssh-keygen failed: {}", result.stderr.trim());
+        }
+
+        let fingerprints: Vec<String> = result
+            .stdout
+            .lines()
+            .filter_map(Self::parse_fingerprint)
+            .collect();
+        if fingerprints.len() != lines.len() {
+            anyhow::bail!("Could not fingerprint host keys from {}", host);
+        }
+
+        Ok(lines
+            .into_iter()
+            .zip(fingerprints)
+            .map(|(line, fingerprint)| ScannedHostKey {
+                info: HostKeyInfo {
+                    key_type: line
+                        .split_whitespace()
+                        .nth(1)
+                        .unwrap_or_default()
+                        .to_string(),
+                    fingerprint,
+                },
+                line,
+            })
+            .collect())
+    }
+
+    /// Keep the `host keytype key` lines from ssh-keyscan output
+    fn parse_keyscan_output(output: &str) -> Vec<String> {
+        output
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter(|line| line.split_whitespace().count() >= 3)
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Extract "SHA256:..." from a line like "256 SHA256:abc host (ED25519)"
+    fn parse_fingerprint(line: &str) -> Option<String> {
+        line.split_whitespace()
+            .find(|part| part.starts_with("SHA256:"))
+            .map(str::to_string)
+    }
+
+    /// Host as written in known_hosts: bare for port 22, `[host]:port` otherwise
+    fn known_hosts_name(host: &str, port: u16) -> String {
+        if port == DEFAULT_SSH_PORT {
+            host.to_string()
+        } else {
+            format!("[{}]:{}", host, port)
+        }
+    }
+
+    fn known_hosts_path() -> Result<PathBuf> {
+        Ok(Platform::home_dir()?.join(".ssh").join("known_hosts"))
+    }
+
     // ===== Validation Helpers =====
 
+    /// Validate an SSH target before any SSH command runs
+    ///
+    /// Enforces the rules documented on `SshConnection`: host matches
+    /// `^[a-zA-Z0-9.-]+$` and does not start with `-`, username matches
+    /// `^[a-z_][a-z0-9_-]*$`, and key_path (when given) is absolute or `~/.ssh/id_*`.
+    pub fn validate_ssh_request(host: &str, user: &str, key_path: Option<&str>) -> Result<()> {
+        Self::validate_host(host)?;
+        Self::validate_username(user)?;
+        if let Some(key_path) = key_path {
+            Self::validate_key_path(key_path)?;
+        }
+        Ok(())
+    }
+
     /// Validate hostname or IP address
-    fn validate_host(host: &str) -> Result<()> {
+    pub(crate) fn validate_host(host: &str) -> Result<()> {
         static HOST_REGEX: OnceLock<Regex> = OnceLock::new();
         let regex =
             HOST_REGEX.get_or_init(|| Regex::new(r"^[a-zA-Z0-9.-]+$").expect("Invalid host regex"));
@@ -240,6 +499,11 @@ impl SshService {
             anyhow::bail!("Invalid host format: {}", host);
         }
 
+        // A leading dash would be parsed by ssh as an option
+        if host.starts_with('-') {
+            anyhow::bail!("Host cannot start with '-': {}", host);
+        }
+
         Ok(())
     }
 
@@ -259,6 +523,37 @@ impl SshService {
 
         Ok(())
     }
+
+    /// Validate SSH private key path: absolute, or `~/.ssh/id_*`
+    fn validate_key_path(key_path: &str) -> Result<()> {
+        if key_path.is_empty() {
+            anyhow::bail!("SSH key path cannot be empty");
+        }
+
+        if key_path
+            .chars()
+            .any(|c| c.is_control() || ";&|$`<>\"'\\".contains(c))
+        {
+            anyhow::bail!("SSH key path contains invalid characters");
+        }
+
+        let is_absolute = key_path.starts_with('/');
+        let is_home_key = key_path
+            .strip_prefix("~/.ssh/")
+            .is_some_and(|name| name.starts_with("id_") && !name.contains('/'));
+        if !is_absolute && !is_home_key {
+            anyhow::bail!(
+                "Invalid SSH key path: {} (use an absolute path or ~/.ssh/id_*)",
+                key_path
+            );
+        }
+
+        if key_path.split('/').any(|part| part == "..") {
+            anyhow::bail!("SSH key path cannot contain '..': {}", key_path);
+        }
+
+        Ok(())
+    }
 }
 
 impl Default for SshService {
@@ -300,4 +595,79 @@ mod tests {
         assert!(SshService::validate_username("9user").is_err()); // starts with number
         assert!(SshService::validate_username("user@host").is_err());
     }
+
+    #[test]
+    fn test_validate_key_path() {
+        assert!(SshService::validate_key_path("/home/user/.ssh/id_ed25519").is_ok());
+        assert!(SshService::validate_key_path("~/.ssh/id_rsa").is_ok());
+        assert!(SshService::validate_key_path("").is_err());
+        assert!(SshService::validate_key_path("id_rsa").is_err());
+        assert!(SshService::validate_key_path("~/.ssh/authorized_keys").is_err());
+        assert!(SshService::validate_key_path("~/.ssh/id_rsa/../../x").is_err());
+        assert!(SshService::validate_key_path("/tmp/../etc/shadow").is_err());
+        assert!(SshService::validate_key_path("/tmp/key\nrm").is_err());
+    }
+
+    #[test]
+    fn test_validate_ssh_request_rejects_injection() {
+        for host in [
+            "host; rm -rf /",
+            "host && id",
+            "$(whoami).example.com",
+            "`id`",
+            "host|nc",
+            "-oProxyCommand=touch",
+            "host\nid",
+        ] {
+            assert!(
+                SshService::validate_ssh_request(host, "root", None).is_err(),
+                "host should be rejected: {:?}",
+                host
+            );
+        }
+        for user in ["root; rm -rf /", "root$(id)", "-lroot", "root name"] {
+            assert!(
+                SshService::validate_ssh_request("example.com", user, None).is_err(),
+                "username should be rejected: {:?}",
+                user
+            );
+        }
+        assert!(
+            SshService::validate_ssh_request("example.com", "root", Some("~/.ssh/id_rsa; id"))
+                .is_err()
+        );
+        assert!(
+            SshService::validate_ssh_request("10.0.0.5", "ubuntu", Some("~/.ssh/id_ed25519"))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_parse_keyscan_output_skips_comments() {
+        let output = "# example.com:22 SSH-2.0-OpenSSH_9.6\n\
+            example.com ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIExample\n\
+            \n\
+            [example.com]:2222 ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABAQ\n";
+        let lines = SshService::parse_keyscan_output(output);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("example.com ssh-ed25519"));
+        assert!(lines[1].starts_with("[example.com]:2222 ssh-rsa"));
+    }
+
+    #[test]
+    fn test_parse_fingerprint_and_known_hosts_name() {
+        assert_eq!(
+            SshService::parse_fingerprint("256 SHA256:q1w2e3r4 example.com (ED25519)").as_deref(),
+            Some("SHA256:q1w2e3r4")
+        );
+        assert_eq!(SshService::parse_fingerprint("garbage"), None);
+        assert_eq!(
+            SshService::known_hosts_name("example.com", 22),
+            "example.com"
+        );
+        assert_eq!(
+            SshService::known_hosts_name("example.com", 2222),
+            "[example.com]:2222"
+        );
+    }
 }
@@ -0,0 +1,264 @@
+//! # SSH Config Parser
+//!
+//! Reads `Host` blocks from an OpenSSH client config (`~/.ssh/config`) so
+//! existing hosts can be imported as multi-server targets.
+//!
+//! Only HostName, User, Port and IdentityFile are read. Wildcard blocks
+//! (`Host *`) supply defaults for the concrete hosts, matching OpenSSH's
+//! first-value-wins rule. `Match` blocks and `Include` are not followed.
+
+use std::path::Path;
+
+use crate::services::ssh::DEFAULT_SSH_PORT;
+
+/// A concrete host from the SSH config
+#[derive(Debug, Clone, PartialEq)]
+pub struct SshHostEntry {
+    /// The `Host` alias
+    pub alias: String,
+    /// `HostName`, or the alias when unset
+    pub hostname: String,
+    pub user: Option<String>,
+    pub port: u16,
+    /// `IdentityFile` with `~/` expanded
+    pub identity_file: Option<String>,
+}
+
+/// Settings collected from one `Host` block
+#[derive(Debug, Default, Clone)]
+struct HostBlock {
+    patterns: Vec<String>,
+    hostname: Option<String>,
+    user: Option<String>,
+    port: Option<u16>,
+    identity_file: Option<String>,
+}
+
+impl HostBlock {
+    /// Whether this block applies to `alias` (`*`/`?` globs, `!` negation)
+    fn matches(&self, alias: &str) -> bool {
+        let mut matched = false;
+        for pattern in &self.patterns {
+            if let Some(negated) = pattern.strip_prefix('!') {
+                if glob_match(negated, alias) {
+                    return false;
+                }
+            } else if glob_match(pattern, alias) {
+                matched = true;
+            }
+        }
+        matched
+    }
+}
+
+/// SSH client config parser
+pub struct SshConfig;
+
+impl SshConfig {
+    /// Parse the config text into concrete hosts, in file order.
+    ///
+    /// `home` expands a leading `~/` in IdentityFile.
+    pub fn parse(content: &str, home: Option<&Path>) -> Vec<SshHostEntry> {
+        let blocks = Self::blocks(content);
+
+        let mut entries: Vec<SshHostEntry> = Vec::new();
+        for block in &blocks {
+            for alias in &block.patterns {
+                let is_pattern = alias.contains(['*', '?', '!']);
+                if is_pattern || entries.iter().any(|e| &e.alias == alias) {
+                    continue;
+                }
+
+                // First value wins across every block matching the alias
+                let mut resolved = HostBlock::default();
+                for b in blocks.iter().filter(|b| b.matches(alias)) {
+                    resolved.hostname = resolved.hostname.or_else(|| b.hostname.clone());
+                    resolved.user = resolved.user.or_else(|| b.user.clone());
+                    resolved.port = resolved.port.or(b.port);
+                    resolved.identity_file =
+                        resolved.identity_file.or_else(|| b.identity_file.clone());
+                }
+
+                entries.push(SshHostEntry {
+                    alias: alias.clone(),
+                    hostname: resolved
+                        .hostname
+                        .map(|h| h.replace("%h", alias))
+                        .unwrap_or_else(|| alias.clone()),
+                    user: resolved.user,
+                    port: resolved.port.unwrap_or(DEFAULT_SSH_PORT),
+                    identity_file: resolved.identity_file.map(|f| expand_home(&f, home)),
+                });
+            }
+        }
+        entries
+    }
+
+    /// Split the config into `Host` blocks; settings before the first
+    /// `Host` line apply to every host
+    fn blocks(content: &str) -> Vec<HostBlock> {
+        let mut blocks = vec![HostBlock {
+            patterns: vec!["*".to_string()],
+            ..Default::default()
+        }];
+        // Inside a `Match` block nothing applies until the next `Host`
+        let mut in_match = false;
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (keyword, value) = split_directive(line);
+            let keyword = keyword.to_ascii_lowercase();
+
+            match keyword.as_str() {
+                "host" => {
+                    in_match = false;
+                    blocks.push(HostBlock {
+                        patterns: value.split_whitespace().map(unquote).collect(),
+                        ..Default::default()
+                    });
+                }
+                "match" => in_match = true,
+                _ if in_match => {}
+                _ => {
+                    let block = blocks.last_mut().expect("blocks is never empty");
+                    let value = unquote(value);
+                    match keyword.as_str() {
+                        "hostname" => block.hostname = block.hostname.take().or(Some(value)),
+                        "user" => block.user = block.user.take().or(Some(value)),
+                        "port" => block.port = block.port.or(value.parse().ok()),
+                        "identityfile" => {
+                            block.identity_file = block.identity_file.take().or(Some(value))
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        blocks
+    }
+}
+
+/// Split `Keyword value` or `Keyword=value`
+fn split_directive(line: &str) -> (&str, &str) {
+    let end = line
+        .find(|c: char| c.is_whitespace() || c == '=')
+        .unwrap_or(line.len());
+    let (keyword, rest) = line.split_at(end);
+    let rest = rest.trim_start();
+    let rest = rest.strip_prefix('=').unwrap_or(rest);
+    (keyword, rest.trim())
+}
+
+fn unquote(value: &str) -> String {
+    value.trim().trim_matches('"').to_string()
+}
+
+fn expand_home(path: &str, home: Option<&Path>) -> String {
+    match (path.strip_prefix("~/"), home) {
+        (Some(rest), Some(home)) => home.join(rest).display().to_string(),
+        _ => path.to_string(),
+    }
+}
+
+/// OpenSSH pattern match: `*` any run of characters, `?` any single one
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let t: Vec<char> = text.chars().collect();
+    let (mut pi, mut ti) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+
+    while ti < t.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == t[ti]) {
+            pi += 1;
+            ti += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            star = Some((pi, ti));
+            pi += 1;
+        } else if let Some((star_pi, star_ti)) = star {
+            pi = star_pi + 1;
+            ti = star_ti + 1;
+            star = Some((star_pi, star_ti + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+# Personal servers
+Host web1 web1-alias
+    HostName 203.0.113.10
+    User deploy
+    IdentityFile ~/.ssh/id_web
+
+Host db
+    HostName=db.example.com
+    Port 2222
+
+Match host bastion
+    User ignored
+
+Host *.internal
+    User ops
+
+Host app.internal
+
+Host *
+    User fallback
+    IdentityFile ~/.ssh/id_default
+"#;
+
+    #[test]
+    fn test_parse_host_blocks() {
+        let entries = SshConfig::parse(CONFIG, Some(Path::new("/home/me")));
+        let aliases: Vec<&str> = entries.iter().map(|e| e.alias.as_str()).collect();
+        assert_eq!(aliases, ["web1", "web1-alias", "db", "app.internal"]);
+
+        let web1 = &entries[0];
+        assert_eq!(web1.hostname, "203.0.113.10");
+        assert_eq!(web1.user.as_deref(), Some("deploy"));
+        assert_eq!(web1.port, DEFAULT_SSH_PORT);
+        assert_eq!(web1.identity_file.as_deref(), Some("/home/me/.ssh/id_web"));
+
+        let db = &entries[2];
+        assert_eq!(db.hostname, "db.example.com");
+        assert_eq!(db.port, 2222);
+        // Defaults come from `Host *`
+        assert_eq!(db.user.as_deref(), Some("fallback"));
+        assert_eq!(
+            db.identity_file.as_deref(),
+            Some("/home/me/.ssh/id_default")
+        );
+
+        // Earlier wildcard block wins over the later `Host *`
+        let app = &entries[3];
+        assert_eq!(app.hostname, "app.internal");
+        assert_eq!(app.user.as_deref(), Some("ops"));
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*", "anything"));
+        assert!(glob_match("*.internal", "app.internal"));
+        assert!(glob_match("web?", "web1"));
+        assert!(!glob_match("web?", "web10"));
+        assert!(!glob_match("*.internal", "internal"));
+    }
+
+    #[test]
+    fn test_negated_pattern_excludes_host() {
+        let config = "Host * !secret\n    User shared\nHost secret other\n";
+        let entries = SshConfig::parse(config, None);
+        assert_eq!(entries[0].alias, "secret");
+        assert_eq!(entries[0].user, None);
+        assert_eq!(entries[1].user.as_deref(), Some("shared"));
+    }
+}
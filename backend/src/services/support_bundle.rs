@@ -0,0 +1,223 @@
+//! # Support Bundle
+//!
+//! Collects what a bug report needs — wizard version, system info, both
+//! configs, recent logs and the `openclaw doctor` report — into one
+//! `.tar.gz`. Config secrets are replaced with `${ENV_VAR}` placeholders
+//! (`ConfigTemplate`) and every text file is passed through
+//! `redact::redact_secrets`, so the archive is safe to attach to an
+//! issue.
+//!
+//! A piece that can't be collected is listed in `manifest.json` with the
+//! reason instead of failing the whole bundle.
+
+use anyhow::{Context, Result};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use serde::Serialize;
+use serde_json::{Value, json};
+
+use crate::services::config::ConfigWriter;
+use crate::services::config_template::ConfigTemplate;
+use crate::services::doctor::DoctorService;
+use crate::services::log_service::{LOG_SERVICES, LogService};
+use crate::services::platform::Platform;
+use crate::services::redact::redact_secrets;
+
+/// Recent log lines included per service
+const BUNDLE_LOG_LINES: usize = 1000;
+
+/// One file in the bundle
+#[derive(Debug, Clone)]
+pub struct BundleEntry {
+    pub name: String,
+    pub contents: Vec<u8>,
+}
+
+/// Support bundle builder
+pub struct SupportBundle;
+
+impl SupportBundle {
+    /// Collect every piece and return the archive with its download file name
+    ///
+    /// Runs `openclaw doctor` and reads files, so call it off the async runtime.
+    pub fn build() -> Result<(String, Vec<u8>)> {
+        let now = chrono::Utc::now();
+        let entries = Self::collect(&now.to_rfc3339());
+        let archive = Self::archive(&entries)?;
+        let file_name = format!("openclaw-support-{}.tar.gz", now.format("%Y%m%d-%H%M%S"));
+        Ok((file_name, archive))
+    }
+
+    /// Gather the bundle files, plus a manifest of what's missing and why
+    fn collect(generated_at: &str) -> Vec<BundleEntry> {
+        let mut entries = Vec::new();
+        let mut missing = Vec::new();
+        let mut add = |name: &str, result: Result<String>| match result {
+            Ok(text) => entries.push(BundleEntry {
+                name: name.to_string(),
+                contents: redact_secrets(&text).into_bytes(),
+            }),
+            Err(e) => missing.push(json!({ "name": name, "reason": format!("{:#}", e) })),
+        };
+
+        add("wizard-version.json", to_json(&Platform::wizard_version()));
+        add("system-info.json", to_json(&Platform::system_info()));
+        add(
+            "config/wizard.json",
+            Platform::wizard_config_path().and_then(|path| Self::redacted_config(&path)),
+        );
+        add(
+            "config/gateway.json",
+            Platform::openclaw_config_path().and_then(|path| Self::redacted_config(&path)),
+        );
+        for service in LOG_SERVICES {
+            add(&format!("logs/{}.log", service), Self::recent_logs(service));
+        }
+        add(
+            "doctor.json",
+            DoctorService::run_diagnostics().and_then(|report| to_json(&report)),
+        );
+
+        let names: Vec<String> = entries.iter().map(|e| e.name.clone()).collect();
+        let manifest = json!({
+            "generated_at": generated_at,
+            "files": names,
+            "missing": missing,
+        });
+        entries.insert(
+            0,
+            BundleEntry {
+                name: "manifest.json".to_string(),
+                contents: serde_json::to_vec_pretty(&manifest).unwrap_or_default(),
+            },
+        );
+        entries
+    }
+
+    /// A config with its secrets replaced by placeholders
+    fn redacted_config(path: &std::path::Path) -> Result<String> {
+        let config: Value = ConfigWriter::read_json(path)?;
+        to_json(&ConfigTemplate::from_config(&config))
+    }
+
+    /// Recent log lines for a service, one per line
+    fn recent_logs(service: &str) -> Result<String> {
+        let logs = LogService::get_recent_logs(service, BUNDLE_LOG_LINES, None, None, false)?;
+        let mut text = String::new();
+        if let Some(path) = &logs.log_path {
+            text.push_str(&format!("# {}\n", path));
+        } else if let Some(unit) = &logs.journald_unit {
+            text.push_str(&format!("# journald unit {}\n", unit));
+        }
+        for line in &logs.lines {
+            text.push_str(&line.content);
+            text.push('\n');
+        }
+        Ok(text)
+    }
+
+    /// Pack entries into a gzipped tarball under a `openclaw-support/` directory
+    fn archive(entries: &[BundleEntry]) -> Result<Vec<u8>> {
+        let encoder = GzEncoder::new(Vec::new(), Compression::default());
+        let mut builder = tar::Builder::new(encoder);
+        let mtime = chrono::Utc::now().timestamp().max(0) as u64;
+
+        for entry in entries {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(entry.contents.len() as u64);
+            header.set_mode(0o644);
+            header.set_mtime(mtime);
+            header.set_cksum();
+            builder
+                .append_data(
+                    &mut header,
+                    format!("openclaw-support/{}", entry.name),
+                    entry.contents.as_slice(),
+                )
+                .with_context(|| format!("Failed to add {} to the bundle", entry.name))?;
+        }
+
+        builder
+            .into_inner()
+            .and_then(|encoder| encoder.finish())
+            .context("Failed to finish the support bundle")
+    }
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<String> {
+    serde_json::to_string_pretty(value).context("Failed to serialize")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    fn unpack(archive: &[u8]) -> Vec<(String, String)> {
+        let mut files = Vec::new();
+        let mut tar = tar::Archive::new(GzDecoder::new(archive));
+        for entry in tar.entries().unwrap() {
+            let mut entry = entry.unwrap();
+            let name = entry.path().unwrap().display().to_string();
+            let mut contents = String::new();
+            entry.read_to_string(&mut contents).unwrap();
+            files.push((name, contents));
+        }
+        files
+    }
+
+    #[test]
+    fn test_archive_round_trips_entries() {
+        let entries = vec![
+            BundleEntry {
+                name: "manifest.json".to_string(),
+                contents: b"{}".to_vec(),
+            },
+            BundleEntry {
+                name: "logs/gateway.log".to_string(),
+                contents: b"line one\n".to_vec(),
+            },
+        ];
+        let files = unpack(&SupportBundle::archive(&entries).unwrap());
+        assert_eq!(
+            files,
+            vec![
+                (
+                    "openclaw-support/manifest.json".to_string(),
+                    "{}".to_string()
+                ),
+                (
+                    "openclaw-support/logs/gateway.log".to_string(),
+                    "line one\n".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_redacted_config_hides_secrets() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("openclaw.json");
+        std::fs::write(
+            &path,
+            r#"{"provider":"anthropic","api_key":"sk-ant-api03-secret","gateway":{"auth":{"token":"t0k"}}}"#,
+        )
+        .unwrap();
+
+        let redacted = SupportBundle::redacted_config(&path).unwrap();
+        assert!(!redacted.contains("sk-ant-api03-secret"));
+        assert!(!redacted.contains("t0k"));
+        assert!(redacted.contains("anthropic"));
+        assert!(SupportBundle::redacted_config(&dir.path().join("missing.json")).is_err());
+    }
+
+    #[test]
+    fn test_collect_always_includes_manifest_and_version() {
+        let entries = SupportBundle::collect("2026-01-01T00:00:00Z");
+        assert_eq!(entries[0].name, "manifest.json");
+        let manifest: Value = serde_json::from_slice(&entries[0].contents).unwrap();
+        assert_eq!(manifest["generated_at"], "2026-01-01T00:00:00Z");
+        assert!(entries.iter().any(|e| e.name == "wizard-version.json"));
+    }
+}
@@ -185,8 +185,7 @@ impl UninstallService {
             })
             .await;
 
-        if let Ok(config_dir) = Platform::config_dir() {
-            let config_path = config_dir.join("openclaw.json");
+        if let Ok(config_path) = Platform::wizard_config_path() {
             if config_path.exists() {
                 match std::fs::remove_file(&config_path) {
                     Ok(_) => {
@@ -1,19 +1,28 @@
 //! # Upgrade Service
 //!
 //! Handles upgrading OpenClaw installation with streaming progress updates.
+//! The OpenClaw config is backed up to ~/.openclaw/backups before anything changes.
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
 use tokio::sync::mpsc;
 
 use crate::models::InstallProgress;
 use crate::services::command::SafeCommand;
+use crate::services::offline::OfflineMode;
+use crate::services::platform::Platform;
 
 /// Upgrade service for updating OpenClaw to the latest version
 pub struct UpgradeService;
 
 impl UpgradeService {
     /// Run upgrade with streaming progress
+    ///
+    /// Fails before touching anything in offline mode, since the npm update
+    /// needs the registry.
     pub async fn run_upgrade(tx: mpsc::Sender<InstallProgress>) -> Result<()> {
+        OfflineMode::ensure_online("Upgrade")?;
+        Self::backup_config(&tx).await?;
         Self::stop_gateway(&tx).await;
         Self::update_npm_package(&tx).await?;
         Self::run_doctor_fix(&tx).await;
@@ -34,6 +43,88 @@ impl UpgradeService {
         Ok(())
     }
 
+    /// Step 0: Back up ~/.openclaw/openclaw.json (0%) - CRITICAL STEP
+    ///
+    /// Skipped when there is no config yet; a failed copy aborts the upgrade.
+    async fn backup_config(tx: &mpsc::Sender<InstallProgress>) -> Result<()> {
+        let _ = tx
+            .send(InstallProgress {
+                stage: "upgrade".into(),
+                status: "running".into(),
+                message: "Backing up configuration...".into(),
+                progress_pct: Some(0),
+                ..Default::default()
+            })
+            .await;
+
+        let openclaw_dir = Platform::home_dir()?.join(".openclaw");
+        let timestamp = chrono::Utc::now().format("%Y%m%d-%H%M%S").to_string();
+
+        match Self::backup_file(
+            &openclaw_dir.join("openclaw.json"),
+            &openclaw_dir.join("backups"),
+            &timestamp,
+        ) {
+            Ok(backup) => {
+                let _ = tx
+                    .send(InstallProgress {
+                        stage: "upgrade".into(),
+                        status: "running".into(),
+                        message: match &backup {
+                            Some(_) => "Configuration backed up".into(),
+                            None => "No configuration to back up (skipped)".into(),
+                        },
+                        output_line: backup.map(|p| format!("Backup: {}", p.display())),
+                        progress_pct: Some(0),
+                        ..Default::default()
+                    })
+                    .await;
+                Ok(())
+            }
+            Err(e) => {
+                let error_msg = format!("Failed to back up configuration: {}", e);
+                let _ = tx
+                    .send(InstallProgress {
+                        stage: "upgrade".into(),
+                        status: "failed".into(),
+                        message: error_msg.clone(),
+                        error: Some(error_msg.clone()),
+                        progress_pct: Some(0),
+                        ..Default::default()
+                    })
+                    .await;
+                Err(anyhow::anyhow!(error_msg))
+            }
+        }
+    }
+
+    /// Copy `source` to `{backup_dir}/{file_name}.{timestamp}.bak`
+    ///
+    /// Returns the backup path, or None if `source` doesn't exist.
+    fn backup_file(source: &Path, backup_dir: &Path, timestamp: &str) -> Result<Option<PathBuf>> {
+        if !source.exists() {
+            return Ok(None);
+        }
+
+        let file_name = source
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "config".to_string());
+        let backup = backup_dir.join(format!("{}.{}.bak", file_name, timestamp));
+
+        std::fs::create_dir_all(backup_dir)
+            .with_context(|| format!("Failed to create {}", backup_dir.display()))?;
+        std::fs::copy(source, &backup).with_context(|| {
+            format!(
+                "Failed to copy {} to {}",
+                source.display(),
+                backup.display()
+            )
+        })?;
+
+        Ok(Some(backup))
+    }
+
     /// Step 1: Stop the gateway daemon (0-15%)
     async fn stop_gateway(tx: &mpsc::Sender<InstallProgress>) {
         let _ = tx
@@ -270,3 +361,39 @@ impl UpgradeService {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_backup_file_copies_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = temp_dir.path().join("openclaw.json");
+        std::fs::write(&source, r#"{"gateway":{"port":3000}}"#).unwrap();
+        let backup_dir = temp_dir.path().join("backups");
+
+        let backup = UpgradeService::backup_file(&source, &backup_dir, "20260301-120000")
+            .unwrap()
+            .unwrap();
+        assert_eq!(backup, backup_dir.join("openclaw.json.20260301-120000.bak"));
+        assert_eq!(
+            std::fs::read_to_string(backup).unwrap(),
+            r#"{"gateway":{"port":3000}}"#
+        );
+    }
+
+    #[test]
+    fn test_backup_file_skips_missing_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let result = UpgradeService::backup_file(
+            &temp_dir.path().join("openclaw.json"),
+            &temp_dir.path().join("backups"),
+            "now",
+        )
+        .unwrap();
+        assert!(result.is_none());
+        assert!(!temp_dir.path().join("backups").exists());
+    }
+}
@@ -0,0 +1,224 @@
+//! # Saved Wizard Config
+//!
+//! Loads the wizard's own `openclaw.json` (in `Platform::config_dir()`) and
+//! upgrades older layouts to the current `WizardConfig` shape on read.
+//!
+//! Each config carries a `schema_version`. Configs written before versioning
+//! have none and are treated as version 0. `MIGRATIONS[n]` upgrades version
+//! `n` to `n + 1`; every applied step is logged. The file itself is left
+//! untouched until the wizard next saves it.
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde_json::{Map, Value, json};
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use crate::models::WizardConfig;
+use crate::services::config_template::ConfigTemplate;
+use crate::services::platform::{DEFAULT_GATEWAY_PORT, Platform};
+
+/// Schema version written by this build
+pub const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+/// A migration step rewrites the object in place and describes each change
+type Migration = fn(&mut Map<String, Value>, &mut Vec<String>);
+
+/// `MIGRATIONS[n]` upgrades a version `n` config to version `n + 1`
+const MIGRATIONS: &[Migration] = &[migrate_v0_to_v1];
+
+/// Loader for the wizard's saved configuration
+pub struct WizardConfigStore;
+
+impl WizardConfigStore {
+    /// Path of the wizard's saved config
+    pub fn path() -> Result<PathBuf> {
+        Platform::wizard_config_path().context("Failed to find config directory")
+    }
+
+    /// Load and migrate the saved wizard config
+    pub fn load() -> Result<WizardConfig> {
+        Self::load_from(&Self::path()?)
+    }
+
+    /// Load and migrate a wizard config from `path`
+    pub fn load_from(path: &Path) -> Result<WizardConfig> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Config not found at {}", path.display()))?;
+        let raw: Value = serde_json::from_str(&content).context("Invalid config JSON")?;
+        // Configs bootstrapped from a template carry ${ENV_VAR} placeholders
+        let raw = ConfigTemplate::resolve_env(&raw).map_err(|e| anyhow!(e.to_string()))?;
+
+        let (migrated, changes) = Self::migrate(raw)?;
+        for change in &changes {
+            info!("Migrated {}: {}", path.display(), change);
+        }
+
+        serde_json::from_value(migrated).context("Config does not match the wizard config shape")
+    }
+
+    /// Upgrade a raw config to `CURRENT_SCHEMA_VERSION`, returning the new
+    /// value and a description of every change made
+    pub fn migrate(raw: Value) -> Result<(Value, Vec<String>)> {
+        let Value::Object(mut obj) = raw else {
+            bail!("Config must be a JSON object");
+        };
+
+        let version = match obj.get("schema_version") {
+            None | Some(Value::Null) => 0,
+            Some(v) => v
+                .as_u64()
+                .and_then(|v| u32::try_from(v).ok())
+                .context("schema_version must be a non-negative integer")?,
+        };
+        if version > CURRENT_SCHEMA_VERSION {
+            bail!(
+                "Config schema_version {} is newer than this wizard supports ({}); upgrade the wizard",
+                version,
+                CURRENT_SCHEMA_VERSION
+            );
+        }
+
+        let mut changes = Vec::new();
+        for (from, step) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+            let mut step_changes = Vec::new();
+            step(&mut obj, &mut step_changes);
+            obj.insert("schema_version".into(), json!(from + 1));
+            changes.push(format!("schema v{} -> v{}", from, from + 1));
+            changes.extend(step_changes);
+        }
+
+        Ok((Value::Object(obj), changes))
+    }
+}
+
+/// v0 → v1: configs saved before versioning.
+///
+/// Besides the wizard shape, v0 files may be a copy of the OpenClaw gateway
+/// config (`ai.*` / `gateway.*`); those are mapped to wizard keys. Required
+/// fields that older wizards didn't write get their defaults.
+fn migrate_v0_to_v1(obj: &mut Map<String, Value>, changes: &mut Vec<String>) {
+    if !obj.contains_key("provider") && (obj.contains_key("ai") || obj.contains_key("gateway")) {
+        let ai = obj.get("ai").cloned().unwrap_or(Value::Null);
+        let gateway = obj.get("gateway").cloned().unwrap_or(Value::Null);
+
+        let mut mapped = |key: &str, value: Option<Value>| {
+            if let Some(value) = value {
+                obj.insert(key.to_string(), value);
+            }
+        };
+        mapped("provider", ai.get("provider").cloned());
+        mapped("api_key", ai.get("apiKey").cloned());
+        mapped(
+            "auth_type",
+            ai.get("auth")
+                .and_then(Value::as_str)
+                .map(|auth| match auth {
+                    "token" => json!("setup-token"),
+                    other => json!(other),
+                }),
+        );
+        mapped("gateway_port", gateway.get("port").cloned());
+        mapped("gateway_bind", gateway.get("bind").cloned());
+        mapped("auth_mode", gateway["auth"].get("mode").cloned());
+        mapped(
+            "auth_credential",
+            gateway["auth"]
+                .get("credential")
+                .or_else(|| gateway["auth"].get("token"))
+                .cloned(),
+        );
+        changes.push("mapped OpenClaw ai/gateway sections to wizard fields".to_string());
+    }
+
+    let defaults = [
+        ("provider", json!("anthropic")),
+        ("api_key", json!("")),
+        ("auth_type", json!("api-key")),
+        ("gateway_port", json!(DEFAULT_GATEWAY_PORT)),
+        ("gateway_bind", json!("127.0.0.1")),
+        ("auth_mode", json!("none")),
+    ];
+    for (key, default) in defaults {
+        if obj.get(key).is_none_or(Value::is_null) {
+            changes.push(format!("set missing {} to {}", key, default));
+            obj.insert(key.to_string(), default);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_config_is_unchanged() {
+        let raw = json!({
+            "schema_version": CURRENT_SCHEMA_VERSION,
+            "provider": "openai",
+            "api_key": "sk-test",
+            "auth_type": "api-key",
+            "gateway_port": 18789,
+            "gateway_bind": "127.0.0.1",
+            "auth_mode": "token",
+        });
+        let (migrated, changes) = WizardConfigStore::migrate(raw.clone()).unwrap();
+        assert_eq!(migrated, raw);
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn test_unversioned_wizard_config_gets_defaults() {
+        let raw = json!({
+            "provider": "openai",
+            "api_key": "sk-test",
+            "auth_type": "api-key",
+            "gateway_port": 18789,
+            "log_dir": "/var/log/openclaw",
+        });
+        let (migrated, changes) = WizardConfigStore::migrate(raw).unwrap();
+        assert_eq!(migrated["schema_version"], 1);
+        assert_eq!(migrated["gateway_port"], 18789);
+        assert_eq!(migrated["gateway_bind"], "127.0.0.1");
+        assert_eq!(migrated["auth_mode"], "none");
+        assert!(changes.iter().any(|c| c.contains("gateway_bind")));
+
+        let config: WizardConfig = serde_json::from_value(migrated).unwrap();
+        assert_eq!(config.log_dir.as_deref(), Some("/var/log/openclaw"));
+        assert_eq!(config.schema_version, Some(1));
+    }
+
+    #[test]
+    fn test_openclaw_shape_is_mapped() {
+        let raw = json!({
+            "ai": { "provider": "anthropic", "apiKey": "sk-ant", "auth": "token" },
+            "gateway": { "port": 4000, "bind": "loopback", "auth": { "mode": "token", "token": "t0k" } },
+        });
+        let (migrated, _) = WizardConfigStore::migrate(raw).unwrap();
+        let config: WizardConfig = serde_json::from_value(migrated).unwrap();
+        assert_eq!(config.api_key, "sk-ant");
+        assert_eq!(config.auth_type, "setup-token");
+        assert_eq!(config.gateway_port, 4000);
+        assert_eq!(config.auth_credential.as_deref(), Some("t0k"));
+    }
+
+    #[test]
+    fn test_rejects_newer_or_invalid_versions() {
+        let newer = json!({ "schema_version": CURRENT_SCHEMA_VERSION + 1 });
+        assert!(WizardConfigStore::migrate(newer).is_err());
+        assert!(WizardConfigStore::migrate(json!({ "schema_version": "one" })).is_err());
+        assert!(WizardConfigStore::migrate(json!([1, 2])).is_err());
+    }
+
+    #[test]
+    fn test_load_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("openclaw.json");
+        std::fs::write(&path, r#"{"provider":"groq","api_key":"gsk"}"#).unwrap();
+
+        let config = WizardConfigStore::load_from(&path).unwrap();
+        assert_eq!(config.provider, "groq");
+        assert_eq!(config.auth_type, "api-key");
+        assert_eq!(config.gateway_port, DEFAULT_GATEWAY_PORT);
+        assert!(WizardConfigStore::load_from(&dir.path().join("missing.json")).is_err());
+    }
+}
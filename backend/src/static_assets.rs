@@ -0,0 +1,86 @@
+//! # Embedded Frontend Assets
+//!
+//! With the `embed-static` feature the built frontend (`backend/static/`) is
+//! compiled into the binary, so a standalone download serves the UI without
+//! the directory on disk. `main` uses these assets only when `--static-dir`
+//! isn't given and no `static/` directory is found.
+
+use axum::{
+    http::{StatusCode, Uri, header},
+    response::{IntoResponse, Response},
+};
+
+#[cfg(feature = "embed-static")]
+#[derive(rust_embed::RustEmbed)]
+#[folder = "static/"]
+#[allow_missing = true]
+struct Assets;
+
+/// Map a request path to the embedded file name (`/` → `index.html`)
+#[cfg_attr(not(feature = "embed-static"), allow(dead_code))]
+fn asset_path(uri_path: &str) -> String {
+    let path = uri_path.trim_start_matches('/');
+    if path.is_empty() || path.ends_with('/') {
+        format!("{}index.html", path)
+    } else {
+        path.to_string()
+    }
+}
+
+/// Fallback handler serving files from the embedded frontend
+#[cfg(feature = "embed-static")]
+pub async fn serve_embedded(uri: Uri) -> Response {
+    let path = asset_path(uri.path());
+    match Assets::get(&path) {
+        Some(file) => (
+            [(header::CONTENT_TYPE, file.metadata.mimetype().to_string())],
+            file.data.into_owned(),
+        )
+            .into_response(),
+        None => (StatusCode::NOT_FOUND, "Not found").into_response(),
+    }
+}
+
+/// Fallback handler for builds without the embedded frontend
+#[cfg(not(feature = "embed-static"))]
+pub async fn serve_embedded(_uri: Uri) -> Response {
+    (
+        StatusCode::NOT_FOUND,
+        [(header::CONTENT_TYPE, "text/plain")],
+        "Frontend assets are not available in this build",
+    )
+        .into_response()
+}
+
+/// Whether the embedded frontend contains an `index.html`
+pub fn has_embedded_index() -> bool {
+    #[cfg(feature = "embed-static")]
+    {
+        Assets::get("index.html").is_some()
+    }
+    #[cfg(not(feature = "embed-static"))]
+    {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asset_path_maps_directories_to_index() {
+        assert_eq!(asset_path("/"), "index.html");
+        assert_eq!(asset_path(""), "index.html");
+        assert_eq!(asset_path("/docs/"), "docs/index.html");
+        assert_eq!(asset_path("/assets/index-abc.js"), "assets/index-abc.js");
+    }
+
+    #[cfg(not(feature = "embed-static"))]
+    #[tokio::test]
+    async fn test_serve_embedded_without_feature_is_not_found() {
+        assert!(!has_embedded_index());
+        let response = serve_embedded(Uri::from_static("/")).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
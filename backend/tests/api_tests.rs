@@ -18,7 +18,10 @@ fn app() -> Router {
 
     Router::new()
         .route("/api/health", get(routes::api::health))
+        .route("/api/health/live", get(routes::api::health_live))
+        .route("/api/health/ready", get(routes::api::health_ready))
         .route("/api/system/info", get(routes::api::system_info))
+        .route("/api/system/identity", get(routes::api::system_identity))
         .route(
             "/api/system/requirements",
             get(routes::api::system_requirements),
@@ -31,10 +34,24 @@ fn app() -> Router {
             "/api/wizard/validate-key",
             post(routes::wizard::validate_api_key),
         )
+        .route("/api/confirm", get(routes::confirm::issue_confirmation))
         .route(
             "/api/wizard/rollback",
             post(routes::wizard::rollback_installation),
         )
+        .route("/api/docker/prune", post(routes::docker::prune_containers))
+        .route(
+            "/api/skills/uninstall-batch",
+            post(routes::skills::uninstall_skill_batch),
+        )
+        .route(
+            "/api/dashboard/config/import",
+            post(routes::dashboard::import_config),
+        )
+        .route(
+            "/api/channels/validate",
+            post(routes::channels::validate_channel_token),
+        )
         .route(
             "/api/services/status",
             get(routes::services::services_status),
@@ -47,6 +64,9 @@ fn app() -> Router {
             "/api/intelligence/security-audit",
             get(routes::intelligence::security_audit),
         )
+        .layer(axum::middleware::from_fn(
+            openclaw_wizard::middleware::correlation_id,
+        ))
 }
 
 async fn get_response(app: Router, uri: &str) -> (StatusCode, String) {
@@ -60,12 +80,23 @@ async fn get_response(app: Router, uri: &str) -> (StatusCode, String) {
 }
 
 async fn post_response(app: Router, uri: &str, body: &str) -> (StatusCode, String) {
-    let request = Request::builder()
+    post_with_headers(app, uri, body, &[]).await
+}
+
+async fn post_with_headers(
+    app: Router,
+    uri: &str,
+    body: &str,
+    headers: &[(&str, &str)],
+) -> (StatusCode, String) {
+    let mut request = Request::builder()
         .method("POST")
         .uri(uri)
-        .header("content-type", "application/json")
-        .body(Body::from(body.to_string()))
-        .unwrap();
+        .header("content-type", "application/json");
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    let request = request.body(Body::from(body.to_string())).unwrap();
 
     let response = app.oneshot(request).await.unwrap();
     let status = response.status();
@@ -79,6 +110,17 @@ async fn test_health_endpoint() {
     let (status, body) = get_response(app(), "/api/health").await;
     assert_eq!(status, StatusCode::OK);
     assert!(body.contains("\"success\""));
+    assert!(body.contains("\"offline\":false"));
+}
+
+#[tokio::test]
+async fn test_health_live_and_ready() {
+    let (status, _body) = get_response(app(), "/api/health/live").await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = get_response(app(), "/api/health/ready").await;
+    assert!(status == StatusCode::OK || status == StatusCode::SERVICE_UNAVAILABLE);
+    assert!(body.contains("\"checks\""));
 }
 
 #[tokio::test]
@@ -89,6 +131,14 @@ async fn test_system_info() {
     assert!(body.contains("\"arch\""));
 }
 
+#[tokio::test]
+async fn test_system_identity() {
+    let (status, body) = get_response(app(), "/api/system/identity").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains("\"config_dir\""));
+    assert!(body.contains("\"config_dir_writable\""));
+}
+
 #[tokio::test]
 async fn test_system_requirements() {
     let (status, body) = get_response(app(), "/api/system/requirements").await;
@@ -96,6 +146,16 @@ async fn test_system_requirements() {
     assert!(body.contains("\"checks\"") || body.contains("\"all_passed\""));
 }
 
+#[tokio::test]
+async fn test_system_requirements_with_features() {
+    let (status, body) = get_response(app(), "/api/system/requirements?features=git").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains("\"Git\""));
+
+    let (status, _body) = get_response(app(), "/api/system/requirements?features=kubernetes").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
 #[tokio::test]
 async fn test_detect_openclaw() {
     let (status, _body) = get_response(app(), "/api/system/detect-openclaw").await;
@@ -145,7 +205,18 @@ async fn test_security_audit() {
 
 #[tokio::test]
 async fn test_rollback_endpoint() {
-    let (status, body) = post_response(app(), "/api/wizard/rollback", "").await;
+    let (status, body) = get_response(app(), "/api/confirm?action=RollbackInstallation").await;
+    assert_eq!(status, StatusCode::OK);
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    let token = json["data"]["token"].as_str().unwrap().to_string();
+
+    let (status, body) = post_with_headers(
+        app(),
+        "/api/wizard/rollback",
+        "",
+        &[("x-confirm-token", &token)],
+    )
+    .await;
     assert_eq!(status, StatusCode::OK);
     assert!(body.contains("\"stages\""));
     assert!(body.contains("\"stop_daemon\""));
@@ -153,6 +224,44 @@ async fn test_rollback_endpoint() {
     assert!(body.contains("\"uninstall_openclaw\""));
 }
 
+#[tokio::test]
+async fn test_rollback_requires_confirmation() {
+    let (status, body) = post_response(app(), "/api/wizard/rollback", "").await;
+    assert_eq!(status, StatusCode::PRECONDITION_REQUIRED);
+    assert!(body.contains("X-Confirm-Token"));
+
+    let (status, _body) = post_with_headers(
+        app(),
+        "/api/wizard/rollback",
+        "",
+        &[("x-confirm-token", "not-a-token")],
+    )
+    .await;
+    assert_eq!(status, StatusCode::PRECONDITION_REQUIRED);
+
+    // Targeted actions can't be confirmed without a target
+    let (status, _body) = get_response(app(), "/api/confirm?action=RemoveContainer").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_skill_batch_uninstall_checks_every_token() {
+    let body = r#"{"skills":[{"name":"skill-a","confirm_token":"not-a-token"}]}"#;
+    let (status, _body) = post_response(app(), "/api/skills/uninstall-batch", body).await;
+    assert_eq!(status, StatusCode::PRECONDITION_REQUIRED);
+
+    let (status, _body) =
+        post_response(app(), "/api/skills/uninstall-batch", r#"{"skills":[]}"#).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_prune_requires_confirmation() {
+    let (status, body) = post_response(app(), "/api/docker/prune", "").await;
+    assert_eq!(status, StatusCode::PRECONDITION_REQUIRED);
+    assert!(body.contains("PruneContainers"));
+}
+
 #[tokio::test]
 async fn test_unknown_route_returns_404() {
     let (status, _body) = get_response(app(), "/api/nonexistent").await;
@@ -167,3 +276,48 @@ async fn test_health_returns_json() {
     let parsed: Result<serde_json::Value, _> = serde_json::from_str(&body);
     assert!(parsed.is_ok(), "Health response should be valid JSON");
 }
+
+#[tokio::test]
+async fn test_request_id_echoed_or_generated() {
+    let request = Request::builder()
+        .uri("/api/health")
+        .header("x-request-id", "client-abc-123")
+        .body(Body::empty())
+        .unwrap();
+    let response = app().oneshot(request).await.unwrap();
+    assert_eq!(response.headers()["x-request-id"], "client-abc-123");
+
+    let request = Request::builder()
+        .uri("/api/health")
+        .body(Body::empty())
+        .unwrap();
+    let response = app().oneshot(request).await.unwrap();
+    let generated = response.headers()["x-request-id"].to_str().unwrap();
+    assert!(generated.starts_with("req-"));
+}
+
+#[tokio::test]
+async fn test_validation_errors_use_error_body() {
+    // Rejected before anything is written to disk
+    let (status, body) = post_response(app(), "/api/dashboard/config/import", "[1, 2]").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["success"], false);
+    assert_eq!(json["error"], "Config must be a JSON object");
+
+    let (status, body) = post_response(
+        app(),
+        "/api/channels/validate",
+        r#"{"platform":"carrier-pigeon","token":"x"}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["success"], false);
+    assert!(
+        json["error"]
+            .as_str()
+            .unwrap()
+            .contains("Unsupported platform")
+    );
+}
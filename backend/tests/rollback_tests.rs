@@ -54,6 +54,7 @@ async fn test_rollback_idempotent() {
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_rollback_result_serializes() {
     let result = RollbackResult {
         success: true,
@@ -4,9 +4,13 @@ import type { OpenClawDetection } from '../types/OpenClawDetection';
 import type { ApiKeyValidationRequest } from '../types/ApiKeyValidationRequest';
 import type { ApiKeyValidationResponse } from '../types/ApiKeyValidationResponse';
 import type { WizardConfig } from '../types/WizardConfig';
+import type { ConfigReconcileReport } from '../types/ConfigReconcileReport';
+import type { ConfigSetResponse } from '../types/ConfigSetResponse';
 import type { InstallRequest } from '../types/InstallRequest';
+import type { InstallPlan } from '../types/InstallPlan';
 import type { ChannelValidationRequest } from '../types/ChannelValidationRequest';
 import type { ChannelValidationResponse } from '../types/ChannelValidationResponse';
+import type { ChannelValidationResult } from '../types/ChannelValidationResult';
 import type { DaemonStatus } from '../types/DaemonStatus';
 import type { HealthSnapshot } from '../types/HealthSnapshot';
 import type { DaemonActionResponse } from '../types/DaemonActionResponse';
@@ -29,6 +33,7 @@ import type { DoctorReport } from '../types/DoctorReport';
 import type { LogsResponse } from '../types/LogsResponse';
 import type { LogAnalysisRequest } from '../types/LogAnalysisRequest';
 import type { LogAnalysis } from '../types/LogAnalysis';
+import type { LogDiagnosis } from '../types/LogDiagnosis';
 import type { CostAnalysis } from '../types/CostAnalysis';
 import type { SecurityAudit } from '../types/SecurityAudit';
 import type { LlmPricingResponse } from '../types/LlmPricingResponse';
@@ -37,6 +42,20 @@ import type { ServerTestResult } from '../types/ServerTestResult';
 import type { ServerDeployResult } from '../types/ServerDeployResult';
 import type { ServerListResponse } from '../types/ServerListResponse';
 import type { RollbackResult } from '../types/RollbackResult';
+import type { ApiRoute } from '../types/ApiRoute';
+import type { CredentialRotationResponse } from '../types/CredentialRotationResponse';
+import type { GeneratedCredential } from '../types/GeneratedCredential';
+import type { SshConfigImportResponse } from '../types/SshConfigImportResponse';
+import type { ConfirmationToken } from '../types/ConfirmationToken';
+import type { DestructiveAction } from '../types/DestructiveAction';
+import type { WizardVersion } from '../types/WizardVersion';
+import type { OpenClawProcess } from '../types/OpenClawProcess';
+import type { SkillBatchInstallRequest } from '../types/SkillBatchInstallRequest';
+import type { SkillBatchSummary } from '../types/SkillBatchSummary';
+import type { SkillBatchUninstallSummary } from '../types/SkillBatchUninstallSummary';
+import type { RecommendedSkillsResponse } from '../types/RecommendedSkillsResponse';
+import type { ChannelAllowlistRequest } from '../types/ChannelAllowlistRequest';
+import type { ChannelAllowlistResponse } from '../types/ChannelAllowlistResponse';
 
 /**
  * Generic API response structure
@@ -47,6 +66,22 @@ interface ApiResponse<T> {
   error: string | null;
 }
 
+/**
+ * Build an Error for a failed response, preferring the backend's
+ * `{success: false, error}` body over the bare HTTP status
+ */
+async function responseError(response: Response): Promise<Error> {
+  try {
+    const json = await response.json() as Partial<ApiResponse<unknown>>;
+    if (json.error) {
+      return new Error(json.error);
+    }
+  } catch {
+    // Non-JSON error body
+  }
+  return new Error(`HTTP ${response.status}: ${response.statusText}`);
+}
+
 /**
  * Fetch wrapper for GET API calls with type safety
  */
@@ -54,7 +89,7 @@ async function fetchAPI<T>(endpoint: string): Promise<T> {
   const response = await fetch(endpoint);
 
   if (!response.ok) {
-    throw new Error(`HTTP ${response.status}: ${response.statusText}`);
+    throw await responseError(response);
   }
 
   const json = await response.json() as ApiResponse<T>;
@@ -69,17 +104,22 @@ async function fetchAPI<T>(endpoint: string): Promise<T> {
 /**
  * Fetch wrapper for POST API calls with JSON body
  */
-async function postAPI<T, B = unknown>(endpoint: string, body: B): Promise<T> {
+async function postAPI<T, B = unknown>(
+  endpoint: string,
+  body: B,
+  headers: Record<string, string> = {},
+): Promise<T> {
   const response = await fetch(endpoint, {
     method: 'POST',
     headers: {
       'Content-Type': 'application/json',
+      ...headers,
     },
     body: JSON.stringify(body),
   });
 
   if (!response.ok) {
-    throw new Error(`HTTP ${response.status}: ${response.statusText}`);
+    throw await responseError(response);
   }
 
   const json = await response.json() as ApiResponse<T>;
@@ -104,7 +144,7 @@ async function putAPI<T, B = unknown>(endpoint: string, body: B): Promise<T> {
   });
 
   if (!response.ok) {
-    throw new Error(`HTTP ${response.status}: ${response.statusText}`);
+    throw await responseError(response);
   }
 
   const json = await response.json() as ApiResponse<T>;
@@ -119,20 +159,63 @@ async function putAPI<T, B = unknown>(endpoint: string, body: B): Promise<T> {
 /**
  * Fetch wrapper for DELETE API calls
  */
-async function deleteAPI(endpoint: string): Promise<void> {
+async function deleteAPI(endpoint: string, headers: Record<string, string> = {}): Promise<void> {
   const response = await fetch(endpoint, {
     method: 'DELETE',
+    headers,
   });
 
   if (!response.ok) {
-    throw new Error(`HTTP ${response.status}: ${response.statusText}`);
+    throw await responseError(response);
   }
 }
 
+/**
+ * Fetch a single-use confirmation token for a destructive action and
+ * return it as the `X-Confirm-Token` header
+ */
+async function confirmHeaders(
+  action: DestructiveAction,
+  target?: string,
+): Promise<Record<string, string>> {
+  return { 'X-Confirm-Token': await confirmToken(action, target) };
+}
+
+/**
+ * Fetch a single-use confirmation token for a destructive action
+ */
+async function confirmToken(action: DestructiveAction, target?: string): Promise<string> {
+  const params = new URLSearchParams({ action });
+  if (target) params.set('target', target);
+  const confirmation = await fetchAPI<ConfirmationToken>(`/api/confirm?${params}`);
+  return confirmation.token;
+}
+
 /**
  * API client with typed methods
  */
 export const api = {
+  /**
+   * List every route the backend exposes
+   */
+  async getRoutes(): Promise<ApiRoute[]> {
+    return fetchAPI<ApiRoute[]>('/api/routes');
+  },
+
+  /**
+   * Get the running wizard's version, git commit and build time
+   */
+  async getWizardVersion(): Promise<WizardVersion> {
+    return fetchAPI<WizardVersion>('/api/system/wizard-version');
+  },
+
+  /**
+   * URL of the support bundle download (redacted configs, logs, doctor report)
+   */
+  supportBundleUrl(): string {
+    return '/api/system/support-bundle';
+  },
+
   /**
    * Get system information
    */
@@ -153,8 +236,9 @@ export const api = {
   /**
    * Get system requirements check results
    */
-  async getSystemRequirements(): Promise<SystemRequirements> {
-    return fetchAPI<SystemRequirements>('/api/system/requirements');
+  async getSystemRequirements(features?: Array<'docker' | 'git'>): Promise<SystemRequirements> {
+    const query = features?.length ? `?features=${features.join(',')}` : '';
+    return fetchAPI<SystemRequirements>(`/api/system/requirements${query}`);
   },
 
   /**
@@ -164,6 +248,24 @@ export const api = {
     return fetchAPI<OpenClawDetection>('/api/system/detect-openclaw');
   },
 
+  /**
+   * List running openclaw processes (strays from failed installs or logins)
+   */
+  async listProcesses(): Promise<OpenClawProcess[]> {
+    return fetchAPI<OpenClawProcess[]>('/api/system/processes');
+  },
+
+  /**
+   * Kill an openclaw process by PID; the backend rejects other processes
+   */
+  async killProcess(pid: number): Promise<OpenClawProcess> {
+    return postAPI<OpenClawProcess>(
+      `/api/system/processes/${pid}/kill`,
+      {},
+      await confirmHeaders('KillProcess', String(pid)),
+    );
+  },
+
   /**
    * Validate an API key
    */
@@ -183,6 +285,24 @@ export const api = {
     await postAPI<void, WizardConfig>('/api/wizard/save-config', config);
   },
 
+  /**
+   * JSON Schema for the wizard config, matching the server's rules
+   */
+  async getConfigSchema(): Promise<Record<string, unknown>> {
+    return fetchAPI<Record<string, unknown>>('/api/wizard/config-schema');
+  },
+
+  /**
+   * Ordered stages an install with these flags will run
+   */
+  async getInstallPlan(request: InstallRequest): Promise<InstallPlan> {
+    const params = new URLSearchParams({
+      install_node: String(request.install_node),
+      install_openclaw: String(request.install_openclaw),
+    });
+    return fetchAPI<InstallPlan>(`/api/wizard/install-plan?${params}`);
+  },
+
   /**
    * Start installation (acknowledgment only, progress via WebSocket)
    */
@@ -202,6 +322,31 @@ export const api = {
     );
   },
 
+  /**
+   * Validate every configured channel token at once
+   */
+  async validateAllChannels(): Promise<ChannelValidationResult[]> {
+    const response = await fetch('/api/channels/validate-all', { method: 'POST' });
+    if (!response.ok) {
+      throw await responseError(response);
+    }
+    const json = await response.json() as ApiResponse<ChannelValidationResult[]>;
+    return json.data ?? [];
+  },
+
+  /**
+   * Add or remove one user in a channel's DM allowlist
+   */
+  async updateChannelAllowlist(
+    platform: string,
+    request: ChannelAllowlistRequest
+  ): Promise<ChannelAllowlistResponse> {
+    return postAPI<ChannelAllowlistResponse, ChannelAllowlistRequest>(
+      `/api/channels/${encodeURIComponent(platform)}/allowlist`,
+      request
+    );
+  },
+
   // Dashboard API methods
   /**
    * Get daemon process status
@@ -270,6 +415,63 @@ export const api = {
     return fetchAPI<Record<string, unknown>>('/api/dashboard/config/export');
   },
 
+  /**
+   * Export current config with secrets replaced by ${ENV_VAR} placeholders
+   */
+  async getConfigTemplate(): Promise<Record<string, unknown>> {
+    return fetchAPI<Record<string, unknown>>('/api/dashboard/config/template');
+  },
+
+  /**
+   * Compare the wizard's saved settings with the config the gateway loads
+   */
+  async getConfigReconcile(): Promise<ConfigReconcileReport> {
+    return fetchAPI<ConfigReconcileReport>('/api/dashboard/config/reconcile');
+  },
+
+  /**
+   * Deploy the wizard's saved settings to the gateway config
+   *
+   * Settings with gateway auth off or a bind beyond loopback are rejected
+   * unless `confirmWeakening` is set after the user has confirmed it.
+   */
+  async syncConfig(confirmWeakening = false): Promise<ConfigReconcileReport> {
+    const headers = confirmWeakening ? await confirmHeaders('WeakenGatewayAccess') : {};
+    return postAPI<ConfigReconcileReport>('/api/dashboard/config/reconcile', {}, headers);
+  },
+
+  /**
+   * Set one allowlisted gateway config key (e.g. "gateway.port")
+   *
+   * Turning gateway auth off or binding beyond loopback is rejected unless
+   * `confirmWeakening` is set after the user has confirmed it.
+   */
+  async setConfigKey(
+    path: string,
+    value: unknown,
+    confirmWeakening = false,
+  ): Promise<ConfigSetResponse> {
+    const headers = confirmWeakening ? await confirmHeaders('WeakenGatewayAccess') : {};
+    return postAPI<ConfigSetResponse>('/api/dashboard/config/set', { path, value }, headers);
+  },
+
+  /**
+   * Generate a random gateway credential (default 32 characters)
+   */
+  async generateCredential(length?: number): Promise<string> {
+    const params = length ? `?length=${length}` : '';
+    const generated = await fetchAPI<GeneratedCredential>(`/api/wizard/generate-credential${params}`);
+    return generated.credential;
+  },
+
+  /**
+   * Rotate the gateway auth credential and restart the gateway.
+   * The new credential is only returned by this call.
+   */
+  async rotateGatewayCredential(): Promise<CredentialRotationResponse> {
+    return postAPI<CredentialRotationResponse>('/api/dashboard/auth/rotate', {});
+  },
+
   /**
    * Get version information (current and latest)
    */
@@ -298,7 +500,7 @@ export const api = {
     });
 
     if (!response.ok) {
-      throw new Error(`HTTP ${response.status}: ${response.statusText}`);
+      throw await responseError(response);
     }
 
     return response.json() as Promise<SshConnectionResponse>;
@@ -314,7 +516,7 @@ export const api = {
     const response = await fetch('/api/docker/status');
 
     if (!response.ok) {
-      throw new Error(`HTTP ${response.status}: ${response.statusText}`);
+      throw await responseError(response);
     }
 
     return response.json() as Promise<DockerStatusResponse>;
@@ -339,7 +541,7 @@ export const api = {
     });
 
     if (!response.ok) {
-      throw new Error(`HTTP ${response.status}: ${response.statusText}`);
+      throw await responseError(response);
     }
 
     return response.json() as Promise<DockerCreateResponse>;
@@ -356,7 +558,7 @@ export const api = {
    * Remove a Docker container
    */
   async removeContainer(id: string): Promise<void> {
-    await deleteAPI(`/api/docker/${id}`);
+    await deleteAPI(`/api/docker/${id}`, await confirmHeaders('RemoveContainer', id));
   },
 
   /**
@@ -405,11 +607,40 @@ export const api = {
     return response.json() as Promise<SkillInstallResponse>;
   },
 
+  /**
+   * Install several skills in order; blocked or failed skills don't stop the rest
+   */
+  async installSkillBatch(request: SkillBatchInstallRequest): Promise<SkillBatchSummary> {
+    return postAPI<SkillBatchSummary>('/api/skills/install-batch', request);
+  },
+
   /**
    * Uninstall a locally installed skill
    */
   async uninstallSkill(name: string): Promise<void> {
-    await deleteAPI(`/api/skills/${encodeURIComponent(name)}`);
+    await deleteAPI(
+      `/api/skills/${encodeURIComponent(name)}`,
+      await confirmHeaders('UninstallSkill', name),
+    );
+  },
+
+  /**
+   * Uninstall several skills in order, confirming each one; failures don't
+   * stop the rest
+   */
+  async uninstallSkillBatch(names: string[]): Promise<SkillBatchUninstallSummary> {
+    const skills = [];
+    for (const name of names) {
+      skills.push({ name, confirm_token: await confirmToken('UninstallSkill', name) });
+    }
+    return postAPI<SkillBatchUninstallSummary>('/api/skills/uninstall-batch', { skills });
+  },
+
+  /**
+   * Curated starter skills with current registry metadata
+   */
+  async getRecommendedSkills(): Promise<RecommendedSkillsResponse> {
+    return fetchAPI<RecommendedSkillsResponse>('/api/skills/recommended');
   },
 
   /**
@@ -431,7 +662,7 @@ export const api = {
     });
 
     if (!response.ok) {
-      throw new Error(`HTTP ${response.status}: ${response.statusText}`);
+      throw await responseError(response);
     }
 
     const json = await response.json();
@@ -506,12 +737,14 @@ export const api = {
     service: string,
     lines?: number,
     level?: string,
-    search?: string
+    search?: string,
+    includeRotated?: boolean
   ): Promise<LogsResponse> {
     const params = new URLSearchParams({ service });
     if (lines) params.set('lines', lines.toString());
     if (level) params.set('level', level);
     if (search) params.set('search', search);
+    if (includeRotated) params.set('include_rotated', 'true');
     return fetchAPI<LogsResponse>(`/api/logs/recent?${params.toString()}`);
   },
 
@@ -522,6 +755,15 @@ export const api = {
     return postAPI<LogAnalysis, LogAnalysisRequest>('/api/logs/analyze', request);
   },
 
+  /**
+   * Diagnose a failing service: recent errors, doctor checks and a combined analysis
+   */
+  async diagnoseLogs(service: 'gateway' | 'daemon', model?: string): Promise<LogDiagnosis> {
+    const params = new URLSearchParams({ service });
+    if (model) params.set('model', model);
+    return postAPI<LogDiagnosis>(`/api/logs/diagnose?${params}`, {});
+  },
+
   // Intelligence API methods (Phase 8)
 
   /**
@@ -562,6 +804,13 @@ export const api = {
     return postAPI<ServerTarget, ServerTarget>('/api/multi-server/servers', server);
   },
 
+  /**
+   * Add servers from the Host entries in ~/.ssh/config
+   */
+  async importSshConfig(): Promise<SshConfigImportResponse> {
+    return postAPI<SshConfigImportResponse>('/api/multi-server/import-ssh-config', {});
+  },
+
   /**
    * Remove a server target by ID
    */
@@ -580,15 +829,30 @@ export const api = {
    * Rollback a deployed server
    */
   async rollbackServer(id: string): Promise<ServerDeployResult> {
-    return postAPI<ServerDeployResult>(`/api/multi-server/rollback/${id}`, {});
+    return postAPI<ServerDeployResult>(
+      `/api/multi-server/rollback/${id}`,
+      {},
+      await confirmHeaders('RollbackServer', id),
+    );
+  },
+
+  /**
+   * Token confirming the OpenClaw uninstall, sent in the "start-uninstall"
+   * WebSocket payload (WebSockets can't carry the X-Confirm-Token header)
+   */
+  async confirmUninstall(): Promise<string> {
+    return confirmToken('UninstallOpenClaw');
   },
 
   /**
    * Rollback local installation (stop daemon, remove config, uninstall)
    */
   async rollbackInstallation(): Promise<RollbackResult> {
-    const response = await fetch('/api/wizard/rollback', { method: 'POST' });
-    if (!response.ok) throw new Error('Rollback failed');
+    const response = await fetch('/api/wizard/rollback', {
+      method: 'POST',
+      headers: await confirmHeaders('RollbackInstallation'),
+    });
+    if (!response.ok) throw await responseError(response);
     return response.json();
   },
 };
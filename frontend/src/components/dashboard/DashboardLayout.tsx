@@ -167,7 +167,8 @@ export function DashboardLayout({ onBackToWizard }: DashboardLayoutProps) {
                     status={services?.gateway?.running ? 'ok' : 'neutral'}
                     subtitle={
                       services?.gateway?.running
-                        ? `PID: ${services.gateway.pid ?? 'N/A'} | ${formatUptime(services.gateway.uptime_seconds)} | ${formatMemory(services.gateway.memory_mb)}`
+                        ? (services.gateway.note ??
+                          `PID: ${services.gateway.pid ?? 'N/A'} | ${formatUptime(services.gateway.uptime_seconds)} | ${formatMemory(services.gateway.memory_mb)}`)
                         : 'Not running'
                     }
                   />
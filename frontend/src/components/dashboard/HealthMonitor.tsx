@@ -62,6 +62,16 @@ export function HealthMonitor({ health, gatewayRunning, onRefresh }: HealthMonit
                 </p>
               </div>
             </>
+          ) : health.gateway_status === 'unauthorized' ? (
+            <>
+              <AlertTriangle className="w-8 h-8 text-yellow-600 dark:text-yellow-500" />
+              <div>
+                <p className="text-sm font-medium text-gray-900 dark:text-gray-100">Gateway Rejected Credentials</p>
+                <p className="text-xs text-gray-600 dark:text-gray-400">
+                  {health.gateway_detail ?? `The gateway is running with ${health.auth_mode} auth but the health check was not authorized.`}
+                </p>
+              </div>
+            </>
           ) : (
             <>
               <XCircle className="w-8 h-8 text-red-600 dark:text-red-500" />
@@ -76,6 +86,15 @@ export function HealthMonitor({ health, gatewayRunning, onRefresh }: HealthMonit
             </>
           )}
         </div>
+        {health.port_mismatch && (
+          <div className="mt-4 flex items-start gap-2 rounded-md border border-yellow-200 dark:border-yellow-800 bg-yellow-50 dark:bg-yellow-900/20 p-3">
+            <AlertTriangle className="w-4 h-4 mt-0.5 shrink-0 text-yellow-600 dark:text-yellow-500" />
+            <p className="text-xs text-yellow-800 dark:text-yellow-300">
+              <span className="font-medium">Restart required. </span>
+              {health.port_mismatch}
+            </p>
+          </div>
+        )}
       </div>
 
       {/* Recent Errors Section */}
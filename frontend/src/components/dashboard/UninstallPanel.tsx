@@ -1,6 +1,7 @@
 import { useState, useRef, useCallback, useEffect } from 'react';
 import { AlertTriangle } from 'lucide-react';
 import { StreamingOutput } from '../ui/StreamingOutput';
+import { api } from '../../api/client';
 
 type UninstallStatus = 'idle' | 'confirming' | 'uninstalling' | 'completed' | 'failed';
 
@@ -13,13 +14,22 @@ export function UninstallPanel() {
   const [error, setError] = useState<string | null>(null);
   const wsRef = useRef<WebSocket | null>(null);
 
-  const startUninstall = useCallback(() => {
+  const startUninstall = useCallback(async () => {
     setStatus('uninstalling');
     setOutput([]);
     setError(null);
     setCurrentMessage('Starting uninstall...');
     setProgressPct(0);
 
+    let confirmToken: string;
+    try {
+      confirmToken = await api.confirmUninstall();
+    } catch (e) {
+      setError(e instanceof Error ? e.message : 'Could not confirm uninstall');
+      setStatus('failed');
+      return;
+    }
+
     const protocol = window.location.protocol === 'https:' ? 'wss:' : 'ws:';
     const ws = new WebSocket(`${protocol}//${window.location.host}/ws`);
     wsRef.current = ws;
@@ -27,7 +37,7 @@ export function UninstallPanel() {
     ws.onopen = () => {
       ws.send(JSON.stringify({
         msg_type: 'start-uninstall',
-        payload: {},
+        payload: { confirm_token: confirmToken },
       }));
     };
 
@@ -66,7 +66,13 @@ export function ConfigureStep() {
   // Auto-generate token for gateway
   useEffect(() => {
     if (gatewayAuthMode === 'token' && !gatewayAuthCredential) {
-      gatewayForm.setValue('authCredential', crypto.randomUUID());
+      api.generateCredential()
+        .catch(() => crypto.randomUUID())
+        .then(credential => {
+          if (!gatewayForm.getValues('authCredential')) {
+            gatewayForm.setValue('authCredential', credential);
+          }
+        });
     }
   }, [gatewayAuthMode, gatewayAuthCredential, gatewayForm]);
 
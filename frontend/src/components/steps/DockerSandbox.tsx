@@ -42,6 +42,7 @@ export function DockerSandbox() {
     closeLogs,
   } = useDockerSandbox();
   const [containerName, setContainerName] = useState('openclaw-sandbox');
+  const maxContainers = status?.max_containers ?? 5;
 
   // ---------- Rendered States ----------
 
@@ -191,7 +192,7 @@ export function DockerSandbox() {
           <div className="flex items-center gap-3 p-3 bg-emerald-950/30 border border-emerald-700/50 rounded-lg">
             <Check className="w-5 h-5 text-emerald-400" />
             <span className="text-emerald-300 text-sm font-medium">
-              Docker{status?.version ? ` v${status.version}` : ''} detected
+              Docker{status?.version ? ` v${status.version}` : ''}{status?.api_version ? ` (API ${status.api_version})` : ''} detected
             </span>
           </div>
         )}
@@ -259,7 +260,8 @@ export function DockerSandbox() {
             onCloseLogs={closeLogs}
             onRefresh={() => {}}
             onCreateAnother={() => createSandbox(containerName)}
-            canCreateMore={containers.length < 5}
+            maxContainers={maxContainers}
+            canCreateMore={containers.length < maxContainers}
           />
         )}
 
@@ -304,6 +306,7 @@ function ContainerManagementPanel({
   onViewLogs,
   onCloseLogs,
   onCreateAnother,
+  maxContainers,
   canCreateMore,
 }: {
   containers: ContainerInfo[];
@@ -315,6 +318,7 @@ function ContainerManagementPanel({
   onCloseLogs: () => void;
   onRefresh: () => void;
   onCreateAnother: () => void;
+  maxContainers: number;
   canCreateMore: boolean;
 }) {
   return (
@@ -323,7 +327,7 @@ function ContainerManagementPanel({
       <div className="flex items-center justify-between">
         <h3 className="text-zinc-100 font-medium">Your Containers</h3>
         <span className="text-xs text-zinc-400 bg-zinc-800 px-2 py-1 rounded">
-          {containers.length}/5
+          {containers.length}/{maxContainers}
         </span>
       </div>
 
@@ -331,7 +335,7 @@ function ContainerManagementPanel({
       <div className="w-full bg-zinc-800 rounded-full h-1.5">
         <div
           className="bg-sky-400/60 h-1.5 rounded-full transition-all"
-          style={{ width: `${(containers.length / 5) * 100}%` }}
+          style={{ width: `${Math.min(containers.length / maxContainers, 1) * 100}%` }}
         />
       </div>
 
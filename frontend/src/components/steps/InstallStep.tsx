@@ -6,10 +6,20 @@ import { ErrorRecovery } from '../ui/ErrorRecovery';
 import { useStreamingOutput } from '../../hooks/useStreamingOutput';
 import { api } from '../../api/client';
 import type { WizardConfig } from '../../types/WizardConfig';
+import type { InstallPlanStage } from '../../types/InstallPlanStage';
 import clsx from 'clsx';
 
 type InstallPhase = 'review' | 'installing' | 'complete';
 
+const INSTALL_REQUEST = { install_node: true, install_openclaw: true };
+
+// Shown until the server's install plan arrives
+const DEFAULT_PLAN: InstallPlanStage[] = [
+  { id: 'node-install', label: 'Install Node.js' },
+  { id: 'openclaw-install', label: 'Install OpenClaw' },
+  { id: 'verify', label: 'Verify and start the gateway' },
+];
+
 interface InstallStepProps {
   onGoToDashboard?: () => void;
 }
@@ -21,6 +31,7 @@ export function InstallStep({ onGoToDashboard }: InstallStepProps) {
   const [phase, setPhase] = useState<InstallPhase>('review');
   const [saveError, setSaveError] = useState<string | null>(null);
   const [isSaving, setIsSaving] = useState(false);
+  const [plan, setPlan] = useState<InstallPlanStage[]>(DEFAULT_PLAN);
 
   const { providerConfig, channelsConfig } = formData;
   const hasRemoteCredentials = !!formData.sshCredentials?.host;
@@ -87,11 +98,10 @@ export function InstallStep({ onGoToDashboard }: InstallStepProps) {
       // Transition to installing
       setPhase('installing');
 
+      api.getInstallPlan(INSTALL_REQUEST).then((p) => setPlan(p.stages)).catch(() => {});
+
       // Start installation
-      startInstall({
-        install_node: true,
-        install_openclaw: true,
-      });
+      startInstall(INSTALL_REQUEST);
     } catch (err) {
       setSaveError(err instanceof Error ? err.message : 'Failed to save configuration');
     } finally {
@@ -100,14 +110,12 @@ export function InstallStep({ onGoToDashboard }: InstallStepProps) {
   };
 
   const handleRetry = () => {
-    startInstall({
-      install_node: true,
-      install_openclaw: true,
-    });
+    startInstall(INSTALL_REQUEST);
   };
 
-  // Auto-transition to complete only when the final 'verify' stage completes
-  if (phase === 'installing' && status === 'completed' && currentStage === 'verify') {
+  // Auto-transition to complete only when the plan's final stage completes
+  const finalStage = plan[plan.length - 1]?.id;
+  if (phase === 'installing' && status === 'completed' && currentStage === finalStage) {
     // Use setTimeout to avoid state update during render
     setTimeout(() => setPhase('complete'), 0);
   }
@@ -301,14 +309,13 @@ export function InstallStep({ onGoToDashboard }: InstallStepProps) {
         <div className="space-y-6">
           {/* Stage indicators */}
           <div className="flex items-center gap-4 justify-center">
-            {['Prepare', 'Install', 'Verify'].map((label, i) => {
-              const stages = ['node-install', 'openclaw-install', 'verify'];
-              const stageIndex = stages.indexOf(currentStage || '');
+            {plan.map(({ id, label }, i) => {
+              const stageIndex = plan.findIndex((stage) => stage.id === currentStage);
               const isActive = stageIndex === i;
               const isDone = stageIndex > i || status === 'completed';
 
               return (
-                <div key={label} className="flex items-center gap-2">
+                <div key={id} className="flex items-center gap-2">
                   {isDone ? (
                     <svg className="h-5 w-5 text-green-500" fill="currentColor" viewBox="0 0 20 20">
                       <path fillRule="evenodd" d="M10 18a8 8 0 100-16 8 8 0 000 16zm3.707-9.293a1 1 0 00-1.414-1.414L9 10.586 7.707 9.293a1 1 0 00-1.414 1.414l2 2a1 1 0 001.414 0l4-4z" clipRule="evenodd" />
@@ -325,7 +332,7 @@ export function InstallStep({ onGoToDashboard }: InstallStepProps) {
                   })}>
                     {label}
                   </span>
-                  {i < 2 && <div className={clsx('w-8 h-0.5', isDone ? 'bg-green-400' : 'bg-gray-300 dark:bg-zinc-600')} />}
+                  {i < plan.length - 1 && <div className={clsx('w-8 h-0.5', isDone ? 'bg-green-400' : 'bg-gray-300 dark:bg-zinc-600')} />}
                 </div>
               );
             })}
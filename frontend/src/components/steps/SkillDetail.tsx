@@ -20,6 +20,7 @@ import {
 } from 'lucide-react';
 import type { SkillMetadata } from '../../types/SkillMetadata';
 import type { ScanResult } from '../../types/ScanResult';
+import type { EngineDetection } from '../../types/EngineDetection';
 
 interface SkillDetailProps {
   skill: SkillMetadata;
@@ -43,6 +44,25 @@ const CAPABILITY_CONFIG: Record<string, { icon: typeof Globe; label: string; war
   docker: { icon: Container, label: 'Docker Access', warning: true },
 };
 
+/**
+ * Engines that flagged the package, so users can spot known false positives
+ */
+function DetectionList({ detections }: { detections: EngineDetection[] }) {
+  if (detections.length === 0) return null;
+  return (
+    <ul className="mt-2 space-y-0.5">
+      {detections.map((d) => (
+        <li key={d.engine} className="text-xs text-zinc-400">
+          <span className={d.category === 'malicious' ? 'text-red-400' : 'text-amber-400'}>
+            {d.engine}
+          </span>
+          {d.result ? `: ${d.result}` : ` (${d.category})`}
+        </li>
+      ))}
+    </ul>
+  );
+}
+
 /**
  * VirusTotal scan results display section.
  * Shows 4 states: clean, suspicious, malicious, not-configured, or not-yet-scanned.
@@ -71,7 +91,7 @@ function VirusTotalSection({
   }
 
   if (scanResult) {
-    const { threat_level, malicious_count, suspicious_count, total_scanners, scan_date, permalink } = scanResult;
+    const { threat_level, malicious_count, suspicious_count, total_scanners, scan_date, permalink, detections } = scanResult;
 
     if (threat_level === 'Clean') {
       return (
@@ -113,6 +133,7 @@ function VirusTotalSection({
                 {malicious_count} malicious / {suspicious_count} suspicious / {total_scanners} total scanners
               </p>
               <p className="text-zinc-400 text-xs mt-0.5">Review before installing.</p>
+              <DetectionList detections={detections ?? []} />
               <p className="text-zinc-500 text-xs mt-0.5">
                 Scanned: {new Date(scan_date).toLocaleDateString()}
               </p>
@@ -143,6 +164,7 @@ function VirusTotalSection({
                 {malicious_count} malicious / {suspicious_count} suspicious / {total_scanners} total scanners
               </p>
               <p className="text-red-400/70 text-xs mt-0.5">This skill cannot be installed.</p>
+              <DetectionList detections={detections ?? []} />
               <p className="text-zinc-500 text-xs mt-0.5">
                 Scanned: {new Date(scan_date).toLocaleDateString()}
               </p>
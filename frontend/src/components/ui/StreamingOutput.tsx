@@ -1,7 +1,20 @@
 import { useEffect, useRef } from 'react';
 
+/** An output line tagged with its severity ("info", "warning" or "error") */
+export interface OutputLine {
+  text: string;
+  level?: string | null;
+}
+
+/** Terminal color for a line's severity */
+function levelClass(level?: string | null): string {
+  if (level === 'error') return 'text-red-400';
+  if (level === 'warning') return 'text-yellow-400';
+  return '';
+}
+
 interface StreamingOutputProps {
-  output: string[];
+  output: Array<string | OutputLine>;
   maxLines?: number;
   stage?: string;
   message?: string;
@@ -77,11 +90,14 @@ export function StreamingOutput({
           </div>
         ) : (
           <>
-            {displayedOutput.map((line, index) => (
-              <div key={index} className="whitespace-pre-wrap break-words">
-                {line}
-              </div>
-            ))}
+            {displayedOutput.map((line, index) => {
+              const { text, level } = typeof line === 'string' ? { text: line, level: null } : line;
+              return (
+                <div key={index} className={`whitespace-pre-wrap break-words ${levelClass(level)}`}>
+                  {text}
+                </div>
+              );
+            })}
             {/* Show current activity as a dimmed status line */}
             {message && (
               <div className="text-gray-600 mt-1 animate-pulse">
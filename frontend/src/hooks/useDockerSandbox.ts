@@ -106,6 +106,24 @@ export function useDockerSandbox() {
     checkDocker();
   }, [checkDocker]);
 
+  // Refresh when a container changes state, including outside the wizard
+  useEffect(() => {
+    if (!status?.available) return;
+    const protocol = window.location.protocol === 'https:' ? 'wss:' : 'ws:';
+    const ws = new WebSocket(`${protocol}//${window.location.host}/ws/docker/events`);
+    ws.onmessage = (e) => {
+      try {
+        const msg = JSON.parse(e.data);
+        if (msg.type === 'container_event') {
+          refreshContainers();
+        }
+      } catch {
+        // Ignore malformed messages
+      }
+    };
+    return () => ws.close();
+  }, [status?.available, refreshContainers]);
+
   return {
     status,
     containers,
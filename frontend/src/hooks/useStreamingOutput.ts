@@ -3,11 +3,12 @@ import { WebSocketClient } from '../api/websocket';
 import type { InstallRequest } from '../types/InstallRequest';
 import type { InstallProgress } from '../types/InstallProgress';
 import type { WsMessage } from '../types/WsMessage';
+import type { OutputLine } from '../components/ui/StreamingOutput';
 
 type InstallStatus = 'idle' | 'running' | 'completed' | 'failed';
 
 interface StreamingOutputState {
-  output: string[];
+  output: OutputLine[];
   currentStage: string;
   currentMessage: string;
   status: InstallStatus;
@@ -17,11 +18,19 @@ interface StreamingOutputState {
   startInstall: (request: InstallRequest) => void;
 }
 
+/** Stage duration as "4m 05s" or "12.3s" */
+function formatElapsed(ms: number): string {
+  const secs = ms / 1000;
+  if (secs < 60) return `${secs.toFixed(1)}s`;
+  const whole = Math.floor(secs);
+  return `${Math.floor(whole / 60)}m ${String(whole % 60).padStart(2, '0')}s`;
+}
+
 /**
  * Hook for streaming installation output via WebSocket
  */
 export function useStreamingOutput(): StreamingOutputState {
-  const [output, setOutput] = useState<string[]>([]);
+  const [output, setOutput] = useState<OutputLine[]>([]);
   const [currentStage, setCurrentStage] = useState<string>('');
   const [currentMessage, setCurrentMessage] = useState<string>('');
   const [status, setStatus] = useState<InstallStatus>('idle');
@@ -54,11 +63,20 @@ export function useStreamingOutput(): StreamingOutputState {
           setStatus(progress.status as InstallStatus);
           setProgressPct(progress.progress_pct ?? null);
 
-          // Add output line if present
+          // Add output line if present, plus a timing line when a stage ends
+          const lines: OutputLine[] = [];
           if (progress.output_line) {
+            lines.push({ text: progress.output_line, level: progress.line_level });
+          }
+          if (progress.elapsed_ms != null) {
+            lines.push({
+              text: `[${progress.stage}] ${progress.status} in ${formatElapsed(progress.elapsed_ms)}`,
+            });
+          }
+          if (lines.length > 0) {
             setOutput((prev) => {
               // Limit to last 500 lines to prevent memory issues
-              const newOutput = [...prev, progress.output_line!];
+              const newOutput = [...prev, ...lines];
               return newOutput.slice(-500);
             });
           }
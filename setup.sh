@@ -60,6 +60,6 @@ echo "  🚀 Launching on http://127.0.0.1:${PORT}"
 echo "     Press Ctrl+C to stop"
 echo ""
 
-# Run from backend/ dir so ServeDir::new("static") resolves correctly
+# Run from backend/ dir so the static/ fallback in the working directory resolves
 cd "$SCRIPT_DIR/backend"
 exec target/release/openclaw-wizard --port "$PORT" $NO_OPEN